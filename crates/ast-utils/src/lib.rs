@@ -6,6 +6,7 @@ pub mod assignment;
 pub mod condition;
 pub mod control_flow;
 pub mod definition;
+pub mod member;
 pub mod reference;
 
 #[inline]
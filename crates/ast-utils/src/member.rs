@@ -0,0 +1,75 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+
+/// The category of a class-like member, declared in canonical member order.
+///
+/// Members are ordered as: trait uses, constants, enum cases, properties, the constructor,
+/// then public, protected, and private methods.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ClassLikeMemberCategory {
+    TraitUse,
+    Constant,
+    EnumCase,
+    Property,
+    Constructor,
+    PublicMethod,
+    ProtectedMethod,
+    PrivateMethod,
+}
+
+impl ClassLikeMemberCategory {
+    /// Returns a human-readable description of the category, in plural form.
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Self::TraitUse => "trait uses",
+            Self::Constant => "constants",
+            Self::EnumCase => "enum cases",
+            Self::Property => "properties",
+            Self::Constructor => "the constructor",
+            Self::PublicMethod => "public methods",
+            Self::ProtectedMethod => "protected methods",
+            Self::PrivateMethod => "private methods",
+        }
+    }
+}
+
+/// Determine the category of a class-like member.
+///
+/// Methods without a visibility modifier are considered public.
+#[inline]
+pub fn get_class_like_member_category(
+    interner: &ThreadedInterner,
+    member: &ClassLikeMember,
+) -> ClassLikeMemberCategory {
+    match member {
+        ClassLikeMember::TraitUse(_) => ClassLikeMemberCategory::TraitUse,
+        ClassLikeMember::Constant(_) => ClassLikeMemberCategory::Constant,
+        ClassLikeMember::EnumCase(_) => ClassLikeMemberCategory::EnumCase,
+        ClassLikeMember::Property(_) => ClassLikeMemberCategory::Property,
+        ClassLikeMember::Method(method) => {
+            if interner.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+                ClassLikeMemberCategory::Constructor
+            } else if method.modifiers.contains_private() {
+                ClassLikeMemberCategory::PrivateMethod
+            } else if method.modifiers.contains_protected() {
+                ClassLikeMemberCategory::ProtectedMethod
+            } else {
+                ClassLikeMemberCategory::PublicMethod
+            }
+        }
+    }
+}
+
+/// Returns the indices of the given members, sorted in canonical member order.
+///
+/// The sort is stable, so members of the same category keep their relative order.
+#[inline]
+pub fn get_sorted_class_like_member_indices(
+    interner: &ThreadedInterner,
+    members: &Sequence<ClassLikeMember>,
+) -> Vec<usize> {
+    let mut indices = (0..members.len()).collect::<Vec<_>>();
+    indices.sort_by_key(|&index| get_class_like_member_category(interner, &members.as_slice()[index]));
+
+    indices
+}
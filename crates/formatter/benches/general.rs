@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+
+use mago_formatter::format;
+use mago_formatter::settings::FormatSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+/// The directories, relative to the root of the workspace, holding the real-world code to format.
+const CORPORA: [&str; 4] = ["composer", "stubs/Core", "stubs/Reflection", "stubs/standard"];
+
+/// Formats each of the given files, parsing them once beforehand so that only the formatter is measured.
+fn bench_format_sources(c: &mut Criterion, group_name: &str, files: Vec<(String, String)>) {
+    let mut group = c.benchmark_group(group_name);
+    group.sample_size(10);
+
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    for (name, content) in files {
+        let size = content.len();
+        let source_id = manager.insert_content(name.clone(), content, SourceCategory::default());
+        let source = manager.load(&source_id).expect("Failed to load source");
+        let (program, error) = parse_source(&interner, &source);
+        assert_eq!(error, None, "Error parsing `{}`", name);
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_function(BenchmarkId::from_parameter(&name), |b| {
+            b.iter(|| black_box(format(&interner, &source, &program, FormatSettings::default())));
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_corpora(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+
+    let files = CORPORA
+        .iter()
+        .map(|corpus| {
+            let mut paths = vec![];
+            collect_php_files(&root.join(corpus), &mut paths);
+
+            let content = paths
+                .iter()
+                .map(|path| fs::read_to_string(path).expect("Failed to read file"))
+                .map(|content| content.trim_start_matches("<?php").to_string())
+                .collect::<String>();
+
+            (corpus.to_string(), format!("<?php\n{}", content))
+        })
+        .collect();
+
+    bench_format_sources(c, "format::corpus", files);
+}
+
+fn bench_generated_arrays(c: &mut Criterion) {
+    let files = [1_000, 10_000, 30_000]
+        .into_iter()
+        .map(|lines| {
+            let mut content = String::from("<?php\n\nreturn [\n");
+            for i in 0..lines {
+                content.push_str(&format!(
+                    "    'key_{}' => ['name' => 'Value {}', 'enabled' => true, 'weight' => {}], // entry {}\n",
+                    i, i, i, i
+                ));
+            }
+            content.push_str("];\n");
+
+            (format!("{} lines", lines), content)
+        })
+        .collect();
+
+    bench_format_sources(c, "format::generated_array", files);
+}
+
+fn bench_nested_arrays(c: &mut Criterion) {
+    let files = [4, 16, 64]
+        .into_iter()
+        .map(|depth| {
+            let mut value = String::from("'leaf'");
+            for level in 0..depth {
+                value =
+                    format!("['level_{}' => {}, 'siblings' => [1, 2, 3], 'value' => {}]", level, value, value.len());
+            }
+
+            let content = (0..100).map(|i| format!("$config_{} = {};\n", i, value)).collect::<String>();
+
+            (format!("depth {}", depth), format!("<?php\n\n{}", content))
+        })
+        .collect();
+
+    bench_format_sources(c, "format::nested_array", files);
+}
+
+fn collect_php_files(directory: &Path, paths: &mut Vec<PathBuf>) {
+    let mut entries = fs::read_dir(directory).expect("Failed to read directory").flatten().collect::<Vec<_>>();
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_php_files(&path, paths);
+        } else if path.extension().is_some_and(|extension| extension == "php") {
+            paths.push(path);
+        }
+    }
+}
+
+criterion_group!(formatter, bench_corpora, bench_generated_arrays, bench_nested_arrays);
+
+criterion_main!(formatter);
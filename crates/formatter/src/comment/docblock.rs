@@ -0,0 +1,481 @@
+use mago_docblock::document::Element;
+use mago_docblock::parse_phpdoc_with_span;
+use mago_span::Position;
+use mago_span::Span;
+
+use crate::comment::Comment;
+use crate::document::Document;
+use crate::document::Fill;
+use crate::document::Group;
+use crate::document::IfBreak;
+use crate::document::Line;
+use crate::Formatter;
+
+/// Tags whose description starts with a type, possibly prefixed with `psalm-` or `phpstan-`.
+const TYPED_TAGS: [&str; 18] = [
+    "param",
+    "param-out",
+    "return",
+    "var",
+    "throws",
+    "property",
+    "property-read",
+    "property-write",
+    "assert",
+    "assert-if-true",
+    "assert-if-false",
+    "self-out",
+    "this-out",
+    "extends",
+    "implements",
+    "use",
+    "mixin",
+    "type",
+];
+
+#[derive(Debug)]
+enum Block<'a> {
+    Empty,
+    Raw(Vec<&'a str>),
+    Text(Vec<&'a str>),
+    Tag(String, String),
+}
+
+impl<'a> Formatter<'a> {
+    /// Prints a multi-line docblock according to the docblock settings.
+    ///
+    /// Returns `None` if none of the docblock settings are enabled, or if the docblock could not be parsed,
+    /// in which case the docblock is printed as a regular block comment.
+    pub(crate) fn print_docblock(&self, comment: Comment) -> Option<Document<'a>> {
+        if !self.settings.docblock_wrap_descriptions
+            && !self.settings.docblock_align_params
+            && !self.settings.docblock_sort_tags
+            && !self.settings.docblock_normalize_types
+        {
+            return None;
+        }
+
+        let content = &self.source_text[comment.start..comment.end];
+        if !content.starts_with("/**") || !content.contains('\n') {
+            return None;
+        }
+
+        // Docblocks with an unusual closing line, e.g. `**/`, or `*/` following the content, are left untouched.
+        if content.lines().last().map(str::trim) != Some("*/") {
+            return None;
+        }
+
+        // Lines missing their `*` prefix are fixed first, as the docblock parser would reject them.
+        let content = self.as_str(
+            content
+                .lines()
+                .enumerate()
+                .map(|(i, line)| match line.trim() {
+                    _ if i == 0 => line.to_string(),
+                    "" => " *".to_string(),
+                    trimmed if trimmed.starts_with('*') => format!(" {}", trimmed),
+                    trimmed => format!(" * {}", trimmed),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+
+        let span =
+            Span::new(Position::new(self.source.identifier, 0), Position::new(self.source.identifier, content.len()));
+        let document = parse_phpdoc_with_span(self.interner, content, span).ok()?;
+
+        let mut blocks = vec![];
+        let mut previous_end = None;
+        for element in document.elements.iter() {
+            let span = match element {
+                Element::Line(span) => *span,
+                Element::Text(text) => text.span,
+                Element::Code(code) => code.span,
+                Element::Annotation(annotation) => annotation.span,
+                Element::Tag(tag) => tag.span,
+            };
+            if let Some(previous_end) = previous_end {
+                // Blank lines that are not part of any element, e.g. after indented code, are preserved.
+                let newlines = content[previous_end..span.start.offset].matches('\n').count();
+                blocks.extend((1..newlines).map(|_| Block::Empty));
+            }
+
+            previous_end = Some(span.start.offset + content[span.start.offset..span.end.offset].trim_end().len());
+            blocks.push(match element {
+                Element::Line(_) => Block::Empty,
+                Element::Text(text) => Block::Text(get_docblock_lines(content, text.span)),
+                Element::Code(code) => Block::Raw(get_docblock_lines(content, code.span)),
+                Element::Annotation(annotation) => Block::Raw(get_docblock_lines(content, annotation.span)),
+                Element::Tag(tag) => {
+                    let name = self.lookup(&tag.name).to_string();
+                    let mut description = self.lookup(&tag.description).to_string();
+                    if self.settings.docblock_normalize_types && is_typed_tag(&name) {
+                        if let Some((r#type, rest)) = split_type(&description) {
+                            description = format!("{}{}", normalize_type(r#type), rest);
+                        }
+                    }
+
+                    Block::Tag(name, description)
+                }
+            });
+        }
+
+        if self.settings.docblock_sort_tags {
+            sort_tags(&mut blocks);
+        }
+
+        let mut lines = vec![];
+        let mut i = 0;
+        while i < blocks.len() {
+            match &blocks[i] {
+                Block::Empty => lines.push(Document::String(" *")),
+                Block::Raw(raw) => lines.extend(raw.iter().map(|line| self.print_docblock_line(line))),
+                Block::Text(text) if self.settings.docblock_wrap_descriptions => {
+                    lines.extend(self.print_wrapped_docblock_text(text))
+                }
+                Block::Text(text) => lines.extend(text.iter().map(|line| self.print_docblock_line(line))),
+                Block::Tag(_, _) if self.settings.docblock_align_params => {
+                    let length = blocks[i..].iter().take_while(|block| get_aligned_param(block).is_some()).count();
+                    if length > 0 {
+                        lines.extend(
+                            align_params(&blocks[i..i + length]).iter().map(|line| self.print_docblock_line(line)),
+                        );
+                        i += length;
+
+                        continue;
+                    }
+
+                    lines.extend(print_tag(&blocks[i]).iter().map(|line| self.print_docblock_line(line)));
+                }
+                Block::Tag(_, _) => {
+                    lines.extend(print_tag(&blocks[i]).iter().map(|line| self.print_docblock_line(line)))
+                }
+            }
+
+            i += 1;
+        }
+
+        let mut contents = vec![Document::String("/**")];
+        for line in lines {
+            contents.push(Document::Line(Line::hardline()));
+            contents.push(line);
+        }
+
+        contents.push(Document::Line(Line::hardline()));
+        contents.push(Document::String(" */"));
+
+        Some(Document::Group(Group::new(contents)))
+    }
+
+    fn print_docblock_line(&self, line: &str) -> Document<'a> {
+        if line.is_empty() {
+            Document::String(" *")
+        } else {
+            Document::String(self.as_str(format!(" * {}", line)))
+        }
+    }
+
+    /// Prints the given text re-wrapped at the print width, keeping inline code and inline tags on a single line.
+    ///
+    /// The text is wrapped by the printer, so the width accounts for the indentation of the docblock in the
+    /// formatted code. List items, headings, tables, and quotes start a new paragraph, and the continuation
+    /// lines of list items are indented to align with their content.
+    fn print_wrapped_docblock_text(&self, lines: &[&str]) -> Vec<Document<'a>> {
+        let mut paragraphs: Vec<(String, usize, bool)> = vec![];
+        for line in lines {
+            let is_verbatim = line.starts_with(['#', '|', '>']);
+            let marker = get_list_marker(line);
+            let continues_paragraph = marker.is_none() && !is_verbatim;
+
+            match paragraphs.last_mut() {
+                Some((text, _, false)) if continues_paragraph => {
+                    text.push(' ');
+                    text.push_str(line);
+                }
+                _ => {
+                    paragraphs.push((line.to_string(), marker.map(|marker| marker.len() + 1).unwrap_or(0), is_verbatim))
+                }
+            }
+        }
+
+        paragraphs
+            .into_iter()
+            .map(|(text, hanging_indent, is_verbatim)| {
+                if is_verbatim {
+                    return self.print_docblock_line(&text);
+                }
+
+                let separator = self.as_str(format!(" * {}", " ".repeat(hanging_indent)));
+                let mut parts = vec![];
+                for word in get_words(&text) {
+                    if !parts.is_empty() {
+                        parts.push(Document::Array(vec![
+                            Document::Line(Line::default()),
+                            Document::IfBreak(IfBreak::then(Document::String(separator))),
+                        ]));
+                    }
+
+                    parts.push(Document::String(self.as_str(word)));
+                }
+
+                Document::Array(vec![Document::String(" * "), Document::Fill(Fill { parts })])
+            })
+            .collect()
+    }
+}
+
+/// Returns the lines of the docblock within the given span, without their ` * ` prefix.
+fn get_docblock_lines(content: &str, span: Span) -> Vec<&str> {
+    content[span.start.offset..span.end.offset]
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                return line.trim_end();
+            }
+
+            let line = line.trim_start();
+            let line = line.strip_prefix('*').unwrap_or(line);
+
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect()
+}
+
+fn is_typed_tag(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    let name = name.strip_prefix("psalm-").or_else(|| name.strip_prefix("phpstan-")).unwrap_or(&name);
+
+    TYPED_TAGS.contains(&name)
+}
+
+/// Splits the description of a tag into its leading type and the rest of the description.
+fn split_type(description: &str) -> Option<(&str, &str)> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut previous = None;
+    let chars = description.char_indices().collect::<Vec<_>>();
+
+    for (index, &(i, c)) in chars.iter().enumerate() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '<' | '(' | '{' | '[') => depth += 1,
+            (None, '>' | ')' | '}' | ']') => depth = depth.saturating_sub(1),
+            (None, _) if c.is_whitespace() && depth == 0 => {
+                let next = chars[index..].iter().map(|(_, c)| *c).find(|c| !c.is_whitespace());
+                let continues = matches!(previous, Some(':' | '|' | '&')) || matches!(next, Some(':' | '|' | '&'));
+                if !continues {
+                    return if i == 0 { None } else { Some((&description[..i], &description[i..])) };
+                }
+            }
+            _ => {}
+        }
+
+        if !c.is_whitespace() {
+            previous = Some(c);
+        }
+    }
+
+    if description.is_empty() || depth != 0 {
+        None
+    } else {
+        Some((description, ""))
+    }
+}
+
+/// Normalizes the spacing of a type, e.g. `array<int,string>` becomes `array<int, string>`.
+fn normalize_type(r#type: &str) -> String {
+    let mut result = String::with_capacity(r#type.len());
+    let mut quote = None;
+    let mut chars = r#type.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, ',') => {
+                while result.ends_with([' ', '\t']) {
+                    result.pop();
+                }
+
+                result.push(',');
+                while chars.next_if(|c| *c == ' ' || *c == '\t').is_some() {}
+                if chars.peek().is_some_and(|c| !matches!(c, '\n' | '>' | ')' | '}' | ']')) {
+                    result.push(' ');
+                }
+
+                continue;
+            }
+            _ => {}
+        }
+
+        result.push(c);
+    }
+
+    result
+}
+
+/// Returns the rank of a tag in the canonical order, or `None` if the tag is not ordered.
+fn get_tag_rank(name: &str) -> Option<usize> {
+    let name = name.to_ascii_lowercase();
+    let name = name.strip_prefix("psalm-").or_else(|| name.strip_prefix("phpstan-")).unwrap_or(&name);
+
+    match name {
+        "template" | "template-covariant" | "template-contravariant" => Some(0),
+        "param" => Some(1),
+        "return" => Some(2),
+        "throws" => Some(3),
+        _ => None,
+    }
+}
+
+/// Sorts the ordered tags of each run of consecutive tags in the canonical order, i.e. `@template`,
+/// `@param`, `@return`, then `@throws`, leaving other tags in place.
+fn sort_tags(blocks: &mut [Block<'_>]) {
+    let mut i = 0;
+    while i < blocks.len() {
+        let length = blocks[i..].iter().take_while(|block| matches!(block, Block::Tag(_, _))).count();
+        if length == 0 {
+            i += 1;
+
+            continue;
+        }
+
+        let run = &mut blocks[i..i + length];
+        let positions = run
+            .iter()
+            .enumerate()
+            .filter_map(|(position, block)| match block {
+                Block::Tag(name, _) => get_tag_rank(name).map(|_| position),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut ordered =
+            positions.iter().map(|position| std::mem::replace(&mut run[*position], Block::Empty)).collect::<Vec<_>>();
+
+        ordered.sort_by_key(|block| match block {
+            Block::Tag(name, _) => get_tag_rank(name),
+            _ => None,
+        });
+
+        for (position, block) in positions.into_iter().zip(ordered) {
+            run[position] = block;
+        }
+
+        i += length;
+    }
+}
+
+fn print_tag(block: &Block<'_>) -> Vec<String> {
+    let Block::Tag(name, description) = block else {
+        return vec![];
+    };
+
+    let mut lines = description.lines().map(|line| line.to_string()).collect::<Vec<_>>();
+    match lines.first_mut() {
+        Some(first) if !first.is_empty() => *first = format!("@{} {}", name, first),
+        Some(first) => *first = format!("@{}", name),
+        None => lines.push(format!("@{}", name)),
+    }
+
+    lines
+}
+
+/// Returns the type, the variable, and the description of a single-line `@param` tag.
+fn get_aligned_param<'b>(block: &'b Block<'_>) -> Option<(&'b str, &'b str, &'b str)> {
+    let Block::Tag(name, description) = block else {
+        return None;
+    };
+
+    if name != "param" || description.contains('\n') {
+        return None;
+    }
+
+    let (r#type, rest) = split_type(description)?;
+    if r#type.starts_with(['$', '&', '.']) {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+    let (variable, description) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    if !variable.trim_start_matches(['&', '.']).starts_with('$') {
+        return None;
+    }
+
+    Some((r#type, variable, description.trim()))
+}
+
+/// Aligns the types, variables, and descriptions of consecutive `@param` tags in columns.
+fn align_params(blocks: &[Block<'_>]) -> Vec<String> {
+    let params = blocks.iter().filter_map(get_aligned_param).collect::<Vec<_>>();
+    let type_width = params.iter().map(|(r#type, _, _)| r#type.chars().count()).max().unwrap_or_default();
+    let variable_width = params.iter().map(|(_, variable, _)| variable.chars().count()).max().unwrap_or_default();
+
+    params
+        .into_iter()
+        .map(|(r#type, variable, description)| {
+            format!("@param {:type_width$} {:variable_width$} {}", r#type, variable, description).trim_end().to_string()
+        })
+        .collect()
+}
+
+fn get_list_marker(line: &str) -> Option<&str> {
+    let (marker, _) = line.split_once(' ')?;
+
+    if matches!(marker, "-" | "*" | "+") {
+        return Some(marker);
+    }
+
+    let number = marker.strip_suffix(['.', ')'])?;
+    if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        return Some(marker);
+    }
+
+    None
+}
+
+/// Splits the text into words, keeping inline code and inline tags as single words.
+fn get_words(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let mut closing: Option<String> = None;
+
+    for word in text.split_whitespace() {
+        // Words that would start a list item, a heading, a tag, or the like when wrapped to the start of a line
+        // are kept with the previous word.
+        let starts_block = get_list_marker(&format!("{} ", word)).is_some()
+            || word.starts_with(['#', '|', '>', '@'])
+            || word.starts_with("```");
+
+        if let Some(delimiter) = &closing {
+            let last = words.last_mut().expect("an open word should exist");
+            last.push(' ');
+            last.push_str(word);
+            if word.contains(delimiter.as_str()) {
+                closing = None;
+            }
+
+            continue;
+        }
+
+        let backticks = word.chars().take_while(|c| *c == '`').count();
+        if backticks > 0 && !word[backticks..].contains(&"`".repeat(backticks)) {
+            closing = Some("`".repeat(backticks));
+        } else if word.contains("{@") && !word[word.find("{@").unwrap_or(0)..].contains('}') {
+            closing = Some("}".to_string());
+        }
+
+        match words.last_mut() {
+            Some(last) if starts_block => {
+                last.push(' ');
+                last.push_str(word);
+            }
+            _ => words.push(word.to_string()),
+        }
+    }
+
+    words
+}
@@ -200,6 +200,44 @@ impl<'a> Formatter<'a> {
         comment.with_line_suffix(false)
     }
 
+    /// Prints the own-line comments following the given range that are indented deeper than the line the
+    /// range starts on, e.g. a `// no break` comment at the end of a switch case body.
+    ///
+    /// Each printed comment is preceded by a hard line, so the result can be appended to an indented body.
+    #[must_use]
+    pub(crate) fn print_trailing_indented_comments(&mut self, range: Span) -> Option<Document<'a>> {
+        let column = |offset: usize| offset - self.source_text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let range_column = column(range.start.offset);
+
+        let mut parts = vec![];
+        let mut end = range.end.offset;
+        while let Some(comment) = self.comments.peek() {
+            let comment = Comment::from_trivia(comment);
+            if comment.start < end
+                || !self.source_text[end..comment.start].trim().is_empty()
+                || !self.has_newline(comment.start, /* backwards */ true)
+                || column(comment.start) <= range_column
+            {
+                break;
+            }
+
+            self.comments.next();
+            parts.push(Document::Line(Line::hardline()));
+            if self.is_previous_line_empty(comment.start) {
+                parts.push(Document::Line(Line::hardline()));
+            }
+
+            parts.push(self.print_comment(comment));
+            end = comment.end;
+        }
+
+        if parts.is_empty() {
+            return None;
+        }
+
+        Some(Document::Array(parts))
+    }
+
     #[must_use]
     pub(crate) fn print_inner_comment(&mut self, range: Span) -> Vec<Document<'a>> {
         let mut parts = vec![];
@@ -265,6 +303,10 @@ impl<'a> Formatter<'a> {
             return Document::String(content);
         }
 
+        if let Some(document) = self.print_docblock(comment) {
+            return document;
+        }
+
         let lines = content.lines().collect::<Vec<_>>();
         let mut contents = vec![];
 
@@ -1,7 +1,10 @@
+use std::rc::Rc;
+
 use bitflags::bitflags;
 
 use mago_ast::Trivia;
 
+pub mod docblock;
 pub mod format;
 
 bitflags! {
@@ -17,6 +20,32 @@ bitflags! {
     }
 }
 
+/// An iterator over the comments of a program that are yet to be printed.
+///
+/// The comments are shared between clones, so that looking ahead doesn't copy the remaining comments.
+#[derive(Debug, Clone, Default)]
+pub struct Comments {
+    comments: Rc<[Trivia]>,
+    index: usize,
+}
+
+impl Comments {
+    pub fn new(comments: Vec<Trivia>) -> Self {
+        Self { comments: comments.into(), index: 0 }
+    }
+}
+
+impl Iterator for Comments {
+    type Item = Trivia;
+
+    fn next(&mut self) -> Option<Trivia> {
+        let comment = self.comments.get(self.index).copied()?;
+        self.index += 1;
+
+        Some(comment)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Comment {
     pub start: usize,
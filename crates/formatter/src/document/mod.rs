@@ -13,6 +13,8 @@ pub enum Document<'a> {
     /// Increase the level of indentation.
     Indent(Vec<Document<'a>>),
     IndentIfBreak(IndentIfBreak<'a>),
+    /// Align the contents to a column, starting every line with the given prefix instead of the current indentation.
+    Align(Align<'a>),
     /// Mark a group of items which the printer should try to fit on one line.
     /// This is the basic command to tell the printer when to break.
     /// Groups are usually nested, and the printer will try to fit everything on one line,
@@ -56,6 +58,11 @@ impl Line {
     pub fn hardline() -> Self {
         Self { hard: true, ..Self::default() }
     }
+
+    /// Specify a line break that is always included in the output, and is not followed by any indentation.
+    pub fn literal_hardline() -> Self {
+        Self { hard: true, literal: true, ..Self::default() }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
@@ -103,6 +110,18 @@ impl<'a> IndentIfBreak<'a> {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
+pub struct Align<'a> {
+    pub alignment: &'a str,
+    pub contents: Vec<Document<'a>>,
+}
+
+impl<'a> Align<'a> {
+    pub fn new(alignment: &'a str, contents: Vec<Document<'a>>) -> Self {
+        Self { alignment, contents }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, PartialOrd, Ord)]
 pub struct Fill<'a> {
     pub parts: Vec<Document<'a>>,
@@ -160,6 +179,7 @@ pub enum Separator {
     #[allow(unused)]
     Softline,
     Hardline,
+    LiteralHardline,
     CommaLine, // [",", line]
     Space,
 }
@@ -189,9 +209,9 @@ impl<'a> Document<'a> {
 
         match self {
             Document::Array(docs) | Document::LineSuffix(docs) | Document::Indent(docs) => docs.iter().any(predicate),
-            Document::IndentIfBreak(IndentIfBreak { contents, .. }) | Document::Group(Group { contents, .. }) => {
-                contents.iter().any(predicate)
-            }
+            Document::IndentIfBreak(IndentIfBreak { contents, .. })
+            | Document::Align(Align { contents, .. })
+            | Document::Group(Group { contents, .. }) => contents.iter().any(predicate),
             Document::IfBreak(IfBreak { break_contents, flat_content, .. }) => {
                 predicate(break_contents) || predicate(flat_content)
             }
@@ -208,6 +228,7 @@ impl<'a> Document<'a> {
                     Separator::Space => Document::String(" "),
                     Separator::Softline => Document::Line(Line::softline()),
                     Separator::Hardline => Document::Line(Line::hardline()),
+                    Separator::LiteralHardline => Document::Line(Line::literal_hardline()),
                     Separator::CommaLine => {
                         Document::Array(vec![Document::String(","), Document::Line(Line::default())])
                     }
@@ -249,6 +270,9 @@ fn print_doc_to_debug(doc: &Document) -> String {
                 if options.is_empty() { String::new() } else { format!(", {{ {} }}", options.join(", ")) };
             format!("indentIfBreak({}{})", print_doc_to_debug(&Document::Array(contents.clone())), options_str)
         }
+        Document::Align(Align { alignment, contents }) => {
+            format!("align({:?}, {})", alignment, print_doc_to_debug(&Document::Array(contents.clone())))
+        }
         Document::Group(Group { contents, should_break, expanded_states, id }) => {
             let mut options = vec![];
             if *should_break {
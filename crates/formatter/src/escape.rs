@@ -0,0 +1,224 @@
+/// The kinds of string whose contents are escaped differently.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum Quote {
+    /// A single-quoted string, where only `\\` and `\'` are escape sequences.
+    Single,
+    /// A double-quoted string.
+    Double,
+    /// A heredoc, which supports the escape sequences of double-quoted strings, except for `\"`.
+    Heredoc,
+    /// A nowdoc, which has no escape sequences.
+    Nowdoc,
+}
+
+/// Returns the bytes represented by the contents of a string, resolving its escape sequences.
+///
+/// Numeric escape sequences may produce bytes that are not valid UTF-8, hence the bytes.
+pub(crate) fn unescape(content: &str, quote: Quote) -> Vec<u8> {
+    let mut result = Vec::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' || quote == Quote::Nowdoc {
+            push_char(&mut result, c);
+
+            continue;
+        }
+
+        let Some(&next) = chars.peek() else {
+            result.push(b'\\');
+
+            break;
+        };
+
+        match (quote, next) {
+            (Quote::Single, '\'' | '\\') | (Quote::Double, '"') | (Quote::Double | Quote::Heredoc, '\\' | '$') => {
+                chars.next();
+                push_char(&mut result, next);
+            }
+            (Quote::Double | Quote::Heredoc, 'n' | 't' | 'r' | 'v' | 'e' | 'f') => {
+                chars.next();
+                result.push(match next {
+                    'n' => b'\n',
+                    't' => b'\t',
+                    'r' => b'\r',
+                    'v' => 0x0B,
+                    'e' => 0x1B,
+                    _ => 0x0C,
+                });
+            }
+            (Quote::Double | Quote::Heredoc, '0'..='7') => {
+                let mut code = 0;
+                for _ in 0..3 {
+                    match chars.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            code = code * 8 + digit;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+
+                result.push((code % 256) as u8);
+            }
+            (Quote::Double | Quote::Heredoc, 'x') => {
+                let digits = chars.clone().skip(1).take(2).take_while(|c| c.is_ascii_hexdigit()).collect::<String>();
+                if digits.is_empty() {
+                    result.push(b'\\');
+
+                    continue;
+                }
+
+                chars.nth(digits.len());
+                result.push(u8::from_str_radix(&digits, 16).unwrap_or_default());
+            }
+            (Quote::Double | Quote::Heredoc, 'u') => match get_unicode_escape(chars.clone().skip(1)) {
+                Some((codepoint, length)) => {
+                    chars.nth(length);
+                    push_char(&mut result, codepoint);
+                }
+                None => result.push(b'\\'),
+            },
+            _ => result.push(b'\\'),
+        }
+    }
+
+    result
+}
+
+/// Normalizes the escape sequences of the contents of a string.
+///
+/// Backslashes that do not start an escape sequence are escaped explicitly, and the digits of
+/// hexadecimal and unicode escape sequences are uppercased. The value of the string is unchanged.
+pub(crate) fn normalize_escapes(content: &str, quote: Quote) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' || quote == Quote::Nowdoc {
+            result.push(c);
+
+            continue;
+        }
+
+        // A trailing backslash is followed by the next part of the string, which may start an interpolation.
+        let Some(&next) = chars.peek() else {
+            result.push(c);
+
+            break;
+        };
+
+        match (quote, next) {
+            (Quote::Single, '\'' | '\\')
+            | (Quote::Double, '"')
+            | (Quote::Double | Quote::Heredoc, '\\' | '$' | 'n' | 't' | 'r' | 'v' | 'e' | 'f' | '0'..='7') => {
+                chars.next();
+                result.push(c);
+                result.push(next);
+            }
+            // `\{` is kept as is, as `\\{` would start an interpolation when followed by `$`.
+            (Quote::Double | Quote::Heredoc, '{') => {
+                result.push(c);
+            }
+            (Quote::Double | Quote::Heredoc, 'x') if chars.clone().nth(1).is_some_and(|c| c.is_ascii_hexdigit()) => {
+                chars.next();
+                result.push_str("\\x");
+                for _ in 0..2 {
+                    match chars.next_if(|c| c.is_ascii_hexdigit()) {
+                        Some(digit) => result.push(digit.to_ascii_uppercase()),
+                        None => break,
+                    }
+                }
+            }
+            (Quote::Double | Quote::Heredoc, 'u') if chars.clone().nth(1) == Some('{') => {
+                chars.next();
+                result.push_str("\\u");
+
+                // An invalid unicode escape sequence is a compile error, which is left for the user to fix.
+                if let Some((_, length)) = get_unicode_escape(chars.clone()) {
+                    for _ in 0..length {
+                        result.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+                    }
+                }
+            }
+            _ => {
+                result.push_str("\\\\");
+            }
+        }
+    }
+
+    result
+}
+
+/// Escapes the given text so that it can be the contents of a single-quoted or double-quoted string.
+pub(crate) fn escape(text: &str, quote: Quote) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match (quote, c) {
+            (Quote::Single, '\\' | '\'') | (Quote::Double, '\\' | '"' | '$') => {
+                result.push('\\');
+                result.push(c);
+            }
+            (Quote::Double, '\n') => result.push_str("\\n"),
+            (Quote::Double, '\t') => result.push_str("\\t"),
+            (Quote::Double, '\r') => result.push_str("\\r"),
+            (Quote::Double, '\u{0B}') => result.push_str("\\v"),
+            (Quote::Double, '\u{1B}') => result.push_str("\\e"),
+            (Quote::Double, '\u{0C}') => result.push_str("\\f"),
+            (Quote::Double, c) if c.is_ascii_control() => result.push_str(&format!("\\x{:02X}", c as u32)),
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Splits the format of a `sprintf` call into the texts around its placeholders.
+///
+/// Only formats whose placeholders are all `%s` are supported, with `%%` standing for a literal `%`.
+/// The returned texts are one more than the placeholders.
+pub(crate) fn split_sprintf_format(format: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut texts = vec![vec![]];
+    let mut bytes = format.iter();
+    while let Some(&byte) = bytes.next() {
+        if byte != b'%' {
+            texts.last_mut()?.push(byte);
+
+            continue;
+        }
+
+        match bytes.next()? {
+            b'%' => texts.last_mut()?.push(b'%'),
+            b's' => texts.push(vec![]),
+            _ => return None,
+        }
+    }
+
+    Some(texts)
+}
+
+/// Returns the codepoint of a `{...}` unicode escape sequence, and the number of characters it spans.
+fn get_unicode_escape(mut chars: impl Iterator<Item = char>) -> Option<(char, usize)> {
+    if chars.next()? != '{' {
+        return None;
+    }
+
+    let mut digits = String::new();
+    loop {
+        match chars.next()? {
+            '}' => break,
+            c if c.is_ascii_hexdigit() => digits.push(c),
+            _ => return None,
+        }
+    }
+
+    if digits.is_empty() {
+        return None;
+    }
+
+    let codepoint = char::from_u32(u32::from_str_radix(&digits, 16).ok()?)?;
+
+    Some((codepoint, digits.len() + 2))
+}
+
+fn push_char(bytes: &mut Vec<u8>, c: char) {
+    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
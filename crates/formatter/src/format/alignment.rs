@@ -0,0 +1,147 @@
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::comment::Comment;
+use crate::comment::Comments;
+use crate::document::group::GroupIdentifier;
+use crate::document::Align;
+use crate::document::Document;
+use crate::document::Group;
+use crate::document::IfBreak;
+use crate::document::IndentIfBreak;
+use crate::document::Line;
+use crate::format::Format;
+use crate::Formatter;
+
+/// The padding printed before the operator of an aligned node.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Alignment {
+    /// The number of spaces needed to reach the widest left-hand side in the alignment group.
+    padding: usize,
+    /// The group that must break for the padding to be printed, if any.
+    group_id: Option<GroupIdentifier>,
+}
+
+/// Registers the alignment of a sequence of nodes.
+///
+/// Each item is the span of a node, and the width of its left-hand side, or `None` if the node can't be aligned.
+///
+/// Consecutive alignable nodes form an alignment group, which is broken by nodes that can't be aligned, blank
+/// lines, and comments. A group is aligned only if it contains at least two nodes, and none of them needs
+/// more padding than `alignment_max_padding`.
+pub(super) fn register_alignment(
+    f: &mut Formatter<'_>,
+    items: Vec<(Span, Option<usize>)>,
+    group_id: Option<GroupIdentifier>,
+) {
+    let mut group: Vec<(Span, usize)> = vec![];
+    for (span, width) in items {
+        let continues_group = match group.last() {
+            Some((previous, _)) => {
+                !has_blank_line_between(f, previous.end.offset, span.start.offset)
+                    && !has_comment_between(f, previous.start.offset, span.start.offset)
+            }
+            None => true,
+        };
+
+        if !continues_group {
+            register_alignment_group(f, std::mem::take(&mut group), group_id);
+        }
+
+        match width {
+            Some(width) => group.push((span, width)),
+            None => register_alignment_group(f, std::mem::take(&mut group), group_id),
+        }
+    }
+
+    register_alignment_group(f, group, group_id);
+}
+
+/// Registers the alignment of a single alignment group, see [`register_alignment`].
+pub(super) fn register_alignment_group(
+    f: &mut Formatter<'_>,
+    group: Vec<(Span, usize)>,
+    group_id: Option<GroupIdentifier>,
+) {
+    if group.len() < 2 {
+        return;
+    }
+
+    let width = group.iter().map(|(_, width)| *width).max().unwrap_or_default();
+    let padding = group.iter().map(|(_, w)| width - w).max().unwrap_or_default();
+    if padding == 0 || padding > f.settings.alignment_max_padding {
+        return;
+    }
+
+    for (span, w) in group {
+        f.alignments.insert(span, Alignment { padding: width - w, group_id });
+    }
+}
+
+/// Returns the padding to print after the left-hand side of the given node, if it is aligned.
+pub(super) fn print_alignment_padding<'a>(f: &Formatter<'a>, span: Span) -> Option<Document<'a>> {
+    let alignment = f.alignments.get(&span)?;
+    if alignment.padding == 0 {
+        return None;
+    }
+
+    let padding = Document::String(f.as_str(" ".repeat(alignment.padding)));
+
+    Some(match alignment.group_id {
+        Some(group_id) => Document::IfBreak(IfBreak::then(padding).with_id(group_id)),
+        None => padding,
+    })
+}
+
+/// Returns the width of the given node when printed on a single line, or `None` if it can't be.
+///
+/// Nodes containing comments are never measured, as they are not printed while measuring.
+pub(super) fn measure<'a, T: Format<'a> + HasSpan>(f: &mut Formatter<'a>, node: &'a T) -> Option<usize> {
+    if has_comment_between(f, node.span().start.offset, node.span().end.offset) {
+        return None;
+    }
+
+    let comments = std::mem::replace(&mut f.comments, Comments::default().peekable());
+    let document = node.format(f);
+    f.comments = comments;
+
+    get_flat_width(&document)
+}
+
+/// Returns the width of the given document when printed on a single line, or `None` if it can't be.
+fn get_flat_width(document: &Document<'_>) -> Option<usize> {
+    let sum = |documents: &[Document<'_>]| documents.iter().map(get_flat_width).sum::<Option<usize>>();
+
+    match document {
+        Document::String(string) => Some(string.chars().count()),
+        Document::Array(documents) | Document::Indent(documents) => sum(documents),
+        Document::IndentIfBreak(IndentIfBreak { contents, .. }) | Document::Align(Align { contents, .. }) => {
+            sum(contents)
+        }
+        Document::Group(Group { contents, should_break, .. }) => {
+            if *should_break {
+                None
+            } else {
+                sum(contents)
+            }
+        }
+        Document::Line(Line { hard: true, .. } | Line { literal: true, .. }) => None,
+        Document::Line(Line { soft: true, .. }) => Some(0),
+        Document::Line(_) => Some(1),
+        Document::IfBreak(IfBreak { flat_content, .. }) => get_flat_width(flat_content),
+        Document::Fill(fill) => sum(&fill.parts),
+        Document::LineSuffix(_) | Document::BreakParent => None,
+    }
+}
+
+fn has_blank_line_between(f: &Formatter<'_>, start: usize, end: usize) -> bool {
+    f.source_text[start..end].matches('\n').count() > 1
+}
+
+pub(super) fn has_comment_between(f: &Formatter<'_>, start: usize, end: usize) -> bool {
+    f.comments.clone().any(|trivia| {
+        let comment = Comment::from_trivia(&trivia);
+
+        comment.start < end && comment.end > start
+    })
+}
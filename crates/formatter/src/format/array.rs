@@ -5,6 +5,8 @@ use crate::document::Document;
 use crate::document::Group;
 use crate::document::IfBreak;
 use crate::document::Line;
+use crate::format::alignment::measure;
+use crate::format::alignment::register_alignment;
 use crate::format::misc;
 use crate::format::Format;
 use crate::Formatter;
@@ -109,6 +111,21 @@ pub(super) fn print_array_like<'a>(f: &mut Formatter<'a>, array_like: ArrayLike<
         return Document::Group(Group::new(parts));
     }
 
+    // The `=>` operators are only aligned when the elements are printed on separate lines.
+    let group_id = f.next_id();
+    if f.settings.align_array_arrows {
+        let items = array_like
+            .elements()
+            .iter()
+            .map(|element| match element {
+                ArrayElement::KeyValue(element) => (element.span(), measure(f, element.key.as_ref())),
+                _ => (element.span(), None),
+            })
+            .collect();
+
+        register_alignment(f, items, Some(group_id));
+    }
+
     parts.push(Document::Indent({
         let len = array_like.len();
         let mut indent_parts = vec![];
@@ -145,7 +162,7 @@ pub(super) fn print_array_like<'a>(f: &mut Formatter<'a>, array_like: ArrayLike<
         array_like.elements()[0].span().start.offset,
     );
 
-    Document::Group(Group::new(parts).with_break(should_break))
+    Document::Group(Group::new(parts).with_break(should_break).with_id(group_id))
 }
 
 fn inline_single_element<'a>(f: &mut Formatter<'a>, array_like: &ArrayLike<'a>) -> Option<Document<'a>> {
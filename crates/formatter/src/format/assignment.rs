@@ -6,6 +6,7 @@ use crate::document::Document;
 use crate::document::Group;
 use crate::document::IndentIfBreak;
 use crate::document::Line;
+use crate::format::alignment::print_alignment_padding;
 use crate::format::binaryish::should_inline_logical_or_coalesce_expression;
 use crate::format::Format;
 use crate::Formatter;
@@ -44,6 +45,19 @@ pub(super) enum AssignmentLikeNode<'a> {
     KeyValueArrayElement(&'a KeyValueArrayElement),
 }
 
+impl HasSpan for AssignmentLikeNode<'_> {
+    fn span(&self) -> Span {
+        match self {
+            Self::AssignmentOperation(assignment) => assignment.span(),
+            Self::ClassLikeConstantItem(constant_item) => constant_item.span(),
+            Self::ConstantItem(constant_item) => constant_item.span(),
+            Self::EnumCaseBackedItem(enum_case_backed_item) => enum_case_backed_item.span(),
+            Self::PropertyConcreteItem(property_item) => property_item.span(),
+            Self::KeyValueArrayElement(element) => element.span(),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum Layout {
     Chain,
@@ -63,6 +77,11 @@ pub(super) fn print_assignment<'a>(
     rhs_expression: &'a Expression,
 ) -> Document<'a> {
     let layout = choose_layout(f, &lhs, &assignment_node, rhs_expression);
+    let lhs = match print_alignment_padding(f, assignment_node.span()) {
+        Some(padding) => Document::Array(vec![lhs, padding]),
+        None => lhs,
+    };
+
     let rhs = rhs_expression.format(f);
 
     match layout {
@@ -1,7 +1,16 @@
 use mago_ast::*;
+use mago_ast_utils::member::get_class_like_member_category;
+use mago_ast_utils::member::get_sorted_class_like_member_indices;
+use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::document::Document;
+use crate::document::Group;
+use crate::document::Line;
+use crate::format::alignment::measure;
+use crate::format::alignment::register_alignment;
+use crate::format::alignment::register_alignment_group;
+use crate::format::Format;
 use crate::settings::BraceStyle;
 use crate::Formatter;
 
@@ -18,5 +27,161 @@ pub fn print_class_like_body<'a>(
         BraceStyle::NextLine => false,
     };
 
-    print_block_of_nodes(f, left_brace, class_like_members, right_brace, inline_empty)
+    if class_like_members.is_empty() || !(f.settings.separate_class_members || f.settings.sort_class_members) {
+        if f.settings.align_class_constants {
+            let items = class_like_members.iter().map(|member| measure_constant_member(f, member)).collect();
+
+            register_alignment(f, items, None);
+        }
+
+        return print_block_of_nodes(f, left_brace, class_like_members, right_brace, inline_empty);
+    }
+
+    let members = class_like_members.as_slice();
+    let order = if f.settings.sort_class_members {
+        get_sorted_class_like_member_indices(f.interner, class_like_members)
+    } else {
+        (0..members.len()).collect::<Vec<_>>()
+    };
+
+    // Whether a blank line is printed before each member, in printing order.
+    let separated = order
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| i > 0 && should_separate_members(f, left_brace, members, order[i - 1], index))
+        .collect::<Vec<_>>();
+
+    if f.settings.align_class_constants {
+        // The members may be moved, and their blank lines changed, so the alignment groups follow the printed
+        // order rather than the source.
+        let mut group = vec![];
+        for (i, &index) in order.iter().enumerate() {
+            if separated[i] || has_leading_comment(f, left_brace, members, index) {
+                register_alignment_group(f, std::mem::take(&mut group), None);
+            }
+
+            match measure_constant_member(f, &members[index]) {
+                (span, Some(width)) => group.push((span, width)),
+                (_, None) => register_alignment_group(f, std::mem::take(&mut group), None),
+            }
+        }
+
+        register_alignment_group(f, group, None);
+    }
+
+    // Members are formatted in source order, so that each of them keeps its comments, and only then reordered.
+    let mut documents = class_like_members.iter().map(|member| Some(member.format(f))).collect::<Vec<_>>();
+    let mut formatted = vec![];
+    for (i, &index) in order.iter().enumerate() {
+        formatted.push(Document::Line(Line::hardline()));
+        if separated[i] {
+            formatted.push(Document::Line(Line::hardline()));
+        }
+
+        formatted.extend(documents[index].take());
+    }
+
+    let mut contents = vec![Document::String("{"), Document::Indent(formatted)];
+    if let Some(comments) = f.print_dangling_comments(left_brace.join(*right_brace), true) {
+        contents.push(comments);
+    } else {
+        contents.push(Document::Line(Line::hardline()));
+    }
+
+    contents.push(Document::String("}"));
+
+    Document::Group(Group::new(contents))
+}
+
+/// Returns the span of the given member, and the width of its name if it is an alignable constant.
+fn measure_constant_member<'a>(f: &mut Formatter<'a>, member: &'a ClassLikeMember) -> (Span, Option<usize>) {
+    match member {
+        ClassLikeMember::Constant(constant) if constant.attribute_lists.is_empty() && constant.items.len() == 1 => {
+            let item = &constant.items.as_slice()[0];
+
+            (item.span(), measure_constant_name(f, constant, item))
+        }
+        _ => (member.span(), None),
+    }
+}
+
+/// Determines whether a blank line should be printed between the given members, which are
+/// printed one after the other, but may not be adjacent in the source.
+fn should_separate_members<'a>(
+    f: &Formatter<'a>,
+    left_brace: &Span,
+    members: &'a [ClassLikeMember],
+    previous: usize,
+    next: usize,
+) -> bool {
+    let previous_category = get_class_like_member_category(f.interner, &members[previous]);
+    let next_category = get_class_like_member_category(f.interner, &members[next]);
+    if previous_category != next_category {
+        return true;
+    }
+
+    if !f.settings.separate_class_members {
+        return f.is_next_line_empty(members[previous].span());
+    }
+
+    if matches!(members[previous], ClassLikeMember::Method(_)) {
+        return true;
+    }
+
+    has_member_metadata(f, left_brace, members, previous) || has_member_metadata(f, left_brace, members, next)
+}
+
+/// Determines whether the given member has a docblock or attributes.
+fn has_member_metadata<'a>(f: &Formatter<'a>, left_brace: &Span, members: &'a [ClassLikeMember], index: usize) -> bool {
+    let member = &members[index];
+    let has_attributes = match member {
+        ClassLikeMember::TraitUse(_) => false,
+        ClassLikeMember::Constant(constant) => !constant.attribute_lists.is_empty(),
+        ClassLikeMember::Property(Property::Plain(property)) => !property.attribute_lists.is_empty(),
+        ClassLikeMember::Property(Property::Hooked(property)) => !property.attribute_lists.is_empty(),
+        ClassLikeMember::EnumCase(case) => !case.attribute_lists.is_empty(),
+        ClassLikeMember::Method(method) => !method.attribute_lists.is_empty(),
+    };
+
+    if has_attributes {
+        return true;
+    }
+
+    get_leading_text(f, left_brace, members, index).lines().any(|line| line.trim_start().starts_with("/**"))
+}
+
+/// Determines whether the given member is preceded by a comment on its own line.
+fn has_leading_comment<'a>(f: &Formatter<'a>, left_brace: &Span, members: &'a [ClassLikeMember], index: usize) -> bool {
+    let text = get_leading_text(f, left_brace, members, index);
+
+    // Trailing comments on the line of the previous member stay with it.
+    let text = if index == 0 { text } else { text.split_once('\n').map_or("", |(_, rest)| rest) };
+
+    !text.trim().is_empty()
+}
+
+/// Returns the source text between the given member and the member, or brace, preceding it in the source.
+fn get_leading_text<'a>(f: &Formatter<'a>, left_brace: &Span, members: &'a [ClassLikeMember], index: usize) -> &'a str {
+    let start = if index == 0 { left_brace.end.offset } else { members[index - 1].span().end.offset };
+
+    &f.source_text[start..members[index].span().start.offset]
+}
+
+/// Returns the width of the constant declaration up to, and including, the name of the given item,
+/// e.g. `public const int FOO` in `public const int FOO = 1;`.
+fn measure_constant_name<'a>(
+    f: &mut Formatter<'a>,
+    constant: &'a ClassLikeConstant,
+    item: &'a ClassLikeConstantItem,
+) -> Option<usize> {
+    let mut width = measure(f, &constant.r#const)? + 1 + measure(f, &item.name)?;
+    for modifier in constant.modifiers.iter() {
+        width += measure(f, modifier)? + 1;
+    }
+
+    if let Some(hint) = &constant.hint {
+        width += measure(f, hint)? + 1;
+    }
+
+    Some(width)
 }
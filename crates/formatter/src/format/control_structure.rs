@@ -346,6 +346,11 @@ impl<'a> Format<'a> for SwitchExpressionCase {
             let mut statements = print_statement_sequence(f, &self.statements);
             if !statements.is_empty() {
                 statements.insert(0, Document::Line(Line::hardline()));
+                if f.settings.indent_trailing_case_comments {
+                    if let Some(comments) = f.print_trailing_indented_comments(self.span()) {
+                        statements.push(comments);
+                    }
+                }
 
                 parts.push(Document::Indent(statements));
             }
@@ -362,6 +367,11 @@ impl<'a> Format<'a> for SwitchDefaultCase {
             let mut statements = print_statement_sequence(f, &self.statements);
             if !statements.is_empty() {
                 statements.insert(0, Document::Line(Line::hardline()));
+                if f.settings.indent_trailing_case_comments {
+                    if let Some(comments) = f.print_trailing_indented_comments(self.span()) {
+                        statements.push(comments);
+                    }
+                }
 
                 parts.push(Document::Indent(statements));
             }
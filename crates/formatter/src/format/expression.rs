@@ -1,8 +1,12 @@
 use mago_ast::*;
+use mago_php_version::feature::Feature;
 use mago_span::HasSpan;
 
 use crate::document::Document;
 use crate::document::Line;
+use crate::format::alignment::measure;
+use crate::format::alignment::print_alignment_padding;
+use crate::format::alignment::register_alignment;
 use crate::format::array::print_array_like;
 use crate::format::array::ArrayLike;
 use crate::format::assignment::print_assignment;
@@ -764,6 +768,9 @@ impl<'a> Format<'a> for MatchDefaultArm {
         wrap!(f, self, MatchDefaultArm, {
             Document::Group(Group::new(vec![
                 self.default.format(f),
+                print_alignment_padding(f, self.span())
+                    .map(|padding| Document::IfBreak(IfBreak::new(Document::empty(), padding)))
+                    .unwrap_or_else(Document::empty),
                 Document::IndentIfBreak(IndentIfBreak::new(vec![
                     Document::IfBreak(IfBreak::new(Document::Line(Line::default()), Document::space())),
                     Document::String("=> "),
@@ -789,6 +796,10 @@ impl<'a> Format<'a> for MatchExpressionArm {
                 }
             }
 
+            if let Some(padding) = print_alignment_padding(f, self.span()) {
+                contents.push(Document::IfBreak(IfBreak::new(Document::empty(), padding)));
+            }
+
             contents.push(Document::IndentIfBreak(IndentIfBreak::new(vec![
                 Document::IfBreak(IfBreak::new(Document::Line(Line::default()), Document::space())),
                 Document::String("=> "),
@@ -818,6 +829,12 @@ impl<'a> Format<'a> for Match {
 
             contents.push(Document::String("{"));
 
+            if f.settings.align_match_arms {
+                let items = self.arms.iter().map(|arm| (arm.span(), measure_match_arm_conditions(f, arm))).collect();
+
+                register_alignment(f, items, None);
+            }
+
             if !self.arms.is_empty() {
                 let mut inner_contents =
                     Document::join(self.arms.iter().map(|arm| arm.format(f)).collect::<Vec<_>>(), Separator::CommaLine);
@@ -842,6 +859,21 @@ impl<'a> Format<'a> for Match {
     }
 }
 
+/// Returns the width of the conditions of the given match arm, e.g. `1, 2` in `1, 2 => 'foo'`.
+fn measure_match_arm_conditions<'a>(f: &mut Formatter<'a>, arm: &'a MatchArm) -> Option<usize> {
+    match arm {
+        MatchArm::Expression(arm) => {
+            let mut width = 2 * (arm.conditions.len() - 1);
+            for condition in arm.conditions.iter() {
+                width += measure(f, condition)?;
+            }
+
+            Some(width)
+        }
+        MatchArm::Default(arm) => measure(f, &arm.default),
+    }
+}
+
 impl<'a> Format<'a> for Conditional {
     fn format(&'a self, f: &mut Formatter<'a>) -> Document<'a> {
         wrap!(f, self, Conditional, {
@@ -909,28 +941,46 @@ impl<'a> Format<'a> for DocumentString {
                 DocumentIndentation::Mixed(t, w) => t + w,
             };
 
-            contents.push(Document::Line(Line::hardline()));
+            // Before PHP 7.3, the closing label must be at the start of a line, so the lines are not indented.
+            let (newline, separator) = if f.settings.php_version.is_supported(Feature::FlexibleHeredocs) {
+                (Line::hardline(), Separator::Hardline)
+            } else {
+                (Line::literal_hardline(), Separator::LiteralHardline)
+            };
+
+            contents.push(Document::Line(newline));
+
+            let mut at_line_start = true;
             for part in self.parts.iter() {
                 let formatted = match part {
                     StringPart::Literal(l) => {
                         let content = f.lookup(&l.value);
                         let mut part_contents = vec![];
-                        for line in Formatter::split_lines(content) {
-                            let line = Formatter::skip_leading_whitespace_up_to(line, indent);
-
-                            part_contents.push(Document::String(line));
+                        for (i, line) in Formatter::split_lines(content).into_iter().enumerate() {
+                            // The text following an interpolated expression on the same line is not indentation.
+                            if i > 0 || at_line_start {
+                                part_contents
+                                    .push(Document::String(Formatter::skip_leading_whitespace_up_to(line, indent)));
+                            } else {
+                                part_contents.push(Document::String(line));
+                            }
                         }
 
-                        part_contents = Document::join(part_contents, Separator::Hardline);
+                        part_contents = Document::join(part_contents, separator);
 
                         // if ends with a newline, add a newline
-                        if content.ends_with('\n') {
-                            part_contents.push(Document::Line(Line::hardline()));
+                        at_line_start = content.ends_with('\n');
+                        if at_line_start {
+                            part_contents.push(Document::Line(newline));
                         }
 
                         Document::Array(part_contents)
                     }
-                    _ => part.format(f),
+                    _ => {
+                        at_line_start = false;
+
+                        part.format(f)
+                    }
                 };
 
                 contents.push(formatted);
@@ -282,3 +282,13 @@ pub(super) fn print_condition<'a>(f: &mut Formatter<'a>, condition: &'a Expressi
         Document::String(")"),
     ]))
 }
+
+/// Returns `true` if the hint currently being formatted is, or is part of, the type of a catch clause.
+pub(super) fn is_catch_clause_hint(f: &Formatter<'_>) -> bool {
+    f.stack
+        .iter()
+        .rev()
+        .skip(1)
+        .find(|node| !matches!(node, Node::Hint(_)))
+        .is_some_and(|node| matches!(node, Node::TryCatchClause(_)))
+}
@@ -11,6 +11,7 @@ use crate::format::call_node::print_call_like_node;
 use crate::format::call_node::CallLikeNode;
 use crate::format::class_like::print_class_like_body;
 use crate::format::misc::has_new_line_in_range;
+use crate::format::misc::is_catch_clause_hint;
 use crate::format::misc::print_attribute_list_sequence;
 use crate::format::misc::print_colon_delimited_body;
 use crate::format::misc::print_modifiers;
@@ -22,6 +23,7 @@ use crate::utils::has_naked_left_side;
 use crate::wrap;
 use crate::Formatter;
 
+pub mod alignment;
 pub mod array;
 pub mod assignment;
 pub mod binaryish;
@@ -36,6 +38,7 @@ pub mod misc;
 pub mod parameters;
 pub mod statement;
 pub mod string;
+pub mod uses;
 
 pub trait Format<'a> {
     #[must_use]
@@ -1343,6 +1346,8 @@ impl<'a> Format<'a> for Hint {
                 Hint::Union(union_hint) => {
                     let spacing = if f.settings.type_spacing > 0 {
                         Document::String(f.as_str(" ".repeat(f.settings.type_spacing)))
+                    } else if f.settings.space_around_catch_union && is_catch_clause_hint(f) {
+                        Document::space()
                     } else {
                         Document::empty()
                     };
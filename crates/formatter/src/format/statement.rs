@@ -1,22 +1,68 @@
 use mago_ast::*;
 use mago_span::HasSpan;
 
+use crate::document::Align;
 use crate::document::Document;
 use crate::document::Line;
+use crate::format::alignment::has_comment_between;
+use crate::format::alignment::measure;
+use crate::format::alignment::register_alignment;
+use crate::format::uses::print_use_statement_run;
 use crate::format::Format;
 use crate::Formatter;
 
 pub fn print_statement_sequence<'a>(f: &mut Formatter<'a>, stmts: &'a Sequence<Statement>) -> Vec<Document<'a>> {
     let mut parts = vec![];
 
+    if f.settings.align_assignments {
+        let items = stmts
+            .iter()
+            .map(|stmt| match stmt {
+                Statement::Expression(ExpressionStatement { expression, .. }) => match expression.as_ref() {
+                    Expression::AssignmentOperation(assignment) => {
+                        // Compound operators, e.g. `.=`, are measured as part of the left-hand side, so that the `=`
+                        // characters are aligned.
+                        let operator_width = assignment.operator.span().length() - 1;
+
+                        (assignment.span(), measure(f, assignment.lhs.as_ref()).map(|width| width + operator_width))
+                    }
+                    _ => (stmt.span(), None),
+                },
+                _ => (stmt.span(), None),
+            })
+            .collect();
+
+        register_alignment(f, items, None);
+    }
+
     let last_non_noop_index = stmts.iter().rposition(|stmt| !matches!(stmt, Statement::Noop(_)));
+    let mut next_index = 0;
+    let mut aligned_block = None;
     for (i, stmt) in stmts.iter().enumerate() {
+        if i < next_index {
+            // This statement was already printed as part of a run of `use` statements.
+            continue;
+        }
+
+        let (i, stmt, document) = match print_use_statement_run(f, stmts, i) {
+            Some((document, last)) => (last, &stmts.as_slice()[last], document),
+            None => match print_echo_tag_run(f, stmts, i) {
+                Some((document, last)) => (last, &stmts.as_slice()[last], document),
+                None => (i, stmt, stmt.format(f)),
+            },
+        };
+
+        next_index = i + 1;
+
         let mut should_add_space = false;
 
         let should_add_new_line = match stmt {
             Statement::ClosingTag(_) => false,
             Statement::Inline(_) => false,
             Statement::Expression(ExpressionStatement { terminator: Terminator::ClosingTag(_), .. }) => false,
+            // A statement followed by inline HTML ends with a closing tag, e.g. `echo $a ?>`, and a new line
+            // would be printed as part of the HTML.
+            _ if matches!(stmts.get(i + 1), Some(Statement::Inline(_))) => false,
             Statement::OpeningTag(_) => {
                 if let Some(index) = f.skip_to_line_end(Some(stmt.span().end_position().offset)) {
                     should_add_space = !f.has_newline(index, false);
@@ -39,7 +85,13 @@ pub fn print_statement_sequence<'a>(f: &mut Formatter<'a>, stmts: &'a Sequence<S
             }
         };
 
-        parts.push(stmt.format(f));
+        parts.push(document);
+
+        if f.settings.template_mode && !should_add_space {
+            if let Some(alignment) = get_block_alignment(f, stmts, i) {
+                aligned_block = Some((parts.len(), alignment));
+            }
+        }
 
         let is_last = if let Some(index) = last_non_noop_index { i == index } else { i == stmts.len() - 1 };
 
@@ -57,7 +109,88 @@ pub fn print_statement_sequence<'a>(f: &mut Formatter<'a>, stmts: &'a Sequence<S
                 }
             }
         }
+
+        if let Some((start, alignment)) = aligned_block {
+            if matches!(stmts.get(i + 1), None | Some(Statement::Inline(_))) {
+                let contents = parts.split_off(start);
+                parts.push(Document::Align(Align::new(alignment, contents)));
+                aligned_block = None;
+            }
+        }
+    }
+
+    if let Some((start, alignment)) = aligned_block {
+        let contents = parts.split_off(start);
+        parts.push(Document::Align(Align::new(alignment, contents)));
     }
 
     parts
 }
+
+/// Prints a PHP block made of a single `echo` statement using the short echo tag, e.g. `<?php echo $a; ?>`
+/// and `<?= $a; ?>` as `<?= $a ?>`.
+///
+/// Returns the document and the index of the last statement of the block, or `None` if the block can't be shortened.
+fn print_echo_tag_run<'a>(
+    f: &mut Formatter<'a>,
+    stmts: &'a Sequence<Statement>,
+    index: usize,
+) -> Option<(Document<'a>, usize)> {
+    if !f.settings.template_mode {
+        return None;
+    }
+
+    let statements = stmts.as_slice();
+    let (tag, value, terminator) = match (statements.get(index), statements.get(index + 1)) {
+        (Some(Statement::OpeningTag(tag @ OpeningTag::Full(_))), Some(Statement::Echo(echo))) => {
+            let [value] = echo.values.as_slice() else {
+                return None;
+            };
+
+            (tag, value, &echo.terminator)
+        }
+        (Some(Statement::OpeningTag(tag @ OpeningTag::Echo(_))), Some(Statement::Expression(statement))) => {
+            (tag, statement.expression.as_ref(), &statement.terminator)
+        }
+        _ => return None,
+    };
+
+    let last = match terminator {
+        Terminator::ClosingTag(_) => index + 1,
+        Terminator::Semicolon(_) if matches!(statements.get(index + 2), Some(Statement::ClosingTag(_))) => index + 2,
+        _ => return None,
+    };
+
+    // A closing tag at the end of the file is removed, so the block is left as it is.
+    let (start, end) = (tag.span().start.offset, statements[last].span().end.offset);
+    if f.source_text[start..end].contains('\n')
+        || has_comment_between(f, start, end)
+        || f.skip_spaces_and_new_lines(Some(end), false).is_none()
+    {
+        return None;
+    }
+
+    let document = Document::Array(vec![Document::String("<?= "), value.format(f), Document::String(" ?>")]);
+    f.scripting_mode = false;
+
+    Some((document, last))
+}
+
+/// Returns the indentation of the inline HTML preceding the opening tag at the given index, if the tag
+/// starts a PHP block spanning multiple lines.
+fn get_block_alignment<'a>(f: &Formatter<'a>, stmts: &'a Sequence<Statement>, index: usize) -> Option<&'a str> {
+    let statements = stmts.as_slice();
+    if !matches!(statements.get(index), Some(Statement::OpeningTag(OpeningTag::Full(_) | OpeningTag::Short(_)))) {
+        return None;
+    }
+
+    let Some(Statement::Inline(inline)) = index.checked_sub(1).and_then(|previous| statements.get(previous)) else {
+        return None;
+    };
+
+    let value = f.lookup(&inline.value);
+    let line = &value[value.rfind('\n')? + 1..];
+    let indentation = &line[..line.len() - line.trim_start().len()];
+
+    (!indentation.is_empty()).then_some(indentation)
+}
@@ -14,6 +14,9 @@ fn get_preferred_quote(raw: &str, enclosing_quote: char, prefer_single_quote: bo
             preferred_quote_count += 1;
         } else if character == alternate_quote_char {
             alternate_quote_count += 1;
+        } else if character == '$' && enclosing_quote == '\'' {
+            // A dollar sign would start an interpolation in a double-quoted string.
+            return enclosing_quote;
         } else if character == '\\' && !matches!(raw.chars().next(), Some(c) if c == enclosing_quote) {
             // If the string contains a backslash followed by the other quote character, we should
             // prefer the existing quote character.
@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use mago_ast::*;
+use mago_span::HasSpan;
+
+use crate::comment::CommentFlags;
+use crate::document::Document;
+use crate::document::Group;
+use crate::document::Line;
+use crate::document::Separator;
+use crate::settings::CasingStyle;
+use crate::settings::UseGroupingStyle;
+use crate::settings::UseSortOrder;
+use crate::Formatter;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+enum UseKind {
+    Class,
+    Function,
+    Constant,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Import<'a> {
+    kind: UseKind,
+    name: &'a str,
+    alias: Option<&'a str>,
+}
+
+/// A single `use` statement, as it is going to be printed.
+///
+/// Declarations without a prefix are printed as `use A, B;`, while declarations with a prefix
+/// are printed as a group, i.e. `use Prefix\{A, B};`. The kind of a declaration is `None` for
+/// mixed groups, where each import carries its own kind.
+#[derive(Debug, Clone)]
+struct Declaration<'a> {
+    kind: Option<UseKind>,
+    prefix: Option<&'a str>,
+    imports: Vec<Import<'a>>,
+}
+
+/// Prints the run of consecutive `use` statements starting at the given index, sorting, grouping,
+/// and deduplicating them according to the settings.
+///
+/// Returns `None` if the statement at the given index is not a `use` statement, if none of the
+/// `use` settings are enabled, or if the run contains comments that would be lost by reordering it.
+///
+/// Otherwise, returns the printed run along with the index of its last statement.
+pub(super) fn print_use_statement_run<'a>(
+    f: &mut Formatter<'a>,
+    stmts: &'a Sequence<Statement>,
+    start: usize,
+) -> Option<(Document<'a>, usize)> {
+    if f.settings.sort_uses == UseSortOrder::None
+        && !f.settings.separate_use_types
+        && f.settings.use_grouping == UseGroupingStyle::Preserve
+        && !f.settings.remove_duplicate_uses
+    {
+        return None;
+    }
+
+    let mut uses = vec![];
+    for stmt in stmts.iter().skip(start) {
+        match stmt {
+            Statement::Use(r#use) if matches!(r#use.terminator, Terminator::Semicolon(_)) => uses.push(r#use),
+            _ => break,
+        }
+    }
+
+    let (first, last) = (uses.first()?, uses.last()?);
+    let span = first.span().join(last.span());
+    if f.has_comment(span, CommentFlags::Dangling) {
+        return None;
+    }
+
+    let mut declarations = uses.iter().map(|r#use| collect_declaration(f, r#use)).collect::<Vec<_>>();
+
+    if f.settings.remove_duplicate_uses {
+        remove_duplicates(&mut declarations);
+    }
+
+    declarations = match f.settings.use_grouping {
+        UseGroupingStyle::Preserve => declarations,
+        UseGroupingStyle::Split => split_declarations(declarations),
+        UseGroupingStyle::Merge => merge_declarations(declarations),
+    };
+
+    if f.settings.separate_use_types {
+        declarations = separate_declarations(declarations);
+    }
+
+    if f.settings.sort_uses != UseSortOrder::None {
+        let order = f.settings.sort_uses;
+        for declaration in declarations.iter_mut() {
+            declaration.imports.sort_by(|a, b| compare_names(order, a.name, b.name));
+        }
+
+        declarations.sort_by(|a, b| compare_names(order, a.sort_key(), b.sort_key()));
+    }
+
+    if f.settings.separate_use_types {
+        declarations.sort_by_key(|declaration| declaration.kind);
+    }
+
+    let leading = f.print_leading_comments(span);
+
+    let mut parts = vec![];
+    let mut previous_kind = None;
+    for declaration in declarations.iter() {
+        if previous_kind.is_some() {
+            parts.push(Document::Line(Line::hardline()));
+            if f.settings.separate_use_types && previous_kind != Some(declaration.kind) {
+                parts.push(Document::Line(Line::hardline()));
+            }
+        }
+
+        parts.push(print_declaration(f, declaration));
+        previous_kind = Some(declaration.kind);
+    }
+
+    let trailing = f.print_trailing_comments(span);
+    let document = f.print_comments(leading, Document::Array(parts), trailing);
+
+    Some((document, start + uses.len() - 1))
+}
+
+impl<'a> Declaration<'a> {
+    fn sort_key(&self) -> &'a str {
+        match self.prefix {
+            Some(prefix) => prefix,
+            None => self.imports.first().map(|import| import.name).unwrap_or_default(),
+        }
+    }
+}
+
+fn collect_declaration<'a>(f: &Formatter<'a>, r#use: &'a Use) -> Declaration<'a> {
+    let import = |kind: UseKind, prefix: Option<&'a str>, item: &'a UseItem| {
+        let name = f.lookup(&item.name.value()).trim_start_matches('\\');
+        let name = match prefix {
+            Some(prefix) => f.as_str(format!("{}\\{}", prefix, name)),
+            None => name,
+        };
+
+        Import { kind, name, alias: item.alias.as_ref().map(|alias| f.lookup(&alias.identifier.value)) }
+    };
+
+    match &r#use.items {
+        UseItems::Sequence(sequence) => Declaration {
+            kind: Some(UseKind::Class),
+            prefix: None,
+            imports: sequence.items.iter().map(|item| import(UseKind::Class, None, item)).collect(),
+        },
+        UseItems::TypedSequence(sequence) => {
+            let kind = get_use_kind(Some(&sequence.r#type));
+
+            Declaration {
+                kind: Some(kind),
+                prefix: None,
+                imports: sequence.items.iter().map(|item| import(kind, None, item)).collect(),
+            }
+        }
+        UseItems::TypedList(list) => {
+            let kind = get_use_kind(Some(&list.r#type));
+            let prefix = f.lookup(&list.namespace.value()).trim_start_matches('\\');
+
+            Declaration {
+                kind: Some(kind),
+                prefix: Some(prefix),
+                imports: list.items.iter().map(|item| import(kind, Some(prefix), item)).collect(),
+            }
+        }
+        UseItems::MixedList(list) => {
+            let prefix = f.lookup(&list.namespace.value()).trim_start_matches('\\');
+
+            Declaration {
+                kind: None,
+                prefix: Some(prefix),
+                imports: list
+                    .items
+                    .iter()
+                    .map(|item| import(get_use_kind(item.r#type.as_ref()), Some(prefix), &item.item))
+                    .collect(),
+            }
+        }
+    }
+}
+
+fn get_use_kind(r#type: Option<&UseType>) -> UseKind {
+    match r#type {
+        None => UseKind::Class,
+        Some(UseType::Function(_)) => UseKind::Function,
+        Some(UseType::Const(_)) => UseKind::Constant,
+    }
+}
+
+/// Removes imports that were already imported by a previous declaration, keeping the first one.
+///
+/// Class and function names are case-insensitive, while constant names are case-sensitive.
+fn remove_duplicates(declarations: &mut Vec<Declaration<'_>>) {
+    let mut seen = HashSet::new();
+    for declaration in declarations.iter_mut() {
+        declaration.imports.retain(|import| {
+            let name = match import.kind {
+                UseKind::Constant => import.name.to_string(),
+                _ => import.name.to_ascii_lowercase(),
+            };
+
+            seen.insert((import.kind, name, import.alias.map(|alias| alias.to_ascii_lowercase())))
+        });
+    }
+
+    declarations.retain(|declaration| !declaration.imports.is_empty());
+}
+
+/// Splits every declaration into one declaration per import, e.g. `use A\{B, C};` becomes `use A\B; use A\C;`.
+fn split_declarations(declarations: Vec<Declaration<'_>>) -> Vec<Declaration<'_>> {
+    declarations
+        .into_iter()
+        .flat_map(|declaration| declaration.imports)
+        .map(|import| Declaration { kind: Some(import.kind), prefix: None, imports: vec![import] })
+        .collect()
+}
+
+/// Merges imports of the same kind sharing the same namespace into a single group,
+/// e.g. `use A\B; use A\C;` becomes `use A\{B, C};`.
+fn merge_declarations(declarations: Vec<Declaration<'_>>) -> Vec<Declaration<'_>> {
+    let mut merged: Vec<Declaration<'_>> = vec![];
+    for import in declarations.into_iter().flat_map(|declaration| declaration.imports) {
+        let Some((namespace, _)) = import.name.rsplit_once('\\') else {
+            merged.push(Declaration { kind: Some(import.kind), prefix: None, imports: vec![import] });
+
+            continue;
+        };
+
+        let existing = merged.iter_mut().find(|declaration| {
+            declaration.kind == Some(import.kind)
+                && declaration.imports[0].name.rsplit_once('\\').is_some_and(|(other, _)| other == namespace)
+        });
+
+        match existing {
+            Some(declaration) => {
+                declaration.prefix = Some(namespace);
+                declaration.imports.push(import);
+            }
+            None => merged.push(Declaration { kind: Some(import.kind), prefix: None, imports: vec![import] }),
+        }
+    }
+
+    merged
+}
+
+/// Splits mixed groups into one group per kind, so that declarations can be ordered by kind.
+fn separate_declarations(declarations: Vec<Declaration<'_>>) -> Vec<Declaration<'_>> {
+    let mut separated = vec![];
+    for declaration in declarations {
+        if declaration.kind.is_some() {
+            separated.push(declaration);
+
+            continue;
+        }
+
+        for kind in [UseKind::Class, UseKind::Function, UseKind::Constant] {
+            let imports = declaration.imports.iter().filter(|import| import.kind == kind).copied().collect::<Vec<_>>();
+
+            if !imports.is_empty() {
+                separated.push(Declaration { kind: Some(kind), prefix: declaration.prefix, imports });
+            }
+        }
+    }
+
+    separated
+}
+
+fn compare_names(order: UseSortOrder, a: &str, b: &str) -> Ordering {
+    // Compare segment by segment, so that `A\B` comes before `A\B\C` and `AB`.
+    let a_segments = a.split('\\');
+    let b_segments = b.split('\\');
+
+    match order {
+        UseSortOrder::None => Ordering::Equal,
+        UseSortOrder::Alphabetical => a_segments.cmp(b_segments),
+        UseSortOrder::CaseInsensitive => a_segments
+            .map(|segment| segment.to_ascii_lowercase())
+            .cmp(b_segments.map(|segment| segment.to_ascii_lowercase()))
+            .then_with(|| a.cmp(b)),
+    }
+}
+
+fn print_declaration<'a>(f: &mut Formatter<'a>, declaration: &Declaration<'a>) -> Document<'a> {
+    let mut contents = vec![print_keyword(f, "use"), Document::space()];
+    if let Some(kind) = declaration.kind {
+        if let Some(keyword) = get_kind_keyword(kind) {
+            contents.push(print_keyword(f, keyword));
+            contents.push(Document::space());
+        }
+    }
+
+    match declaration.prefix {
+        Some(prefix) => {
+            let mut items = Document::join(
+                declaration.imports.iter().map(|import| print_import(f, declaration, import, prefix)).collect(),
+                Separator::CommaLine,
+            );
+            items.insert(0, Document::Line(Line::softline()));
+
+            contents.push(Document::String(prefix));
+            contents.push(Document::String("\\"));
+            contents.push(Document::String("{"));
+            contents.push(Document::Indent(items));
+            contents.push(Document::Line(Line::softline()));
+            contents.push(Document::String("}"));
+        }
+        None => {
+            contents.push(Document::Group(Group::new(vec![
+                Document::Indent(Document::join(
+                    declaration.imports.iter().map(|import| print_import(f, declaration, import, "")).collect(),
+                    Separator::CommaLine,
+                )),
+                Document::Line(Line::softline()),
+            ])));
+        }
+    }
+
+    contents.push(Document::String(";"));
+
+    Document::Group(Group::new(contents))
+}
+
+fn print_import<'a>(
+    f: &mut Formatter<'a>,
+    declaration: &Declaration<'a>,
+    import: &Import<'a>,
+    prefix: &str,
+) -> Document<'a> {
+    let mut parts = vec![];
+    if declaration.kind.is_none() {
+        if let Some(keyword) = get_kind_keyword(import.kind) {
+            parts.push(print_keyword(f, keyword));
+            parts.push(Document::space());
+        }
+    }
+
+    if prefix.is_empty() {
+        parts.push(Document::String(import.name));
+    } else {
+        parts.push(Document::String(&import.name[prefix.len() + 1..]));
+    }
+
+    if let Some(alias) = import.alias {
+        parts.push(Document::space());
+        parts.push(print_keyword(f, "as"));
+        parts.push(Document::space());
+        parts.push(Document::String(alias));
+    }
+
+    Document::Group(Group::new(parts))
+}
+
+fn get_kind_keyword(kind: UseKind) -> Option<&'static str> {
+    match kind {
+        UseKind::Class => None,
+        UseKind::Function => Some("function"),
+        UseKind::Constant => Some("const"),
+    }
+}
+
+fn print_keyword<'a>(f: &Formatter<'a>, keyword: &'static str) -> Document<'a> {
+    match f.settings.keyword_case {
+        CasingStyle::Lowercase => Document::String(keyword),
+        CasingStyle::Uppercase => Document::String(f.as_str(keyword.to_ascii_uppercase())),
+    }
+}
@@ -1,30 +1,37 @@
 use std::iter::Peekable;
-use std::vec::IntoIter;
+use std::time::Instant;
+
+use ahash::HashMap;
 
 use mago_ast::Node;
 use mago_ast::Program;
-use mago_ast::Trivia;
 use mago_interner::StringIdentifier;
 use mago_interner::ThreadedInterner;
 use mago_source::Source;
 use mago_span::Span;
 
+use crate::comment::Comments;
 use crate::document::group::GroupIdentifier;
 use crate::document::group::GroupIdentifierBuilder;
 use crate::document::Document;
+use crate::format::alignment::Alignment;
 use crate::format::Format;
 use crate::printer::Printer;
 use crate::settings::FormatSettings;
 
+pub mod preset;
 pub mod settings;
+pub mod verify;
 
 mod binaryish;
 mod comment;
 mod document;
+mod escape;
 mod format;
 mod macros;
 mod parens;
 mod printer;
+mod rewrite;
 mod utils;
 
 pub fn format<'a>(
@@ -33,9 +40,46 @@ pub fn format<'a>(
     program: &'a Program,
     settings: FormatSettings,
 ) -> String {
-    let mut formatter = Formatter::new(interner, source, settings);
+    format_until(interner, source, program, settings, None).expect("formatting without a deadline always completes")
+}
+
+/// Formats the given program like [`format`], giving up once the given deadline has passed.
+///
+/// Returns `None` if the deadline passed before the formatted code was printed.
+pub fn format_with_deadline<'a>(
+    interner: &'a ThreadedInterner,
+    source: &'a Source,
+    program: &'a Program,
+    settings: FormatSettings,
+    deadline: Instant,
+) -> Option<String> {
+    format_until(interner, source, program, settings, Some(deadline))
+}
+
+fn format_until<'a>(
+    interner: &'a ThreadedInterner,
+    source: &'a Source,
+    program: &'a Program,
+    settings: FormatSettings,
+    deadline: Option<Instant>,
+) -> Option<String> {
+    if let Some(code) = rewrite::rewrite(interner, interner.lookup(&source.content), program, &settings) {
+        let source = Source {
+            identifier: source.identifier,
+            path: source.path.clone(),
+            content: interner.intern(&code),
+            size: code.len(),
+            lines: std::iter::once(0).chain(code.match_indices('\n').map(|(i, _)| i + 1)).collect(),
+        };
+
+        // The rewritten code is always valid, but the original program is printed as a fallback.
+        let (program, error) = mago_parser::parse_source(interner, &source);
+        if error.is_none() {
+            return Formatter::new(interner, &source, settings).print(&program, deadline);
+        }
+    }
 
-    formatter.format(program)
+    Formatter::new(interner, source, settings).print(program, deadline)
 }
 
 struct ArgumentState {
@@ -49,10 +93,11 @@ pub struct Formatter<'a> {
     source_text: &'a str,
     settings: FormatSettings,
     stack: Vec<Node<'a>>,
-    comments: Peekable<IntoIter<Trivia>>,
+    comments: Peekable<Comments>,
     scripting_mode: bool,
     id_builder: GroupIdentifierBuilder,
     argument_state: ArgumentState,
+    alignments: HashMap<Span, Alignment>,
 }
 
 impl<'a> Formatter<'a> {
@@ -63,24 +108,32 @@ impl<'a> Formatter<'a> {
             source_text: interner.lookup(&source.content),
             settings,
             stack: vec![],
-            comments: vec![].into_iter().peekable(),
+            comments: Comments::default().peekable(),
             scripting_mode: false,
             id_builder: GroupIdentifierBuilder::new(),
             argument_state: ArgumentState { expand_first_argument: false, expand_last_argument: false },
+            alignments: HashMap::default(),
         }
     }
 
     pub fn format(&mut self, program: &'a Program) -> String {
+        self.print(program, None).expect("printing without a deadline always completes")
+    }
+
+    fn print(&mut self, program: &'a Program, deadline: Option<Instant>) -> Option<String> {
         let document = self.build(program);
 
-        let printer = Printer::new(document, self.source, self.settings);
+        let mut printer = Printer::new(document, self.source, self.settings);
+        if let Some(deadline) = deadline {
+            printer = printer.with_deadline(deadline);
+        }
 
         printer.build()
     }
 
     fn build(&mut self, program: &'a Program) -> Document<'a> {
         self.comments =
-            program.trivia.iter().filter(|t| t.kind.is_comment()).copied().collect::<Vec<_>>().into_iter().peekable();
+            Comments::new(program.trivia.iter().filter(|t| t.kind.is_comment()).copied().collect()).peekable();
 
         program.format(self)
     }
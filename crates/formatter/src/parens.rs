@@ -84,30 +84,10 @@ impl<'a> Formatter<'a> {
             Some(Node::ArrayAppend(_)) => {
                 return true;
             }
-            Some(Node::ArrayAccess(access)) => {
-                // we add parentheses if the parent is an array access and the child is a binaryish node
-                //
-                // Example:
-                //
-                // ```php
-                // ($foo ?? $bar)[$baz];
-                // ```
-                //
-                // requires parentheses, if we remove them, the code will be interpreted as:
-                //
-                // ```php
-                // $foo ?? ($bar[$baz]);
-                // ```
-                return access.left_bracket.start.offset > node.span().start.offset;
-            }
             _ => {
-                let grand_parent_node = self.nth_parent_kind(3);
-
-                if let Some(Node::Access(_)) = grand_parent_node {
-                    return true;
-                } else {
-                    return false;
-                }
+                // A binary operation that is accessed, e.g. `($foo ?? $bar)[$baz]` or `($foo . $bar)->baz`,
+                // is wrapped by its expression, see `called_or_accessed_node_needs_parenthesis`.
+                return false;
             }
         };
 
@@ -0,0 +1,94 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::settings::*;
+
+/// A named set of format settings, following a published coding standard.
+///
+/// Presets only provide the defaults; any setting specified alongside a preset takes precedence.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum FormatPreset {
+    /// The default settings of the formatter.
+    #[default]
+    #[serde(alias = "default")]
+    Default,
+    /// The PSR-12 extended coding style.
+    ///
+    /// See <https://www.php-fig.org/psr/psr-12/>.
+    #[serde(alias = "psr-12", alias = "psr12", alias = "psr_12")]
+    Psr12,
+    /// The PER coding style 2.0, which extends and replaces PSR-12.
+    ///
+    /// See <https://www.php-fig.org/per/coding-style/>.
+    #[serde(alias = "per-cs-2.0", alias = "per-cs", alias = "per_cs", alias = "per")]
+    PerCs,
+    /// The coding style of Laravel, as enforced by Laravel Pint.
+    ///
+    /// See <https://laravel.com/docs/contributions#coding-style>.
+    #[serde(alias = "laravel")]
+    Laravel,
+    /// The coding standards of Symfony.
+    ///
+    /// See <https://symfony.com/doc/current/contributing/code/standards.html>.
+    #[serde(alias = "symfony")]
+    Symfony,
+}
+
+impl FormatPreset {
+    /// Returns the format settings of this preset.
+    pub fn settings(&self) -> FormatSettings {
+        let default = FormatSettings::default();
+
+        match self {
+            Self::Default => default,
+            Self::Psr12 => FormatSettings {
+                print_width: 120,
+                tab_width: 4,
+                use_tabs: false,
+                end_of_line: EndOfLine::Lf,
+                keyword_case: CasingStyle::Lowercase,
+                trailing_comma: false,
+                closure_brace_style: BraceStyle::SameLine,
+                function_brace_style: BraceStyle::NextLine,
+                method_brace_style: BraceStyle::NextLine,
+                classlike_brace_style: BraceStyle::NextLine,
+                control_brace_style: BraceStyle::SameLine,
+                space_before_closure_params: true,
+                space_after_closure_use: true,
+                static_before_visibility: false,
+                null_type_hint: NullTypeHint::Question,
+                space_around_catch_union: true,
+                indent_trailing_case_comments: true,
+                ..default
+            },
+            Self::PerCs => FormatSettings {
+                trailing_comma: true,
+                space_before_arrow_function_params: true,
+                space_concatenation: true,
+                ..Self::Psr12.settings()
+            },
+            Self::Laravel => FormatSettings {
+                single_quote: true,
+                trailing_comma: true,
+                space_before_arrow_function_params: true,
+                space_concatenation: false,
+                method_chain_break_threshold: 6,
+                method_chain_breaking_style: MethodChainBreakingStyle::NextLine,
+                sort_uses: UseSortOrder::Alphabetical,
+                remove_duplicate_uses: true,
+                space_around_catch_union: false,
+                ..Self::Psr12.settings()
+            },
+            Self::Symfony => FormatSettings {
+                single_quote: true,
+                trailing_comma: true,
+                space_before_arrow_function_params: true,
+                space_concatenation: false,
+                sort_uses: UseSortOrder::Alphabetical,
+                remove_duplicate_uses: true,
+                docblock_align_params: true,
+                ..Self::Psr12.settings()
+            },
+        }
+    }
+}
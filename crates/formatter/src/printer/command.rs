@@ -1,13 +1,13 @@
 use crate::document::Document;
 
 pub struct Command<'a> {
-    pub indent: Indent,
+    pub indent: Indent<'a>,
     pub mode: Mode,
     pub document: Document<'a>,
 }
 
 impl<'a> Command<'a> {
-    pub fn new(indent: Indent, mode: Mode, document: Document<'a>) -> Self {
+    pub fn new(indent: Indent<'a>, mode: Mode, document: Document<'a>) -> Self {
         Self { indent, mode, document }
     }
 
@@ -34,17 +34,23 @@ impl Mode {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct Indent {
+pub struct Indent<'a> {
     pub root: bool,
     pub length: usize,
+    /// The prefix printed before the indentation, e.g. the indentation of the surrounding inline HTML.
+    pub alignment: &'a str,
 }
 
-impl Indent {
+impl<'a> Indent<'a> {
     pub fn root() -> Self {
-        Self { root: true, length: 0 }
+        Self { root: true, length: 0, alignment: "" }
     }
 
-    pub fn new(length: usize) -> Self {
-        Self { root: false, length }
+    pub fn indented(self) -> Self {
+        Self { root: false, length: self.length + 1, alignment: self.alignment }
+    }
+
+    pub fn aligned(alignment: &'a str) -> Self {
+        Self { root: false, length: 0, alignment }
     }
 }
@@ -1,10 +1,12 @@
-use std::collections::VecDeque;
+use std::slice;
+use std::time::Instant;
 
 use ahash::HashMap;
 
 use mago_source::Source;
 
 use crate::document::group::GroupIdentifier;
+use crate::document::Align;
 use crate::document::Document;
 use crate::document::Fill;
 use crate::document::IfBreak;
@@ -17,6 +19,9 @@ use crate::settings::FormatSettings;
 
 mod command;
 
+/// The number of commands printed between two checks of the deadline.
+const DEADLINE_CHECK_INTERVAL: usize = 4096;
+
 pub struct Printer<'a> {
     settings: FormatSettings,
     out: Vec<u8>,
@@ -25,6 +30,7 @@ pub struct Printer<'a> {
     line_suffix: Vec<Command<'a>>,
     group_mode_map: HashMap<GroupIdentifier, Mode>,
     new_line: &'static str,
+    deadline: Option<Instant>,
 }
 
 impl<'a> Printer<'a> {
@@ -32,6 +38,8 @@ impl<'a> Printer<'a> {
         // Preallocate for performance because the output will very likely
         // be the same size as the original text.
         let out = Vec::with_capacity(source.size);
+        let mut document = document;
+        Self::propagate_breaks(&mut document);
         let cmds = vec![Command::new(Indent::root(), Mode::Break, document)];
 
         Self {
@@ -42,20 +50,37 @@ impl<'a> Printer<'a> {
             line_suffix: vec![],
             group_mode_map: HashMap::default(),
             new_line: settings.end_of_line.as_str(),
+            deadline: None,
         }
     }
 
-    pub fn build(mut self) -> String {
-        self.print_doc_to_string();
+    /// Stops printing once the given deadline has passed.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Prints the document, returning `None` if the deadline passed before it was printed.
+    pub fn build(mut self) -> Option<String> {
+        if !self.print_doc_to_string() {
+            return None;
+        }
 
         // SAFETY: We should have constructed valid UTF8 strings
-        unsafe { String::from_utf8_unchecked(self.out) }
+        Some(unsafe { String::from_utf8_unchecked(self.out) })
     }
 
-    /// Turn Doc into a string
-    pub fn print_doc_to_string(&mut self) {
-        while let Some(Command { indent, mut document, mode }) = self.commands.pop() {
-            Self::propagate_breaks(&mut document);
+    /// Turn Doc into a string, returning `false` if the deadline passed before it was done.
+    pub fn print_doc_to_string(&mut self) -> bool {
+        let mut printed = 0_usize;
+        while let Some(Command { indent, document, mode }) = self.commands.pop() {
+            if printed % DEADLINE_CHECK_INTERVAL == 0
+                && self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                return false;
+            }
+
+            printed += 1;
 
             match document {
                 Document::String(s) => self.handle_str(s),
@@ -63,6 +88,7 @@ impl<'a> Printer<'a> {
                 Document::Indent(docs) => self.handle_indent(indent, mode, docs),
                 Document::Group(_) => self.handle_group(indent, mode, document),
                 Document::IndentIfBreak(docs) => self.handle_indent_if_break(indent, mode, docs),
+                Document::Align(align) => self.handle_align(mode, align),
                 Document::Line(line) => self.handle_line(line, indent, mode, document),
                 Document::LineSuffix(docs) => self.handle_line_suffix(indent, mode, docs),
                 Document::IfBreak(if_break) => self.handle_if_break(if_break, indent, mode),
@@ -74,6 +100,8 @@ impl<'a> Printer<'a> {
                 self.commands.extend(self.line_suffix.drain(..).rev());
             }
         }
+
+        true
     }
 
     fn remaining_width(&self) -> isize {
@@ -82,18 +110,29 @@ impl<'a> Printer<'a> {
 
     fn handle_str(&mut self, s: &str) {
         self.out.extend(s.as_bytes());
-        self.position += s.len();
+        match s.rfind('\n') {
+            // In template mode, strings spanning multiple lines, e.g. inline HTML, leave the position at the start
+            // of their last line.
+            Some(index) if self.settings.template_mode => self.position = s.len() - index - 1,
+            _ => self.position += s.len(),
+        }
     }
 
-    fn handle_array(&mut self, indent: Indent, mode: Mode, docs: Vec<Document<'a>>) {
+    fn handle_array(&mut self, indent: Indent<'a>, mode: Mode, docs: Vec<Document<'a>>) {
         self.commands.extend(docs.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
     }
 
-    fn handle_indent(&mut self, indent: Indent, mode: Mode, docs: Vec<Document<'a>>) {
-        self.commands.extend(docs.into_iter().rev().map(|doc| Command::new(Indent::new(indent.length + 1), mode, doc)));
+    fn handle_indent(&mut self, indent: Indent<'a>, mode: Mode, docs: Vec<Document<'a>>) {
+        self.commands.extend(docs.into_iter().rev().map(|doc| Command::new(indent.indented(), mode, doc)));
     }
 
-    fn handle_group(&mut self, indent: Indent, mode: Mode, doc: Document<'a>) {
+    fn handle_align(&mut self, mode: Mode, align: Align<'a>) {
+        let indent = Indent::aligned(align.alignment);
+
+        self.commands.extend(align.contents.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
+    }
+
+    fn handle_group(&mut self, indent: Indent<'a>, mode: Mode, doc: Document<'a>) {
         match mode {
             Mode::Flat => {
                 let Document::Group(group) = doc else {
@@ -153,7 +192,7 @@ impl<'a> Printer<'a> {
         }
     }
 
-    fn handle_indent_if_break(&mut self, indent: Indent, mode: Mode, doc: IndentIfBreak<'a>) {
+    fn handle_indent_if_break(&mut self, indent: Indent<'a>, mode: Mode, doc: IndentIfBreak<'a>) {
         let IndentIfBreak { contents, group_id } = doc;
         let group_mode = group_id.map_or(Some(mode), |id| self.group_mode_map.get(&id).copied());
 
@@ -162,15 +201,13 @@ impl<'a> Printer<'a> {
                 self.commands.extend(contents.into_iter().rev().map(|doc| Command::new(indent, mode, doc)));
             }
             Some(Mode::Break) => {
-                self.commands.extend(
-                    contents.into_iter().rev().map(|doc| Command::new(Indent::new(indent.length + 1), mode, doc)),
-                );
+                self.commands.extend(contents.into_iter().rev().map(|doc| Command::new(indent.indented(), mode, doc)));
             }
             None => {}
         }
     }
 
-    fn handle_line(&mut self, line: Line, indent: Indent, mode: Mode, doc: Document<'a>) {
+    fn handle_line(&mut self, line: Line, indent: Indent<'a>, mode: Mode, doc: Document<'a>) {
         if mode.is_flat() && !line.hard {
             if !line.soft {
                 self.out.push(b' ');
@@ -198,14 +235,14 @@ impl<'a> Printer<'a> {
 
         self.trim();
         self.out.extend(self.new_line.as_bytes());
-        self.position = self.indent(indent.length);
+        self.position = self.indent(indent);
     }
 
-    fn handle_line_suffix(&mut self, indent: Indent, mode: Mode, docs: Vec<Document<'a>>) {
+    fn handle_line_suffix(&mut self, indent: Indent<'a>, mode: Mode, docs: Vec<Document<'a>>) {
         self.line_suffix.push(Command { indent, mode, document: Document::Array(docs) });
     }
 
-    fn handle_if_break(&mut self, if_break: IfBreak<'a>, indent: Indent, mode: Mode) {
+    fn handle_if_break(&mut self, if_break: IfBreak<'a>, indent: Indent<'a>, mode: Mode) {
         let IfBreak { break_contents, flat_content, group_id } = if_break;
         let Some(group_mode) = group_id.map_or(Some(mode), |id| self.group_mode_map.get(&id).copied()) else {
             return;
@@ -221,7 +258,7 @@ impl<'a> Printer<'a> {
         }
     }
 
-    fn handle_fill(&mut self, indent: Indent, mode: Mode, fill: Fill<'a>) {
+    fn handle_fill(&mut self, indent: Indent<'a>, mode: Mode, fill: Fill<'a>) {
         let mut fill = fill;
         let remaining_width = self.remaining_width();
         let original_parts_len = fill.parts().len();
@@ -307,15 +344,20 @@ impl<'a> Printer<'a> {
         };
     }
 
-    fn indent(&mut self, size: usize) -> usize {
-        if self.settings.use_tabs {
+    fn indent(&mut self, indent: Indent<'a>) -> usize {
+        self.out.extend(indent.alignment.as_bytes());
+
+        let size = indent.length;
+        let width = if self.settings.use_tabs {
             self.out.extend("\t".repeat(size).as_bytes());
             size
         } else {
             let count = self.settings.tab_width * size;
             self.out.extend(" ".repeat(count).as_bytes());
             count
-        }
+        };
+
+        indent.alignment.len() + width
     }
 
     fn trim(&mut self) {
@@ -342,32 +384,57 @@ impl<'a> Printer<'a> {
 
     fn fits(&self, next: &Command<'a>, width: isize) -> bool {
         let mut remaining_width = width;
-        let mut queue: VecDeque<(Mode, &Document)> = VecDeque::new();
+        // The documents left to measure are kept as iterators over their siblings, rather than being copied
+        // one by one, so that measuring the start of a huge array doesn't take time proportional to its size.
+        let mut stack: Vec<(Mode, slice::Iter<'_, Document<'a>>)> =
+            vec![(next.mode, slice::from_ref(&next.document).iter())];
         let mut cmds = self.commands.iter().rev();
 
-        queue.push_front((next.mode, &next.document));
-        while let Some((mode, doc)) = queue.pop_front() {
+        loop {
+            let Some((mode, documents)) = stack.last_mut() else {
+                match cmds.next() {
+                    Some(cmd) => {
+                        stack.push((cmd.mode, slice::from_ref(&cmd.document).iter()));
+
+                        continue;
+                    }
+                    None => return true,
+                }
+            };
+
+            let mode = *mode;
+            let Some(doc) = documents.next() else {
+                stack.pop();
+
+                continue;
+            };
+
             match doc {
                 Document::String(string) => {
+                    // In template mode, the rest of a string spanning multiple lines, e.g. inline HTML, starts
+                    // on a new line.
+                    if let Some(index) = string.find('\n').filter(|_| self.settings.template_mode) {
+                        return remaining_width >= index as isize;
+                    }
+
                     remaining_width -= string.len() as isize;
                 }
                 Document::IndentIfBreak(IndentIfBreak { contents: docs, .. })
+                | Document::Align(Align { contents: docs, .. })
                 | Document::Indent(docs)
                 | Document::Array(docs) => {
-                    // Prepend docs to the queue
-                    for d in docs.iter().rev() {
-                        queue.push_front((mode, d));
-                    }
+                    stack.push((mode, docs.iter()));
                 }
                 Document::Group(group) => {
                     let mode = if group.should_break { Mode::Break } else { mode };
-                    if group.expanded_states.is_some() && mode.is_break() {
-                        queue.push_front((mode, group.expanded_states.as_ref().unwrap().last().unwrap()));
-                    } else {
-                        for d in group.contents.iter().rev() {
-                            queue.push_front((mode, d));
+                    match &group.expanded_states {
+                        Some(expanded_states) if mode.is_break() => {
+                            stack.push((mode, slice::from_ref(expanded_states.last().unwrap()).iter()));
                         }
-                    };
+                        _ => {
+                            stack.push((mode, group.contents.iter()));
+                        }
+                    }
                 }
                 Document::IfBreak(if_break_doc) => {
                     let group_mode =
@@ -376,7 +443,7 @@ impl<'a> Printer<'a> {
                     let contents =
                         if group_mode.is_break() { &if_break_doc.break_contents } else { &if_break_doc.flat_content };
 
-                    queue.push_front((mode, contents));
+                    stack.push((mode, slice::from_ref(contents.as_ref()).iter()));
                 }
                 Document::Line(line) => {
                     if mode.is_break() || line.hard {
@@ -387,12 +454,10 @@ impl<'a> Printer<'a> {
                     }
                 }
                 Document::Fill(fill) => {
-                    for part in fill.parts().iter().rev() {
-                        queue.push_front((mode, part));
-                    }
+                    stack.push((mode, fill.parts().iter()));
                 }
                 Document::LineSuffix(_) => {
-                    break;
+                    return true;
                 }
                 Document::BreakParent => {}
             }
@@ -400,39 +465,51 @@ impl<'a> Printer<'a> {
             if remaining_width < 0 {
                 return false;
             }
-
-            if queue.is_empty() {
-                if let Some(cmd) = cmds.next() {
-                    queue.push_back((cmd.mode, &cmd.document));
-                }
-            }
         }
-
-        true
     }
 
+    /// Marks the groups containing a forced break as broken, and returns whether the document contains one.
+    ///
+    /// Every document is visited exactly once, so this is only called on the root document.
     pub fn propagate_breaks(doc: &mut Document<'_>) -> bool {
-        let check_array = |arr: &mut Vec<Document<'_>>| arr.iter_mut().rev().any(|doc| Self::propagate_breaks(doc));
+        // All documents are visited, even after a break is found, so that the nested groups are marked too.
+        let check_array = |arr: &mut Vec<Document<'_>>| {
+            let mut should_break = false;
+            for doc in arr.iter_mut() {
+                should_break |= Self::propagate_breaks(doc);
+            }
+
+            should_break
+        };
 
         match doc {
             Document::BreakParent => true,
             Document::Group(group) => {
                 let mut should_break = false;
                 if let Some(expanded_states) = &mut group.expanded_states {
-                    should_break = expanded_states.iter_mut().rev().any(Self::propagate_breaks);
-                }
-                if !should_break {
-                    should_break = check_array(&mut group.contents);
+                    should_break = check_array(expanded_states);
                 }
+                should_break = check_array(&mut group.contents) || should_break;
                 if group.expanded_states.is_none() && should_break {
                     group.should_break = should_break;
                 }
                 group.should_break
             }
-            Document::IfBreak(d) => Self::propagate_breaks(&mut d.break_contents),
+            Document::IfBreak(d) => {
+                let should_break = Self::propagate_breaks(&mut d.break_contents);
+                Self::propagate_breaks(&mut d.flat_content);
+
+                should_break
+            }
             Document::Array(arr)
             | Document::Indent(arr)
-            | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. }) => check_array(arr),
+            | Document::IndentIfBreak(IndentIfBreak { contents: arr, .. })
+            | Document::Align(Align { contents: arr, .. }) => check_array(arr),
+            Document::LineSuffix(arr) | Document::Fill(Fill { parts: arr }) => {
+                check_array(arr);
+
+                false
+            }
             _ => false,
         }
     }
@@ -0,0 +1,448 @@
+use std::ops::Range;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::escape::escape;
+use crate::escape::normalize_escapes;
+use crate::escape::split_sprintf_format;
+use crate::escape::unescape;
+use crate::escape::Quote;
+use crate::settings::FormatSettings;
+use crate::settings::StringInterpolationStyle;
+
+/// A part of a string built from literals and expressions.
+enum Piece<'a> {
+    Text(Vec<u8>),
+    Expression(&'a str),
+}
+
+/// Rewrites the strings of the given program according to the settings, before it is printed.
+///
+/// Converting a string to another form changes its tokens, not just their layout, so the rewritten code
+/// is parsed again and printed in place of the original.
+///
+/// # Returns
+///
+/// The rewritten code, or `None` if nothing needs to be rewritten.
+pub(crate) fn rewrite(
+    interner: &ThreadedInterner,
+    code: &str,
+    program: &Program,
+    settings: &FormatSettings,
+) -> Option<String> {
+    if settings.string_interpolation == StringInterpolationStyle::Preserve
+        && !settings.normalize_escapes
+        && !settings.heredoc_to_nowdoc
+    {
+        return None;
+    }
+
+    let mut rewriter = StringRewriter {
+        interner,
+        code,
+        settings,
+        namespaced: program.statements.iter().any(|statement| matches!(statement, Statement::Namespace(_))),
+        comments: program.trivia.iter().filter(|trivia| trivia.kind.is_comment()).map(|trivia| trivia.span).collect(),
+        edits: vec![],
+    };
+
+    rewriter.walk_program(program, &mut ());
+
+    // Strings are converted as a whole, so an edit within a converted string is discarded.
+    let mut edits = rewriter.edits;
+    edits.sort_by(|(a, _), (b, _)| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+
+    let mut result = String::with_capacity(code.len());
+    let mut offset = 0;
+    for (range, replacement) in edits {
+        if range.start < offset {
+            continue;
+        }
+
+        result.push_str(&code[offset..range.start]);
+        result.push_str(&replacement);
+        offset = range.end;
+    }
+
+    result.push_str(&code[offset..]);
+
+    (result != code).then_some(result)
+}
+
+struct StringRewriter<'a> {
+    interner: &'a ThreadedInterner,
+    code: &'a str,
+    settings: &'a FormatSettings,
+    namespaced: bool,
+    comments: Vec<Span>,
+    edits: Vec<(Range<usize>, String)>,
+}
+
+impl MutWalker<()> for StringRewriter<'_> {
+    fn walk_in_literal_string(&mut self, literal_string: &LiteralString, _: &mut ()) {
+        if !self.settings.normalize_escapes {
+            return;
+        }
+
+        let quote = match literal_string.kind {
+            LiteralStringKind::SingleQuoted => Quote::Single,
+            LiteralStringKind::DoubleQuoted => Quote::Double,
+        };
+
+        let raw = self.interner.lookup(&literal_string.value);
+        let delimiter = &raw[..1];
+        let content = &raw[1..raw.len() - 1];
+
+        self.edit(literal_string.span, format!("{}{}{}", delimiter, normalize_escapes(content, quote), delimiter));
+    }
+
+    fn walk_in_interpolated_string(&mut self, interpolated_string: &InterpolatedString, _: &mut ()) {
+        if matches!(
+            self.settings.string_interpolation,
+            StringInterpolationStyle::Concatenate | StringInterpolationStyle::Sprintf
+        ) {
+            if let Some(pieces) = self.get_interpolated_string_pieces(interpolated_string) {
+                self.convert(interpolated_string.span(), pieces);
+            }
+        }
+
+        self.normalize_parts(&interpolated_string.parts, Quote::Double);
+    }
+
+    fn walk_in_document_string(&mut self, document_string: &DocumentString, _: &mut ()) {
+        if document_string.kind == DocumentKind::Nowdoc {
+            return;
+        }
+
+        if self.settings.heredoc_to_nowdoc && is_plain_heredoc(self.interner, document_string) {
+            let open = &self.code[document_string.open.start.offset..document_string.open.end.offset];
+            let label = self.interner.lookup(&document_string.label);
+            let rest = open.find(label).map_or("", |position| &open[position + label.len()..]);
+
+            self.edit(document_string.open, format!("<<<'{}'{}", label, rest.trim_start_matches('"')));
+
+            return;
+        }
+
+        self.normalize_parts(&document_string.parts, Quote::Heredoc);
+    }
+
+    fn walk_in_binary(&mut self, binary: &Binary, _: &mut ()) {
+        if !binary.operator.is_concatenation()
+            || !matches!(
+                self.settings.string_interpolation,
+                StringInterpolationStyle::Interpolate | StringInterpolationStyle::Sprintf
+            )
+        {
+            return;
+        }
+
+        let mut pieces = vec![];
+        if self.collect_concatenation_pieces(&binary.lhs, &mut pieces).is_some()
+            && self.collect_concatenation_pieces(&binary.rhs, &mut pieces).is_some()
+        {
+            self.convert(binary.span(), pieces);
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, _: &mut ()) {
+        if !matches!(
+            self.settings.string_interpolation,
+            StringInterpolationStyle::Interpolate | StringInterpolationStyle::Concatenate
+        ) {
+            return;
+        }
+
+        if let Some(pieces) = self.get_sprintf_pieces(function_call) {
+            self.convert(function_call.span(), pieces);
+        }
+    }
+}
+
+impl<'a> StringRewriter<'a> {
+    fn edit(&mut self, span: Span, replacement: String) {
+        if self.code[span.start.offset..span.end.offset] != replacement {
+            self.edits.push((span.start.offset..span.end.offset, replacement));
+        }
+    }
+
+    fn normalize_parts(&mut self, parts: &Sequence<StringPart>, quote: Quote) {
+        if !self.settings.normalize_escapes {
+            return;
+        }
+
+        for part in parts.iter() {
+            if let StringPart::Literal(literal) = part {
+                self.edit(literal.span, normalize_escapes(self.interner.lookup(&literal.value), quote));
+            }
+        }
+    }
+
+    /// Replaces the given string with the form specified by the settings.
+    ///
+    /// Strings without any text or expression, and strings containing comments, are left as they are.
+    fn convert(&mut self, span: Span, pieces: Vec<Piece<'a>>) {
+        let pieces = merge_pieces(pieces);
+        if !pieces.iter().any(|piece| matches!(piece, Piece::Text(_)))
+            || !pieces.iter().any(|piece| matches!(piece, Piece::Expression(_)))
+            || self
+                .comments
+                .iter()
+                .any(|comment| span.start.offset <= comment.start.offset && comment.end.offset <= span.end.offset)
+        {
+            return;
+        }
+
+        let replacement = match self.settings.string_interpolation {
+            StringInterpolationStyle::Preserve => return,
+            StringInterpolationStyle::Interpolate => {
+                let mut string = String::from("\"");
+                for piece in pieces.iter() {
+                    match piece {
+                        Piece::Text(text) => {
+                            let Ok(text) = std::str::from_utf8(text) else {
+                                return;
+                            };
+
+                            string.push_str(&escape(text, Quote::Double));
+                        }
+                        Piece::Expression(expression) => {
+                            string.push('{');
+                            string.push_str(expression);
+                            string.push('}');
+                        }
+                    }
+                }
+
+                string.push('"');
+                string
+            }
+            StringInterpolationStyle::Concatenate => {
+                let mut operands = vec![];
+                for piece in pieces.iter() {
+                    operands.push(match piece {
+                        Piece::Text(text) => {
+                            let Some(literal) = get_string_literal(text) else {
+                                return;
+                            };
+
+                            literal
+                        }
+                        Piece::Expression(expression) => expression.to_string(),
+                    });
+                }
+
+                // The parentheses are removed by the printer where they are not needed.
+                format!("({})", operands.join(" . "))
+            }
+            StringInterpolationStyle::Sprintf => {
+                let mut format = vec![];
+                let mut arguments = vec![];
+                for piece in pieces.iter() {
+                    match piece {
+                        Piece::Text(text) => {
+                            for &byte in text.iter() {
+                                if byte == b'%' {
+                                    format.push(b'%');
+                                }
+
+                                format.push(byte);
+                            }
+                        }
+                        Piece::Expression(expression) => {
+                            format.extend_from_slice(b"%s");
+                            arguments.push(*expression);
+                        }
+                    }
+                }
+
+                let Some(format) = get_string_literal(&format) else {
+                    return;
+                };
+
+                let function = if self.namespaced { "\\sprintf" } else { "sprintf" };
+
+                format!("{}({}, {})", function, format, arguments.join(", "))
+            }
+        };
+
+        self.edit(span, replacement);
+    }
+
+    fn get_interpolated_string_pieces(&self, interpolated_string: &InterpolatedString) -> Option<Vec<Piece<'a>>> {
+        let mut pieces = vec![];
+        for part in interpolated_string.parts.iter() {
+            pieces.push(match part {
+                StringPart::Literal(literal) => {
+                    Piece::Text(unescape(self.interner.lookup(&literal.value), Quote::Double))
+                }
+                // Array accesses with an unquoted key, e.g. `"$foo[bar]"`, have a different meaning outside of
+                // a string, so only braced array accesses are moved.
+                StringPart::Expression(expression) => match expression.as_ref() {
+                    Expression::Variable(Variable::Direct(_)) | Expression::Access(_) if is_simple(expression) => {
+                        Piece::Expression(self.get_text(expression.span()))
+                    }
+                    _ => return None,
+                },
+                StringPart::BracedExpression(braced) if is_simple(&braced.expression) => {
+                    Piece::Expression(self.get_text(braced.expression.span()))
+                }
+                StringPart::BracedExpression(_) => return None,
+            });
+        }
+
+        Some(pieces)
+    }
+
+    fn collect_concatenation_pieces(&self, expression: &Expression, pieces: &mut Vec<Piece<'a>>) -> Option<()> {
+        match expression {
+            Expression::Parenthesized(parenthesized) => {
+                self.collect_concatenation_pieces(&parenthesized.expression, pieces)
+            }
+            Expression::Binary(binary) if binary.operator.is_concatenation() => {
+                self.collect_concatenation_pieces(&binary.lhs, pieces)?;
+                self.collect_concatenation_pieces(&binary.rhs, pieces)
+            }
+            Expression::Literal(Literal::String(literal_string)) => {
+                pieces.push(Piece::Text(get_literal_string_value(self.interner, literal_string)));
+
+                Some(())
+            }
+            Expression::CompositeString(CompositeString::Interpolated(interpolated_string)) => {
+                pieces.extend(self.get_interpolated_string_pieces(interpolated_string)?);
+
+                Some(())
+            }
+            _ if is_simple(expression) => {
+                pieces.push(Piece::Expression(self.get_text(expression.span())));
+
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn get_sprintf_pieces(&self, function_call: &FunctionCall) -> Option<Vec<Piece<'a>>> {
+        let (format, arguments) = get_sprintf_arguments(self.interner, function_call)?;
+        let texts = split_sprintf_format(&get_literal_string_value(self.interner, format))?;
+        if texts.len() != arguments.len() + 1 {
+            return None;
+        }
+
+        let mut pieces = vec![];
+        for (i, text) in texts.into_iter().enumerate() {
+            pieces.push(Piece::Text(text));
+            if let Some(argument) = arguments.get(i) {
+                if !is_simple(argument) {
+                    return None;
+                }
+
+                pieces.push(Piece::Expression(self.get_text(argument.span())));
+            }
+        }
+
+        Some(pieces)
+    }
+
+    fn get_text(&self, span: Span) -> &'a str {
+        &self.code[span.start.offset..span.end.offset]
+    }
+}
+
+/// Returns the format and the arguments of a `sprintf` call, if the format is a string literal.
+pub(crate) fn get_sprintf_arguments<'b>(
+    interner: &ThreadedInterner,
+    function_call: &'b FunctionCall,
+) -> Option<(&'b LiteralString, Vec<&'b Expression>)> {
+    let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+        return None;
+    };
+
+    if !interner.lookup(&identifier.value()).trim_start_matches('\\').eq_ignore_ascii_case("sprintf") {
+        return None;
+    }
+
+    let mut arguments = vec![];
+    for argument in function_call.argument_list.arguments.iter() {
+        match argument {
+            Argument::Positional(PositionalArgument { ellipsis: None, value }) => arguments.push(value),
+            _ => return None,
+        }
+    }
+
+    if arguments.is_empty() {
+        return None;
+    }
+
+    let Expression::Literal(Literal::String(format)) = arguments.remove(0) else {
+        return None;
+    };
+
+    Some((format, arguments))
+}
+
+/// Returns the value of a string literal.
+pub(crate) fn get_literal_string_value(interner: &ThreadedInterner, literal_string: &LiteralString) -> Vec<u8> {
+    let raw = interner.lookup(&literal_string.value);
+    let content = if raw.len() >= 2 { &raw[1..raw.len() - 1] } else { raw };
+
+    unescape(
+        content,
+        match literal_string.kind {
+            LiteralStringKind::SingleQuoted => Quote::Single,
+            LiteralStringKind::DoubleQuoted => Quote::Double,
+        },
+    )
+}
+
+/// Determines whether the given expression is simple enough to be moved between an interpolation,
+/// a concatenation, and the arguments of a `sprintf` call, e.g. `$foo`, `$foo->bar`, or `$foo['bar']`.
+fn is_simple(expression: &Expression) -> bool {
+    match expression {
+        Expression::Variable(Variable::Direct(_)) => true,
+        Expression::Access(Access::Property(PropertyAccess { object, property, .. }))
+        | Expression::Access(Access::NullSafeProperty(NullSafePropertyAccess { object, property, .. })) => {
+            matches!(property, ClassLikeMemberSelector::Identifier(_)) && is_simple(object)
+        }
+        Expression::ArrayAccess(ArrayAccess { array, index, .. }) => {
+            matches!(index.as_ref(), Expression::Literal(Literal::Integer(_) | Literal::String(_))) && is_simple(array)
+        }
+        _ => false,
+    }
+}
+
+/// Determines whether the given heredoc can be written as a nowdoc.
+fn is_plain_heredoc(interner: &ThreadedInterner, document_string: &DocumentString) -> bool {
+    document_string.parts.iter().all(|part| match part {
+        StringPart::Literal(literal) => !interner.lookup(&literal.value).contains('\\'),
+        _ => false,
+    })
+}
+
+/// Returns a string literal with the given value, single-quoted unless it contains control characters.
+fn get_string_literal(value: &[u8]) -> Option<String> {
+    let value = std::str::from_utf8(value).ok()?;
+
+    Some(if value.chars().any(|c| c.is_ascii_control()) {
+        format!("\"{}\"", escape(value, Quote::Double))
+    } else {
+        format!("'{}'", escape(value, Quote::Single))
+    })
+}
+
+fn merge_pieces(pieces: Vec<Piece<'_>>) -> Vec<Piece<'_>> {
+    let mut merged: Vec<Piece<'_>> = vec![];
+    for piece in pieces {
+        match (merged.last_mut(), piece) {
+            (Some(Piece::Text(previous)), Piece::Text(text)) => previous.extend(text),
+            (_, Piece::Text(text)) if text.is_empty() => {}
+            (_, piece) => merged.push(piece),
+        }
+    }
+
+    merged
+}
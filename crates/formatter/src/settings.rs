@@ -3,6 +3,8 @@ use std::str::FromStr;
 use serde::Deserialize;
 use serde::Serialize;
 
+use mago_php_version::PHPVersion;
+
 /// Format settings for the PHP printer.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
 pub struct FormatSettings {
@@ -45,6 +47,67 @@ pub struct FormatSettings {
     #[serde(default = "default_true")]
     pub single_quote: bool,
 
+    /// How to print strings that only interpolate simple variables, property accesses, and array accesses.
+    ///
+    /// Strings are converted between interpolation, concatenation, and `sprintf` calls using only `%s`
+    /// placeholders, as long as every embedded expression is simple enough to be moved.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // string_interpolation = Interpolate
+    /// $message = "Hello, {$user->name}!";
+    ///
+    /// // string_interpolation = Concatenate
+    /// $message = 'Hello, ' . $user->name . '!';
+    ///
+    /// // string_interpolation = Sprintf
+    /// $message = sprintf('Hello, %s!', $user->name);
+    /// ```
+    ///
+    /// Default: Preserve
+    #[serde(default)]
+    pub string_interpolation: StringInterpolationStyle,
+
+    /// Whether to normalize the escape sequences of strings.
+    ///
+    /// When enabled, backslashes that do not start an escape sequence are escaped explicitly, and the
+    /// digits of hexadecimal and unicode escape sequences are printed in uppercase.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // normalize_escapes = false
+    /// $pattern = "/\d+\x1b/";
+    ///
+    /// // normalize_escapes = true
+    /// $pattern = "/\\d+\x1B/";
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub normalize_escapes: bool,
+
+    /// Whether to convert heredocs that contain no interpolation and no escape sequences into nowdocs.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // heredoc_to_nowdoc = false
+    /// $text = <<<TEXT
+    ///     Hello, world!
+    ///     TEXT;
+    ///
+    /// // heredoc_to_nowdoc = true
+    /// $text = <<<'TEXT'
+    ///     Hello, world!
+    ///     TEXT;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub heredoc_to_nowdoc: bool,
+
     /// Enable or disable trailing commas in multi-line syntactic structures.
     ///
     /// When enabled, the formatter will add a trailing comma to the last element in a multi-line list, array,
@@ -382,6 +445,56 @@ pub struct FormatSettings {
     #[serde(default = "default_type_spacing")]
     pub type_spacing: usize,
 
+    /// Whether to add a space around the `|` separating the types of a catch clause,
+    /// regardless of `type_spacing`.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // space_around_catch_union = true
+    /// try {
+    /// } catch (FooException | BarException $e) {
+    /// }
+    ///
+    /// // space_around_catch_union = false
+    /// try {
+    /// } catch (FooException|BarException $e) {
+    /// }
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub space_around_catch_union: bool,
+
+    /// Whether to keep the own-line comments ending the body of a switch case at the indentation of the body,
+    /// instead of the indentation of the case.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // indent_trailing_case_comments = true
+    /// switch ($value) {
+    ///     case 1:
+    ///         foo();
+    ///         // no break
+    ///     case 2:
+    ///         bar();
+    /// }
+    ///
+    /// // indent_trailing_case_comments = false
+    /// switch ($value) {
+    ///     case 1:
+    ///         foo();
+    ///     // no break
+    ///     case 2:
+    ///         bar();
+    /// }
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub indent_trailing_case_comments: bool,
+
     /// The minimum length of a method call chain that triggers line-breaking formatting.
     ///
     /// When the number of chained method calls exceeds this threshold, the formatter will break the chain into multiple lines.
@@ -486,6 +599,380 @@ pub struct FormatSettings {
     /// Default: false
     #[serde(default = "default_false")]
     pub line_before_binary_operator: bool,
+    /// How to sort consecutive `use` statements.
+    ///
+    /// Sorting only applies to `use` statements that are not separated by other statements,
+    /// and is skipped if any comment appears between them.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // sort_uses = None
+    /// use Foo\Bar;
+    /// use App\baz;
+    /// use App\Qux;
+    ///
+    /// // sort_uses = Alphabetical
+    /// use App\Qux;
+    /// use App\baz;
+    /// use Foo\Bar;
+    ///
+    /// // sort_uses = CaseInsensitive
+    /// use App\baz;
+    /// use App\Qux;
+    /// use Foo\Bar;
+    /// ```
+    ///
+    /// Default: None
+    #[serde(default)]
+    pub sort_uses: UseSortOrder,
+
+    /// Whether to group `use` statements by kind, i.e. classes first, then functions, then constants,
+    /// with a blank line between each kind.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // separate_use_types = false
+    /// use App\Foo;
+    /// use function App\bar;
+    /// use App\Baz;
+    ///
+    /// // separate_use_types = true
+    /// use App\Foo;
+    /// use App\Baz;
+    ///
+    /// use function App\bar;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub separate_use_types: bool,
+
+    /// How to print grouped `use` statements, e.g. `use App\{Foo, Bar};`.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // use_grouping = Preserve
+    /// use App\{Foo, Bar};
+    /// use App\Baz;
+    ///
+    /// // use_grouping = Split
+    /// use App\Foo;
+    /// use App\Bar;
+    /// use App\Baz;
+    ///
+    /// // use_grouping = Merge
+    /// use App\{Foo, Bar, Baz};
+    /// ```
+    ///
+    /// Default: Preserve
+    #[serde(default)]
+    pub use_grouping: UseGroupingStyle,
+
+    /// Whether to remove duplicate `use` statements.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // remove_duplicate_uses = false
+    /// use App\Foo;
+    /// use App\Foo;
+    ///
+    /// // remove_duplicate_uses = true
+    /// use App\Foo;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub remove_duplicate_uses: bool,
+
+    /// Whether to normalize the blank lines between the members of classes, interfaces, traits, and enums.
+    ///
+    /// When enabled, methods are always surrounded by exactly one blank line, as are members of different kinds.
+    /// Consecutive members of the same kind, e.g. properties, are only separated by a blank line if one of them
+    /// has a docblock or attributes.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // separate_class_members = true
+    /// class Foo
+    /// {
+    ///     public const A = 1;
+    ///     public const B = 2;
+    ///
+    ///     public $foo;
+    ///     public $bar;
+    ///
+    ///     public function foo() {}
+    ///
+    ///     public function bar() {}
+    /// }
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub separate_class_members: bool,
+
+    /// Whether to sort the members of classes, interfaces, traits, and enums in canonical order.
+    ///
+    /// Members are ordered as: trait uses, constants, enum cases, properties, the constructor,
+    /// then public, protected, and private methods. Members of the same kind keep their relative order.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // sort_class_members = false
+    /// class Foo
+    /// {
+    ///     private function bar() {}
+    ///
+    ///     public function __construct() {}
+    ///
+    ///     use FooTrait;
+    /// }
+    ///
+    /// // sort_class_members = true
+    /// class Foo
+    /// {
+    ///     use FooTrait;
+    ///
+    ///     public function __construct() {}
+    ///
+    ///     private function bar() {}
+    /// }
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub sort_class_members: bool,
+
+    /// Whether to re-wrap the descriptions of docblocks at `print_width`.
+    ///
+    /// Inline code, inline tags, lists, headings, and tables are preserved.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // docblock_wrap_descriptions = false
+    /// /**
+    ///  * A very long description that goes well beyond the configured print width of the formatter and keeps going.
+    ///  */
+    ///
+    /// // docblock_wrap_descriptions = true
+    /// /**
+    ///  * A very long description that goes well beyond the configured print width of the formatter
+    ///  * and keeps going.
+    ///  */
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub docblock_wrap_descriptions: bool,
+
+    /// Whether to align the types, names, and descriptions of consecutive `@param` tags in columns.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // docblock_align_params = false
+    /// /**
+    ///  * @param int $a The first number.
+    ///  * @param string|null $name The name.
+    ///  */
+    ///
+    /// // docblock_align_params = true
+    /// /**
+    ///  * @param int         $a    The first number.
+    ///  * @param string|null $name The name.
+    ///  */
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub docblock_align_params: bool,
+
+    /// Whether to sort docblock tags in the canonical order: `@template`, `@param`, `@return`, then `@throws`.
+    ///
+    /// Other tags are left in place.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // docblock_sort_tags = false
+    /// /**
+    ///  * @return int
+    ///  * @param int $a
+    ///  */
+    ///
+    /// // docblock_sort_tags = true
+    /// /**
+    ///  * @param int $a
+    ///  * @return int
+    ///  */
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub docblock_sort_tags: bool,
+
+    /// Whether to normalize the spacing of types in docblock tags.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // docblock_normalize_types = false
+    /// /**
+    ///  * @param array<int,string> $a
+    ///  */
+    ///
+    /// // docblock_normalize_types = true
+    /// /**
+    ///  * @param array<int, string> $a
+    ///  */
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub docblock_normalize_types: bool,
+
+    /// Whether to align the assignment operators of consecutive assignment statements.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_assignments = false
+    /// $a = 1;
+    /// $foo = 2;
+    ///
+    /// // align_assignments = true
+    /// $a   = 1;
+    /// $foo = 2;
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_assignments: bool,
+
+    /// Whether to align the `=>` operators of multi-line associative arrays.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_array_arrows = false
+    /// $array = [
+    ///     'a' => 1,
+    ///     'foo' => 2,
+    /// ];
+    ///
+    /// // align_array_arrows = true
+    /// $array = [
+    ///     'a'   => 1,
+    ///     'foo' => 2,
+    /// ];
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_array_arrows: bool,
+
+    /// Whether to align the `=>` operators of match arms.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_match_arms = false
+    /// match ($value) {
+    ///     1 => 'one',
+    ///     10, 20 => 'many',
+    ///     default => 'other',
+    /// };
+    ///
+    /// // align_match_arms = true
+    /// match ($value) {
+    ///     1       => 'one',
+    ///     10, 20  => 'many',
+    ///     default => 'other',
+    /// };
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_match_arms: bool,
+
+    /// Whether to align the values of consecutive class constants.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // align_class_constants = false
+    /// class Foo {
+    ///     const A = 1;
+    ///     const FOO = 2;
+    /// }
+    ///
+    /// // align_class_constants = true
+    /// class Foo {
+    ///     const A   = 1;
+    ///     const FOO = 2;
+    /// }
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub align_class_constants: bool,
+
+    /// The maximum number of spaces that may be added to align an operator.
+    ///
+    /// If aligning a group of consecutive assignments, array elements, match arms, or class constants
+    /// requires more padding than this, the group is not aligned.
+    ///
+    /// Default: 20
+    #[serde(default = "default_alignment_max_padding")]
+    pub alignment_max_padding: usize,
+
+    /// Format files mixing inline HTML with PHP, such as views and themes, as templates.
+    ///
+    /// When enabled, inline HTML is printed exactly as it is, PHP blocks spanning multiple lines are
+    /// indented to the column of their opening tag, and single `echo` statements are printed using
+    /// the short echo tag.
+    ///
+    /// Example:
+    ///
+    /// ```php
+    /// // template_mode = false
+    /// <ul>
+    ///     <?php
+    /// $items = get_items();
+    /// ?>
+    ///     <li><?php echo $item; ?></li>
+    /// </ul>
+    ///
+    /// // template_mode = true
+    /// <ul>
+    ///     <?php
+    ///     $items = get_items();
+    ///     ?>
+    ///     <li><?= $item ?></li>
+    /// </ul>
+    /// ```
+    ///
+    /// Default: false
+    #[serde(default = "default_false")]
+    pub template_mode: bool,
+
+    /// The version of PHP the formatted code must run on.
+    ///
+    /// Heredocs and nowdocs are only re-indented using the flexible syntax of PHP 7.3 and later
+    /// if this version supports it, and are otherwise printed as they are.
+    ///
+    /// This is not a format option of its own, and is set from the `php_version` of the configuration.
+    ///
+    /// Default: the latest supported PHP version
+    #[serde(default = "default_php_version")]
+    pub php_version: PHPVersion,
 }
 
 impl Default for FormatSettings {
@@ -497,6 +984,9 @@ impl Default for FormatSettings {
             use_tabs: false,
             end_of_line: EndOfLine::default(),
             single_quote: true,
+            string_interpolation: StringInterpolationStyle::default(),
+            normalize_escapes: false,
+            heredoc_to_nowdoc: false,
             trailing_comma: true,
             space_around_declare_equals: false,
             keyword_case: CasingStyle::default(),
@@ -513,11 +1003,30 @@ impl Default for FormatSettings {
             static_before_visibility: false,
             null_type_hint: NullTypeHint::default(),
             type_spacing: default_type_spacing(),
+            space_around_catch_union: false,
+            indent_trailing_case_comments: false,
             method_chain_break_threshold: default_method_chain_break_threshold(),
             break_promoted_properties_list: true,
             space_concatenation: true,
             method_chain_breaking_style: MethodChainBreakingStyle::NextLine,
             line_before_binary_operator: false,
+            sort_uses: UseSortOrder::default(),
+            separate_use_types: false,
+            use_grouping: UseGroupingStyle::default(),
+            remove_duplicate_uses: false,
+            separate_class_members: false,
+            sort_class_members: false,
+            docblock_wrap_descriptions: false,
+            docblock_align_params: false,
+            docblock_sort_tags: false,
+            docblock_normalize_types: false,
+            align_assignments: false,
+            align_array_arrows: false,
+            align_match_arms: false,
+            align_class_constants: false,
+            alignment_max_padding: default_alignment_max_padding(),
+            template_mode: false,
+            php_version: default_php_version(),
         }
     }
 }
@@ -564,6 +1073,48 @@ pub enum MethodChainBreakingStyle {
     NextLine,
 }
 
+/// Specifies how to sort `use` statements.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum UseSortOrder {
+    #[default]
+    #[serde(alias = "none")]
+    None,
+    #[serde(alias = "alphabetical")]
+    Alphabetical,
+    #[serde(alias = "case_insensitive")]
+    CaseInsensitive,
+}
+
+/// Specifies how to print grouped `use` statements.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum UseGroupingStyle {
+    #[default]
+    #[serde(alias = "preserve")]
+    Preserve,
+    #[serde(alias = "split")]
+    Split,
+    #[serde(alias = "merge")]
+    Merge,
+}
+
+/// Specifies how to print strings that interpolate simple expressions.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub enum StringInterpolationStyle {
+    /// Print strings as they are written.
+    #[default]
+    #[serde(alias = "preserve")]
+    Preserve,
+    /// Interpolate the expressions into double-quoted strings, e.g. `"Hello, {$name}!"`.
+    #[serde(alias = "interpolate")]
+    Interpolate,
+    /// Concatenate the expressions with string literals, e.g. `'Hello, ' . $name . '!'`.
+    #[serde(alias = "concatenate")]
+    Concatenate,
+    /// Pass the expressions to a `sprintf` call, e.g. `sprintf('Hello, %s!', $name)`.
+    #[serde(alias = "sprintf")]
+    Sprintf,
+}
+
 impl BraceStyle {
     pub fn same_line() -> Self {
         Self::SameLine
@@ -642,6 +1193,14 @@ fn default_method_chain_break_threshold() -> usize {
     4
 }
 
+fn default_alignment_max_padding() -> usize {
+    20
+}
+
+fn default_php_version() -> PHPVersion {
+    PHPVersion::LATEST
+}
+
 fn default_false() -> bool {
     false
 }
@@ -0,0 +1,692 @@
+use std::fmt;
+
+use mago_ast::*;
+use mago_ast_utils::member::get_sorted_class_like_member_indices;
+use mago_interner::ThreadedInterner;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::escape::split_sprintf_format;
+use crate::escape::unescape;
+use crate::escape::Quote;
+use crate::rewrite::get_literal_string_value;
+use crate::rewrite::get_sprintf_arguments;
+use crate::settings::FormatSettings;
+
+/// An error describing how a formatted program differs from the original one.
+///
+/// Every variant carries the span, in the original program, of the node or comment that
+/// was changed, so that the difference can be reported to the user.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum VerificationError {
+    /// A node of the original program was changed in the formatted program.
+    ChangedNode { span: Span, original: String, formatted: String },
+    /// A node of the original program is missing from the formatted program.
+    MissingNode { span: Span, original: String },
+    /// The formatted program contains a node that does not exist in the original program.
+    AddedNode { span: Span, formatted: String },
+    /// A comment of the original program was changed or is missing from the formatted program.
+    ChangedComment { span: Span },
+    /// The formatted program contains a comment that does not exist in the original program.
+    AddedComment { span: Span },
+}
+
+/// A node of the program, stripped of its spans and trivia.
+///
+/// The span is kept only to report the location of a difference, and is never compared.
+#[derive(Debug, Clone)]
+struct Tree {
+    span: Span,
+    label: String,
+    children: Vec<Tree>,
+}
+
+/// The program a tree is built for.
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    interner: &'a ThreadedInterner,
+    code: &'a str,
+    settings: &'a FormatSettings,
+    /// Whether the program is the original one, whose modifiers and class members are put in the order
+    /// the formatter prints them in.
+    is_original: bool,
+}
+
+/// A part of a string built from literals and expressions.
+enum StringPiece {
+    Text(Vec<u8>),
+    Tree(Tree),
+}
+
+/// Verifies that the formatted program is equivalent to the original one.
+///
+/// The programs are compared node by node, ignoring spans and trivia, except for comments, which
+/// are compared separately regardless of their whitespace. Changes that the formatter is allowed to
+/// make, such as removing redundant parentheses, changing the case of keywords, the quotes of
+/// strings, the style of nullable types, or the order of `use` statements, are not considered differences.
+///
+/// Modifiers must be in the order set by `static_before_visibility`, and class members must be in
+/// canonical order if `sort_class_members` is enabled, or in their original order otherwise.
+///
+/// Strings are compared by value, so interpolations, concatenations, and `sprintf` calls using only `%s`
+/// placeholders are equivalent if they build the same string, and the indentation of heredocs is ignored.
+///
+/// PHP blocks echoing a single value are equivalent whether they use the `echo` statement or the short echo tag.
+///
+/// As class members are moved along with their comments, comments are compared regardless of their order.
+/// Docblocks are compared line by line with their whitespace collapsed, and may only be re-wrapped, have
+/// their tags sorted, or have their types normalized if the corresponding docblock settings are enabled.
+///
+/// # Arguments
+///
+/// * `interner` - The interner used to parse both programs.
+/// * `settings` - The settings the code was formatted with.
+/// * `original_code` - The code before formatting.
+/// * `original` - The program parsed from the code before formatting.
+/// * `formatted_code` - The formatted code.
+/// * `formatted` - The program parsed from the formatted code.
+///
+/// # Returns
+///
+/// `Ok(())` if the programs are equivalent, or the first difference found.
+pub fn verify(
+    interner: &ThreadedInterner,
+    settings: &FormatSettings,
+    original_code: &str,
+    original: &Program,
+    formatted_code: &str,
+    formatted: &Program,
+) -> Result<(), VerificationError> {
+    let context = Context { interner, code: original_code, settings, is_original: true };
+    let original_tree = build_tree(&context, Node::Program(original));
+    let context = Context { interner, code: formatted_code, settings, is_original: false };
+    let formatted_tree = build_tree(&context, Node::Program(formatted));
+
+    compare_trees(&original_tree, &formatted_tree)?;
+    compare_comments(interner, settings, original, formatted)
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ChangedNode { original, formatted, .. } => {
+                write!(f, "`{}` was changed to `{}`", original, formatted)
+            }
+            Self::MissingNode { original, .. } => {
+                write!(f, "`{}` is missing from the formatted code", original)
+            }
+            Self::AddedNode { formatted, .. } => {
+                write!(f, "`{}` was added to the formatted code", formatted)
+            }
+            Self::ChangedComment { .. } => {
+                write!(f, "a comment was changed or is missing from the formatted code")
+            }
+            Self::AddedComment { .. } => {
+                write!(f, "a comment was added to the formatted code")
+            }
+        }
+    }
+}
+
+impl std::error::Error for VerificationError {}
+
+impl HasSpan for VerificationError {
+    fn span(&self) -> Span {
+        match self {
+            Self::ChangedNode { span, .. }
+            | Self::MissingNode { span, .. }
+            | Self::AddedNode { span, .. }
+            | Self::ChangedComment { span }
+            | Self::AddedComment { span } => *span,
+        }
+    }
+}
+
+impl Tree {
+    fn leaf(span: Span, label: String) -> Self {
+        Self { span, label, children: vec![] }
+    }
+}
+
+fn compare_trees(original: &Tree, formatted: &Tree) -> Result<(), VerificationError> {
+    if original.label != formatted.label {
+        return Err(VerificationError::ChangedNode {
+            span: original.span,
+            original: original.label.clone(),
+            formatted: formatted.label.clone(),
+        });
+    }
+
+    for (original_child, formatted_child) in original.children.iter().zip(formatted.children.iter()) {
+        compare_trees(original_child, formatted_child)?;
+    }
+
+    if let Some(missing) = original.children.get(formatted.children.len()) {
+        return Err(VerificationError::MissingNode { span: missing.span, original: missing.label.clone() });
+    }
+
+    if let Some(added) = formatted.children.get(original.children.len()) {
+        return Err(VerificationError::AddedNode { span: original.span, formatted: added.label.clone() });
+    }
+
+    Ok(())
+}
+
+fn compare_comments(
+    interner: &ThreadedInterner,
+    settings: &FormatSettings,
+    original: &Program,
+    formatted: &Program,
+) -> Result<(), VerificationError> {
+    let comments = |program: &Program| {
+        program
+            .trivia
+            .iter()
+            .filter(|trivia| trivia.kind.is_comment())
+            .map(|trivia| {
+                let value = interner.lookup(&trivia.value);
+                if value.starts_with("/**") {
+                    return (trivia.span, normalize_docblock(settings, value));
+                }
+
+                // The formatter may re-indent comments, and add the missing `*` prefix to lines of block comments.
+                let text = value
+                    .lines()
+                    .flat_map(|line| line.trim().trim_start_matches('*').chars().filter(|c| !c.is_whitespace()))
+                    .collect::<String>();
+
+                (trivia.span, text)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut original_comments = comments(original);
+    let mut formatted_comments = comments(formatted);
+    original_comments.sort_by(|(a_span, a), (b_span, b)| a.cmp(b).then(a_span.cmp(b_span)));
+    formatted_comments.sort_by(|(a_span, a), (b_span, b)| a.cmp(b).then(a_span.cmp(b_span)));
+
+    for (i, (span, text)) in original_comments.iter().enumerate() {
+        if !matches!(formatted_comments.get(i), Some((_, formatted_text)) if formatted_text == text) {
+            return Err(VerificationError::ChangedComment { span: *span });
+        }
+    }
+
+    if formatted_comments.len() > original_comments.len() {
+        return Err(VerificationError::AddedComment { span: original.span() });
+    }
+
+    Ok(())
+}
+
+/// Returns the entries of the given docblock, one per line, with their whitespace collapsed.
+///
+/// A tag and the lines continuing it, or consecutive lines of text, form a single entry. The lines of an entry
+/// are joined if the formatter may re-wrap them, and the tags of each run of consecutive tags are sorted if the
+/// formatter may reorder them.
+fn normalize_docblock(settings: &FormatSettings, value: &str) -> String {
+    let lines = value.lines().map(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix("/**").unwrap_or(line);
+        let line = line.strip_suffix("*/").unwrap_or(line).trim();
+
+        line.strip_prefix('*').unwrap_or(line).split_whitespace().collect::<Vec<_>>().join(" ")
+    });
+
+    let mut entries: Vec<String> = vec![];
+    let mut continues_entry = false;
+    for line in lines {
+        if line.is_empty() {
+            continues_entry = false;
+
+            continue;
+        }
+
+        match entries.last_mut() {
+            Some(entry) if continues_entry && !line.starts_with('@') => {
+                entry.push(if settings.docblock_wrap_descriptions { ' ' } else { '\n' });
+                entry.push_str(&line);
+            }
+            _ => entries.push(line),
+        }
+
+        continues_entry = true;
+    }
+
+    if settings.docblock_normalize_types {
+        for entry in entries.iter_mut().filter(|entry| entry.starts_with('@')) {
+            *entry = entry.replace(" ,", ",").replace(", ", ",");
+        }
+    }
+
+    if settings.docblock_sort_tags {
+        let mut i = 0;
+        while i < entries.len() {
+            let length = entries[i..].iter().take_while(|entry| entry.starts_with('@')).count();
+            entries[i..i + length].sort();
+            i += length.max(1);
+        }
+    }
+
+    entries.join("\n")
+}
+
+fn build_tree(context: &Context<'_>, node: Node<'_>) -> Tree {
+    let Context { interner, code, .. } = *context;
+
+    if let Node::Expression(expression) = node {
+        if let Some(pieces) = get_string_pieces(context, expression) {
+            return build_string_tree(node.span(), "String", pieces);
+        }
+    }
+
+    match node {
+        // Parentheses are added and removed by the formatter, precedence changes show up in the structure.
+        Node::Expression(Expression::Parenthesized(parenthesized)) => {
+            return build_tree(context, Node::Expression(&parenthesized.expression));
+        }
+        Node::Hint(Hint::Parenthesized(parenthesized)) => {
+            return build_tree(context, Node::Hint(&parenthesized.hint));
+        }
+        // `?T`, `null|T`, and `T|null` are equivalent.
+        Node::Hint(Hint::Nullable(NullableHint { hint, .. })) => {
+            return build_nullable_hint_tree(context, node.span(), hint);
+        }
+        Node::Hint(Hint::Union(UnionHint { left, right, .. })) if matches!(left.as_ref(), Hint::Null(_)) => {
+            return build_nullable_hint_tree(context, node.span(), right);
+        }
+        Node::Hint(Hint::Union(UnionHint { left, right, .. })) if matches!(right.as_ref(), Hint::Null(_)) => {
+            return build_nullable_hint_tree(context, node.span(), left);
+        }
+        // Built-in types are case-insensitive.
+        Node::Hint(
+            Hint::Void(identifier)
+            | Hint::Never(identifier)
+            | Hint::Float(identifier)
+            | Hint::Bool(identifier)
+            | Hint::Integer(identifier)
+            | Hint::String(identifier)
+            | Hint::Object(identifier)
+            | Hint::Mixed(identifier)
+            | Hint::Iterable(identifier),
+        ) => {
+            return Tree::leaf(
+                identifier.span,
+                format!("Hint({})", interner.lookup(&identifier.value).to_ascii_lowercase()),
+            );
+        }
+        Node::LiteralString(string) => {
+            return Tree::leaf(
+                string.span,
+                format!("LiteralString({})", get_literal_string_value(interner, string).escape_ascii()),
+            );
+        }
+        Node::DocumentString(document_string) => {
+            return build_document_string_tree(context, document_string);
+        }
+        _ => {}
+    }
+
+    let mut children = node.children();
+    if let Node::Program(_) = node {
+        // A closing tag at the end of the file, followed by nothing but whitespace, is removed by the formatter.
+        while let Some(Node::Statement(Statement::ClosingTag(_) | Statement::Inline(_))) = children.last() {
+            if let Some(Node::Statement(Statement::Inline(inline))) = children.last() {
+                if !interner.lookup(&inline.value).trim().is_empty() {
+                    break;
+                }
+            }
+
+            children.pop();
+        }
+    }
+
+    if children.is_empty() {
+        let span = node.span();
+        let mut text = code[span.start.offset..span.end.offset].to_string();
+
+        // Whitespace is only significant in inline HTML and in the parts of strings.
+        if !matches!(node, Node::Inline(_) | Node::LiteralStringPart(_)) {
+            text.retain(|c| !c.is_whitespace());
+        }
+
+        // Keywords, tags, operators, casts, and numbers are case-insensitive.
+        if matches!(
+            node,
+            Node::Keyword(_)
+                | Node::FullOpeningTag(_)
+                | Node::ShortOpeningTag(_)
+                | Node::EchoOpeningTag(_)
+                | Node::ClosingTag(_)
+                | Node::BinaryOperator(_)
+                | Node::UnaryPrefixOperator(_)
+                | Node::UnaryPostfixOperator(_)
+                | Node::AssignmentOperator(_)
+                | Node::LiteralInteger(_)
+                | Node::LiteralFloat(_)
+        ) {
+            text = text.to_ascii_lowercase();
+        }
+
+        return Tree::leaf(span, format!("{}({})", node.kind(), text));
+    }
+
+    if context.is_original {
+        sort_children(context, node, &mut children);
+    }
+
+    let mut trees: Vec<Tree> = vec![];
+    let mut uses = vec![];
+    let mut index = 0;
+    while index < children.len() {
+        let child = children[index];
+        index += 1;
+
+        if let Some((value, length)) = get_echo_block(&children, index - 1) {
+            trees.push(Tree {
+                span: child.span(),
+                label: "EchoBlock".to_string(),
+                children: vec![build_tree(context, Node::Expression(value))],
+            });
+            index += length - 1;
+
+            continue;
+        }
+
+        if let Node::Statement(Statement::Use(r#use)) = child {
+            uses.push(r#use);
+
+            continue;
+        }
+
+        if !uses.is_empty() {
+            trees.push(build_imports_tree(interner, std::mem::take(&mut uses)));
+        }
+
+        trees.push(build_tree(context, child));
+    }
+
+    if !uses.is_empty() {
+        trees.push(build_imports_tree(interner, uses));
+    }
+
+    Tree { span: node.span(), label: node.kind().to_string(), children: trees }
+}
+
+/// Sorts the modifiers and class members of the given children of a node of the original program
+/// in the order the formatter prints them in.
+fn sort_children<'a>(context: &Context<'_>, node: Node<'a>, children: &mut [Node<'a>]) {
+    let mut i = 0;
+    while i < children.len() {
+        let length = children[i..].iter().take_while(|child| matches!(child, Node::Modifier(_))).count();
+        children[i..i + length].sort_by_key(|child| match child {
+            Node::Modifier(modifier) => get_modifier_rank(context.settings, modifier),
+            _ => 0,
+        });
+
+        i += length.max(1);
+    }
+
+    if !context.settings.sort_class_members {
+        return;
+    }
+
+    let members = match node {
+        Node::Class(class) => &class.members,
+        Node::Interface(interface) => &interface.members,
+        Node::Trait(r#trait) => &r#trait.members,
+        Node::Enum(r#enum) => &r#enum.members,
+        Node::AnonymousClass(anonymous_class) => &anonymous_class.members,
+        _ => return,
+    };
+
+    let Some(start) = children.iter().position(|child| matches!(child, Node::ClassLikeMember(_))) else {
+        return;
+    };
+
+    let run = &mut children[start..start + members.len()];
+    let sorted = get_sorted_class_like_member_indices(context.interner, members)
+        .into_iter()
+        .map(|index| run[index])
+        .collect::<Vec<_>>();
+
+    run.copy_from_slice(&sorted);
+}
+
+/// Returns the position of the given modifier in the order the formatter prints modifiers in.
+fn get_modifier_rank(settings: &FormatSettings, modifier: &Modifier) -> usize {
+    let (visibility, other) = if settings.static_before_visibility { (4, 2) } else { (2, 4) };
+
+    match modifier {
+        Modifier::Final(_) => 0,
+        Modifier::Abstract(_) => 1,
+        Modifier::Public(_) | Modifier::Protected(_) | Modifier::Private(_) => visibility,
+        Modifier::PublicSet(_) | Modifier::ProtectedSet(_) | Modifier::PrivateSet(_) => visibility + 1,
+        Modifier::Static(_) => other,
+        Modifier::Readonly(_) => other + 1,
+    }
+}
+
+/// Returns the value of the PHP block starting at the given index, and the number of statements in the block,
+/// if the block echoes a single value, e.g. `<?php echo $a; ?>` or `<?= $a ?>`.
+///
+/// The closing tag of the block may be missing if it is at the end of the children, as it is removed at the end of the file.
+fn get_echo_block<'a>(children: &[Node<'a>], index: usize) -> Option<(&'a Expression, usize)> {
+    let (value, terminator) = match (children.get(index)?, children.get(index + 1)?) {
+        (Node::Statement(Statement::OpeningTag(OpeningTag::Full(_))), Node::Statement(Statement::Echo(echo))) => {
+            let [value] = echo.values.as_slice() else {
+                return None;
+            };
+
+            (value, &echo.terminator)
+        }
+        (
+            Node::Statement(Statement::OpeningTag(OpeningTag::Echo(_))),
+            Node::Statement(Statement::Expression(statement)),
+        ) => (statement.expression.as_ref(), &statement.terminator),
+        _ => return None,
+    };
+
+    match (terminator, children.get(index + 2)) {
+        (Terminator::ClosingTag(_), _) | (Terminator::Semicolon(_), None) => Some((value, 2)),
+        (Terminator::Semicolon(_), Some(Node::Statement(Statement::ClosingTag(_)))) => Some((value, 3)),
+        _ => None,
+    }
+}
+
+/// Returns the parts of the string built by the given expression, if it is an interpolated string,
+/// a concatenation, or a `sprintf` call using only `%s` placeholders.
+fn get_string_pieces(context: &Context<'_>, expression: &Expression) -> Option<Vec<StringPiece>> {
+    let interner = context.interner;
+
+    match expression {
+        Expression::Parenthesized(parenthesized) => get_string_pieces(context, &parenthesized.expression),
+        Expression::Binary(binary) if binary.operator.is_concatenation() => {
+            let mut pieces = get_operand_pieces(context, &binary.lhs);
+            pieces.extend(get_operand_pieces(context, &binary.rhs));
+
+            Some(pieces)
+        }
+        Expression::CompositeString(CompositeString::Interpolated(interpolated_string)) => Some(
+            interpolated_string
+                .parts
+                .iter()
+                .map(|part| match part {
+                    StringPart::Literal(literal) => {
+                        StringPiece::Text(unescape(interner.lookup(&literal.value), Quote::Double))
+                    }
+                    StringPart::Expression(expression) => {
+                        StringPiece::Tree(build_tree(context, Node::Expression(expression)))
+                    }
+                    StringPart::BracedExpression(braced) => {
+                        StringPiece::Tree(build_tree(context, Node::Expression(&braced.expression)))
+                    }
+                })
+                .collect(),
+        ),
+        Expression::Call(Call::Function(function_call)) => {
+            let (format, arguments) = get_sprintf_arguments(interner, function_call)?;
+            let texts = split_sprintf_format(&get_literal_string_value(interner, format))?;
+            if texts.len() != arguments.len() + 1 {
+                return None;
+            }
+
+            let mut pieces = vec![];
+            for (i, text) in texts.into_iter().enumerate() {
+                pieces.push(StringPiece::Text(text));
+                if let Some(argument) = arguments.get(i) {
+                    pieces.push(StringPiece::Tree(build_tree(context, Node::Expression(argument))));
+                }
+            }
+
+            Some(pieces)
+        }
+        _ => None,
+    }
+}
+
+fn get_operand_pieces(context: &Context<'_>, mut expression: &Expression) -> Vec<StringPiece> {
+    while let Expression::Parenthesized(parenthesized) = expression {
+        expression = &parenthesized.expression;
+    }
+
+    if let Expression::Literal(Literal::String(literal_string)) = expression {
+        return vec![StringPiece::Text(get_literal_string_value(context.interner, literal_string))];
+    }
+
+    get_string_pieces(context, expression)
+        .unwrap_or_else(|| vec![StringPiece::Tree(build_tree(context, Node::Expression(expression)))])
+}
+
+/// Builds a tree for a heredoc or nowdoc, ignoring the indentation removed by its closing label.
+fn build_document_string_tree(context: &Context<'_>, document_string: &DocumentString) -> Tree {
+    let indent = match document_string.indentation {
+        DocumentIndentation::None => 0,
+        DocumentIndentation::Whitespace(n) | DocumentIndentation::Tab(n) => n,
+        DocumentIndentation::Mixed(t, w) => t + w,
+    };
+
+    let quote = match document_string.kind {
+        DocumentKind::Heredoc => Quote::Heredoc,
+        DocumentKind::Nowdoc => Quote::Nowdoc,
+    };
+
+    let mut pieces = vec![];
+    let mut at_line_start = true;
+    for part in document_string.parts.iter() {
+        let expression = match part {
+            StringPart::Literal(literal) => {
+                let content = context.interner.lookup(&literal.value);
+                let mut text = String::with_capacity(content.len());
+                for (i, line) in content.split_inclusive('\n').enumerate() {
+                    if i > 0 || at_line_start {
+                        let skipped = line
+                            .bytes()
+                            .take(indent)
+                            .take_while(|byte| byte.is_ascii_whitespace() && *byte != b'\n')
+                            .count();
+
+                        text.push_str(&line[skipped..]);
+                    } else {
+                        text.push_str(line);
+                    }
+                }
+
+                at_line_start = content.ends_with('\n');
+                pieces.push(StringPiece::Text(unescape(&text, quote)));
+
+                continue;
+            }
+            StringPart::Expression(expression) => expression,
+            StringPart::BracedExpression(braced) => &braced.expression,
+        };
+
+        at_line_start = false;
+        pieces.push(StringPiece::Tree(build_tree(context, Node::Expression(expression))));
+    }
+
+    build_string_tree(document_string.span(), "DocumentString", pieces)
+}
+
+fn build_string_tree(span: Span, label: &str, pieces: Vec<StringPiece>) -> Tree {
+    let mut children = vec![];
+    let mut text: Option<Vec<u8>> = None;
+    for piece in pieces {
+        match piece {
+            StringPiece::Text(bytes) => text.get_or_insert_with(Vec::new).extend(bytes),
+            StringPiece::Tree(tree) => {
+                if let Some(text) = text.take().filter(|text| !text.is_empty()) {
+                    children.push(Tree::leaf(span, format!("Text({})", text.escape_ascii())));
+                }
+
+                children.push(tree);
+            }
+        }
+    }
+
+    if let Some(text) = text.filter(|text| !text.is_empty()) {
+        children.push(Tree::leaf(span, format!("Text({})", text.escape_ascii())));
+    }
+
+    Tree { span, label: label.to_string(), children }
+}
+
+fn build_nullable_hint_tree(context: &Context<'_>, span: Span, hint: &Hint) -> Tree {
+    Tree { span, label: "NullableHint".to_string(), children: vec![build_tree(context, Node::Hint(hint))] }
+}
+
+/// Builds a single tree for a run of consecutive `use` statements, as the formatter may
+/// sort, split, merge, or deduplicate them.
+fn build_imports_tree(interner: &ThreadedInterner, uses: Vec<&Use>) -> Tree {
+    let mut imports = vec![];
+    for r#use in uses.iter() {
+        let mut import = |kind: Option<&UseType>, prefix: Option<&Identifier>, item: &UseItem| {
+            let kind = match kind {
+                None => "class",
+                Some(UseType::Function(_)) => "function",
+                Some(UseType::Const(_)) => "const",
+            };
+
+            let mut name = interner.lookup(&item.name.value()).trim_start_matches('\\').to_string();
+            if let Some(prefix) = prefix {
+                name = format!("{}\\{}", interner.lookup(&prefix.value()).trim_start_matches('\\'), name);
+            }
+
+            // Class and function names are case-insensitive.
+            if kind != "const" {
+                name = name.to_ascii_lowercase();
+            }
+
+            let alias = item.alias.as_ref().map(|alias| interner.lookup(&alias.identifier.value));
+
+            imports.push(match alias {
+                Some(alias) => format!("Import({} {} as {})", kind, name, alias),
+                None => format!("Import({} {})", kind, name),
+            });
+        };
+
+        match &r#use.items {
+            UseItems::Sequence(sequence) => sequence.items.iter().for_each(|item| import(None, None, item)),
+            UseItems::TypedSequence(sequence) => {
+                sequence.items.iter().for_each(|item| import(Some(&sequence.r#type), None, item))
+            }
+            UseItems::TypedList(list) => {
+                list.items.iter().for_each(|item| import(Some(&list.r#type), Some(&list.namespace), item))
+            }
+            UseItems::MixedList(list) => {
+                list.items.iter().for_each(|item| import(item.r#type.as_ref(), Some(&list.namespace), &item.item))
+            }
+        }
+    }
+
+    // Duplicate imports are a compile error, so removing them does not change the behavior.
+    imports.sort();
+    imports.dedup();
+
+    let span = uses[0].span().join(uses[uses.len() - 1].span());
+
+    Tree {
+        span,
+        label: "Imports".to_string(),
+        children: imports.into_iter().map(|import| Tree::leaf(span, import)).collect(),
+    }
+}
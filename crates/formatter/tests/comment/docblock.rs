@@ -0,0 +1,287 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_docblock_is_untouched_by_default() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * @return int
+         * @param array<int,string> $a
+         */
+        function foo(array $a): int
+        {
+            return 1;
+        }
+    "#};
+
+    test_format(code, code, FormatSettings::default());
+}
+
+#[test]
+pub fn test_docblock_wrap_descriptions() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * This is a very long description that goes well beyond the configured print width of the formatter, so it is wrapped.
+         * A short line that is merged with the `inline code` before it.
+         *
+         * - A list item that is long enough to be wrapped onto the next line with the continuation indented.
+         * - A short item.
+         *
+         * @return void
+         */
+        function foo(): void
+        {
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        /**
+         * This is a very long description that goes well beyond the configured print
+         * width of the formatter, so it is wrapped. A short line that is merged with
+         * the `inline code` before it.
+         *
+         * - A list item that is long enough to be wrapped onto the next line with the
+         *   continuation indented.
+         * - A short item.
+         *
+         * @return void
+         */
+        function foo(): void
+        {
+        }
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { print_width: 80, docblock_wrap_descriptions: true, ..Default::default() },
+    );
+}
+
+#[test]
+pub fn test_docblock_wrap_descriptions_uses_formatted_indentation() {
+    let code = indoc! {r#"
+        <?php
+
+        namespace App {
+        class Foo
+        {
+        /**
+         * A description that is long enough to be wrapped once it is indented within the class - even though
+         * it fits when it is not indented.
+         */
+        public function bar(): void
+        {
+        }
+        }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        namespace App {
+            class Foo
+            {
+                /**
+                 * A description that is long enough to be wrapped once it is indented within the class -
+                 * even though it fits when it is not indented.
+                 */
+                public function bar(): void
+                {
+                }
+            }
+        }
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { print_width: 100, docblock_wrap_descriptions: true, ..Default::default() },
+    );
+}
+
+#[test]
+pub fn test_docblock_with_missing_prefix() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * @param bool $throw If true, then throw an exception
+        instead of returning null.
+         * @param int $depth The depth.
+         */
+        function foo(bool $throw, int $depth): void
+        {
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        /**
+         * @param bool $throw If true, then throw an exception
+         * instead of returning null.
+         * @param int $depth The depth.
+         */
+        function foo(bool $throw, int $depth): void
+        {
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { docblock_align_params: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_docblock_align_params() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * Adds numbers.
+         *
+         * @param int $a The first number.
+         * @param list<int>|null $rest
+         * @param string ...$names The names.
+         * @return int
+         */
+        function foo(int $a, array $rest, string ...$names): int
+        {
+            return $a;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        /**
+         * Adds numbers.
+         *
+         * @param int            $a        The first number.
+         * @param list<int>|null $rest
+         * @param string         ...$names The names.
+         * @return int
+         */
+        function foo(int $a, array $rest, string ...$names): int
+        {
+            return $a;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { docblock_align_params: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_docblock_sort_tags() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * @throws RuntimeException
+         * @return T
+         * @see bar()
+         * @param T $value
+         * @template T
+         */
+        function foo(mixed $value): mixed
+        {
+            return $value;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        /**
+         * @template T
+         * @param T $value
+         * @see bar()
+         * @return T
+         * @throws RuntimeException
+         */
+        function foo(mixed $value): mixed
+        {
+            return $value;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { docblock_sort_tags: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_docblock_normalize_types() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * @param array<int,string> $a A list, of names.
+         * @param array{a: int,b:string} $b
+         * @param callable(int,string): void $c
+         * @return array<int ,  'a,b'>
+         */
+        function foo(array $a, array $b, callable $c): array
+        {
+            return [];
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        /**
+         * @param array<int, string> $a A list, of names.
+         * @param array{a: int, b:string} $b
+         * @param callable(int, string): void $c
+         * @return array<int, 'a,b'>
+         */
+        function foo(array $a, array $b, callable $c): array
+        {
+            return [];
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { docblock_normalize_types: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_docblock_with_code_is_preserved() {
+    let code = indoc! {r#"
+        <?php
+
+        /**
+         * Example:
+         *
+         * ```php
+         * foo(1,2);
+         * ```
+         *
+         *     $indented = code( 1 );
+         *
+         * @param int $a
+         */
+        function foo(int $a): void
+        {
+        }
+    "#};
+
+    test_format(
+        code,
+        code,
+        FormatSettings {
+            docblock_wrap_descriptions: true,
+            docblock_align_params: true,
+            docblock_sort_tags: true,
+            docblock_normalize_types: true,
+            ..Default::default()
+        },
+    );
+}
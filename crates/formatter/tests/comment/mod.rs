@@ -1 +1,2 @@
+pub mod docblock;
 pub mod multiline;
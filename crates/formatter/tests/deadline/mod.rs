@@ -0,0 +1,49 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use indoc::indoc;
+
+use mago_formatter::format;
+use mago_formatter::format_with_deadline;
+use mago_formatter::settings::FormatSettings;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+const CODE: &str = indoc! {r#"
+    <?php
+
+    function foo($a, $b) { return $a + $b; }
+"#};
+
+fn format_until(deadline: Instant) -> (Option<String>, String) {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let source_id = manager.insert_content("code.php".to_string(), CODE.to_string(), SourceCategory::default());
+    let source = manager.load(&source_id).expect("Failed to load source");
+    let (program, error) = parse_source(&interner, &source);
+    assert_eq!(error, None, "Error parsing code");
+
+    let settings = FormatSettings::default();
+
+    (
+        format_with_deadline(&interner, &source, &program, settings, deadline),
+        format(&interner, &source, &program, settings),
+    )
+}
+
+#[test]
+pub fn test_format_before_deadline() {
+    let (formatted, expected) = format_until(Instant::now() + Duration::from_secs(60));
+
+    assert_eq!(formatted, Some(expected));
+}
+
+#[test]
+pub fn test_format_after_deadline() {
+    let (formatted, _) = format_until(Instant::now());
+
+    assert_eq!(formatted, None);
+}
@@ -0,0 +1,157 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_align_assignments() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = 1;
+        $foo = 2;
+        $this->bar['baz'] .= 'qux';
+        // A comment breaks the group.
+        $b = 3;
+        $longer = 4;
+
+        $c = 5;
+        echo $c;
+        $d = 6;
+        $foobar = 7;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a                 = 1;
+        $foo               = 2;
+        $this->bar['baz'] .= 'qux';
+        // A comment breaks the group.
+        $b      = 3;
+        $longer = 4;
+
+        $c = 5;
+        echo $c;
+        $d      = 6;
+        $foobar = 7;
+    "#};
+
+    test_format(code, expected, FormatSettings { align_assignments: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_align_assignments_skips_groups_exceeding_max_padding() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = 1;
+        $aVeryLongVariableName = 2;
+    "#};
+
+    test_format(
+        code,
+        code,
+        FormatSettings { align_assignments: true, alignment_max_padding: 10, ..Default::default() },
+    );
+}
+
+#[test]
+pub fn test_align_array_arrows() {
+    let code = indoc! {r#"
+        <?php
+
+        $short = ['a' => 1, 'foo' => 2];
+
+        $long = [
+            'a' => 1,
+            'foo' => 2,
+            3,
+            'bar' => 4,
+            'bazqux' => 5,
+        ];
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $short = ['a' => 1, 'foo' => 2];
+
+        $long = [
+            'a'   => 1,
+            'foo' => 2,
+            3,
+            'bar'    => 4,
+            'bazqux' => 5,
+        ];
+    "#};
+
+    test_format(code, expected, FormatSettings { align_array_arrows: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_align_match_arms() {
+    let code = indoc! {r#"
+        <?php
+
+        $result = match ($value) {
+            1 => 'one',
+            10, 20 => 'many',
+            default => 'other',
+        };
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $result = match ($value) {
+            1       => 'one',
+            10, 20  => 'many',
+            default => 'other',
+        };
+    "#};
+
+    test_format(code, expected, FormatSettings { align_match_arms: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_align_class_constants() {
+    let code = indoc! {r#"
+        <?php
+
+        class Foo
+        {
+            const A = 1;
+            public const FOO = 2;
+            private const int BAR = 3;
+
+            const B = 4;
+            const BAZ = 5;
+
+            public function qux(): void
+            {
+            }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        class Foo
+        {
+            const A               = 1;
+            public const FOO      = 2;
+            private const int BAR = 3;
+
+            const B   = 4;
+            const BAZ = 5;
+
+            public function qux(): void
+            {
+            }
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { align_class_constants: true, ..Default::default() });
+}
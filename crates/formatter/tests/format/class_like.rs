@@ -0,0 +1,176 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_separate_class_members() {
+    let code = indoc! {r#"
+        <?php
+
+        class Foo
+        {
+            use Bar;
+            public const A = 1;
+
+            public const B = 2;
+            public $foo;
+
+
+            public $bar;
+            /**
+             * The baz.
+             */
+            public $baz;
+            public function foo()
+            {
+            }
+            public function bar()
+            {
+            }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        class Foo
+        {
+            use Bar;
+
+            public const A = 1;
+            public const B = 2;
+
+            public $foo;
+            public $bar;
+
+            /**
+             * The baz.
+             */
+            public $baz;
+
+            public function foo()
+            {
+            }
+
+            public function bar()
+            {
+            }
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { separate_class_members: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_sort_class_members() {
+    let code = indoc! {r#"
+        <?php
+
+        enum Suit: string
+        {
+            // Private helpers.
+            private function helper(): void
+            {
+            }
+
+            public function color(): string
+            {
+                return 'Red';
+            }
+
+            protected function label(): string
+            {
+                return 'Hearts';
+            }
+
+            case Hearts = 'H';
+            case Spades = 'S';
+
+            const Wild = self::Spades;
+
+            use SuitTrait;
+            // The end.
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        enum Suit: string
+        {
+            use SuitTrait;
+
+            const Wild = self::Spades;
+
+            case Hearts = 'H';
+            case Spades = 'S';
+
+            public function color(): string
+            {
+                return 'Red';
+            }
+
+            protected function label(): string
+            {
+                return 'Hearts';
+            }
+
+            // Private helpers.
+            private function helper(): void
+            {
+            }
+            // The end.
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { sort_class_members: true, ..Default::default() });
+}
+
+#[test]
+pub fn test_sort_and_separate_class_members() {
+    let code = indoc! {r#"
+        <?php
+
+        final class User
+        {
+            public function getName(): string
+            {
+                return $this->name;
+            }
+            public function __construct(string $name)
+            {
+                $this->name = $name;
+            }
+            private string $name;
+            private int $age = 0;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        final class User
+        {
+            private string $name;
+            private int $age = 0;
+
+            public function __construct(string $name)
+            {
+                $this->name = $name;
+            }
+
+            public function getName(): string
+            {
+                return $this->name;
+            }
+        }
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { sort_class_members: true, separate_class_members: true, ..Default::default() },
+    );
+}
@@ -177,3 +177,35 @@ pub fn test_inline_for_no_indent() {
 
     test_format(code, expected, FormatSettings::default())
 }
+
+#[test]
+pub fn test_trailing_case_comments() {
+    let code = indoc! {r#"
+        <?php
+
+        switch ($value) {
+            case 1:
+                foo();
+                // no break
+            default:
+                bar();
+                // trailing comment
+        }
+    "#};
+
+    let dedented = indoc! {r#"
+        <?php
+
+        switch ($value) {
+            case 1:
+                foo();
+            // no break
+            default:
+                bar();
+            // trailing comment
+        }
+    "#};
+
+    test_format(code, dedented, FormatSettings::default());
+    test_format(code, code, FormatSettings { indent_trailing_case_comments: true, ..Default::default() });
+}
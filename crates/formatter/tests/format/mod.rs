@@ -4,11 +4,15 @@ use mago_formatter::settings::FormatSettings;
 
 use crate::test_format;
 
+pub mod alignment;
 pub mod assignment;
 pub mod binaryish;
+pub mod class_like;
 pub mod control_structure;
 pub mod expression;
 pub mod string;
+pub mod template;
+pub mod uses;
 
 #[test]
 pub fn test_shebang() {
@@ -29,6 +33,23 @@ pub fn test_shebang() {
     test_format(code, expected, FormatSettings::default())
 }
 
+#[test]
+pub fn test_inline_html_is_kept_after_closing_tag_terminator() {
+    // Outside of template mode, a new line after `?>` would still be printed as part of the HTML.
+    let code = indoc! {r#"
+        <p><?php echo $a ?></p>
+        <p><?php return ?></p>
+        <p><?php foo() ?>
+        </p>
+        <?php
+
+        $b = 1;
+        echo $b ?><footer></footer>
+    "#};
+
+    test_format(code, code, FormatSettings { template_mode: false, ..Default::default() })
+}
+
 #[test]
 pub fn test_inline_html() {
     let code = indoc! {r#"
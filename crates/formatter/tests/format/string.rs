@@ -1,6 +1,8 @@
 use indoc::indoc;
 
 use mago_formatter::settings::FormatSettings;
+use mago_formatter::settings::StringInterpolationStyle;
+use mago_php_version::PHPVersion;
 
 use crate::test_format;
 
@@ -65,3 +67,235 @@ pub fn test_double_quote() {
 
     test_format(code, expected, FormatSettings { single_quote: false, ..Default::default() })
 }
+
+#[test]
+pub fn test_double_quote_does_not_interpolate() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = '$ref';
+        $b = '{$ref}';
+        $c = 'price: 5$';
+        $d = 'no dollar sign';
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = '$ref';
+        $b = '{$ref}';
+        $c = 'price: 5$';
+        $d = "no dollar sign";
+    "#};
+
+    test_format(code, expected, FormatSettings { single_quote: false, ..Default::default() })
+}
+
+#[test]
+pub fn test_string_interpolation() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = 'Hello, ' . $user->name . '!';
+        $b = sprintf('%s%% of %s', $rate, $items['total']);
+        $c = 'Hello, ' . $name . '!' . PHP_EOL;
+        $d = 'Hello, ' . /* name */ $name;
+        $e = sprintf('%d items', $count);
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = "Hello, {$user->name}!";
+        $b = "{$rate}% of {$items['total']}";
+        $c = "Hello, {$name}!" . PHP_EOL;
+        $d = 'Hello, ' . /* name */ $name;
+        $e = sprintf('%d items', $count);
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { string_interpolation: StringInterpolationStyle::Interpolate, ..Default::default() },
+    )
+}
+
+#[test]
+pub fn test_string_concatenation() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = "Hello, {$user->name}!\n";
+        $b = "Items: $count" . PHP_EOL;
+        $c = strlen("$a-$b");
+        $d = "Value: {$foo->bar()}";
+        $e = "Key: $items[key]";
+        $f = "v $w"[0];
+        $g = "v $w" + 1;
+        $h = "v $w" === $x;
+        $i = "v $w" ** 2;
+        $j = "v $w" ?: $y;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = 'Hello, ' . $user->name . "!\n";
+        $b = 'Items: ' . $count . PHP_EOL;
+        $c = strlen($a . '-' . $b);
+        $d = "Value: {$foo->bar()}";
+        $e = "Key: $items[key]";
+        $f = ('v ' . $w)[0];
+        $g = ('v ' . $w) + 1;
+        $h = ('v ' . $w) === $x;
+        $i = ('v ' . $w) ** 2;
+        $j = ('v ' . $w) ?: $y;
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { string_interpolation: StringInterpolationStyle::Concatenate, ..Default::default() },
+    )
+}
+
+#[test]
+pub fn test_string_sprintf() {
+    let code = indoc! {r#"
+        <?php
+
+        namespace App;
+
+        $a = "Progress: {$progress}%";
+        $b = 'Hello, ' . $name . '!';
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        namespace App;
+
+        $a = \sprintf('Progress: %s%%', $progress);
+        $b = \sprintf('Hello, %s!', $name);
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings { string_interpolation: StringInterpolationStyle::Sprintf, ..Default::default() },
+    )
+}
+
+#[test]
+pub fn test_normalize_escapes() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = '\d+ \' \\';
+        $b = "\d+ \x1b \u{1f600} \$ \" \{$c}";
+        $c = <<<EOT
+            \d+ \"quoted\" \n
+            EOT;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = '\\d+ \' \\';
+        $b = "\\d+ \x1B \u{1F600} \$ \" \{$c}";
+        $c = <<<EOT
+        \\d+ \\"quoted\\" \n
+        EOT;
+    "#};
+
+    test_format(code, expected, FormatSettings { normalize_escapes: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_heredoc_to_nowdoc() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = <<<"EOT"
+        Hello, world!
+        EOT;
+        $b = <<<EOT
+        Hello, $name!
+        EOT;
+        $c = <<<EOT
+        Hello, world!\n
+        EOT;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = <<<'EOT'
+        Hello, world!
+        EOT;
+        $b = <<<EOT
+        Hello, $name!
+        EOT;
+        $c = <<<EOT
+        Hello, world!\n
+        EOT;
+    "#};
+
+    test_format(code, expected, FormatSettings { heredoc_to_nowdoc: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_heredoc_is_reindented() {
+    let code = indoc! {r#"
+        <?php
+
+        function foo() {
+            return <<<EOT
+        Hello {$name}  world
+          done
+        EOT;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        function foo()
+        {
+            return <<<EOT
+            Hello {$name}  world
+              done
+            EOT;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings::default())
+}
+
+#[test]
+pub fn test_heredoc_is_not_reindented_before_php_73() {
+    let code = indoc! {r#"
+        <?php
+
+        function foo() {
+            return <<<EOT
+        Hello {$name}  world
+          done
+        EOT;
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        function foo()
+        {
+            return <<<EOT
+        Hello {$name}  world
+          done
+        EOT;
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { php_version: PHPVersion::PHP72, ..Default::default() })
+}
@@ -0,0 +1,131 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+
+use crate::test_format;
+
+#[test]
+pub fn test_template_blocks_are_aligned() {
+    let code = indoc! {r#"
+        <html>
+          <body>
+            <?php if ($user): ?>
+              <p>Hello, <?= $user->name ?>!</p>
+            <?php endif; ?>
+            <?php
+          $x = 1;
+              $y = foo($x,
+            2);
+            ?>
+            <p><?php echo $x; ?></p>
+          </body>
+        </html>
+    "#};
+
+    let expected = indoc! {r#"
+        <html>
+          <body>
+            <?php if ($user): ?>
+              <p>Hello, <?= $user->name ?>!</p>
+            <?php endif; ?>
+            <?php
+            $x = 1;
+            $y = foo($x, 2);
+            ?>
+            <p><?= $x ?></p>
+          </body>
+        </html>
+    "#};
+
+    test_format(code, expected, FormatSettings { template_mode: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_template_nested_blocks_are_aligned() {
+    let code = indoc! {r#"
+        <ul>
+        	<?php foreach ($items as $item): ?>
+        		<?php
+        		if ($item->hidden) {
+        		continue;
+        		}
+        		?>
+        		<li><?= $item->name; ?></li>
+        	<?php endforeach; ?>
+        </ul>
+    "#};
+
+    let expected = indoc! {r#"
+        <ul>
+        	<?php foreach ($items as $item): ?>
+        		<?php
+        		if ($item->hidden) {
+        		    continue;
+        		}
+        		?>
+        		<li><?= $item->name ?></li>
+        	<?php endforeach; ?>
+        </ul>
+    "#};
+
+    test_format(code, expected, FormatSettings { template_mode: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_template_echo_is_kept_when_not_shortenable() {
+    let code = indoc! {r#"
+        <p><?php echo $a, $b; ?></p>
+        <p><?php echo /* name */ $name; ?></p>
+        <p><?php echo $title; ?>
+    "#};
+
+    let expected = indoc! {r#"
+        <p><?php echo $a, $b; ?></p>
+        <p><?php echo /* name */ $name; ?></p>
+        <p><?php echo $title;
+    "#};
+
+    test_format(code, expected, FormatSettings { template_mode: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_template_inline_html_is_kept_after_closing_tag_terminator() {
+    let code = indoc! {r#"
+        <p><?php echo $a, $b ?></p>
+        <p><?php foo() ?>
+        </p>
+    "#};
+
+    test_format(code, code, FormatSettings { template_mode: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_template_inline_if_conditions_are_kept_on_one_line() {
+    let code = indoc! {r#"
+        <html>
+            <?php if ($foo): ?>
+                Foo
+            <?php elseif (
+                $bar
+            ): ?>
+                Bar
+            <?php else: ?>
+                Neither
+            <?php endif; ?>
+        </html>
+    "#};
+
+    let expected = indoc! {r#"
+        <html>
+            <?php if ($foo): ?>
+                Foo
+            <?php elseif ($bar): ?>
+                Bar
+            <?php else: ?>
+                Neither
+            <?php endif; ?>
+        </html>
+    "#};
+
+    test_format(code, expected, FormatSettings { template_mode: true, ..Default::default() })
+}
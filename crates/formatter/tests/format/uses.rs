@@ -0,0 +1,230 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+use mago_formatter::settings::UseGroupingStyle;
+use mago_formatter::settings::UseSortOrder;
+
+use crate::test_format;
+
+#[test]
+pub fn test_uses_are_preserved_by_default() {
+    let code = indoc! {r#"
+        <?php
+
+        use Foo\Bar;
+        use App\{Qux, Baz};
+        use App\Baz;
+
+        use function App\helper;
+    "#};
+
+    test_format(code, code, FormatSettings::default())
+}
+
+#[test]
+pub fn test_sort_uses_alphabetically() {
+    let code = indoc! {r#"
+        <?php
+
+        namespace App;
+
+        use Foo\Bar;
+        use App\baz;
+
+        use App\Qux, App\Bar\Baz;
+        use function App\helper;
+
+        final class Foo
+        {
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        namespace App;
+
+        use App\Bar\Baz, App\Qux;
+        use App\baz;
+        use function App\helper;
+        use Foo\Bar;
+
+        final class Foo
+        {
+        }
+    "#};
+
+    test_format(code, expected, FormatSettings { sort_uses: UseSortOrder::Alphabetical, ..Default::default() })
+}
+
+#[test]
+pub fn test_sort_uses_case_insensitively() {
+    let code = indoc! {r#"
+        <?php
+
+        use Foo\Bar;
+        use App\Qux;
+        use App\baz;
+        use App\{Zoo, Bar as Alias};
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use App\{Bar as Alias, Zoo};
+        use App\baz;
+        use App\Qux;
+        use Foo\Bar;
+    "#};
+
+    test_format(code, expected, FormatSettings { sort_uses: UseSortOrder::CaseInsensitive, ..Default::default() })
+}
+
+#[test]
+pub fn test_separate_use_types() {
+    let code = indoc! {r#"
+        <?php
+
+        use const App\VERSION;
+        use App\Foo;
+        use App\Baz;
+        use function App\helper;
+        use App\{Bar, function other, const OTHER};
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use App\Foo;
+        use App\Baz;
+        use App\{Bar};
+
+        use function App\helper;
+        use function App\{other};
+
+        use const App\VERSION;
+        use const App\{OTHER};
+    "#};
+
+    test_format(code, expected, FormatSettings { separate_use_types: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_split_grouped_uses() {
+    let code = indoc! {r#"
+        <?php
+
+        use App\{Foo, Bar as Baz, function helper};
+        use function App\Sub\{first, second};
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use App\Foo;
+        use App\Bar as Baz;
+        use function App\helper;
+        use function App\Sub\first;
+        use function App\Sub\second;
+    "#};
+
+    test_format(code, expected, FormatSettings { use_grouping: UseGroupingStyle::Split, ..Default::default() })
+}
+
+#[test]
+pub fn test_merge_uses() {
+    let code = indoc! {r#"
+        <?php
+
+        use App\Foo;
+        use Exception;
+        use App\Bar as Baz;
+        use function App\helper;
+        use App\Sub\Qux;
+        use function App\other;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use App\{Bar as Baz, Foo};
+        use function App\{helper, other};
+        use App\Sub\Qux;
+        use Exception;
+    "#};
+
+    test_format(
+        code,
+        expected,
+        FormatSettings {
+            use_grouping: UseGroupingStyle::Merge,
+            sort_uses: UseSortOrder::Alphabetical,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+pub fn test_remove_duplicate_uses() {
+    let code = indoc! {r#"
+        <?php
+
+        use App\Foo;
+        use App\{Bar, Foo};
+        use App\foo;
+        use const App\FOO;
+        use const App\Foo;
+        use const App\FOO;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use App\Foo;
+        use App\{Bar};
+        use const App\FOO;
+        use const App\Foo;
+    "#};
+
+    test_format(code, expected, FormatSettings { remove_duplicate_uses: true, ..Default::default() })
+}
+
+#[test]
+pub fn test_uses_with_comments_are_preserved() {
+    let code = indoc! {r#"
+        <?php
+
+        // Imports
+        use Foo\Bar;
+        // The application
+        use App\Baz;
+        use App\Qux; // Trailing
+    "#};
+
+    test_format(code, code, FormatSettings { sort_uses: UseSortOrder::Alphabetical, ..Default::default() })
+}
+
+#[test]
+pub fn test_sorted_uses_keep_surrounding_comments() {
+    let code = indoc! {r#"
+        <?php
+
+        // Imports
+        use Foo\Bar;
+        use App\Baz; // Trailing
+
+        echo 1;
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        // Imports
+        use App\Baz;
+        use Foo\Bar; // Trailing
+
+        echo 1;
+    "#};
+
+    test_format(code, expected, FormatSettings { sort_uses: UseSortOrder::Alphabetical, ..Default::default() })
+}
@@ -1,17 +1,21 @@
 use mago_formatter::settings::FormatSettings;
+use mago_formatter::verify::verify;
 use mago_interner::ThreadedInterner;
 use mago_parser::parse_source;
 use mago_source::SourceCategory;
 use mago_source::SourceManager;
 
 pub mod comment;
+pub mod deadline;
 pub mod format;
 pub mod parens;
+pub mod preset;
+pub mod verify;
 
 /// Test that the given code is formatted to the expected result.
 ///
 /// This function will parse the given code, format it, parse the formatted code, and then format it again
-/// to ensure that the formatter is idempotent.
+/// to ensure that the formatter is idempotent, and that the formatted code is equivalent to the original code.
 ///
 /// # Arguments
 ///
@@ -30,10 +34,13 @@ pub fn test_format(code: impl AsRef<str>, expected: &str, settings: FormatSettin
     pretty_assertions::assert_eq!(expected, formatted_code, "Formatted code does not match expected");
 
     let formatted_code_id =
-        manager.insert_content("formatted_code.php".to_string(), formatted_code, SourceCategory::default());
+        manager.insert_content("formatted_code.php".to_string(), formatted_code.clone(), SourceCategory::default());
     let formatted_code_source = manager.load(&formatted_code_id).expect("Failed to load formatted code source");
     let (formatted_code_program, error) = parse_source(&interner, &formatted_code_source);
     assert_eq!(error, None, "Error parsing formatted code");
+    let verification =
+        verify(&interner, &settings, code.as_ref(), &code_program, &formatted_code, &formatted_code_program);
+    assert_eq!(verification, Ok(()), "Formatted code is not equivalent");
     let reformatted_code = mago_formatter::format(&interner, &formatted_code_source, &formatted_code_program, settings);
     pretty_assertions::assert_eq!(expected, reformatted_code, "Reformatted code does not match expected");
 }
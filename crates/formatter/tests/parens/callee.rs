@@ -28,3 +28,28 @@ pub fn test_callee_needs_parens() {
 
     test_format(code, expected, FormatSettings::default())
 }
+
+#[test]
+pub fn test_accessed_binary_needs_parens_once() {
+    let code = indoc! {r#"
+        <?php
+
+        $a = ($foo ?? $bar)[$baz];
+        $b = ($foo . $bar)[0];
+        $c = (($foo . $bar))->baz;
+        $d = ($foo . $bar)::BAZ;
+        $e = $foo[$bar . $baz];
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        $a = ($foo ?? $bar)[$baz];
+        $b = ($foo . $bar)[0];
+        $c = ($foo . $bar)->baz;
+        $d = ($foo . $bar)::BAZ;
+        $e = $foo[$bar . $baz];
+    "#};
+
+    test_format(code, expected, FormatSettings::default())
+}
@@ -0,0 +1,39 @@
+<?php
+
+namespace App\Http\Controllers;
+
+use App\Models\User;
+use Illuminate\Http\Request;
+use Illuminate\Support\Facades\Cache;
+use Illuminate\View\View;
+
+class UserController extends Controller
+{
+    /**
+     * Display the profile of the given user.
+     */
+    public function show(Request $request, string $id): View
+    {
+        $user = Cache::remember('users.'.$id, 60, fn () => User::findOrFail($id));
+
+        return view('user.profile', [
+            'user' => $user,
+            'posts' => $user->posts()->latest()->take(10)->get(),
+        ]);
+    }
+
+    /**
+     * Store a new user.
+     */
+    public function store(Request $request): RedirectResponse
+    {
+        $validated = $request->validate([
+            'name' => ['required', 'string', 'max:255'],
+            'email' => ['required', 'email', 'unique:users'],
+        ]);
+
+        $user = User::create($validated);
+
+        return redirect()->route('users.show', ['id' => $user->id]);
+    }
+}
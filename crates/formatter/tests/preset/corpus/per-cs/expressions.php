@@ -0,0 +1,15 @@
+<?php
+
+$greeting = 'Hello ' . $name . '!';
+
+$instance = new Foo();
+
+$func = fn (int $x, int $y): int => $x + $y;
+
+$returnValue = match ($expr) {
+    0 => 'First case',
+    1, 2, 3 => multipleCases(),
+    default => 'Default case',
+};
+
+$someVeryLongResultVariable = $someVeryLongFunctionName($someVeryLongArgumentName, $anotherVeryLongArgumentName);
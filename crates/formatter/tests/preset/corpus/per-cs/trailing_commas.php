@@ -0,0 +1,21 @@
+<?php
+
+function beep(
+    string $someVeryLongArgumentName,
+    string $anotherVeryLongArgumentName,
+    string $yetAnotherVeryLongArgumentName,
+): string {
+    return $someVeryLongArgumentName . $anotherVeryLongArgumentName . $yetAnotherVeryLongArgumentName;
+}
+
+$array = [
+    'someVeryLongKeyName' => $someVeryLongValueName,
+    'anotherVeryLongKeyName' => $anotherVeryLongValueName,
+    'yetAnotherVeryLongKeyName' => $yetAnotherVeryLongValueName,
+];
+
+$result = someVeryLongFunctionName(
+    $someVeryLongArgumentName,
+    $anotherVeryLongArgumentName,
+    $yetAnotherVeryLongArgumentName,
+);
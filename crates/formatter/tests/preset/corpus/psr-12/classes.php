@@ -0,0 +1,39 @@
+<?php
+
+namespace Vendor\Package;
+
+use FooClass;
+use BarClass as Bar;
+use OtherVendor\OtherPackage\BazClass;
+
+class ClassName extends ParentClass implements \ArrayAccess, \Countable
+{
+    use FirstTrait;
+    use SecondTrait;
+    use ThirdTrait;
+
+    public $foo = null;
+    public static int $bar = 0;
+
+    public function fooBarBaz($arg1, &$arg2, $arg3 = [])
+    {
+        // method body
+    }
+}
+
+abstract class AbstractClassName
+{
+    protected static $foo;
+
+    abstract protected function zim();
+
+    final public static function bar()
+    {
+        // method body
+    }
+}
+
+function fooBarBaz($arg1, &$arg2, $arg3 = [])
+{
+    // function body
+}
@@ -0,0 +1,15 @@
+<?php
+
+$i++;
+++$j;
+
+$intValue = (int) $input;
+
+if ($a === $b) {
+    $foo = $bar ?? $a ?? $b;
+} elseif ($a > $b) {
+    $foo = $a + $b + $c;
+}
+
+$variable = $foo ? 'foo' : 'bar';
+$variable = $foo ?: 'bar';
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use mago_formatter::preset::FormatPreset;
+
+use crate::test_format;
+
+/// Test that every file in the conformance corpus of the given preset is left unchanged by the formatter.
+///
+/// The corpus of each preset consists of the examples published alongside the coding standard it follows.
+fn test_preset_corpus(directory: &str, preset: FormatPreset) {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/preset/corpus").join(directory);
+
+    let mut files = std::fs::read_dir(&directory)
+        .expect("Failed to read corpus directory")
+        .map(|entry| entry.expect("Failed to read corpus entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "php"))
+        .collect::<Vec<_>>();
+
+    files.sort();
+
+    assert!(!files.is_empty(), "Corpus directory `{}` is empty", directory.display());
+
+    for file in files {
+        let code = std::fs::read_to_string(&file).expect("Failed to read corpus file");
+
+        test_format(&code, &code, preset.settings());
+    }
+}
+
+#[test]
+fn test_psr12_corpus() {
+    test_preset_corpus("psr-12", FormatPreset::Psr12);
+}
+
+#[test]
+fn test_per_cs_corpus() {
+    test_preset_corpus("per-cs", FormatPreset::PerCs);
+}
+
+#[test]
+fn test_laravel_corpus() {
+    test_preset_corpus("laravel", FormatPreset::Laravel);
+}
+
+#[test]
+fn test_symfony_corpus() {
+    test_preset_corpus("symfony", FormatPreset::Symfony);
+}
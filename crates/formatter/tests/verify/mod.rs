@@ -0,0 +1,287 @@
+use indoc::indoc;
+
+use mago_formatter::settings::FormatSettings;
+use mago_formatter::verify::verify;
+use mago_formatter::verify::VerificationError;
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+fn verify_code(original: &str, formatted: &str, settings: FormatSettings) -> Result<(), VerificationError> {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let original_id =
+        manager.insert_content("original.php".to_string(), original.to_string(), SourceCategory::default());
+    let original_source = manager.load(&original_id).expect("Failed to load original source");
+    let (original_program, error) = parse_source(&interner, &original_source);
+    assert_eq!(error, None, "Error parsing original code");
+
+    let formatted_id =
+        manager.insert_content("formatted.php".to_string(), formatted.to_string(), SourceCategory::default());
+    let formatted_source = manager.load(&formatted_id).expect("Failed to load formatted source");
+    let (formatted_program, error) = parse_source(&interner, &formatted_source);
+    assert_eq!(error, None, "Error parsing formatted code");
+
+    verify(&interner, &settings, original, &original_program, formatted, &formatted_program)
+}
+
+#[test]
+pub fn test_equivalent_code_is_verified() {
+    let original = indoc! {r#"
+        <?PHP
+
+        use App\{function bar};
+        use App\Foo, App\Baz;
+
+        final class Qux {
+            PUBLIC STATIC ?STRING $value = "Hello, world!";
+            public function run((Foo&Baz)|null $foo): void { return (bar(1 + 2)); }
+        }
+        /*
+          Comment
+         */
+        ?>
+    "#};
+
+    let formatted = indoc! {r#"
+        <?php
+
+        use App\Baz;
+        use App\Foo;
+        use function App\bar;
+
+        final class Qux
+        {
+            static public null|string $value = 'Hello, world!';
+
+            public function run(null|(Foo&Baz) $foo): void
+            {
+                return bar(1 + 2);
+            }
+        }
+        /*
+         * Comment
+         */
+    "#};
+
+    assert_eq!(
+        verify_code(original, formatted, FormatSettings { static_before_visibility: true, ..Default::default() }),
+        Ok(())
+    );
+}
+
+#[test]
+pub fn test_changed_precedence_is_rejected() {
+    let original = "<?php\n\n$a = ($b + $c) * $d;\n";
+    let formatted = "<?php\n\n$a = $b + $c * $d;\n";
+
+    assert!(matches!(
+        verify_code(original, formatted, FormatSettings::default()),
+        Err(VerificationError::ChangedNode { .. })
+    ));
+}
+
+#[test]
+pub fn test_changed_string_is_rejected() {
+    let original = "<?php\n\n$a = '$ref';\n";
+    let formatted = "<?php\n\n$a = \"$ref\";\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+
+    let original = "<?php\n\n$a = 'a\\nb';\n";
+    let formatted = "<?php\n\n$a = \"a\\nb\";\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+}
+
+#[test]
+pub fn test_dropped_comment_is_rejected() {
+    let original = "<?php\n\n// Comment\n$a = 1;\n";
+    let formatted = "<?php\n\n$a = 1;\n";
+
+    assert!(matches!(
+        verify_code(original, formatted, FormatSettings::default()),
+        Err(VerificationError::ChangedComment { .. })
+    ));
+}
+
+#[test]
+pub fn test_dropped_statement_is_rejected() {
+    let original = "<?php\n\n$a = 1;\n$b = 2;\n";
+    let formatted = "<?php\n\n$a = 1;\n";
+
+    assert!(matches!(
+        verify_code(original, formatted, FormatSettings::default()),
+        Err(VerificationError::MissingNode { .. })
+    ));
+}
+
+#[test]
+pub fn test_reordered_class_members_are_verified() {
+    let original = "<?php\n\nclass A {\n    // Bar.\n    public function bar() {}\n    public $foo;\n}\n";
+    let formatted = "<?php\n\nclass A {\n    public $foo;\n    // Bar.\n    public function bar() {}\n}\n";
+
+    assert_eq!(
+        verify_code(original, formatted, FormatSettings { sort_class_members: true, ..Default::default() }),
+        Ok(())
+    );
+}
+
+#[test]
+pub fn test_reordered_class_members_are_rejected_without_sorting() {
+    let original = "<?php\n\nclass A {\n    public function bar() {}\n    public $foo;\n}\n";
+    let formatted = "<?php\n\nclass A {\n    public $foo;\n    public function bar() {}\n}\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+}
+
+#[test]
+pub fn test_reordered_modifiers_are_verified() {
+    let original = "<?php\n\nclass A {\n    static public $foo;\n    readonly public int $bar;\n}\n";
+    let formatted = "<?php\n\nclass A {\n    public static $foo;\n    public readonly int $bar;\n}\n";
+
+    assert_eq!(verify_code(original, formatted, FormatSettings::default()), Ok(()));
+    assert!(verify_code(original, formatted, FormatSettings { static_before_visibility: true, ..Default::default() })
+        .is_err());
+}
+
+#[test]
+pub fn test_changed_class_member_is_rejected() {
+    let original = "<?php\n\nclass A {\n    public function bar() {}\n    public $foo;\n}\n";
+    let formatted = "<?php\n\nclass A {\n    public $foo;\n    public function baz() {}\n}\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+}
+
+#[test]
+pub fn test_equivalent_strings_are_verified() {
+    let original = indoc! {r#"
+        <?php
+
+        $a = "Hello, {$user->name}!\n";
+        $b = 'Hello, ' . $name . '!';
+        $c = sprintf('%s%%', $rate);
+        $d = <<<EOT
+        Hello $name  world
+          done
+        EOT;
+    "#};
+
+    let formatted = indoc! {r#"
+        <?php
+
+        $a = sprintf("Hello, %s!\n", $user->name);
+        $b = "Hello, {$name}!";
+        $c = $rate . '%';
+        $d = <<<EOT
+            Hello $name  world
+              done
+            EOT;
+    "#};
+
+    assert_eq!(verify_code(original, formatted, FormatSettings::default()), Ok(()));
+}
+
+#[test]
+pub fn test_changed_interpolation_is_rejected() {
+    let original = "<?php\n\n$a = \"Hello, {$name}!\";\n";
+    let formatted = "<?php\n\n$a = 'Hello, ' . $user . '!';\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+
+    let original = "<?php\n\n$a = <<<EOT\n  Hello {$name}  world\n  EOT;\n";
+    let formatted = "<?php\n\n$a = <<<EOT\n  Hello {$name}world\n  EOT;\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+}
+
+#[test]
+pub fn test_echo_tags_are_verified() {
+    let original = "<div><?php echo $a; ?></div><p><?php echo $b ?></p><p><?= $c; ?></p>\n";
+    let formatted = "<div><?= $a ?></div><p><?= $b ?></p><p><?= $c ?></p>\n";
+
+    assert_eq!(verify_code(original, formatted, FormatSettings::default()), Ok(()));
+
+    let original = "<div><?php echo $a, $b; ?></div>\n";
+    let formatted = "<div><?= $a ?></div>\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+}
+
+#[test]
+pub fn test_docblocks_are_verified() {
+    let original = indoc! {r#"
+        <?php
+
+        /**
+         * Adds two numbers.
+         *
+         * @return int
+         * @param   int  $a
+         * @param int $b
+         */
+        function add($a, $b) {}
+    "#};
+
+    let formatted = indoc! {r#"
+        <?php
+
+        /**
+         * Adds two numbers.
+         *
+         * @return int
+         * @param int $a
+         * @param int $b
+         */
+        function add($a, $b) {}
+    "#};
+
+    assert_eq!(verify_code(original, formatted, FormatSettings::default()), Ok(()));
+
+    let formatted = indoc! {r#"
+        <?php
+
+        /**
+         * Adds two numbers.
+         *
+         * @param int $a
+         * @param int $b
+         * @return int
+         */
+        function add($a, $b) {}
+    "#};
+
+    assert!(matches!(
+        verify_code(original, formatted, FormatSettings::default()),
+        Err(VerificationError::ChangedComment { .. })
+    ));
+    assert_eq!(
+        verify_code(original, formatted, FormatSettings { docblock_sort_tags: true, ..Default::default() }),
+        Ok(())
+    );
+}
+
+#[test]
+pub fn test_changed_docblocks_are_rejected() {
+    let original = "<?php\n\n/**\n * Returns the sum.\n *\n * @return int\n */\nfunction sum() {}\n";
+    let formatted = "<?php\n\n/**\n * Returns the mus.\n *\n * @return int\n */\nfunction sum() {}\n";
+
+    assert!(matches!(
+        verify_code(original, formatted, FormatSettings::default()),
+        Err(VerificationError::ChangedComment { .. })
+    ));
+}
+
+#[test]
+pub fn test_rewrapped_docblocks_are_verified() {
+    let original = "<?php\n\n/**\n * A long description\n * that continues here.\n */\nfunction foo() {}\n";
+    let formatted = "<?php\n\n/**\n * A long description that continues here.\n */\nfunction foo() {}\n";
+
+    assert!(verify_code(original, formatted, FormatSettings::default()).is_err());
+    assert_eq!(
+        verify_code(original, formatted, FormatSettings { docblock_wrap_descriptions: true, ..Default::default() }),
+        Ok(())
+    );
+}
@@ -1,4 +1,5 @@
 use ahash::HashMap;
+use mago_php_version::feature::Feature;
 use mago_php_version::PHPVersion;
 use serde::Serialize;
 use toml::Value;
@@ -67,6 +68,11 @@ pub struct RuleDefinition {
     /// The maximum PHP version supported by this rule (exclusive), if any.
     pub maximum_supported_php_version: Option<PHPVersion>,
 
+    /// The PHP feature detected by this rule, if any.
+    ///
+    /// The rule only applies to PHP versions that do not support this feature.
+    pub feature: Option<Feature>,
+
     /// Whether this rule is deprecated and should not be used.
     pub deprecated: bool,
 }
@@ -153,6 +159,7 @@ impl RuleDefinition {
             deprecated: false,
             minimum_supported_php_version: None,
             maximum_supported_php_version: None,
+            feature: None,
         }
     }
 
@@ -176,6 +183,7 @@ impl RuleDefinition {
             deprecated: false,
             minimum_supported_php_version: None,
             maximum_supported_php_version: None,
+            feature: None,
         }
     }
 
@@ -249,6 +257,23 @@ impl RuleDefinition {
         self
     }
 
+    /// Sets the PHP feature detected by this rule.
+    ///
+    /// The rule is only applied when the configured PHP version does not support the feature,
+    /// as determined by [`PHPVersion::is_supported`].
+    ///
+    /// # Parameters
+    ///
+    /// * `feature` - The PHP feature detected by this rule.
+    ///
+    /// # Returns
+    ///
+    /// A modified `RuleDefinition` with the feature set.
+    pub fn with_feature(mut self, feature: Feature) -> Self {
+        self.feature = Some(feature);
+        self
+    }
+
     /// Marks this rule as deprecated, meaning it should not be used.
     ///
     /// # Returns
@@ -271,6 +296,7 @@ impl RuleDefinition {
     }
 
     /// Checks whether this rule supports a given PHP version, based on its minimum and maximum
+    /// supported versions, and on whether the version supports the feature detected by the rule.
     ///
     /// # Parameters
     ///
@@ -292,6 +318,12 @@ impl RuleDefinition {
             }
         }
 
+        if let Some(feature) = self.feature {
+            if version.is_supported(feature) {
+                return false;
+            }
+        }
+
         true
     }
 }
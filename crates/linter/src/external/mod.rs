@@ -0,0 +1,563 @@
+//! Support for third-party linter plugins compiled to WebAssembly.
+//!
+//! External plugins are WebAssembly modules that are executed in a sandboxed interpreter, they have
+//! no access to the file system, the network, or the host environment, except through the narrow
+//! interface described below. All data is exchanged as UTF-8 encoded JSON, using the structures
+//! defined in the [`view`] module.
+//!
+//! # Module interface
+//!
+//! A plugin module must export:
+//!
+//! - `memory`: the linear memory of the module.
+//! - `mago_alloc(size: i32) -> i32`: allocates `size` bytes in the module memory, and returns
+//!   a pointer to them, the host uses this to pass data to the plugin.
+//! - `mago_plugin_definition() -> i64`: returns a [`PluginDefinitionView`] describing the plugin
+//!   and its rules.
+//! - `mago_lint(pointer: i32, length: i32) -> i64`: receives a [`LintInput`], and returns a list
+//!   of [`IssueView`]s.
+//!
+//! Values returned as `i64` are packed slices, where the upper 32 bits are the pointer, and the
+//! lower 32 bits are the length of the returned JSON document.
+//!
+//! A plugin module may import `mago.query(pointer: i32, length: i32) -> i64`, which receives a
+//! [`QueryView`] and returns the JSON serialized result, or `null` if nothing was found.
+//!
+//! Instances of a plugin module are reused across calls, so a plugin must release the memory it
+//! allocates through `mago_alloc` once it is done with it. An instance is discarded if a call fails.
+//!
+//! Annotations and fixes returned by a plugin must use byte offsets that lie on character boundaries
+//! of the linted source, annotations and fixes with invalid offsets are dropped, and reported as errors.
+//!
+//! [`PluginDefinitionView`]: view::PluginDefinitionView
+//! [`LintInput`]: view::LintInput
+//! [`IssueView`]: view::IssueView
+//! [`QueryView`]: view::QueryView
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use wasmi::Caller;
+use wasmi::Config;
+use wasmi::Engine;
+use wasmi::Extern;
+use wasmi::Instance;
+use wasmi::Linker;
+use wasmi::Memory;
+use wasmi::Module;
+use wasmi::Store;
+
+use mago_ast::Program;
+use mago_fixer::FixOperation;
+use mago_fixer::FixPlan;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_span::Position;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::PluginDefinition;
+use crate::definition::RuleDefinition;
+use crate::external::view::IssueView;
+use crate::external::view::LintInput;
+use crate::external::view::NameView;
+use crate::external::view::PluginDefinitionView;
+use crate::external::view::QueryView;
+use crate::external::view::RuleDefinitionView;
+use crate::external::view::SourceView;
+use crate::external::view::ABI_VERSION;
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod view;
+
+/// The default amount of fuel given to a plugin for each call.
+///
+/// Every executed WebAssembly instruction consumes fuel, once it runs out, the call is aborted.
+/// This prevents a misbehaving plugin from hanging the linter.
+pub const DEFAULT_FUEL: u64 = 10_000_000_000;
+
+/// An error that occurred while loading or running an external plugin.
+#[derive(Debug)]
+pub enum ExternalPluginError {
+    /// The plugin file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The plugin module could not be compiled, instantiated, or executed.
+    Wasm(String),
+    /// The plugin module does not export a required item.
+    MissingExport(&'static str),
+    /// The data exchanged with the plugin is not valid.
+    InvalidData(String),
+}
+
+/// A linter plugin loaded from a WebAssembly module.
+#[derive(Debug, Clone)]
+pub struct ExternalPlugin {
+    name: &'static str,
+    description: &'static str,
+    rules: Vec<RuleDefinition>,
+    module: Arc<ExternalModule>,
+}
+
+/// A rule provided by an [`ExternalPlugin`].
+#[derive(Debug, Clone)]
+pub struct ExternalRule {
+    definition: RuleDefinition,
+    module: Arc<ExternalModule>,
+}
+
+#[derive(Debug)]
+struct ExternalModule {
+    engine: Engine,
+    module: Module,
+    fuel: u64,
+    /// The codebase that host functions query on behalf of the plugin.
+    codebase: Arc<CodebaseReflection>,
+    /// Instances that are not in use, they are reused to avoid instantiating the module on every call.
+    instances: Mutex<Vec<ExternalInstance>>,
+}
+
+#[derive(Debug)]
+struct ExternalInstance {
+    store: Store<HostState>,
+    instance: Instance,
+}
+
+/// The state made available to host functions while a plugin is running.
+#[derive(Debug)]
+struct HostState {
+    interner: ThreadedInterner,
+    codebase: Arc<CodebaseReflection>,
+    position: Position,
+}
+
+impl ExternalPlugin {
+    /// Loads an external plugin from the WebAssembly module at the given path.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path to the `.wasm` file.
+    /// - `codebase`: The codebase the plugin can query, usually the one of the linter it is added to.
+    ///
+    /// # Returns
+    ///
+    /// The loaded plugin, or an error if the module could not be read, compiled, or does not
+    /// implement the plugin interface.
+    pub fn load(path: impl AsRef<Path>, codebase: Arc<CodebaseReflection>) -> Result<Self, ExternalPluginError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| ExternalPluginError::Io(path.to_path_buf(), error))?;
+
+        Self::from_bytes(&bytes, codebase)
+    }
+
+    /// Loads an external plugin from the given WebAssembly module bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The binary WebAssembly module.
+    /// - `codebase`: The codebase the plugin can query, usually the one of the linter it is added to.
+    ///
+    /// # Returns
+    ///
+    /// The loaded plugin, or an error if the module could not be compiled, or does not
+    /// implement the plugin interface.
+    pub fn from_bytes(bytes: &[u8], codebase: Arc<CodebaseReflection>) -> Result<Self, ExternalPluginError> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes).map_err(ExternalPluginError::from_wasm)?;
+        let module = Arc::new(ExternalModule {
+            engine,
+            module,
+            fuel: DEFAULT_FUEL,
+            codebase,
+            instances: Mutex::new(Vec::new()),
+        });
+
+        let state = HostState {
+            interner: ThreadedInterner::new(),
+            codebase: module.codebase.clone(),
+            position: Position::dummy(0),
+        };
+
+        let definition =
+            module.call(state, |store, instance| call_packed(store, instance, "mago_plugin_definition", ()))?;
+        let definition: PluginDefinitionView =
+            serde_json::from_slice(&definition).map_err(ExternalPluginError::from_json)?;
+
+        Ok(Self {
+            name: leak(definition.name),
+            description: leak(definition.description),
+            rules: definition.rules.into_iter().map(RuleDefinitionView::into_definition).collect(),
+            module,
+        })
+    }
+}
+
+impl Plugin for ExternalPlugin {
+    fn get_definition(&self) -> PluginDefinition {
+        PluginDefinition { name: self.name, description: self.description, enabled_by_default: true }
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        self.rules
+            .iter()
+            .map(|definition| {
+                Box::new(ExternalRule { definition: definition.clone(), module: self.module.clone() }) as Box<dyn Rule>
+            })
+            .collect()
+    }
+}
+
+impl Rule for ExternalRule {
+    fn get_definition(&self) -> RuleDefinition {
+        self.definition.clone()
+    }
+
+    fn lint(&self, program: &Program, context: &mut LintContext<'_>) {
+        match self.run(program, context) {
+            Ok(issues) => {
+                let source = context.semantics.source.identifier;
+                let content = context.interner.lookup(&context.semantics.source.content);
+
+                for issue in issues {
+                    let mut converted = Issue::new(issue.level.unwrap_or(context.level()), issue.message);
+                    for annotation in issue.annotations {
+                        if !is_valid_range(content, annotation.start, annotation.end) {
+                            tracing::error!(
+                                "External rule `{}` returned an annotation with invalid offsets {}..{}, it was dropped.",
+                                context.rule.slug,
+                                annotation.start,
+                                annotation.end
+                            );
+
+                            continue;
+                        }
+
+                        let span =
+                            Span::new(Position::new(source, annotation.start), Position::new(source, annotation.end));
+                        let mut converted_annotation = Annotation::new(annotation.kind, span);
+                        if let Some(message) = annotation.message {
+                            converted_annotation = converted_annotation.with_message(message);
+                        }
+
+                        converted = converted.with_annotation(converted_annotation);
+                    }
+
+                    for note in issue.notes {
+                        converted = converted.with_note(note);
+                    }
+
+                    if let Some(help) = issue.help {
+                        converted = converted.with_help(help);
+                    }
+
+                    if let Some(link) = issue.link {
+                        converted = converted.with_link(link);
+                    }
+
+                    match issue.fix {
+                        Some(plan) if !is_valid_fix(content, &plan) => {
+                            tracing::error!(
+                                "External rule `{}` returned a fix with invalid offsets, it was dropped.",
+                                context.rule.slug
+                            );
+
+                            context.report(converted);
+                        }
+                        Some(plan) => context.report_with_fix(converted, |p| p.merge(plan)),
+                        None => context.report(converted),
+                    }
+                }
+            }
+            Err(error) => {
+                tracing::error!("External rule `{}` failed: {}", context.rule.slug, error);
+            }
+        }
+    }
+}
+
+impl ExternalRule {
+    fn run(&self, program: &Program, context: &LintContext<'_>) -> Result<Vec<IssueView>, ExternalPluginError> {
+        let source = &context.semantics.source;
+
+        let mut names = context
+            .semantics
+            .names
+            .all()
+            .into_iter()
+            .map(|(offset, (name, imported))| NameView {
+                offset: *offset,
+                name: context.interner.lookup(name),
+                imported: *imported,
+            })
+            .collect::<Vec<_>>();
+        names.sort_by_key(|name| name.offset);
+
+        let input = LintInput {
+            abi_version: ABI_VERSION,
+            rule: self.definition.name,
+            php_version: context.php_version.to_string(),
+            options: &context.rule.settings.options,
+            source: SourceView {
+                name: context.interner.lookup(&source.identifier.value()),
+                content: context.interner.lookup(&source.content),
+            },
+            names,
+            program,
+        };
+
+        let input = serde_json::to_vec(&input).map_err(ExternalPluginError::from_json)?;
+        let state = HostState {
+            interner: context.interner.clone(),
+            codebase: self.module.codebase.clone(),
+            position: Position::start_of(source.identifier),
+        };
+
+        let output = self.module.call(state, |store, instance| {
+            let (pointer, length) = write_to_guest(store, instance, &input)?;
+
+            call_packed(store, instance, "mago_lint", (pointer, length))
+        })?;
+
+        serde_json::from_slice(&output).map_err(ExternalPluginError::from_json)
+    }
+}
+
+impl RuleDefinitionView {
+    fn into_definition(self) -> RuleDefinition {
+        let name = leak(self.name);
+
+        match self.level {
+            Some(level) => RuleDefinition::enabled(name, level),
+            None => RuleDefinition::disabled(name),
+        }
+        .with_description(leak(self.description))
+    }
+}
+
+impl ExternalModule {
+    /// Calls the given function with an instance of the module, reusing an idle instance if there is one.
+    ///
+    /// The instance is given the provided state and a full tank of fuel, and is made available again
+    /// once the function returns, unless it failed, in which case the state of the instance is unknown.
+    fn call<T>(
+        &self,
+        state: HostState,
+        f: impl FnOnce(&mut Store<HostState>, &Instance) -> Result<T, ExternalPluginError>,
+    ) -> Result<T, ExternalPluginError> {
+        let idle = self.instances.lock().expect("Unable to lock instances: poisoned lock").pop();
+        let mut instance = match idle {
+            Some(mut instance) => {
+                *instance.store.data_mut() = state;
+
+                instance
+            }
+            None => self.instantiate(state)?,
+        };
+
+        instance.store.set_fuel(self.fuel).map_err(ExternalPluginError::from_wasm)?;
+
+        let result = f(&mut instance.store, &instance.instance)?;
+        self.instances.lock().expect("Unable to lock instances: poisoned lock").push(instance);
+
+        Ok(result)
+    }
+
+    fn instantiate(&self, state: HostState) -> Result<ExternalInstance, ExternalPluginError> {
+        let mut store = Store::new(&self.engine, state);
+
+        let mut linker = Linker::new(&self.engine);
+        linker.func_wrap("mago", "query", query).map_err(ExternalPluginError::from_wasm)?;
+
+        let instance = linker
+            .instantiate(&mut store, &self.module)
+            .and_then(|instance| instance.start(&mut store))
+            .map_err(ExternalPluginError::from_wasm)?;
+
+        Ok(ExternalInstance { store, instance })
+    }
+}
+
+impl ExternalPluginError {
+    fn from_wasm(error: impl std::fmt::Display) -> Self {
+        Self::Wasm(error.to_string())
+    }
+
+    fn from_json(error: serde_json::Error) -> Self {
+        Self::InvalidData(error.to_string())
+    }
+}
+
+impl std::fmt::Display for ExternalPluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(path, error) => write!(f, "failed to read plugin `{}`: {}", path.display(), error),
+            Self::Wasm(error) => write!(f, "webassembly error: {}", error),
+            Self::MissingExport(name) => write!(f, "plugin does not export `{}`", name),
+            Self::InvalidData(error) => write!(f, "invalid data exchanged with plugin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ExternalPluginError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ExternalRule {}
+
+/// Handles a `mago.query` call from a plugin.
+fn query(mut caller: Caller<'_, HostState>, pointer: i32, length: i32) -> i64 {
+    let Some(memory) = get_memory(&caller) else {
+        return 0;
+    };
+
+    let Some(bytes) = read_from_guest(memory.data(&caller), pointer, length) else {
+        return 0;
+    };
+
+    let result = match serde_json::from_slice::<QueryView>(bytes) {
+        Ok(query) => resolve_query(caller.data(), query),
+        Err(error) => serde_json::json!({ "error": error.to_string() }),
+    };
+
+    let Ok(result) = serde_json::to_vec(&result) else {
+        return 0;
+    };
+
+    let Some(alloc) = caller.get_export("mago_alloc").and_then(Extern::into_func) else {
+        return 0;
+    };
+
+    let Ok(alloc) = alloc.typed::<i32, i32>(&caller) else {
+        return 0;
+    };
+
+    let Ok(target) = alloc.call(&mut caller, result.len() as i32) else {
+        return 0;
+    };
+
+    if memory.write(&mut caller, target as u32 as usize, &result).is_err() {
+        return 0;
+    }
+
+    pack(target, result.len() as i32)
+}
+
+fn resolve_query(state: &HostState, query: QueryView) -> serde_json::Value {
+    let interner = &state.interner;
+    let codebase = &state.codebase;
+
+    let result = match query {
+        QueryView::Lookup { id } => serde_json::to_value(interner.lookup(&StringIdentifier::new(id))),
+        QueryView::ClassLike { name } => {
+            serde_json::to_value(codebase.get_named_class_like(interner, &interner.intern(name)))
+        }
+        QueryView::Function { name } => serde_json::to_value(codebase.get_function(interner, &interner.intern(name))),
+        QueryView::Constant { name } => serde_json::to_value(codebase.get_constant(interner, &interner.intern(name))),
+        QueryView::EnclosingClassLike { offset } => {
+            serde_json::to_value(codebase.get_enclosing_class_like(&Position::new(state.position.source, offset)))
+        }
+        QueryView::EnclosingFunctionLike { offset } => {
+            serde_json::to_value(codebase.get_enclosing_function_like(&Position::new(state.position.source, offset)))
+        }
+    };
+
+    result.unwrap_or(serde_json::Value::Null)
+}
+
+fn call_packed<Params: wasmi::WasmParams>(
+    store: &mut Store<HostState>,
+    instance: &Instance,
+    name: &'static str,
+    params: Params,
+) -> Result<Vec<u8>, ExternalPluginError> {
+    let function =
+        instance.get_typed_func::<Params, i64>(&*store, name).map_err(|_| ExternalPluginError::MissingExport(name))?;
+
+    let packed = function.call(&mut *store, params).map_err(ExternalPluginError::from_wasm)?;
+    let memory = instance.get_memory(&*store, "memory").ok_or(ExternalPluginError::MissingExport("memory"))?;
+
+    read_from_guest(memory.data(&*store), (packed >> 32) as i32, packed as i32)
+        .map(|bytes| bytes.to_vec())
+        .ok_or_else(|| ExternalPluginError::InvalidData(format!("`{}` returned an out of bounds slice", name)))
+}
+
+fn write_to_guest(
+    store: &mut Store<HostState>,
+    instance: &Instance,
+    bytes: &[u8],
+) -> Result<(i32, i32), ExternalPluginError> {
+    let alloc = instance
+        .get_typed_func::<i32, i32>(&*store, "mago_alloc")
+        .map_err(|_| ExternalPluginError::MissingExport("mago_alloc"))?;
+    let memory = instance.get_memory(&*store, "memory").ok_or(ExternalPluginError::MissingExport("memory"))?;
+
+    let length = i32::try_from(bytes.len()).map_err(ExternalPluginError::from_wasm)?;
+    let pointer = alloc.call(&mut *store, length).map_err(ExternalPluginError::from_wasm)?;
+    memory.write(&mut *store, pointer as u32 as usize, bytes).map_err(ExternalPluginError::from_wasm)?;
+
+    Ok((pointer, length))
+}
+
+fn get_memory(caller: &Caller<'_, HostState>) -> Option<Memory> {
+    caller.get_export("memory").and_then(Extern::into_memory)
+}
+
+fn read_from_guest(memory: &[u8], pointer: i32, length: i32) -> Option<&[u8]> {
+    let start = pointer as u32 as usize;
+    let end = start.checked_add(length as u32 as usize)?;
+
+    memory.get(start..end)
+}
+
+fn pack(pointer: i32, length: i32) -> i64 {
+    ((pointer as u32 as i64) << 32) | (length as u32 as i64)
+}
+
+/// Determines whether the given byte offsets delimit a valid range of the given content.
+fn is_valid_range(content: &str, start: usize, end: usize) -> bool {
+    start <= end && content.is_char_boundary(start) && content.is_char_boundary(end)
+}
+
+/// Determines whether all the operations of the given fix plan use valid byte offsets of the given content.
+fn is_valid_fix(content: &str, plan: &FixPlan) -> bool {
+    plan.get_operations().iter().all(|operation| match operation {
+        FixOperation::Insert { offset, .. } => content.is_char_boundary(*offset),
+        FixOperation::Replace { range, .. } | FixOperation::Delete { range, .. } => {
+            is_valid_range(content, range.start, range.end)
+        }
+    })
+}
+
+/// Plugin and rule definitions use `&'static str`, so names of plugins and rules that are not
+/// known at compile time are leaked to satisfy that requirement.
+///
+/// Each distinct name is leaked only once, so loading the same plugins again does not leak more memory.
+pub(crate) fn leak(value: String) -> &'static str {
+    static LEAKED: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Default::default);
+
+    let mut leaked = LEAKED.lock().expect("Unable to lock leaked names: poisoned lock");
+    if let Some(existing) = leaked.get(value.as_str()) {
+        return existing;
+    }
+
+    let value: &'static str = Box::leak(value.into_boxed_str());
+    leaked.insert(value);
+
+    value
+}
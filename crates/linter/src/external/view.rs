@@ -0,0 +1,173 @@
+use ahash::HashMap;
+use serde::Deserialize;
+use serde::Serialize;
+use toml::value::Value;
+
+use mago_ast::Program;
+use mago_fixer::FixPlan;
+use mago_reporting::AnnotationKind;
+use mago_reporting::Level;
+
+/// The version of the serialized view exchanged with external plugins.
+///
+/// This number is bumped whenever a breaking change is made to any of the structures
+/// in this module, and is passed to the plugin alongside every request, so that plugins
+/// can refuse to run against a view they do not understand.
+pub const ABI_VERSION: u32 = 1;
+
+/// The definition of an external plugin, as returned by its `mago_plugin_definition` export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginDefinitionView {
+    /// The name of the plugin, e.g. `"House Rules"`.
+    pub name: String,
+
+    /// A brief description of the plugin.
+    #[serde(default)]
+    pub description: String,
+
+    /// The rules provided by this plugin.
+    #[serde(default)]
+    pub rules: Vec<RuleDefinitionView>,
+}
+
+/// The definition of a single rule provided by an external plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleDefinitionView {
+    /// The name of the rule, e.g. `"No Legacy Helpers"`.
+    pub name: String,
+
+    /// The default level of the rule, or `None` if the rule is disabled by default.
+    #[serde(default)]
+    pub level: Option<Level>,
+
+    /// A human-readable summary of the rule's purpose.
+    #[serde(default)]
+    pub description: String,
+}
+
+/// The input passed to an external plugin for every linted source.
+#[derive(Debug, Clone, Serialize)]
+pub struct LintInput<'a> {
+    /// The version of this view, see [`ABI_VERSION`].
+    pub abi_version: u32,
+
+    /// The name of the rule being run.
+    pub rule: &'a str,
+
+    /// The PHP version being linted against, e.g. `"8.3.0"`.
+    pub php_version: String,
+
+    /// The rule-specific options configured by the user.
+    pub options: &'a HashMap<String, Value>,
+
+    /// The source being linted.
+    pub source: SourceView<'a>,
+
+    /// The resolved names of the program, sorted by offset.
+    pub names: Vec<NameView<'a>>,
+
+    /// The abstract syntax tree of the source.
+    ///
+    /// Interned strings appear as numeric identifiers, and can be resolved
+    /// using the `lookup` query.
+    pub program: &'a Program,
+}
+
+/// A view of the source being linted.
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceView<'a> {
+    /// The name of the source, usually its path relative to the workspace.
+    pub name: &'a str,
+
+    /// The full content of the source.
+    pub content: &'a str,
+}
+
+/// A resolved name at a given offset in the source.
+#[derive(Debug, Clone, Serialize)]
+pub struct NameView<'a> {
+    /// The byte offset of the identifier in the source.
+    pub offset: usize,
+
+    /// The fully qualified name the identifier resolves to.
+    pub name: &'a str,
+
+    /// Whether the name was resolved through a `use` statement.
+    pub imported: bool,
+}
+
+/// An issue reported by an external plugin.
+///
+/// Spans are expressed as byte offsets in the source being linted, the linter
+/// attaches the source identifier itself when converting this view into an [`Issue`].
+///
+/// [`Issue`]: mago_reporting::Issue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueView {
+    /// The level of the issue, defaults to the configured level of the rule.
+    #[serde(default)]
+    pub level: Option<Level>,
+
+    /// The main message of the issue.
+    pub message: String,
+
+    /// The annotations of the issue.
+    #[serde(default)]
+    pub annotations: Vec<AnnotationView>,
+
+    /// Additional notes.
+    #[serde(default)]
+    pub notes: Vec<String>,
+
+    /// An optional help message.
+    #[serde(default)]
+    pub help: Option<String>,
+
+    /// An optional link to the documentation of the rule.
+    #[serde(default)]
+    pub link: Option<String>,
+
+    /// An optional fix for the issue.
+    #[serde(default)]
+    pub fix: Option<FixPlan>,
+}
+
+/// An annotation of an issue reported by an external plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnotationView {
+    /// The start offset of the annotated span.
+    pub start: usize,
+
+    /// The end offset of the annotated span.
+    pub end: usize,
+
+    /// An optional message for the annotation.
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// The kind of the annotation, defaults to `Primary`.
+    #[serde(default = "default_annotation_kind")]
+    pub kind: AnnotationKind,
+}
+
+/// A query sent by an external plugin to the host through the `mago.query` import.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "query", rename_all = "kebab-case")]
+pub enum QueryView {
+    /// Resolves an interned string identifier found in the program.
+    Lookup { id: usize },
+    /// Retrieves the reflection of a class, interface, trait, or enum by name.
+    ClassLike { name: String },
+    /// Retrieves the reflection of a function by name.
+    Function { name: String },
+    /// Retrieves the reflection of a constant by name.
+    Constant { name: String },
+    /// Retrieves the reflection of the class-like enclosing the given offset.
+    EnclosingClassLike { offset: usize },
+    /// Retrieves the reflection of the function-like enclosing the given offset.
+    EnclosingFunctionLike { offset: usize },
+}
+
+fn default_annotation_kind() -> AnnotationKind {
+    AnnotationKind::Primary
+}
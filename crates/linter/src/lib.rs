@@ -9,6 +9,7 @@ use mago_reporting::Level;
 use mago_semantics::Semantics;
 
 use crate::context::Context;
+use crate::definition::PluginDefinition;
 use crate::plugin::Plugin;
 use crate::rule::ConfiguredRule;
 use crate::rule::Rule;
@@ -18,6 +19,8 @@ use crate::settings::Settings;
 pub mod consts;
 pub mod context;
 pub mod definition;
+pub mod external;
+pub mod pattern;
 pub mod plugin;
 pub mod rule;
 pub mod settings;
@@ -84,26 +87,40 @@ impl Linter {
 
         tracing::debug!("Adding plugin `{plugin_slug}`...");
 
-        let enabled = self.settings.plugins.iter().any(|p| p.eq(&plugin_slug));
-        if !enabled {
-            if self.settings.default_plugins && plugin_definition.enabled_by_default {
-                tracing::debug!("Enabling default plugin `{plugin_slug}`.");
-            } else {
-                tracing::debug!(
-                    "Plugin `{plugin_slug}` is not enabled in the configuration and is not a default plugin, skipping."
-                );
-
-                return;
-            }
-        } else {
-            tracing::debug!("Enabling plugin `{plugin_slug}`.");
+        if !self.is_plugin_enabled(&plugin_definition) {
+            tracing::debug!(
+                "Plugin `{plugin_slug}` is not enabled in the configuration and is not a default plugin, skipping."
+            );
+
+            return;
         }
 
+        tracing::debug!("Enabling plugin `{plugin_slug}`.");
+
         for rule in plugin.get_rules() {
             self.add_rule(&plugin_slug, rule);
         }
     }
 
+    /// Checks whether a plugin is enabled, either in the settings, or as a default plugin.
+    ///
+    /// This is the check performed by [`Linter::add_plugin`], plugins that need costly preparation,
+    /// such as analyzing the whole codebase, can use it to skip the preparation when they are not enabled.
+    ///
+    /// # Parameters
+    ///
+    /// - `definition`: The definition of the plugin.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the rules of the plugin would be added to the linter, `false` otherwise.
+    pub fn is_plugin_enabled(&self, definition: &PluginDefinition) -> bool {
+        let plugin_slug = definition.get_slug();
+
+        self.settings.plugins.iter().any(|p| p.eq(&plugin_slug))
+            || (self.settings.default_plugins && definition.enabled_by_default)
+    }
+
     /// Adds a rule to the linter.
     ///
     /// This method will add a rule to the linter. The rule will be enabled if it is enabled in the settings.
@@ -159,6 +176,11 @@ impl Linter {
         });
     }
 
+    /// Returns the codebase reflection shared by the linter, and the plugins that query it.
+    pub fn get_codebase(&self) -> &Arc<CodebaseReflection> {
+        &self.codebase
+    }
+
     /// Returns a read lock for the vector of [`ConfiguredRule`] instances maintained by the linter.
     ///
     /// This method provides direct, read-only access to all currently configured rules.
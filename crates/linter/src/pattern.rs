@@ -0,0 +1,192 @@
+//! Declarative rules defined using code patterns.
+//!
+//! Pattern rules are written in the configuration instead of being compiled into the linter,
+//! each rule matches a [`Pattern`] (e.g. `$x->setUp($$$args)`), and reports every match with a
+//! configured message, optionally suggesting a rewrite using a [`Template`].
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_ast::Program;
+use mago_fixer::SafetyClassification;
+use mago_interner::ThreadedInterner;
+use mago_pattern::error::PatternError;
+use mago_pattern::Pattern;
+use mago_pattern::Template;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::PluginDefinition;
+use crate::definition::RuleDefinition;
+use crate::external::leak;
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+/// The definition of a rule based on a code pattern, as written in the configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatternRuleDefinition {
+    /// The name of the rule, e.g. `"No Direct Set Up Call"`.
+    pub name: String,
+
+    /// The pattern to match, e.g. `$x->setUp($$$args)`.
+    pub pattern: String,
+
+    /// The message reported for each match.
+    pub message: String,
+
+    /// The default level of the rule.
+    pub level: Level,
+
+    /// An optional help message.
+    pub help: Option<String>,
+
+    /// An optional template used to rewrite matches, e.g. `$x->initialize($$$args)`.
+    pub rewrite: Option<String>,
+
+    /// When set, only matches inside a class-like that extends or implements
+    /// the given fully qualified class name are reported.
+    pub extends: Option<String>,
+}
+
+/// A plugin bundling all the pattern rules defined in the configuration.
+#[derive(Debug, Clone)]
+pub struct PatternPlugin {
+    rules: Vec<PatternRule>,
+}
+
+/// A rule that reports matches of a code pattern.
+#[derive(Debug, Clone)]
+pub struct PatternRule {
+    definition: RuleDefinition,
+    pattern: Pattern,
+    message: String,
+    help: Option<String>,
+    rewrite: Option<Template>,
+    extends: Option<String>,
+}
+
+impl PatternPlugin {
+    /// Creates a new plugin from the given rule definitions.
+    ///
+    /// # Parameters
+    ///
+    /// - `interner`: The interner used to parse the patterns.
+    /// - `definitions`: The definitions of the rules.
+    ///
+    /// # Returns
+    ///
+    /// The plugin, or an error if one of the patterns is invalid.
+    pub fn new(
+        interner: &ThreadedInterner,
+        definitions: impl IntoIterator<Item = PatternRuleDefinition>,
+    ) -> Result<Self, PatternError> {
+        let rules = definitions
+            .into_iter()
+            .map(|definition| PatternRule::new(interner, definition))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { rules })
+    }
+}
+
+impl PatternRule {
+    /// Creates a new rule from the given definition.
+    ///
+    /// # Parameters
+    ///
+    /// - `interner`: The interner used to parse the pattern.
+    /// - `definition`: The definition of the rule.
+    ///
+    /// # Returns
+    ///
+    /// The rule, or an error if the pattern is invalid.
+    pub fn new(interner: &ThreadedInterner, definition: PatternRuleDefinition) -> Result<Self, PatternError> {
+        let pattern = Pattern::new(interner, &definition.pattern)?;
+        let rule_definition = RuleDefinition::enabled(leak(definition.name), definition.level)
+            .with_description(leak(format!("Reports code matching the pattern `{}`.", pattern.get_code())));
+
+        Ok(Self {
+            definition: rule_definition,
+            pattern,
+            message: definition.message,
+            help: definition.help,
+            rewrite: definition.rewrite.as_deref().map(Template::new),
+            extends: definition.extends.map(|extends| extends.trim_start_matches('\\').to_ascii_lowercase()),
+        })
+    }
+
+    fn is_within_scope(&self, context: &LintContext<'_>, class_like: Option<&ClassLikeReflection>) -> bool {
+        let Some(extends) = &self.extends else {
+            return true;
+        };
+
+        let Some(class_like) = class_like else {
+            return false;
+        };
+
+        let inheritance = &class_like.inheritance;
+
+        inheritance
+            .direct_extended_class
+            .iter()
+            .chain(inheritance.all_extended_classes.iter())
+            .chain(inheritance.all_implemented_interfaces.iter())
+            .chain(inheritance.all_extended_interfaces.iter())
+            .any(|name| context.lookup(&name.value).eq_ignore_ascii_case(extends))
+    }
+}
+
+impl Plugin for PatternPlugin {
+    fn get_definition(&self) -> PluginDefinition {
+        PluginDefinition {
+            name: "Patterns",
+            description: "Provides the rules defined using code patterns in the configuration.",
+            enabled_by_default: true,
+        }
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        self.rules.iter().map(|rule| Box::new(rule.clone()) as Box<dyn Rule>).collect()
+    }
+}
+
+impl Rule for PatternRule {
+    fn get_definition(&self) -> RuleDefinition {
+        self.definition.clone()
+    }
+
+    fn lint(&self, program: &Program, context: &mut LintContext<'_>) {
+        let content = context.interner.lookup(&context.semantics.source.content);
+
+        for result in self.pattern.find_all(content, program) {
+            let class_like = context.codebase.get_enclosing_class_like(&result.span.start);
+            if !self.is_within_scope(context, class_like) {
+                continue;
+            }
+
+            let mut issue = Issue::new(context.level(), self.message.clone())
+                .with_annotation(Annotation::primary(result.span).with_message("This code matches the pattern."));
+
+            if let Some(help) = &self.help {
+                issue = issue.with_help(help.clone());
+            }
+
+            match &self.rewrite {
+                Some(template) => {
+                    let replacement = template.render(&result);
+
+                    context.report_with_fix(issue, |plan| {
+                        plan.replace(result.span.to_range(), replacement, SafetyClassification::PotentiallyUnsafe)
+                    });
+                }
+                None => context.report(issue),
+            }
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PatternRule {}
@@ -3,6 +3,9 @@ use crate::plugin::analysis::rules::inheritance::InheritanceRule;
 use crate::plugin::analysis::rules::instantiation::InstantiationRule;
 use crate::plugin::analysis::rules::undefined_constant::UndefinedConstantRule;
 use crate::plugin::analysis::rules::undefined_function::UndefinedFunctionRule;
+use crate::plugin::analysis::rules::unhandled_exception::UnhandledExceptionRule;
+use crate::plugin::analysis::rules::unnecessary_throws_tag::UnnecessaryThrowsTagRule;
+use crate::plugin::analysis::rules::unreachable_catch_clause::UnreachableCatchClauseRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -27,6 +30,9 @@ impl Plugin for AnalysisPlugin {
             Box::new(InstantiationRule),
             Box::new(UndefinedConstantRule),
             Box::new(UndefinedFunctionRule),
+            Box::new(UnhandledExceptionRule),
+            Box::new(UnnecessaryThrowsTagRule),
+            Box::new(UnreachableCatchClauseRule),
         ]
     }
 }
@@ -2,3 +2,7 @@ pub mod inheritance;
 pub mod instantiation;
 pub mod undefined_constant;
 pub mod undefined_function;
+pub mod unhandled_exception;
+pub mod unnecessary_throws_tag;
+pub mod unreachable_catch_clause;
+pub mod utils;
@@ -0,0 +1,240 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::analysis::rules::utils::analyze_block;
+use crate::plugin::analysis::rules::utils::analyze_expression;
+use crate::plugin::analysis::rules::utils::is_exception_subtype;
+use crate::plugin::analysis::rules::utils::ExceptionFlow;
+use crate::rule::Rule;
+
+const UNCHECKED: &str = "unchecked";
+const UNCHECKED_DEFAULT: [&str; 2] = ["Error", "LogicException"];
+
+#[derive(Clone, Debug)]
+pub struct UnhandledExceptionRule;
+
+impl Rule for UnhandledExceptionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Unhandled Exception")
+            .with_description(indoc! {"
+                Detects exceptions that are thrown within a function or method, either by a `throw` expression
+                or by a call to a function or method declaring them in its `@throws` tags, but are neither caught
+                nor declared in the `@throws` tags of the enclosing function or method.
+
+                Closures and arrow functions are checked on their own, against the `@throws` tags of the docblock
+                immediately preceding them, as their bodies only run when they are called.
+
+                Exceptions that are subtypes of one of the `unchecked` classes are ignored.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: UNCHECKED,
+                r#type: "array<string>",
+                description:
+                    "A list of exception classes that do not need to be caught or declared, along with their subtypes.",
+                default: Value::Array(UNCHECKED_DEFAULT.iter().map(|class| Value::String(class.to_string())).collect()),
+            })
+            .with_example(RuleUsageExample::valid(
+                "A declared exception",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws RuntimeException
+                     */
+                    function read_file(string $path): string
+                    {
+                        if (!is_file($path)) {
+                            throw new RuntimeException('The file does not exist.');
+                        }
+
+                        return 'content';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "A caught exception",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws RuntimeException
+                     */
+                    function read_file(string $path): string
+                    {
+                        throw new RuntimeException('The file does not exist.');
+                    }
+
+                    function read_file_or_default(string $path): string
+                    {
+                        try {
+                            return read_file($path);
+                        } catch (RuntimeException) {
+                            return 'default';
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "An exception declared through an imported alias",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Filesystem;
+
+                    use RuntimeException as FilesystemException;
+
+                    /**
+                     * @throws FilesystemException
+                     */
+                    function read_file(string $path): string
+                    {
+                        throw new \RuntimeException('The file does not exist.');
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "An exception declared in the docblock of a closure",
+                indoc! {r#"
+                    <?php
+
+                    $read = /** @throws RuntimeException */ function (string $path): string {
+                        throw new RuntimeException('The file does not exist.');
+                    };
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An exception that is neither caught nor declared",
+                indoc! {r#"
+                    <?php
+
+                    final class FileReader
+                    {
+                        /**
+                         * @throws RuntimeException
+                         */
+                        public function read(string $path): string
+                        {
+                            throw new RuntimeException('The file does not exist.');
+                        }
+
+                        public function readAll(string ...$paths): string
+                        {
+                            $content = '';
+                            foreach ($paths as $path) {
+                                $content .= $this->read($path);
+                            }
+
+                            return $content;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A closure throwing an exception that is neither caught nor declared",
+                indoc! {r#"
+                    <?php
+
+                    $read = function (string $path): string {
+                        if (!is_file($path)) {
+                            throw new RuntimeException('The file does not exist.');
+                        }
+
+                        return 'content';
+                    };
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnhandledExceptionRule {
+    fn walk_in_function<'ast>(&self, function: &'ast Function, context: &mut LintContext<'a>) {
+        let name = context.semantics.names.get(&function.name);
+        let reflection = context.codebase.get_function(context.interner, name);
+        let name = context.lookup(name).to_string();
+
+        let flow = analyze_block(&function.body, context);
+
+        check(flow, format!("function `{}`", name), reflection, context);
+    }
+
+    fn walk_in_method<'ast>(&self, method: &'ast Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        let class_like = context.codebase.get_enclosing_class_like(method);
+        let reflection = class_like.and_then(|class_like| class_like.get_method(&method.name.value));
+        let name = context.lookup(&method.name.value).to_string();
+
+        let flow = analyze_block(block, context);
+
+        check(flow, format!("method `{}`", name), reflection, context);
+    }
+
+    fn walk_in_closure<'ast>(&self, closure: &'ast Closure, context: &mut LintContext<'a>) {
+        let reflection = context.codebase.get_closure(closure);
+        let flow = analyze_block(&closure.body, context);
+
+        check(flow, "the closure".to_string(), reflection, context);
+    }
+
+    fn walk_in_arrow_function<'ast>(&self, arrow_function: &'ast ArrowFunction, context: &mut LintContext<'a>) {
+        let reflection = context.codebase.get_arrow_function(arrow_function);
+        let flow = analyze_expression(&arrow_function.expression, context);
+
+        check(flow, "the arrow function".to_string(), reflection, context);
+    }
+}
+
+fn check(
+    flow: ExceptionFlow,
+    kind: String,
+    reflection: Option<&FunctionLikeReflection>,
+    context: &mut LintContext<'_>,
+) {
+    let unchecked = match context.option(UNCHECKED).and_then(|o| o.as_array()) {
+        Some(unchecked) => unchecked.iter().filter_map(|class| class.as_str()).map(|class| class.to_string()).collect(),
+        None => UNCHECKED_DEFAULT.iter().map(|class| class.to_string()).collect::<Vec<_>>(),
+    };
+
+    let declared: Vec<String> = reflection
+        .map(|reflection| {
+            reflection.thrown_types.iter().map(|thrown_type| context.lookup(&thrown_type.value).to_string()).collect()
+        })
+        .unwrap_or_default();
+
+    for site in flow.sites {
+        let is_handled = unchecked
+            .iter()
+            .chain(declared.iter())
+            .any(|class| is_exception_subtype(&site.exception, class.trim_start_matches('\\'), context));
+
+        if is_handled {
+            continue;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Exception `{}` is neither caught nor declared by {}.", site.exception, kind),
+        )
+        .with_annotation(
+            Annotation::primary(site.span).with_message(format!("Exception `{}` may be thrown here.", site.exception)),
+        )
+        .with_note("Exceptions that are not caught should be declared, so that callers know to handle them.")
+        .with_help(format!(
+            "Catch the exception, or declare it by adding `@throws \\{}` to the docblock of {}.",
+            site.exception, kind
+        ));
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,178 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::analysis::rules::utils::analyze_block;
+use crate::plugin::analysis::rules::utils::analyze_expression;
+use crate::plugin::analysis::rules::utils::are_exceptions_related;
+use crate::plugin::analysis::rules::utils::is_exception_subtype;
+use crate::plugin::analysis::rules::utils::ExceptionFlow;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnnecessaryThrowsTagRule;
+
+impl Rule for UnnecessaryThrowsTagRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unnecessary Throws Tag", Level::Warning)
+            .with_description(indoc! {"
+                Detects `@throws` tags declaring exceptions that can not be thrown by the function or method,
+                as neither its `throw` expressions nor the functions and methods it calls throw them.
+
+                The `@throws` tags of closures and arrow functions, declared in the docblock immediately preceding
+                them, are checked the same way.
+
+                Methods overriding a parent method are ignored, as their `@throws` tags may be dictated by the
+                parent method, and so are `Error` exceptions, which may be thrown by the engine itself.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A `@throws` tag declaring a thrown exception",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws InvalidArgumentException
+                     */
+                    function divide(int $a, int $b): float
+                    {
+                        if (0 === $b) {
+                            throw new InvalidArgumentException('Division by zero.');
+                        }
+
+                        return $a / $b;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A `@throws` tag declaring an exception that can not be thrown",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws InvalidArgumentException
+                     * @throws RuntimeException
+                     */
+                    function divide(int $a, int $b): float
+                    {
+                        if (0 === $b) {
+                            throw new InvalidArgumentException('Division by zero.');
+                        }
+
+                        return $a / $b;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A `@throws` tag declaring an exception that can not be thrown by a closure",
+                indoc! {r#"
+                    <?php
+
+                    $divide = /** @throws RuntimeException */ function (int $a, int $b): float {
+                        return $a / $b;
+                    };
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnnecessaryThrowsTagRule {
+    fn walk_in_function<'ast>(&self, function: &'ast Function, context: &mut LintContext<'a>) {
+        let name = context.semantics.names.get(&function.name);
+        let Some(reflection) = context.codebase.get_function(context.interner, name) else {
+            return;
+        };
+
+        let name = context.lookup(name).to_string();
+
+        check(|context| analyze_block(&function.body, context), format!("function `{}`", name), reflection, context);
+    }
+
+    fn walk_in_method<'ast>(&self, method: &'ast Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        let Some(reflection) = context
+            .codebase
+            .get_enclosing_class_like(method)
+            .and_then(|class_like| class_like.get_method(&method.name.value))
+        else {
+            return;
+        };
+
+        if reflection.is_overriding {
+            return;
+        }
+
+        let name = context.lookup(&method.name.value).to_string();
+
+        check(|context| analyze_block(block, context), format!("method `{}`", name), reflection, context);
+    }
+
+    fn walk_in_closure<'ast>(&self, closure: &'ast Closure, context: &mut LintContext<'a>) {
+        let Some(reflection) = context.codebase.get_closure(closure) else {
+            return;
+        };
+
+        check(|context| analyze_block(&closure.body, context), "the closure".to_string(), reflection, context);
+    }
+
+    fn walk_in_arrow_function<'ast>(&self, arrow_function: &'ast ArrowFunction, context: &mut LintContext<'a>) {
+        let Some(reflection) = context.codebase.get_arrow_function(arrow_function) else {
+            return;
+        };
+
+        check(
+            |context| analyze_expression(&arrow_function.expression, context),
+            "the arrow function".to_string(),
+            reflection,
+            context,
+        );
+    }
+}
+
+fn check(
+    analyze: impl FnOnce(&LintContext<'_>) -> ExceptionFlow,
+    kind: String,
+    reflection: &FunctionLikeReflection,
+    context: &mut LintContext<'_>,
+) {
+    if reflection.thrown_types.is_empty() {
+        return;
+    }
+
+    let flow = analyze(context);
+    if !flow.is_complete {
+        // The block may throw exceptions that could not be determined.
+        return;
+    }
+
+    for thrown_type in &reflection.thrown_types {
+        let exception = context.lookup(&thrown_type.value);
+        if is_exception_subtype(exception, "Error", context) {
+            continue;
+        }
+
+        if flow.sites.iter().any(|site| are_exceptions_related(&site.exception, exception, context)) {
+            continue;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Exception `{}` is declared as thrown by {}, but can not be thrown.", exception, kind),
+        )
+        .with_annotation(
+            Annotation::primary(thrown_type.span).with_message(format!("Exception `{}` is declared here.", exception)),
+        )
+        .with_note(format!("Neither the body of {} nor the functions it calls throw this exception.", kind))
+        .with_help("Remove the `@throws` tag, or update it to declare the exceptions that can be thrown.");
+
+        context.report(issue);
+    }
+}
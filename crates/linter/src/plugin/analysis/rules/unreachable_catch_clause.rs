@@ -0,0 +1,117 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::analysis::rules::utils::analyze_block;
+use crate::plugin::analysis::rules::utils::are_exceptions_related;
+use crate::plugin::analysis::rules::utils::get_caught_types;
+use crate::plugin::analysis::rules::utils::is_exception_subtype;
+use crate::plugin::analysis::rules::utils::is_known_class_like;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnreachableCatchClauseRule;
+
+impl Rule for UnreachableCatchClauseRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unreachable Catch Clause", Level::Warning)
+            .with_description(indoc! {"
+                Detects `catch` clauses catching exceptions that the `try` block never throws, neither through
+                its `throw` expressions nor through the functions and methods it calls.
+
+                Catching `Throwable`, `Exception`, or `Error` exceptions is always allowed, as such exceptions may
+                be thrown by the engine itself.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Catching an exception thrown by a called function",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws RuntimeException
+                     */
+                    function connect(): void
+                    {
+                        throw new RuntimeException('Connection refused.');
+                    }
+
+                    try {
+                        connect();
+                    } catch (RuntimeException $e) {
+                        echo $e->getMessage();
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Catching an exception that is never thrown",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @throws RuntimeException
+                     */
+                    function connect(): void
+                    {
+                        throw new RuntimeException('Connection refused.');
+                    }
+
+                    try {
+                        connect();
+                    } catch (RuntimeException $e) {
+                        echo $e->getMessage();
+                    } catch (InvalidArgumentException $e) {
+                        echo 'Invalid argument.';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for UnreachableCatchClauseRule {
+    fn walk_in_try<'ast>(&self, r#try: &'ast Try, context: &mut LintContext<'a>) {
+        if r#try.catch_clauses.is_empty() {
+            return;
+        }
+
+        let flow = analyze_block(&r#try.block, context);
+        if !flow.is_complete {
+            // The block may throw exceptions that could not be determined.
+            return;
+        }
+
+        for clause in r#try.catch_clauses.iter() {
+            for (exception, span) in get_caught_types(clause, context) {
+                if exception.eq_ignore_ascii_case("Throwable")
+                    || exception.eq_ignore_ascii_case("Exception")
+                    || is_exception_subtype(&exception, "Error", context)
+                    || !is_known_class_like(&exception, context)
+                {
+                    continue;
+                }
+
+                if flow.sites.iter().any(|site| are_exceptions_related(&site.exception, &exception, context)) {
+                    continue;
+                }
+
+                let issue = Issue::new(
+                    context.level(),
+                    format!("Exception `{}` is never thrown by the `try` block.", exception),
+                )
+                .with_annotation(
+                    Annotation::primary(span).with_message(format!("Exception `{}` is caught here.", exception)),
+                )
+                .with_annotation(Annotation::secondary(r#try.block.span()).with_message("The `try` block is here."))
+                .with_note("Neither the `try` block nor the functions it calls throw this exception.")
+                .with_help("Remove the exception from the `catch` clause, or remove the clause entirely.");
+
+                context.report(issue);
+            }
+        }
+    }
+}
@@ -0,0 +1,441 @@
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::function_like::FunctionLikeReflection;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+
+/// A point where an exception may be thrown, either by a `throw` expression, or by a call
+/// to a function-like declaring the exception in its `@throws` tags.
+#[derive(Debug, Clone)]
+pub struct ThrowSite {
+    /// The fully qualified name of the thrown exception.
+    pub exception: String,
+    /// The span of the expression throwing the exception.
+    pub span: Span,
+}
+
+/// The exceptions a block of code can throw, without being caught within the block.
+#[derive(Debug, Clone)]
+pub struct ExceptionFlow {
+    pub sites: Vec<ThrowSite>,
+    /// Whether every expression that may throw within the block was understood.
+    ///
+    /// When `false`, the block may throw exceptions that are not listed in `sites`, e.g. through
+    /// a call to a method of an unknown object.
+    pub is_complete: bool,
+}
+
+/// Computes the exceptions the given block can throw, without them being caught within the block.
+///
+/// Closures, arrow functions, and class-likes declared within the block are not analyzed, as
+/// their bodies only run when they are called; they are analyzed on their own instead.
+pub fn analyze_block(block: &Block, context: &LintContext<'_>) -> ExceptionFlow {
+    use crate::plugin::analysis::rules::utils::internal::ExceptionWalker;
+    use crate::plugin::analysis::rules::utils::internal::State;
+
+    let class_like = context.codebase.get_enclosing_class_like(block);
+    let mut context = (State::new(class_like), context);
+
+    ExceptionWalker.walk_block(block, &mut context);
+
+    let state = context.0;
+
+    ExceptionFlow { sites: state.frames.into_iter().flatten().collect(), is_complete: state.is_complete }
+}
+
+/// Computes the exceptions the given expression can throw, such as the body of an arrow function.
+///
+/// Closures, arrow functions, and class-likes declared within the expression are not analyzed.
+pub fn analyze_expression(expression: &Expression, context: &LintContext<'_>) -> ExceptionFlow {
+    use crate::plugin::analysis::rules::utils::internal::ExceptionWalker;
+    use crate::plugin::analysis::rules::utils::internal::State;
+
+    let class_like = context.codebase.get_enclosing_class_like(expression);
+    let mut context = (State::new(class_like), context);
+
+    ExceptionWalker.walk_expression(expression, &mut context);
+
+    let state = context.0;
+
+    ExceptionFlow { sites: state.frames.into_iter().flatten().collect(), is_complete: state.is_complete }
+}
+
+/// Returns the fully qualified names of the exception types caught by the given catch clause.
+pub fn get_caught_types(clause: &TryCatchClause, context: &LintContext<'_>) -> Vec<(String, Span)> {
+    let mut types = vec![];
+    collect_hint_types(&clause.hint, context, &mut types);
+
+    types
+}
+
+/// Determines if the given exception is the given class, or one of its subtypes.
+///
+/// Unknown exceptions are only considered subtypes of themselves, and of `Throwable`.
+pub fn is_exception_subtype(exception: &str, class: &str, context: &LintContext<'_>) -> bool {
+    if exception.eq_ignore_ascii_case(class) || class.eq_ignore_ascii_case("Throwable") {
+        return true;
+    }
+
+    let Some(reflection) = context.codebase.get_named_class_like(context.interner, &context.interner.intern(exception))
+    else {
+        return false;
+    };
+
+    let inheritance = &reflection.inheritance;
+
+    inheritance
+        .all_extended_classes
+        .iter()
+        .chain(inheritance.all_implemented_interfaces.iter())
+        .chain(inheritance.all_extended_interfaces.iter())
+        .any(|name| context.lookup(&name.value).eq_ignore_ascii_case(class))
+}
+
+/// Determines if a value of one of the given classes may be a value of the other.
+///
+/// This is the case if one class is a subtype of the other, or if either of them is unknown.
+pub fn are_exceptions_related(left: &str, right: &str, context: &LintContext<'_>) -> bool {
+    if !is_known_class_like(left, context) || !is_known_class_like(right, context) {
+        return true;
+    }
+
+    is_exception_subtype(left, right, context) || is_exception_subtype(right, left, context)
+}
+
+/// Determines if a class-like with the given name is part of the codebase.
+pub fn is_known_class_like(name: &str, context: &LintContext<'_>) -> bool {
+    context.codebase.get_named_class_like(context.interner, &context.interner.intern(name)).is_some()
+}
+
+/// Finds the method with the given name, declared or inherited by the given class-like.
+pub fn find_method<'a>(
+    class_like: &'a ClassLikeReflection,
+    name: &str,
+    context: &'a LintContext<'_>,
+) -> Option<&'a FunctionLikeReflection> {
+    let methods = &class_like.methods;
+
+    if let Some((_, method)) =
+        methods.members.iter().find(|(method, _)| context.lookup(method).eq_ignore_ascii_case(name))
+    {
+        return Some(method);
+    }
+
+    let (method, declaring_class_like) =
+        methods.appering_members.iter().find(|(method, _)| context.lookup(method).eq_ignore_ascii_case(name))?;
+
+    context.codebase.get_class_like(*declaring_class_like)?.get_method(method)
+}
+
+fn collect_hint_types(hint: &Hint, context: &LintContext<'_>, types: &mut Vec<(String, Span)>) {
+    match hint {
+        Hint::Identifier(identifier) => types.push((context.lookup_name(identifier).to_string(), identifier.span())),
+        Hint::Union(union) => {
+            collect_hint_types(&union.left, context, types);
+            collect_hint_types(&union.right, context, types);
+        }
+        Hint::Parenthesized(parenthesized) => collect_hint_types(&parenthesized.hint, context, types),
+        _ => {}
+    }
+}
+
+mod internal {
+    use mago_ast::*;
+    use mago_interner::StringIdentifier;
+    use mago_reflection::class_like::ClassLikeReflection;
+    use mago_reflection::function_like::FunctionLikeReflection;
+    use mago_span::*;
+    use mago_walker::Walker;
+
+    use crate::context::LintContext;
+    use crate::plugin::analysis::rules::utils::find_method;
+    use crate::plugin::analysis::rules::utils::get_caught_types;
+    use crate::plugin::analysis::rules::utils::is_exception_subtype;
+    use crate::plugin::analysis::rules::utils::ThrowSite;
+
+    #[derive(Debug)]
+    pub(super) struct State<'a> {
+        /// The throw sites of the enclosing `try` blocks, the innermost being the last.
+        pub frames: Vec<Vec<ThrowSite>>,
+        /// The variables of the enclosing `catch` clauses, along with the types they catch.
+        pub caught: Vec<(StringIdentifier, Vec<String>)>,
+        pub class_like: Option<&'a ClassLikeReflection>,
+        pub is_complete: bool,
+    }
+
+    impl<'a> State<'a> {
+        pub fn new(class_like: Option<&'a ClassLikeReflection>) -> Self {
+            Self { frames: vec![vec![]], caught: vec![], class_like, is_complete: true }
+        }
+
+        fn add(&mut self, exception: String, span: Span) {
+            if let Some(frame) = self.frames.last_mut() {
+                frame.push(ThrowSite { exception, span });
+            }
+        }
+
+        fn add_function_like(
+            &mut self,
+            function_like: Option<&FunctionLikeReflection>,
+            span: Span,
+            context: &LintContext<'_>,
+        ) {
+            let Some(function_like) = function_like else {
+                self.is_complete = false;
+
+                return;
+            };
+
+            for thrown_type in &function_like.thrown_types {
+                self.add(context.lookup(&thrown_type.value).to_string(), span);
+            }
+        }
+
+        fn get_parent(&self, context: &LintContext<'a>) -> Option<&'a ClassLikeReflection> {
+            let parent = self.class_like?.inheritance.direct_extended_class.as_ref()?;
+
+            context.codebase.get_named_class_like(context.interner, &parent.value)
+        }
+    }
+
+    #[derive(Debug)]
+    pub(super) struct ExceptionWalker;
+
+    impl<'a> ExceptionWalker {
+        fn resolve_class_like(
+            &self,
+            expression: &Expression,
+            context: &(State<'a>, &'a LintContext<'a>),
+        ) -> Option<&'a ClassLikeReflection> {
+            let (state, lint_context) = context;
+
+            match expression {
+                Expression::Identifier(identifier) => {
+                    let name = lint_context.lookup_name(identifier);
+
+                    lint_context
+                        .codebase
+                        .get_named_class_like(lint_context.interner, &lint_context.interner.intern(name))
+                }
+                Expression::Self_(_) | Expression::Static(_) => state.class_like,
+                Expression::Parent(_) => state.get_parent(lint_context),
+                _ => None,
+            }
+        }
+
+        fn add_method_call(
+            &self,
+            class_like: Option<&'a ClassLikeReflection>,
+            selector: &ClassLikeMemberSelector,
+            span: Span,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let (Some(class_like), ClassLikeMemberSelector::Identifier(method)) = (class_like, selector) else {
+                context.0.is_complete = false;
+
+                return;
+            };
+
+            let method = find_method(class_like, context.1.lookup(&method.value), context.1);
+
+            context.0.add_function_like(method, span, context.1);
+        }
+    }
+
+    impl<'a> Walker<(State<'a>, &'a LintContext<'a>)> for ExceptionWalker {
+        fn walk_in_throw<'ast>(&self, throw: &'ast Throw, context: &mut (State<'a>, &'a LintContext<'a>)) {
+            match throw.exception.as_ref() {
+                Expression::Instantiation(instantiation) => {
+                    match self.resolve_class_like(&instantiation.class, context) {
+                        Some(class_like) => {
+                            let Some(name) = class_like.name.inner() else {
+                                context.0.is_complete = false;
+
+                                return;
+                            };
+
+                            let exception = context.1.lookup(&name.value).to_string();
+                            context.0.add(exception, throw.span());
+                        }
+                        None => match instantiation.class.as_ref() {
+                            Expression::Identifier(identifier) => {
+                                // The exception class is unknown, but its name is still meaningful.
+                                let exception = context.1.lookup_name(identifier).to_string();
+                                context.0.add(exception, throw.span());
+                            }
+                            _ => context.0.is_complete = false,
+                        },
+                    }
+                }
+                Expression::Variable(Variable::Direct(variable)) => {
+                    let types = context.0.caught.iter().rev().find(|(name, _)| *name == variable.name);
+
+                    match types.map(|(_, types)| types.clone()) {
+                        Some(types) => {
+                            for exception in types {
+                                context.0.add(exception, throw.span());
+                            }
+                        }
+                        None => context.0.is_complete = false,
+                    }
+                }
+                _ => context.0.is_complete = false,
+            }
+        }
+
+        fn walk_in_function_call<'ast>(
+            &self,
+            function_call: &'ast FunctionCall,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+                context.0.is_complete = false;
+
+                return;
+            };
+
+            let lint_context = context.1;
+            let name = lint_context.interner.intern(lint_context.resolve_function_name(identifier));
+            let function = lint_context.codebase.get_function(lint_context.interner, &name);
+
+            context.0.add_function_like(function, function_call.span(), lint_context);
+        }
+
+        fn walk_in_method_call<'ast>(
+            &self,
+            method_call: &'ast MethodCall,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let class_like = if is_this(&method_call.object, context.1) { context.0.class_like } else { None };
+
+            self.add_method_call(class_like, &method_call.method, method_call.span(), context);
+        }
+
+        fn walk_in_null_safe_method_call<'ast>(
+            &self,
+            null_safe_method_call: &'ast NullSafeMethodCall,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let class_like =
+                if is_this(&null_safe_method_call.object, context.1) { context.0.class_like } else { None };
+
+            self.add_method_call(class_like, &null_safe_method_call.method, null_safe_method_call.span(), context);
+        }
+
+        fn walk_in_static_method_call<'ast>(
+            &self,
+            static_method_call: &'ast StaticMethodCall,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let class_like = self.resolve_class_like(&static_method_call.class, context);
+
+            self.add_method_call(class_like, &static_method_call.method, static_method_call.span(), context);
+        }
+
+        fn walk_in_instantiation<'ast>(
+            &self,
+            instantiation: &'ast Instantiation,
+            context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+            let Some(class_like) = self.resolve_class_like(&instantiation.class, context) else {
+                context.0.is_complete = false;
+
+                return;
+            };
+
+            // A class without a constructor can not throw when instantiated.
+            if let Some(constructor) = find_method(class_like, "__construct", context.1) {
+                context.0.add_function_like(Some(constructor), instantiation.span(), context.1);
+            }
+        }
+
+        fn walk_in_construct<'ast>(&self, construct: &'ast Construct, context: &mut (State<'a>, &'a LintContext<'a>)) {
+            if matches!(
+                construct,
+                Construct::Eval(_)
+                    | Construct::Include(_)
+                    | Construct::IncludeOnce(_)
+                    | Construct::Require(_)
+                    | Construct::RequireOnce(_)
+            ) {
+                context.0.is_complete = false;
+            }
+        }
+
+        fn walk_try<'ast>(&self, r#try: &'ast Try, context: &mut (State<'a>, &'a LintContext<'a>)) {
+            context.0.frames.push(vec![]);
+            self.walk_block(&r#try.block, context);
+            let sites = context.0.frames.pop().unwrap_or_default();
+
+            let clauses = r#try
+                .catch_clauses
+                .iter()
+                .map(|clause| {
+                    let types =
+                        get_caught_types(clause, context.1).into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+
+                    (clause, types)
+                })
+                .collect::<Vec<_>>();
+
+            for site in sites {
+                let is_caught = clauses.iter().any(|(_, types)| {
+                    types.iter().any(|r#type| is_exception_subtype(&site.exception, r#type, context.1))
+                });
+
+                if !is_caught {
+                    context.0.add(site.exception, site.span);
+                }
+            }
+
+            for (clause, types) in clauses {
+                let variable = clause.variable.as_ref().map(|variable| variable.name);
+                if let Some(variable) = variable {
+                    context.0.caught.push((variable, types));
+                }
+
+                self.walk_block(&clause.block, context);
+
+                if variable.is_some() {
+                    context.0.caught.pop();
+                }
+            }
+
+            if let Some(finally_clause) = &r#try.finally_clause {
+                self.walk_block(&finally_clause.block, context);
+            }
+        }
+
+        fn walk_closure<'ast>(&self, _closure: &'ast Closure, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+
+        fn walk_arrow_function<'ast>(
+            &self,
+            _arrow_function: &'ast ArrowFunction,
+            _context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+        }
+
+        fn walk_function<'ast>(&self, _function: &'ast Function, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+
+        fn walk_anonymous_class<'ast>(
+            &self,
+            _anonymous_class: &'ast AnonymousClass,
+            _context: &mut (State<'a>, &'a LintContext<'a>),
+        ) {
+        }
+
+        fn walk_class<'ast>(&self, _class: &'ast Class, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+
+        fn walk_interface<'ast>(&self, _interface: &'ast Interface, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+
+        fn walk_trait<'ast>(&self, _trait: &'ast Trait, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+
+        fn walk_enum<'ast>(&self, _enum: &'ast Enum, _context: &mut (State<'a>, &'a LintContext<'a>)) {}
+    }
+
+    fn is_this(expression: &Expression, context: &LintContext<'_>) -> bool {
+        matches!(expression, Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this")
+    }
+}
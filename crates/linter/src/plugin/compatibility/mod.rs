@@ -1,17 +1,53 @@
 use crate::definition::PluginDefinition;
+use crate::plugin::compatibility::rules::internal::incompatible_internal_function_call::IncompatibleInternalFunctionCallRule;
+use crate::plugin::compatibility::rules::internal::unavailable_internal_class::UnavailableInternalClassRule;
+use crate::plugin::compatibility::rules::internal::unavailable_internal_function::UnavailableInternalFunctionRule;
+use crate::plugin::compatibility::rules::php73::flexible_heredocs_feature::FlexibleHeredocsFeatureRule;
 use crate::plugin::compatibility::rules::php74::arrow_functions_feature::ArrowFunctionsFeatureRule;
 use crate::plugin::compatibility::rules::php74::null_coalesce_assignment_feature::NullCoalesceAssignmentFeatureRule;
+use crate::plugin::compatibility::rules::php80::abstract_trait_methods_feature::AbstractTraitMethodsFeatureRule;
+use crate::plugin::compatibility::rules::php80::case_insensitive_constant_names_feature::CaseInsensitiveConstantNamesFeatureRule;
+use crate::plugin::compatibility::rules::php80::class_constant_on_expression_feature::ClassConstantOnExpressionFeatureRule;
+use crate::plugin::compatibility::rules::php80::legacy_constructor_feature::LegacyConstructorFeatureRule;
 use crate::plugin::compatibility::rules::php80::named_arguments_feature::NamedArgumentsFeatureRule;
+use crate::plugin::compatibility::rules::php80::non_capturing_catches_feature::NonCapturingCatchesFeatureRule;
 use crate::plugin::compatibility::rules::php80::promoted_properties_feature::PromotedPropertiesFeatureRule;
+use crate::plugin::compatibility::rules::php80::throw_expression_feature::ThrowExpressionFeatureRule;
 use crate::plugin::compatibility::rules::php80::union_type_hint_feature::UnionTypeHintFeatureRule;
+use crate::plugin::compatibility::rules::php80::unset_cast_feature::UnsetCastFeatureRule;
+use crate::plugin::compatibility::rules::php81::array_unpacking_with_string_keys_feature::ArrayUnpackingWithStringKeysFeatureRule;
 use crate::plugin::compatibility::rules::php81::closure_creation_feature::ClosureCreationFeatureRule;
+use crate::plugin::compatibility::rules::php81::enums_feature::EnumsFeatureRule;
+use crate::plugin::compatibility::rules::php81::final_constants_feature::FinalConstantsFeatureRule;
+use crate::plugin::compatibility::rules::php81::never_return_type_feature::NeverReturnTypeFeatureRule;
+use crate::plugin::compatibility::rules::php81::new_in_initializers_feature::NewInInitializersFeatureRule;
+use crate::plugin::compatibility::rules::php81::pure_intersection_types_feature::PureIntersectionTypesFeatureRule;
+use crate::plugin::compatibility::rules::php81::readonly_properties_feature::ReadonlyPropertiesFeatureRule;
+use crate::plugin::compatibility::rules::php82::constants_in_traits_feature::ConstantsInTraitsFeatureRule;
+use crate::plugin::compatibility::rules::php82::disjunctive_normal_form_types_feature::DisjunctiveNormalFormTypesFeatureRule;
+use crate::plugin::compatibility::rules::php82::never_return_type_in_arrow_function_feature::NeverReturnTypeInArrowFunctionFeatureRule;
+use crate::plugin::compatibility::rules::php82::readonly_classes_feature::ReadonlyClassesFeatureRule;
+use crate::plugin::compatibility::rules::php83::dynamic_class_constant_access_feature::DynamicClassConstantAccessFeatureRule;
+use crate::plugin::compatibility::rules::php83::readonly_anonymous_classes_feature::ReadonlyAnonymousClassesFeatureRule;
+use crate::plugin::compatibility::rules::php83::typed_class_constants_feature::TypedClassConstantsFeatureRule;
 use crate::plugin::compatibility::rules::php84::asymmetric_visibility_feature::AsymmetricVisibilityFeatureRule;
+use crate::plugin::compatibility::rules::php84::property_hooks_feature::PropertyHooksFeatureRule;
+use crate::plugin::compatibility::rules::php85::closure_in_constant_expressions_feature::ClosureInConstantExpressionsFeatureRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
 
 pub mod rules;
 
+/// Provides a rule for each syntactic [`Feature`](mago_php_version::feature::Feature) that is not supported by
+/// every PHP version, applied when the configured PHP version does not support it.
+///
+/// The features that are deprecated, rather than unsupported, are left to the `deprecation` plugin, as the
+/// code still runs on the configured PHP version:
+///
+/// - `ImplicitlyNullableParameterTypes`, deprecated in PHP 8.4, is covered by `deprecation/implicitly-nullable-parameter`.
+/// - `RequiredParameterAfterOptional`, and its `NullableAndDefaultNull` and `UnionOrMixed` variants, deprecated
+///   in PHP 8.0, 8.1, and 8.3, are all reported from PHP 8.0 by `deprecation/optional-parameter-before-required`.
 #[derive(Debug)]
 pub struct CompatibilityPlugin;
 
@@ -26,17 +62,49 @@ impl Plugin for CompatibilityPlugin {
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
         vec![
+            // Internal
+            Box::new(IncompatibleInternalFunctionCallRule),
+            Box::new(UnavailableInternalClassRule),
+            Box::new(UnavailableInternalFunctionRule),
+            // PHP 7.3
+            Box::new(FlexibleHeredocsFeatureRule),
             // PHP 7.4
             Box::new(ArrowFunctionsFeatureRule),
             Box::new(NullCoalesceAssignmentFeatureRule),
             // PHP 8.0
+            Box::new(AbstractTraitMethodsFeatureRule),
+            Box::new(CaseInsensitiveConstantNamesFeatureRule),
+            Box::new(ClassConstantOnExpressionFeatureRule),
+            Box::new(LegacyConstructorFeatureRule),
             Box::new(NamedArgumentsFeatureRule),
+            Box::new(NonCapturingCatchesFeatureRule),
             Box::new(PromotedPropertiesFeatureRule),
+            Box::new(ThrowExpressionFeatureRule),
             Box::new(UnionTypeHintFeatureRule),
+            Box::new(UnsetCastFeatureRule),
             // PHP 8.1
+            Box::new(ArrayUnpackingWithStringKeysFeatureRule),
             Box::new(ClosureCreationFeatureRule),
+            Box::new(EnumsFeatureRule),
+            Box::new(FinalConstantsFeatureRule),
+            Box::new(NeverReturnTypeFeatureRule),
+            Box::new(NewInInitializersFeatureRule),
+            Box::new(PureIntersectionTypesFeatureRule),
+            Box::new(ReadonlyPropertiesFeatureRule),
+            // PHP 8.2
+            Box::new(ConstantsInTraitsFeatureRule),
+            Box::new(DisjunctiveNormalFormTypesFeatureRule),
+            Box::new(NeverReturnTypeInArrowFunctionFeatureRule),
+            Box::new(ReadonlyClassesFeatureRule),
+            // PHP 8.3
+            Box::new(DynamicClassConstantAccessFeatureRule),
+            Box::new(ReadonlyAnonymousClassesFeatureRule),
+            Box::new(TypedClassConstantsFeatureRule),
             // PHP 8.4
             Box::new(AsymmetricVisibilityFeatureRule),
+            Box::new(PropertyHooksFeatureRule),
+            // PHP 8.5
+            Box::new(ClosureInConstantExpressionsFeatureRule),
         ]
     }
 }
@@ -0,0 +1,137 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct IncompatibleInternalFunctionCallRule;
+
+impl Rule for IncompatibleInternalFunctionCallRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Incompatible Internal Function Call", Level::Error)
+            .with_description(indoc! {"
+                Detects calls to internal functions whose arguments do not match the signature of the function
+                in the configured PHP version, because parameters were added, removed, or made required between
+                PHP versions, such as the second parameter of `parse_str()`, which is required since PHP 8.0.
+
+                Signatures are determined from the `#[PhpStormStubsElementAvailable]` attributes of the bundled PHP stubs.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Passing the result array to `parse_str()`",
+                indoc! {r#"
+                    <?php
+
+                    parse_str($query, $result);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Omitting the result array of `parse_str()`, which is required since PHP 8.0",
+                indoc! {r#"
+                    <?php
+
+                    parse_str($query);
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for IncompatibleInternalFunctionCallRule {
+    fn walk_in_function_call(&self, function_call: &FunctionCall, context: &mut LintContext<'_>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.resolve_function_name(identifier);
+        let function_name_id = context.interner.intern(function_name);
+        let Some(function) = context.codebase.get_function(context.interner, &function_name_id) else {
+            return;
+        };
+
+        // Calls to functions that are not available at all are reported by the `unavailable-internal-function` rule.
+        if !function.has_versioned_signature() || !function.availability_reflection.is_available_in(context.php_version)
+        {
+            return;
+        }
+
+        let arguments = &function_call.argument_list.arguments;
+        if arguments.iter().any(|argument| match argument {
+            Argument::Positional(argument) => argument.ellipsis.is_some(),
+            Argument::Named(argument) => argument.ellipsis.is_some(),
+        }) {
+            // The number of unpacked arguments is unknown.
+            return;
+        }
+
+        let parameters = function.get_parameters_in(context.php_version);
+        let positional_arguments =
+            arguments.iter().filter(|argument| matches!(argument, Argument::Positional(_))).count();
+        let is_variadic = parameters.iter().any(|parameter| parameter.is_variadic);
+
+        let mut issues = vec![];
+        if !is_variadic && positional_arguments > parameters.len() {
+            issues.push(format!(
+                "Function `{}` accepts at most {} argument(s) in PHP {}, but {} were given.",
+                function_name,
+                parameters.len(),
+                context.php_version,
+                positional_arguments
+            ));
+        }
+
+        for (position, parameter) in parameters.iter().enumerate() {
+            if parameter.default.is_some() || parameter.is_variadic || position < positional_arguments {
+                continue;
+            }
+
+            let parameter_name = context.lookup(&parameter.name);
+            let is_named = arguments.iter().any(|argument| match argument {
+                Argument::Named(argument) => context.lookup(&argument.name.value) == &parameter_name[1..],
+                Argument::Positional(_) => false,
+            });
+
+            if !is_named {
+                issues.push(format!(
+                    "Function `{}` requires parameter `{}` in PHP {}.",
+                    function_name, parameter_name, context.php_version
+                ));
+            }
+        }
+
+        for argument in arguments.iter() {
+            let Argument::Named(argument) = argument else {
+                continue;
+            };
+
+            let argument_name = context.lookup(&argument.name.value);
+            let is_named =
+                |parameter: &FunctionLikeParameterReflection| &context.lookup(&parameter.name)[1..] == argument_name;
+
+            // Only parameters that exist in other PHP versions are reported, as unknown parameters are not a compatibility issue.
+            if !parameters.iter().any(|parameter| is_named(parameter)) && function.parameters.iter().any(is_named) {
+                issues.push(format!(
+                    "Function `{}` does not have a parameter named `{}` in PHP {}.",
+                    function_name, argument_name, context.php_version
+                ));
+            }
+        }
+
+        for message in issues {
+            let issue = Issue::new(context.level(), message)
+                .with_annotation(
+                    Annotation::primary(function_call.span())
+                        .with_message(format!("This call is incompatible with PHP {}.", context.php_version)),
+                )
+                .with_help("Update the arguments to match the signature of the function in the configured PHP version, or update the `php_version` setting.");
+
+            context.report(issue);
+        }
+    }
+}
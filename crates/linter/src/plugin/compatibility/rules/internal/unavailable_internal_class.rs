@@ -0,0 +1,108 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnavailableInternalClassRule;
+
+impl Rule for UnavailableInternalClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unavailable Internal Class", Level::Error)
+            .with_description(indoc! {"
+                Detects usages of internal classes, interfaces, and enums that are not available in the configured
+                PHP version, when instantiated, extended, implemented, or accessed statically.
+
+                Availability is determined from the `@since` and `@removed` tags of the bundled PHP stubs.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Extending a class available in all PHP versions",
+                indoc! {r#"
+                    <?php
+
+                    final class NotFoundException extends Exception
+                    {
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for UnavailableInternalClassRule {
+    fn walk_in_instantiation(&self, instantiation: &Instantiation, context: &mut LintContext<'_>) {
+        if let Expression::Identifier(identifier) = instantiation.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_static_method_call(&self, static_method_call: &StaticMethodCall, context: &mut LintContext<'_>) {
+        if let Expression::Identifier(identifier) = static_method_call.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_class_constant_access(
+        &self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut LintContext<'_>,
+    ) {
+        if let Expression::Identifier(identifier) = class_constant_access.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_extends(&self, extends: &Extends, context: &mut LintContext<'_>) {
+        for identifier in extends.types.iter() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_implements(&self, implements: &Implements, context: &mut LintContext<'_>) {
+        for identifier in implements.types.iter() {
+            check(identifier, context);
+        }
+    }
+}
+
+fn check(identifier: &Identifier, context: &mut LintContext<'_>) {
+    let Some(class_like) =
+        context.codebase.get_named_class_like(context.interner, context.semantics.names.get(identifier))
+    else {
+        return;
+    };
+
+    let availability = class_like.availability_reflection;
+    if availability.is_available_in(context.php_version) {
+        return;
+    }
+
+    let class_name = context.lookup_name(identifier);
+    let message = match availability.removed {
+        Some(removed) if context.php_version >= removed => {
+            format!("`{}` was removed in PHP {}.", class_name, removed)
+        }
+        _ => format!(
+            "`{}` is only available in PHP {} and above.",
+            class_name,
+            availability.since.unwrap_or(context.php_version)
+        ),
+    };
+
+    let issue = Issue::new(context.level(), message)
+        .with_annotation(
+            Annotation::primary(identifier.span())
+                .with_message(format!("`{}` is not available in PHP {}.", class_name, context.php_version)),
+        )
+        .with_help(
+            "Use an alternative that is available in the configured PHP version, or update the `php_version` setting.",
+        );
+
+    context.report(issue);
+}
@@ -0,0 +1,87 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnavailableInternalFunctionRule;
+
+impl Rule for UnavailableInternalFunctionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unavailable Internal Function", Level::Error)
+            .with_description(indoc! {"
+                Detects calls to internal functions that are not available in the configured PHP version,
+                either because they were introduced in a later version, such as `str_contains()` in PHP 8.0,
+                or because they were removed, such as `each()` in PHP 8.0.
+
+                Availability is determined from the `@since` and `@removed` tags, and the `#[PhpStormStubsElementAvailable]`
+                attributes, of the bundled PHP stubs.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using `foreach`",
+                indoc! {r#"
+                    <?php
+
+                    foreach ($items as $key => $value) {
+                        echo $key, ': ', $value;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `each()`, which was removed in PHP 8.0",
+                indoc! {r#"
+                    <?php
+
+                    while (list($key, $value) = each($items)) {
+                        echo $key, ': ', $value;
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for UnavailableInternalFunctionRule {
+    fn walk_in_function_call(&self, function_call: &FunctionCall, context: &mut LintContext<'_>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.resolve_function_name(identifier);
+        let function_name_id = context.interner.intern(function_name);
+        let Some(function) = context.codebase.get_function(context.interner, &function_name_id) else {
+            return;
+        };
+
+        let availability = function.availability_reflection;
+        if availability.is_available_in(context.php_version) {
+            return;
+        }
+
+        let message = match availability.removed {
+            Some(removed) if context.php_version >= removed => {
+                format!("Function `{}` was removed in PHP {}.", function_name, removed)
+            }
+            _ => format!(
+                "Function `{}` is only available in PHP {} and above.",
+                function_name,
+                availability.since.unwrap_or(context.php_version)
+            ),
+        };
+
+        let issue = Issue::new(context.level(), message)
+            .with_annotation(Annotation::primary(identifier.span()).with_message(format!(
+                "`{}` is not available in PHP {}.",
+                function_name, context.php_version
+            )))
+            .with_help("Use an alternative that is available in the configured PHP version, or update the `php_version` setting.");
+
+        context.report(issue);
+    }
+}
@@ -1,18 +1,60 @@
+pub mod internal {
+    pub mod incompatible_internal_function_call;
+    pub mod unavailable_internal_class;
+    pub mod unavailable_internal_function;
+}
+
+pub mod php73 {
+    pub mod flexible_heredocs_feature;
+}
+
 pub mod php74 {
     pub mod arrow_functions_feature;
     pub mod null_coalesce_assignment_feature;
 }
 
 pub mod php80 {
+    pub mod abstract_trait_methods_feature;
+    pub mod case_insensitive_constant_names_feature;
+    pub mod class_constant_on_expression_feature;
+    pub mod legacy_constructor_feature;
     pub mod named_arguments_feature;
+    pub mod non_capturing_catches_feature;
     pub mod promoted_properties_feature;
+    pub mod throw_expression_feature;
     pub mod union_type_hint_feature;
+    pub mod unset_cast_feature;
 }
 
 pub mod php81 {
+    pub mod array_unpacking_with_string_keys_feature;
     pub mod closure_creation_feature;
+    pub mod enums_feature;
+    pub mod final_constants_feature;
+    pub mod never_return_type_feature;
+    pub mod new_in_initializers_feature;
+    pub mod pure_intersection_types_feature;
+    pub mod readonly_properties_feature;
+}
+
+pub mod php82 {
+    pub mod constants_in_traits_feature;
+    pub mod disjunctive_normal_form_types_feature;
+    pub mod never_return_type_in_arrow_function_feature;
+    pub mod readonly_classes_feature;
+}
+
+pub mod php83 {
+    pub mod dynamic_class_constant_access_feature;
+    pub mod readonly_anonymous_classes_feature;
+    pub mod typed_class_constants_feature;
 }
 
 pub mod php84 {
     pub mod asymmetric_visibility_feature;
+    pub mod property_hooks_feature;
+}
+
+pub mod php85 {
+    pub mod closure_in_constant_expressions_feature;
 }
@@ -0,0 +1,72 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct FlexibleHeredocsFeatureRule;
+
+impl Rule for FlexibleHeredocsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Flexible Heredocs Feature", Level::Error)
+            .with_feature(Feature::FlexibleHeredocs)
+            .with_description(indoc! {r#"
+                Detects usage of the flexible heredoc and nowdoc syntax, introduced in PHP 7.3.
+                This syntax allows the closing marker to be indented, and to be followed by
+                other code on the same line.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using a closing marker at the start of the line (compatible with <7.3)",
+                indoc! {r#"
+                    <?php
+
+                    $html = <<<HTML
+                    <p>Hello</p>
+                    HTML;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using an indented closing marker (PHP 7.3+)",
+                indoc! {r#"
+                    <?php
+
+                    function render(): string {
+                        return <<<HTML
+                            <p>Hello</p>
+                            HTML;
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for FlexibleHeredocsFeatureRule {
+    fn walk_in_document_string(&self, document_string: &DocumentString, context: &mut LintContext<'_>) {
+        let content = context.interner.lookup(&context.semantics.source.content);
+        let rest = &content[document_string.close.end.offset..];
+        let rest = rest.strip_prefix(';').unwrap_or(rest);
+
+        let is_indented = !matches!(document_string.indentation, DocumentIndentation::None);
+        if !is_indented && (rest.is_empty() || rest.starts_with('\n') || rest.starts_with('\r')) {
+            return;
+        }
+
+        let issue =
+            Issue::new(context.level(), "Flexible heredoc and nowdoc syntax is only available in PHP 7.3 and above.")
+                .with_annotation(Annotation::primary(document_string.close).with_message(if is_indented {
+                    "Indented closing marker used here."
+                } else {
+                    "Closing marker followed by code on the same line used here."
+                }))
+                .with_help("Move the closing marker to the start of its own line.");
+
+        context.report(issue);
+    }
+}
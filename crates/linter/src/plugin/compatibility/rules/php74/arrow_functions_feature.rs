@@ -1,7 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::ast::*;
-use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
 use mago_reporting::*;
 use mago_span::*;
 use mago_walker::Walker;
@@ -17,7 +17,7 @@ pub struct ArrowFunctionsFeatureRule;
 impl Rule for ArrowFunctionsFeatureRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Arrow Functions Feature", Level::Error)
-            .with_maximum_supported_php_version(PHPVersion::PHP74)
+            .with_feature(Feature::ArrowFunctions)
             .with_description(indoc! {"
                 Flags any usage of the `fn` keyword for arrow functions, which was introduced in PHP 7.4.
 
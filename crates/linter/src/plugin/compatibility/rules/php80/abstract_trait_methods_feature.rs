@@ -0,0 +1,69 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct AbstractTraitMethodsFeatureRule;
+
+impl Rule for AbstractTraitMethodsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Abstract Trait Methods Feature", Level::Error)
+            .with_feature(Feature::AbstractTraitMethods)
+            .with_description(indoc! {r#"
+                Detects private abstract methods declared in traits, introduced in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring a protected abstract method in a trait (compatible with <8.0)",
+                indoc! {r#"
+                    <?php
+
+                    trait Greeter {
+                        abstract protected function getName(): string;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a private abstract method in a trait (PHP 8.0+)",
+                indoc! {r#"
+                    <?php
+
+                    trait Greeter {
+                        abstract private function getName(): string;
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for AbstractTraitMethodsFeatureRule {
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut LintContext<'_>) {
+        for member in r#trait.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            if !method.modifiers.contains_abstract() || !method.modifiers.contains_private() {
+                continue;
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                "Private abstract methods in traits are only available in PHP 8.0 and above.",
+            )
+            .with_annotation(Annotation::primary(method.span()).with_message("Private abstract method declared here."))
+            .with_annotation(Annotation::secondary(r#trait.name.span()).with_message("Trait declared here."))
+            .with_help("Declare the method as `protected` or `public` instead.");
+
+            context.report(issue);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct CaseInsensitiveConstantNamesFeatureRule;
+
+impl Rule for CaseInsensitiveConstantNamesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Case Insensitive Constant Names Feature", Level::Error)
+            .with_feature(Feature::CaseInsensitiveConstantNames)
+            .with_description(indoc! {r#"
+                Detects constants declared as case-insensitive by passing `true` as the third argument of `define()`,
+                which is no longer supported in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring a case-sensitive constant",
+                indoc! {r#"
+                    <?php
+
+                    define('FOO', 1);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a case-insensitive constant (no longer supported in PHP 8.0)",
+                indoc! {r#"
+                    <?php
+
+                    define('FOO', 1, true);
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for CaseInsensitiveConstantNamesFeatureRule {
+    fn walk_in_function_call(&self, function_call: &FunctionCall, context: &mut LintContext<'_>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        if !context.resolve_function_name(identifier).eq_ignore_ascii_case("define") {
+            return;
+        }
+
+        let flag =
+            function_call.argument_list.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+                Argument::Positional(argument) if index == 2 => Some(&argument.value),
+                Argument::Named(argument) if context.lookup(&argument.name.value) == "case_insensitive" => {
+                    Some(&argument.value)
+                }
+                _ => None,
+            });
+
+        // Passing `false` is still allowed, as it declares a case-sensitive constant.
+        let Some(flag) = flag.filter(|flag| !matches!(flag, Expression::Literal(Literal::False(_)))) else {
+            return;
+        };
+
+        let issue =
+            Issue::new(context.level(), "Case-insensitive constants are no longer supported in PHP 8.0 and above.")
+                .with_annotation(Annotation::primary(flag.span()).with_message("Case-insensitive flag passed here."))
+                .with_annotation(Annotation::secondary(function_call.span()))
+                .with_help("Remove the third argument, and use the exact case of the constant name everywhere.");
+
+        context.report(issue);
+    }
+}
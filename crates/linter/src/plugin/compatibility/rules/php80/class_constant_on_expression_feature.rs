@@ -0,0 +1,72 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ClassConstantOnExpressionFeatureRule;
+
+impl Rule for ClassConstantOnExpressionFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Class Constant On Expression Feature", Level::Error)
+            .with_feature(Feature::ClassConstantOnExpression)
+            .with_description(indoc! {r#"
+                Detects usage of `::class` on objects (e.g. `$object::class`), introduced in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `get_class()` (compatible with <8.0)",
+                indoc! {r#"
+                    <?php
+
+                    $class = get_class($object);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `::class` on an object (PHP 8.0+)",
+                indoc! {r#"
+                    <?php
+
+                    $class = $object::class;
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ClassConstantOnExpressionFeatureRule {
+    fn walk_in_class_constant_access(
+        &self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut LintContext<'_>,
+    ) {
+        let ClassLikeConstantSelector::Identifier(constant) = &class_constant_access.constant else {
+            return;
+        };
+
+        if !context.lookup(&constant.value).eq_ignore_ascii_case("class") {
+            return;
+        }
+
+        if matches!(
+            class_constant_access.class.as_ref(),
+            Expression::Identifier(_) | Expression::Self_(_) | Expression::Static(_) | Expression::Parent(_)
+        ) {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Using `::class` on objects is only available in PHP 8.0 and above.")
+            .with_annotation(
+                Annotation::primary(class_constant_access.span()).with_message("`::class` used on an object here."),
+            )
+            .with_help("Use `get_class()` instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,98 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct LegacyConstructorFeatureRule;
+
+impl Rule for LegacyConstructorFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Legacy Constructor Feature", Level::Error)
+            .with_feature(Feature::LegacyConstructor)
+            .with_description(indoc! {r#"
+                Detects PHP 4 style constructors, methods named after their class in a class outside of a namespace,
+                which are no longer called as constructors in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `__construct`",
+                indoc! {r#"
+                    <?php
+
+                    class Foo
+                    {
+                        public function __construct()
+                        {
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a method named after the class (no longer a constructor in PHP 8.0)",
+                indoc! {r#"
+                    <?php
+
+                    class Foo
+                    {
+                        public function Foo()
+                        {
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for LegacyConstructorFeatureRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'_>) {
+        // Methods named after their class were never constructors in namespaced classes.
+        if context.lookup_name(&class.name).contains('\\') {
+            return;
+        }
+
+        let class_name = context.lookup(&class.name.value);
+        let methods = class.members.iter().filter_map(|member| match member {
+            ClassLikeMember::Method(method) => Some(method),
+            _ => None,
+        });
+
+        let mut legacy_constructor = None;
+        for method in methods {
+            let method_name = context.lookup(&method.name.value);
+            if method_name.eq_ignore_ascii_case("__construct") {
+                // The `__construct` method takes precedence over the legacy constructor.
+                return;
+            }
+
+            if method_name.eq_ignore_ascii_case(class_name) {
+                legacy_constructor = Some(method);
+            }
+        }
+
+        let Some(method) = legacy_constructor else {
+            return;
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Method `{}::{}()` is no longer a constructor in PHP 8.0 and above.",
+                class_name,
+                context.lookup(&method.name.value)
+            ),
+        )
+        .with_annotation(Annotation::primary(method.name.span()).with_message("Legacy constructor declared here."))
+        .with_annotation(Annotation::secondary(class.name.span()).with_message("Class declared here."))
+        .with_help("Rename the method to `__construct`.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,66 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NonCapturingCatchesFeatureRule;
+
+impl Rule for NonCapturingCatchesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Non Capturing Catches Feature", Level::Error)
+            .with_feature(Feature::NonCapturingCatches)
+            .with_description(indoc! {r#"
+                Detects `catch` clauses without a variable (e.g. `catch (Exception)`), introduced in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Capturing the exception (compatible with <8.0)",
+                indoc! {r#"
+                    <?php
+
+                    try {
+                        connect();
+                    } catch (ConnectionException $e) {
+                        retry();
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Catching an exception without a variable (PHP 8.0+)",
+                indoc! {r#"
+                    <?php
+
+                    try {
+                        connect();
+                    } catch (ConnectionException) {
+                        retry();
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for NonCapturingCatchesFeatureRule {
+    fn walk_in_try_catch_clause(&self, try_catch_clause: &TryCatchClause, context: &mut LintContext<'_>) {
+        if try_catch_clause.variable.is_some() {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Non-capturing catches are only available in PHP 8.0 and above.")
+            .with_annotation(
+                Annotation::primary(try_catch_clause.hint.span())
+                    .with_message("Exception caught without a variable here."),
+            )
+            .with_help("Add a variable to the `catch` clause.");
+
+        context.report(issue);
+    }
+}
@@ -1,7 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::FunctionLikeParameter;
-use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
 use mago_reporting::Annotation;
 use mago_reporting::Issue;
 use mago_reporting::Level;
@@ -19,7 +19,7 @@ pub struct PromotedPropertiesFeatureRule;
 impl Rule for PromotedPropertiesFeatureRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Promoted Properties Feature", Level::Error)
-            .with_maximum_supported_php_version(PHPVersion::PHP80)
+            .with_feature(Feature::PromotedProperties)
             .with_description(indoc! {r#"
                 Detects usage of constructor property promotion, introduced in PHP 8.0.
                 This syntax allows property definitions in the constructor signature (e.g.
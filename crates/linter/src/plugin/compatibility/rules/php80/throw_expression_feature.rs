@@ -0,0 +1,91 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ThrowExpressionFeatureRule;
+
+impl Rule for ThrowExpressionFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Throw Expression Feature", Level::Error)
+            .with_feature(Feature::ThrowExpression)
+            .with_description(indoc! {r#"
+                Detects usage of `throw` as an expression (e.g. `$value ?? throw new Exception()`),
+                introduced in PHP 8.0. Before PHP 8.0, `throw` could only be used as a statement.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `throw` as a statement (compatible with <8.0)",
+                indoc! {r#"
+                    <?php
+
+                    if (null === $user) {
+                        throw new UserNotFoundException();
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `throw` as an expression (PHP 8.0+)",
+                indoc! {r#"
+                    <?php
+
+                    $user = $repository->find($id) ?? throw new UserNotFoundException();
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ThrowExpressionFeatureRule {
+    fn walk_in_arrow_function(&self, arrow_function: &ArrowFunction, context: &mut LintContext<'_>) {
+        check(&arrow_function.expression, context);
+    }
+
+    fn walk_in_binary(&self, binary: &Binary, context: &mut LintContext<'_>) {
+        check(&binary.lhs, context);
+        check(&binary.rhs, context);
+    }
+
+    fn walk_in_conditional(&self, conditional: &Conditional, context: &mut LintContext<'_>) {
+        if let Some(then) = &conditional.then {
+            check(then, context);
+        }
+
+        check(&conditional.r#else, context);
+    }
+
+    fn walk_in_assignment(&self, assignment: &Assignment, context: &mut LintContext<'_>) {
+        check(&assignment.rhs, context);
+    }
+
+    fn walk_in_match_expression_arm(&self, match_expression_arm: &MatchExpressionArm, context: &mut LintContext<'_>) {
+        check(&match_expression_arm.expression, context);
+    }
+
+    fn walk_in_match_default_arm(&self, match_default_arm: &MatchDefaultArm, context: &mut LintContext<'_>) {
+        check(&match_default_arm.expression, context);
+    }
+
+    fn walk_in_argument(&self, argument: &Argument, context: &mut LintContext<'_>) {
+        check(argument.value(), context);
+    }
+}
+
+fn check(expression: &Expression, context: &mut LintContext<'_>) {
+    let Expression::Throw(throw) = expression else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "Throw expressions are only available in PHP 8.0 and above.")
+        .with_annotation(Annotation::primary(throw.span()).with_message("`throw` used as an expression here."))
+        .with_help("Use `throw` as a statement instead.");
+
+    context.report(issue);
+}
@@ -3,7 +3,7 @@ use indoc::indoc;
 use mago_ast::FunctionLikeReturnTypeHint;
 use mago_ast::Hint;
 use mago_ast::PlainProperty;
-use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
 use mago_reporting::Annotation;
 use mago_reporting::Issue;
 use mago_reporting::Level;
@@ -21,7 +21,7 @@ pub struct UnionTypeHintFeatureRule;
 impl Rule for UnionTypeHintFeatureRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Union Type Hint Feature", Level::Error)
-            .with_maximum_supported_php_version(PHPVersion::PHP80)
+            .with_feature(Feature::NativeUnionTypes)
             .with_description(indoc! {"
                 Detects usage of union type hints (e.g. `int|float` or `A|B`) which were introduced in PHP 8.0.
             "})
@@ -0,0 +1,56 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct UnsetCastFeatureRule;
+
+impl Rule for UnsetCastFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Unset Cast Feature", Level::Error)
+            .with_feature(Feature::UnsetCast)
+            .with_description(indoc! {r#"
+                Detects usage of the `(unset)` cast, which was removed in PHP 8.0.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `null` instead of the `(unset)` cast",
+                indoc! {r#"
+                    <?php
+
+                    $value = null;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using the `(unset)` cast (removed in PHP 8.0)",
+                indoc! {r#"
+                    <?php
+
+                    $value = (unset) $input;
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for UnsetCastFeatureRule {
+    fn walk_in_unary_prefix(&self, unary_prefix: &UnaryPrefix, context: &mut LintContext<'_>) {
+        let UnaryPrefixOperator::UnsetCast(span, _) = unary_prefix.operator else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "The `(unset)` cast is no longer available in PHP 8.0 and above.")
+            .with_annotation(Annotation::primary(span).with_message("`(unset)` cast used here."))
+            .with_annotation(Annotation::secondary(unary_prefix.span()))
+            .with_help("Use `null` instead of casting the expression to `null`.");
+
+        context.report(issue);
+    }
+}
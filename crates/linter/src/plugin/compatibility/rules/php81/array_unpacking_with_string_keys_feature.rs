@@ -0,0 +1,79 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ArrayUnpackingWithStringKeysFeatureRule;
+
+impl Rule for ArrayUnpackingWithStringKeysFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Array Unpacking With String Keys Feature", Level::Error)
+            .with_feature(Feature::ArrayUnpackingWithStringKeys)
+            .with_description(indoc! {r#"
+                Detects unpacking of array literals with string keys (e.g. `[...['a' => 1]]`), introduced in PHP 8.1.
+
+                Only array literals are checked, as the keys of other unpacked values are not known statically.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `array_merge()` (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    $options = array_merge(['debug' => false], $overrides);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Unpacking an array with string keys (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    $options = [...['debug' => false], ...$overrides];
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ArrayUnpackingWithStringKeysFeatureRule {
+    fn walk_in_variadic_array_element(
+        &self,
+        variadic_array_element: &VariadicArrayElement,
+        context: &mut LintContext<'_>,
+    ) {
+        let elements = match variadic_array_element.value.as_ref() {
+            Expression::Array(array) => &array.elements,
+            Expression::LegacyArray(array) => &array.elements,
+            _ => return,
+        };
+
+        let has_string_key = elements.iter().any(|element| {
+            matches!(
+                element,
+                ArrayElement::KeyValue(KeyValueArrayElement { key, .. })
+                    if matches!(key.as_ref(), Expression::Literal(Literal::String(_)))
+            )
+        });
+
+        if !has_string_key {
+            return;
+        }
+
+        let issue =
+            Issue::new(context.level(), "Unpacking arrays with string keys is only available in PHP 8.1 and above.")
+                .with_annotation(
+                    Annotation::primary(variadic_array_element.span())
+                        .with_message("Array with string keys unpacked here."),
+                )
+                .with_help("Use `array_merge()` instead.");
+
+        context.report(issue);
+    }
+}
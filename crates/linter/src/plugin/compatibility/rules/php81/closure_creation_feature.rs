@@ -1,7 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::ClosureCreation;
-use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
 use mago_reporting::Annotation;
 use mago_reporting::Issue;
 use mago_reporting::Level;
@@ -19,7 +19,7 @@ pub struct ClosureCreationFeatureRule;
 impl Rule for ClosureCreationFeatureRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Closure Creation Feature", Level::Error)
-            .with_maximum_supported_php_version(PHPVersion::PHP81)
+            .with_feature(Feature::ClosureCreation)
             .with_description(indoc! {r#"
                 Detects usage of the closure creation syntax (e.g. `$var = foo(...)`)
                 introduced in PHP 8.1. This feature allows creating a closure
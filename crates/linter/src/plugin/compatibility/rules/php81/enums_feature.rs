@@ -0,0 +1,56 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct EnumsFeatureRule;
+
+impl Rule for EnumsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Enums Feature", Level::Error)
+            .with_feature(Feature::Enums)
+            .with_description(indoc! {r#"
+                Detects enum declarations, introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using class constants (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    final class Suit {
+                        public const HEARTS = 'hearts';
+                        public const SPADES = 'spades';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring an enum (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    enum Suit: string {
+                        case Hearts = 'hearts';
+                        case Spades = 'spades';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for EnumsFeatureRule {
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'_>) {
+        let issue = Issue::new(context.level(), "Enums are only available in PHP 8.1 and above.")
+            .with_annotation(Annotation::primary(r#enum.span()).with_message("Enum declared here."));
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,59 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct FinalConstantsFeatureRule;
+
+impl Rule for FinalConstantsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Final Constants Feature", Level::Error)
+            .with_feature(Feature::FinalConstants)
+            .with_description(indoc! {r#"
+                Detects class constants declared with the `final` modifier, introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring a constant without `final` (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    class Config {
+                        public const VERSION = '1.0';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a `final` constant (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    class Config {
+                        final public const VERSION = '1.0';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for FinalConstantsFeatureRule {
+    fn walk_in_class_like_constant(&self, class_like_constant: &ClassLikeConstant, context: &mut LintContext<'_>) {
+        let Some(modifier) = class_like_constant.modifiers.get_final() else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "Final class constants are only available in PHP 8.1 and above.")
+            .with_annotation(Annotation::primary(modifier.span()).with_message("`final` modifier used here."))
+            .with_help("Remove the `final` modifier.");
+
+        context.report(issue);
+    }
+}
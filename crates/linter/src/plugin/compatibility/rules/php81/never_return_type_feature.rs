@@ -0,0 +1,74 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NeverReturnTypeFeatureRule;
+
+impl Rule for NeverReturnTypeFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Never Return Type Feature", Level::Error)
+            .with_feature(Feature::NeverReturnType)
+            .with_description(indoc! {r#"
+                Detects functions, methods, and closures declared with the `never` return type,
+                introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Documenting the `never` return type (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @return never
+                     */
+                    function fail(string $message) {
+                        throw new RuntimeException($message);
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using the `never` return type (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    function fail(string $message): never {
+                        throw new RuntimeException($message);
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for NeverReturnTypeFeatureRule {
+    fn walk_in_function(&self, function: &Function, context: &mut LintContext<'_>) {
+        check(function.return_type_hint.as_ref(), context);
+    }
+
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'_>) {
+        check(method.return_type_hint.as_ref(), context);
+    }
+
+    fn walk_in_closure(&self, closure: &Closure, context: &mut LintContext<'_>) {
+        check(closure.return_type_hint.as_ref(), context);
+    }
+}
+
+fn check(return_type_hint: Option<&FunctionLikeReturnTypeHint>, context: &mut LintContext<'_>) {
+    let Some(FunctionLikeReturnTypeHint { hint: Hint::Never(never), .. }) = return_type_hint else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "The `never` return type is only available in PHP 8.1 and above.")
+        .with_annotation(Annotation::primary(never.span()).with_message("`never` return type used here."));
+
+    context.report(issue);
+}
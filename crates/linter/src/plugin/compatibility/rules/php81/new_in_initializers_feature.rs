@@ -0,0 +1,91 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NewInInitializersFeatureRule;
+
+impl Rule for NewInInitializersFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("New In Initializers Feature", Level::Error)
+            .with_feature(Feature::NewInInitializers)
+            .with_description(indoc! {r#"
+                Detects usage of `new` in parameter default values, static variable initializers,
+                global constant initializers, and attribute arguments, introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Creating the default value in the body (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    class Service {
+                        private $logger;
+
+                        public function __construct(?Logger $logger = null) {
+                            $this->logger = $logger ?? new NullLogger();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `new` in a parameter default value (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    class Service {
+                        public function __construct(
+                            private Logger $logger = new NullLogger(),
+                        ) {}
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for NewInInitializersFeatureRule {
+    fn walk_in_function_like_parameter_default_value(
+        &self,
+        function_like_parameter_default_value: &FunctionLikeParameterDefaultValue,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&function_like_parameter_default_value.value, context);
+    }
+
+    fn walk_in_static_concrete_item(&self, static_concrete_item: &StaticConcreteItem, context: &mut LintContext<'_>) {
+        check(&static_concrete_item.value, context);
+    }
+
+    fn walk_in_constant_item(&self, constant_item: &ConstantItem, context: &mut LintContext<'_>) {
+        check(&constant_item.value, context);
+    }
+
+    fn walk_in_attribute(&self, attribute: &Attribute, context: &mut LintContext<'_>) {
+        let Some(argument_list) = &attribute.arguments else {
+            return;
+        };
+
+        for argument in argument_list.arguments.iter() {
+            check(argument.value(), context);
+        }
+    }
+}
+
+fn check(expression: &Expression, context: &mut LintContext<'_>) {
+    let Expression::Instantiation(instantiation) = expression else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "Using `new` in initializers is only available in PHP 8.1 and above.")
+        .with_annotation(Annotation::primary(instantiation.span()).with_message("`new` used in an initializer here."));
+
+    context.report(issue);
+}
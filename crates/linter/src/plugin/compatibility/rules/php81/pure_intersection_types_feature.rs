@@ -0,0 +1,97 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct PureIntersectionTypesFeatureRule;
+
+impl Rule for PureIntersectionTypesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Pure Intersection Types Feature", Level::Error)
+            .with_feature(Feature::PureIntersectionTypes)
+            .with_description(indoc! {r#"
+                Detects usage of intersection type hints (e.g. `Countable&Traversable`), introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Documenting the intersection type (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    /**
+                     * @param Countable&Traversable $items
+                     */
+                    function process($items): void {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using an intersection type hint (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    function process(Countable&Traversable $items): void {}
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for PureIntersectionTypesFeatureRule {
+    fn walk_in_function_like_parameter(
+        &self,
+        function_like_parameter: &FunctionLikeParameter,
+        context: &mut LintContext<'_>,
+    ) {
+        if let Some(hint) = &function_like_parameter.hint {
+            check(hint, context);
+        }
+    }
+
+    fn walk_in_function_like_return_type_hint(
+        &self,
+        function_like_return_type_hint: &FunctionLikeReturnTypeHint,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&function_like_return_type_hint.hint, context);
+    }
+
+    fn walk_in_plain_property(&self, plain_property: &PlainProperty, context: &mut LintContext<'_>) {
+        if let Some(hint) = &plain_property.hint {
+            check(hint, context);
+        }
+    }
+
+    fn walk_in_hooked_property(&self, hooked_property: &HookedProperty, context: &mut LintContext<'_>) {
+        if let Some(hint) = &hooked_property.hint {
+            check(hint, context);
+        }
+    }
+
+    fn walk_in_class_like_constant(&self, class_like_constant: &ClassLikeConstant, context: &mut LintContext<'_>) {
+        if let Some(hint) = &class_like_constant.hint {
+            check(hint, context);
+        }
+    }
+}
+
+/// Reports the hint if it is an intersection type hint; intersections nested in union type hints
+/// are disjunctive normal form types, which are covered by a separate rule.
+fn check(hint: &Hint, context: &mut LintContext<'_>) {
+    let Hint::Intersection(intersection_hint) = hint else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "Intersection type hints are only available in PHP 8.1 and above.")
+        .with_annotation(
+            Annotation::primary(intersection_hint.span()).with_message("Intersection type hint used here."),
+        );
+
+    context.report(issue);
+}
@@ -0,0 +1,83 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReadonlyPropertiesFeatureRule;
+
+impl Rule for ReadonlyPropertiesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Readonly Properties Feature", Level::Error)
+            .with_feature(Feature::ReadonlyProperties)
+            .with_description(indoc! {r#"
+                Detects properties, including promoted properties, declared with the `readonly` modifier,
+                introduced in PHP 8.1.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using a private property with a getter (compatible with <8.1)",
+                indoc! {r#"
+                    <?php
+
+                    class User {
+                        private $id;
+
+                        public function __construct(int $id) {
+                            $this->id = $id;
+                        }
+
+                        public function getId(): int {
+                            return $this->id;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a `readonly` property (PHP 8.1+)",
+                indoc! {r#"
+                    <?php
+
+                    class User {
+                        public readonly int $id;
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ReadonlyPropertiesFeatureRule {
+    fn walk_in_plain_property(&self, plain_property: &PlainProperty, context: &mut LintContext<'_>) {
+        check(&plain_property.modifiers, context);
+    }
+
+    fn walk_in_hooked_property(&self, hooked_property: &HookedProperty, context: &mut LintContext<'_>) {
+        check(&hooked_property.modifiers, context);
+    }
+
+    fn walk_in_function_like_parameter(
+        &self,
+        function_like_parameter: &FunctionLikeParameter,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&function_like_parameter.modifiers, context);
+    }
+}
+
+fn check(modifiers: &Sequence<Modifier>, context: &mut LintContext<'_>) {
+    let Some(modifier) = modifiers.get_readonly() else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "Readonly properties are only available in PHP 8.1 and above.")
+        .with_annotation(Annotation::primary(modifier.span()).with_message("`readonly` modifier used here."));
+
+    context.report(issue);
+}
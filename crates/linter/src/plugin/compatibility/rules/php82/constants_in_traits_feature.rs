@@ -0,0 +1,61 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ConstantsInTraitsFeatureRule;
+
+impl Rule for ConstantsInTraitsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Constants In Traits Feature", Level::Error)
+            .with_feature(Feature::ConstantsInTraits)
+            .with_description(indoc! {r#"
+                Detects constants declared in traits, introduced in PHP 8.2.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring the constant in an interface (compatible with <8.2)",
+                indoc! {r#"
+                    <?php
+
+                    interface HasVersion {
+                        public const VERSION = '1.0';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a constant in a trait (PHP 8.2+)",
+                indoc! {r#"
+                    <?php
+
+                    trait HasVersion {
+                        public const VERSION = '1.0';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ConstantsInTraitsFeatureRule {
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut LintContext<'_>) {
+        for member in r#trait.members.iter() {
+            let ClassLikeMember::Constant(constant) = member else {
+                continue;
+            };
+
+            let issue = Issue::new(context.level(), "Constants in traits are only available in PHP 8.2 and above.")
+                .with_annotation(Annotation::primary(constant.span()).with_message("Constant declared here."))
+                .with_annotation(Annotation::secondary(r#trait.name.span()).with_message("Trait declared here."));
+
+            context.report(issue);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DisjunctiveNormalFormTypesFeatureRule;
+
+impl Rule for DisjunctiveNormalFormTypesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Disjunctive Normal Form Types Feature", Level::Error)
+            .with_feature(Feature::DisjunctiveNormalForm)
+            .with_description(indoc! {r#"
+                Detects usage of disjunctive normal form (DNF) type hints (e.g. `(A&B)|null`), introduced in PHP 8.2.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using a union type hint (compatible with <8.2)",
+                indoc! {r#"
+                    <?php
+
+                    function process(Countable|Traversable $items): void {}
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a DNF type hint (PHP 8.2+)",
+                indoc! {r#"
+                    <?php
+
+                    function process((Countable&Traversable)|null $items): void {}
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for DisjunctiveNormalFormTypesFeatureRule {
+    fn walk_in_parenthesized_hint(&self, parenthesized_hint: &ParenthesizedHint, context: &mut LintContext<'_>) {
+        let issue =
+            Issue::new(context.level(), "Disjunctive normal form type hints are only available in PHP 8.2 and above.")
+                .with_annotation(
+                    Annotation::primary(parenthesized_hint.span()).with_message("Parenthesized type hint used here."),
+                );
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,60 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NeverReturnTypeInArrowFunctionFeatureRule;
+
+impl Rule for NeverReturnTypeInArrowFunctionFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Never Return Type In Arrow Function Feature", Level::Error)
+            .with_feature(Feature::NeverReturnTypeInArrowFunction)
+            .with_description(indoc! {r#"
+                Detects arrow functions declared with the `never` return type, introduced in PHP 8.2.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using a closure (compatible with <8.2)",
+                indoc! {r#"
+                    <?php
+
+                    $fail = function (string $message): never {
+                        throw new RuntimeException($message);
+                    };
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using the `never` return type in an arrow function (PHP 8.2+)",
+                indoc! {r#"
+                    <?php
+
+                    $fail = fn (string $message): never => throw new RuntimeException($message);
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for NeverReturnTypeInArrowFunctionFeatureRule {
+    fn walk_in_arrow_function(&self, arrow_function: &ArrowFunction, context: &mut LintContext<'_>) {
+        let Some(FunctionLikeReturnTypeHint { hint: Hint::Never(never), .. }) = &arrow_function.return_type_hint else {
+            return;
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            "The `never` return type in arrow functions is only available in PHP 8.2 and above.",
+        )
+        .with_annotation(Annotation::primary(never.span()).with_message("`never` return type used here."))
+        .with_help("Use a closure instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,65 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReadonlyClassesFeatureRule;
+
+impl Rule for ReadonlyClassesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Readonly Classes Feature", Level::Error)
+            .with_feature(Feature::ReadonlyClasses)
+            .with_description(indoc! {r#"
+                Detects classes declared with the `readonly` modifier, introduced in PHP 8.2.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring `readonly` properties (compatible with <8.2)",
+                indoc! {r#"
+                    <?php
+
+                    final class Point {
+                        public function __construct(
+                            public readonly int $x,
+                            public readonly int $y,
+                        ) {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a `readonly` class (PHP 8.2+)",
+                indoc! {r#"
+                    <?php
+
+                    final readonly class Point {
+                        public function __construct(
+                            public int $x,
+                            public int $y,
+                        ) {}
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ReadonlyClassesFeatureRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'_>) {
+        let Some(modifier) = class.modifiers.get_readonly() else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "Readonly classes are only available in PHP 8.2 and above.")
+            .with_annotation(Annotation::primary(modifier.span()).with_message("`readonly` modifier used here."))
+            .with_help("Remove the `readonly` modifier, and declare each property as `readonly` instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,62 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DynamicClassConstantAccessFeatureRule;
+
+impl Rule for DynamicClassConstantAccessFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Dynamic Class Constant Access Feature", Level::Error)
+            .with_feature(Feature::DynamicClassConstantAccess)
+            .with_description(indoc! {r#"
+                Detects dynamic class constant access (e.g. `Foo::{$name}`), introduced in PHP 8.3.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using `constant()` (compatible with <8.3)",
+                indoc! {r#"
+                    <?php
+
+                    $value = constant(Config::class . '::' . $name);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Accessing a class constant dynamically (PHP 8.3+)",
+                indoc! {r#"
+                    <?php
+
+                    $value = Config::{$name};
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for DynamicClassConstantAccessFeatureRule {
+    fn walk_in_class_constant_access(
+        &self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut LintContext<'_>,
+    ) {
+        let ClassLikeConstantSelector::Expression(selector) = &class_constant_access.constant else {
+            return;
+        };
+
+        let issue =
+            Issue::new(context.level(), "Dynamic class constant access is only available in PHP 8.3 and above.")
+                .with_annotation(
+                    Annotation::primary(selector.span()).with_message("Dynamic class constant access used here."),
+                )
+                .with_help("Use the `constant()` function instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,65 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ReadonlyAnonymousClassesFeatureRule;
+
+impl Rule for ReadonlyAnonymousClassesFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Readonly Anonymous Classes Feature", Level::Error)
+            .with_feature(Feature::ReadonlyAnonymousClasses)
+            .with_description(indoc! {r#"
+                Detects anonymous classes declared with the `readonly` modifier, introduced in PHP 8.3.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring `readonly` properties (compatible with <8.3)",
+                indoc! {r#"
+                    <?php
+
+                    $point = new class(1, 2) {
+                        public function __construct(
+                            public readonly int $x,
+                            public readonly int $y,
+                        ) {}
+                    };
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a `readonly` anonymous class (PHP 8.3+)",
+                indoc! {r#"
+                    <?php
+
+                    $point = new readonly class(1, 2) {
+                        public function __construct(
+                            public int $x,
+                            public int $y,
+                        ) {}
+                    };
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ReadonlyAnonymousClassesFeatureRule {
+    fn walk_in_anonymous_class(&self, anonymous_class: &AnonymousClass, context: &mut LintContext<'_>) {
+        let Some(modifier) = anonymous_class.modifiers.get_readonly() else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "Readonly anonymous classes are only available in PHP 8.3 and above.")
+            .with_annotation(Annotation::primary(modifier.span()).with_message("`readonly` modifier used here."))
+            .with_help("Remove the `readonly` modifier, and declare each property as `readonly` instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,60 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct TypedClassConstantsFeatureRule;
+
+impl Rule for TypedClassConstantsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Typed Class Constants Feature", Level::Error)
+            .with_feature(Feature::TypedClassLikeConstants)
+            .with_description(indoc! {r#"
+                Detects class constants declared with a type hint (e.g. `const string NAME = 'x';`),
+                introduced in PHP 8.3.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Declaring an untyped constant (compatible with <8.3)",
+                indoc! {r#"
+                    <?php
+
+                    class Config {
+                        public const VERSION = '1.0';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Declaring a typed constant (PHP 8.3+)",
+                indoc! {r#"
+                    <?php
+
+                    class Config {
+                        public const string VERSION = '1.0';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for TypedClassConstantsFeatureRule {
+    fn walk_in_class_like_constant(&self, class_like_constant: &ClassLikeConstant, context: &mut LintContext<'_>) {
+        let Some(hint) = &class_like_constant.hint else {
+            return;
+        };
+
+        let issue = Issue::new(context.level(), "Typed class constants are only available in PHP 8.3 and above.")
+            .with_annotation(Annotation::primary(hint.span()).with_message("Constant type hint used here."))
+            .with_help("Remove the type hint.");
+
+        context.report(issue);
+    }
+}
@@ -1,7 +1,7 @@
 use indoc::indoc;
 
 use mago_ast::PlainProperty;
-use mago_php_version::PHPVersion;
+use mago_php_version::feature::Feature;
 use mago_reporting::Annotation;
 use mago_reporting::Issue;
 use mago_reporting::Level;
@@ -19,7 +19,7 @@ pub struct AsymmetricVisibilityFeatureRule;
 impl Rule for AsymmetricVisibilityFeatureRule {
     fn get_definition(&self) -> RuleDefinition {
         RuleDefinition::enabled("Asymmetric Visibility Feature", Level::Error)
-            .with_maximum_supported_php_version(PHPVersion::PHP84)
+            .with_feature(Feature::AsymmetricVisibility)
             .with_description(indoc! {r#"
                 Detects usage of asymmetric visibility on properties or methods
                 (e.g., `public protected(set)`, `protected private(set)`), introduced in PHP 8.4.
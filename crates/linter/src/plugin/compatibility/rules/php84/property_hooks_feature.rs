@@ -0,0 +1,69 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct PropertyHooksFeatureRule;
+
+impl Rule for PropertyHooksFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Property Hooks Feature", Level::Error)
+            .with_feature(Feature::PropertyHooks)
+            .with_description(indoc! {r#"
+                Detects properties, including promoted properties, declared with hooks, introduced in PHP 8.4.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Using getter methods (compatible with <8.4)",
+                indoc! {r#"
+                    <?php
+
+                    class User {
+                        public function __construct(
+                            private string $first,
+                            private string $last,
+                        ) {}
+
+                        public function getFullName(): string {
+                            return $this->first . ' ' . $this->last;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a property hook (PHP 8.4+)",
+                indoc! {r#"
+                    <?php
+
+                    class User {
+                        public string $fullName {
+                            get => $this->first . ' ' . $this->last;
+                        }
+
+                        public function __construct(
+                            private string $first,
+                            private string $last,
+                        ) {}
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for PropertyHooksFeatureRule {
+    fn walk_in_property_hook_list(&self, property_hook_list: &PropertyHookList, context: &mut LintContext<'_>) {
+        let issue = Issue::new(context.level(), "Property hooks are only available in PHP 8.4 and above.")
+            .with_annotation(Annotation::primary(property_hook_list.span()).with_message("Property hooks used here."))
+            .with_help("Use getter and setter methods instead.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,104 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ClosureInConstantExpressionsFeatureRule;
+
+impl Rule for ClosureInConstantExpressionsFeatureRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Closure In Constant Expressions Feature", Level::Error)
+            .with_feature(Feature::ClosureInConstantExpressions)
+            .with_description(indoc! {r#"
+                Detects closures used in constant expressions, such as constant values, parameter and
+                property default values, and attribute arguments, introduced in PHP 8.5.
+            "#})
+            .with_example(RuleUsageExample::valid(
+                "Creating the closure in the body (compatible with <8.5)",
+                indoc! {r#"
+                    <?php
+
+                    function filter(array $items, ?Closure $predicate = null): array {
+                        return array_filter($items, $predicate ?? function ($item) {
+                            return null !== $item;
+                        });
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a closure as a parameter default value (PHP 8.5+)",
+                indoc! {r#"
+                    <?php
+
+                    function filter(array $items, Closure $predicate = static function ($item) {
+                        return null !== $item;
+                    }): array {
+                        return array_filter($items, $predicate);
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for ClosureInConstantExpressionsFeatureRule {
+    fn walk_in_function_like_parameter_default_value(
+        &self,
+        function_like_parameter_default_value: &FunctionLikeParameterDefaultValue,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&function_like_parameter_default_value.value, context);
+    }
+
+    fn walk_in_property_concrete_item(
+        &self,
+        property_concrete_item: &PropertyConcreteItem,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&property_concrete_item.value, context);
+    }
+
+    fn walk_in_class_like_constant_item(
+        &self,
+        class_like_constant_item: &ClassLikeConstantItem,
+        context: &mut LintContext<'_>,
+    ) {
+        check(&class_like_constant_item.value, context);
+    }
+
+    fn walk_in_constant_item(&self, constant_item: &ConstantItem, context: &mut LintContext<'_>) {
+        check(&constant_item.value, context);
+    }
+
+    fn walk_in_attribute(&self, attribute: &Attribute, context: &mut LintContext<'_>) {
+        let Some(argument_list) = &attribute.arguments else {
+            return;
+        };
+
+        for argument in argument_list.arguments.iter() {
+            check(argument.value(), context);
+        }
+    }
+}
+
+fn check(expression: &Expression, context: &mut LintContext<'_>) {
+    let Expression::Closure(closure) = expression else {
+        return;
+    };
+
+    let issue =
+        Issue::new(context.level(), "Closures in constant expressions are only available in PHP 8.5 and above.")
+            .with_annotation(
+                Annotation::primary(closure.span()).with_message("Closure used in a constant expression here."),
+            );
+
+    context.report(issue);
+}
@@ -1,5 +1,6 @@
 use crate::definition::PluginDefinition;
 use crate::plugin::consistency::rules::array_syntax::ArraySyntaxRule;
+use crate::plugin::consistency::rules::class_member_order::ClassMemberOrderRule;
 use crate::plugin::consistency::rules::lowercase_hint::LowercaseHintRule;
 use crate::plugin::consistency::rules::lowercase_keyword::LowercaseKeywordRule;
 use crate::plugin::consistency::rules::no_function_aliases::NoFunctionAliasesRule;
@@ -26,6 +27,7 @@ impl Plugin for ConsistencyPlugin {
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
         vec![
             Box::new(ArraySyntaxRule),
+            Box::new(ClassMemberOrderRule),
             Box::new(LowercaseHintRule),
             Box::new(LowercaseKeywordRule),
             Box::new(NoFunctionAliasesRule),
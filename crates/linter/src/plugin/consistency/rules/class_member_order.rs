@@ -0,0 +1,184 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_ast_utils::member::get_class_like_member_category;
+use mago_ast_utils::member::get_sorted_class_like_member_indices;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct ClassMemberOrderRule;
+
+impl Rule for ClassMemberOrderRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::disabled("Class Member Order")
+            .with_description(indoc! {"
+                Enforces a canonical order for the members of classes, interfaces, traits, and enums:
+                trait uses, constants, enum cases, properties, the constructor, then public, protected,
+                and private methods.
+
+                This rule reports the same order the formatter applies with `sort_class_members`,
+                for teams that prefer not to have the formatter move code.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Members in canonical order",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        use HasName;
+
+                        public const ADMIN = 'admin';
+
+                        private int $age = 0;
+
+                        public function __construct(private string $role)
+                        {
+                        }
+
+                        public function getRole(): string
+                        {
+                            return $this->role;
+                        }
+
+                        private function isAdmin(): bool
+                        {
+                            return $this->role === self::ADMIN;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A property declared after a method",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function getAge(): int
+                        {
+                            return $this->age;
+                        }
+
+                        private int $age = 0;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A private method declared before a public method",
+                indoc! {r#"
+                    <?php
+
+                    enum Suit
+                    {
+                        case Hearts;
+
+                        private function helper(): void
+                        {
+                        }
+
+                        public function color(): string
+                        {
+                            return 'Red';
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ClassMemberOrderRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        check(&class.left_brace, &class.members, context);
+    }
+
+    fn walk_in_interface(&self, interface: &Interface, context: &mut LintContext<'a>) {
+        check(&interface.left_brace, &interface.members, context);
+    }
+
+    fn walk_in_trait(&self, r#trait: &Trait, context: &mut LintContext<'a>) {
+        check(&r#trait.left_brace, &r#trait.members, context);
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        check(&r#enum.left_brace, &r#enum.members, context);
+    }
+
+    fn walk_in_anonymous_class(&self, anonymous_class: &AnonymousClass, context: &mut LintContext<'a>) {
+        check(&anonymous_class.left_brace, &anonymous_class.members, context);
+    }
+}
+
+#[inline]
+fn check(left_brace: &Span, members: &Sequence<ClassLikeMember>, context: &mut LintContext<'_>) {
+    let mut previous: Option<&ClassLikeMember> = None;
+    let mut misplaced = None;
+    for member in members.iter() {
+        if let Some(previous) = previous {
+            let category = get_class_like_member_category(context.interner, member);
+            if get_class_like_member_category(context.interner, previous) > category {
+                misplaced = Some((member, previous, category));
+
+                break;
+            }
+        }
+
+        previous = Some(member);
+    }
+
+    let Some((member, previous, category)) = misplaced else {
+        return;
+    };
+
+    let issue = Issue::new(context.level(), "Class members are not in canonical order.")
+        .with_annotation(
+            Annotation::primary(member.span())
+                .with_message(format!("This member belongs with {}, before this point.", category.get_description())),
+        )
+        .with_annotation(Annotation::secondary(previous.span()).with_message(format!(
+            "It is declared after {}.",
+            get_class_like_member_category(context.interner, previous).get_description()
+        )))
+        .with_note(
+            "Members are ordered as: trait uses, constants, enum cases, properties, the constructor, then public, protected, and private methods.",
+        )
+        .with_help("Move the member to its place in the canonical order.");
+
+    // Each member is moved along with the whitespace and comments preceding it, up to the end of the
+    // line of the previous member, so that trailing comments stay with the member they follow.
+    let content = context.lookup(&context.semantics.source.content);
+    let slice = members.as_slice();
+    let mut chunks = vec![];
+    let mut start = left_brace.end.offset;
+    for (i, member) in slice.iter().enumerate() {
+        let mut end = member.span().end.offset;
+        if let Some(next) = slice.get(i + 1) {
+            if let Some(newline) = content[end..next.span().start.offset].find('\n') {
+                end += newline;
+            }
+        }
+
+        chunks.push(&content[start..end]);
+        start = end;
+    }
+
+    let range = left_brace.end.offset..start;
+    let replacement = get_sorted_class_like_member_indices(context.interner, members)
+        .into_iter()
+        .map(|index| chunks[index])
+        .collect::<String>();
+
+    context.report_with_fix(issue, |plan| {
+        // Reordering properties changes the order in which they are iterated, serialized, and dumped.
+        plan.replace(range, replacement, SafetyClassification::PotentiallyUnsafe);
+    });
+}
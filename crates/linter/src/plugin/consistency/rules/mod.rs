@@ -1,4 +1,5 @@
 pub mod array_syntax;
+pub mod class_member_order;
 pub mod lowercase_hint;
 pub mod lowercase_keyword;
 pub mod no_function_aliases;
@@ -1,4 +1,6 @@
 use crate::definition::PluginDefinition;
+use crate::plugin::deprecation::rules::internal::deprecated_internal_class::DeprecatedInternalClassRule;
+use crate::plugin::deprecation::rules::internal::deprecated_internal_function::DeprecatedInternalFunctionRule;
 use crate::plugin::deprecation::rules::php80::optional_parameter_before_required::OptionalParameterBeforeRequiredRule;
 use crate::plugin::deprecation::rules::php82::return_by_reference_from_void_function::ReturnByReferenceFromVoidFunctionRule;
 use crate::plugin::deprecation::rules::php84::implicitly_nullable_parameter::ImplicitlyNullableParameterRule;
@@ -23,6 +25,9 @@ impl Plugin for DeprecationPlugin {
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
         vec![
+            // Internal
+            Box::new(DeprecatedInternalClassRule),
+            Box::new(DeprecatedInternalFunctionRule),
             // PHP 8.0
             Box::new(OptionalParameterBeforeRequiredRule),
             // PHP 8.2
@@ -0,0 +1,100 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DeprecatedInternalClassRule;
+
+impl Rule for DeprecatedInternalClassRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Deprecated Internal Class", Level::Warning)
+            .with_description(indoc! {"
+                Detects usages of internal classes, interfaces, and enums that are deprecated in the configured
+                PHP version, when instantiated, extended, implemented, or accessed statically.
+
+                Deprecations are determined from the `@deprecated` tags, and `#[Deprecated]` attributes, of the
+                bundled PHP stubs.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Extending a class that is not deprecated",
+                indoc! {r#"
+                    <?php
+
+                    final class NotFoundException extends Exception
+                    {
+                    }
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for DeprecatedInternalClassRule {
+    fn walk_in_instantiation(&self, instantiation: &Instantiation, context: &mut LintContext<'_>) {
+        if let Expression::Identifier(identifier) = instantiation.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_static_method_call(&self, static_method_call: &StaticMethodCall, context: &mut LintContext<'_>) {
+        if let Expression::Identifier(identifier) = static_method_call.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_class_constant_access(
+        &self,
+        class_constant_access: &ClassConstantAccess,
+        context: &mut LintContext<'_>,
+    ) {
+        if let Expression::Identifier(identifier) = class_constant_access.class.as_ref() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_extends(&self, extends: &Extends, context: &mut LintContext<'_>) {
+        for identifier in extends.types.iter() {
+            check(identifier, context);
+        }
+    }
+
+    fn walk_in_implements(&self, implements: &Implements, context: &mut LintContext<'_>) {
+        for identifier in implements.types.iter() {
+            check(identifier, context);
+        }
+    }
+}
+
+fn check(identifier: &Identifier, context: &mut LintContext<'_>) {
+    let Some(class_like) =
+        context.codebase.get_named_class_like(context.interner, context.semantics.names.get(identifier))
+    else {
+        return;
+    };
+
+    let availability = class_like.availability_reflection;
+    let Some(deprecated) = availability.deprecated else {
+        return;
+    };
+
+    if !availability.is_deprecated_in(context.php_version) {
+        return;
+    }
+
+    let class_name = context.lookup_name(identifier);
+    let issue = Issue::new(context.level(), format!("`{}` is deprecated since PHP {}.", class_name, deprecated))
+        .with_annotation(
+            Annotation::primary(identifier.span())
+                .with_message(format!("`{}` is deprecated in PHP {}.", class_name, context.php_version)),
+        )
+        .with_help("Replace the usage with a non-deprecated alternative, see the PHP manual for details.");
+
+    context.report(issue);
+}
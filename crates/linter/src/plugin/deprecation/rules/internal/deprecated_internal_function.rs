@@ -0,0 +1,78 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DeprecatedInternalFunctionRule;
+
+impl Rule for DeprecatedInternalFunctionRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Deprecated Internal Function", Level::Warning)
+            .with_description(indoc! {"
+                Detects calls to internal functions that are deprecated in the configured PHP version,
+                such as `utf8_encode()`, which is deprecated since PHP 8.2.
+
+                Deprecations are determined from the `@deprecated` tags, and `#[Deprecated]` attributes, of the
+                bundled PHP stubs.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using `mb_convert_encoding()`",
+                indoc! {r#"
+                    <?php
+
+                    $utf8 = mb_convert_encoding($latin1, 'UTF-8', 'ISO-8859-1');
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `utf8_encode()`",
+                indoc! {r#"
+                    <?php
+
+                    $utf8 = utf8_encode($latin1);
+                "#},
+            ))
+    }
+}
+
+impl Walker<LintContext<'_>> for DeprecatedInternalFunctionRule {
+    fn walk_in_function_call(&self, function_call: &FunctionCall, context: &mut LintContext<'_>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let function_name = context.resolve_function_name(identifier);
+        let function_name_id = context.interner.intern(function_name);
+        let Some(function) = context.codebase.get_function(context.interner, &function_name_id) else {
+            return;
+        };
+
+        let availability = function.availability_reflection;
+        let Some(deprecated) = availability.deprecated else {
+            return;
+        };
+
+        if !availability.is_deprecated_in(context.php_version) {
+            return;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Function `{}` is deprecated since PHP {}.", function_name, deprecated),
+        )
+        .with_annotation(
+            Annotation::primary(identifier.span())
+                .with_message(format!("`{}` is deprecated in PHP {}.", function_name, context.php_version)),
+        )
+        .with_help("Replace the call with a non-deprecated alternative, see the PHP manual for details.");
+
+        context.report(issue);
+    }
+}
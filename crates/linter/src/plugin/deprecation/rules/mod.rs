@@ -1,3 +1,8 @@
+pub mod internal {
+    pub mod deprecated_internal_class;
+    pub mod deprecated_internal_function;
+}
+
 pub mod php80 {
     pub mod optional_parameter_before_required;
 }
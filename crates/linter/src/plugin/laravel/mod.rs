@@ -1,4 +1,11 @@
 use crate::definition::PluginDefinition;
+use crate::plugin::laravel::rules::best_practices::no_debug_helpers::NoDebugHelpersRule;
+use crate::plugin::laravel::rules::best_practices::no_env_outside_config::NoEnvOutsideConfigRule;
+use crate::plugin::laravel::rules::best_practices::no_facades_in_domain::NoFacadesInDomainRule;
+use crate::plugin::laravel::rules::best_practices::no_route_closures::NoRouteClosuresRule;
+use crate::plugin::laravel::rules::best_practices::relationship_should_declare_return_type::RelationshipShouldDeclareReturnTypeRule;
+use crate::plugin::laravel::rules::safety::model_should_guard_attributes::ModelShouldGuardAttributesRule;
+use crate::plugin::laravel::rules::safety::no_raw_query_interpolation::NoRawQueryInterpolationRule;
 use crate::plugin::laravel::rules::safety::no_request_all::NoRequestAllRule;
 
 use crate::plugin::Plugin;
@@ -19,6 +26,15 @@ impl Plugin for LaravelPlugin {
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(NoRequestAllRule)]
+        vec![
+            Box::new(ModelShouldGuardAttributesRule),
+            Box::new(NoDebugHelpersRule),
+            Box::new(NoEnvOutsideConfigRule),
+            Box::new(NoFacadesInDomainRule),
+            Box::new(NoRawQueryInterpolationRule),
+            Box::new(NoRequestAllRule),
+            Box::new(NoRouteClosuresRule),
+            Box::new(RelationshipShouldDeclareReturnTypeRule),
+        ]
     }
 }
@@ -0,0 +1,104 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::get_method_name;
+use crate::plugin::laravel::rules::utils::is_helper;
+use crate::rule::Rule;
+
+const DEBUG_FUNCTIONS: [&str; 3] = ["dd", "ddd", "dump"];
+
+#[derive(Clone, Debug)]
+pub struct NoDebugHelpersRule;
+
+impl Rule for NoDebugHelpersRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Debug Helpers", Level::Error)
+            .with_description(indoc! {"
+                Detects calls to the `dd()`, `ddd()`, and `dump()` debug helpers, as well as the `->dd()` and
+                `->dump()` methods of collections and query builders.
+
+                These helpers are meant to be used during development, and should not be left in the code.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Logging a value",
+                indoc! {r#"
+                    <?php
+
+                    use Illuminate\Support\Facades\Log;
+
+                    Log::debug('Users loaded.', ['users' => $users]);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Dumping a value",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Http\Controllers;
+
+                    final class UserController
+                    {
+                        public function index(): void
+                        {
+                            dd($this->users);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Dumping a query",
+                indoc! {r#"
+                    <?php
+
+                    use App\Models\User;
+
+                    $users = User::query()->where('active', true)->dump()->get();
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoDebugHelpersRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        let Some(function) = DEBUG_FUNCTIONS.iter().find(|function| is_helper(context, identifier, function)) else {
+            return;
+        };
+
+        report(format!("`{}()`", function), function_call.span(), context);
+    }
+
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Some(method) = get_method_name(context, &method_call.method) else {
+            return;
+        };
+
+        // `dump()` is a common method name, e.g. for serializers, so only report it when called without arguments.
+        let is_debug_method = method.eq_ignore_ascii_case("dd")
+            || (method.eq_ignore_ascii_case("dump") && method_call.argument_list.arguments.is_empty());
+
+        if !is_debug_method {
+            return;
+        }
+
+        report(format!("`->{}()`", method), method_call.span(), context);
+    }
+}
+
+fn report(helper: String, span: mago_span::Span, context: &mut LintContext<'_>) {
+    let issue = Issue::new(context.level(), format!("Debug helper {} should not be left in the code.", helper))
+        .with_annotation(Annotation::primary(span).with_message(format!("{} is called here.", helper)))
+        .with_help("Remove the debug helper, or use a logger instead.");
+
+    context.report(issue);
+}
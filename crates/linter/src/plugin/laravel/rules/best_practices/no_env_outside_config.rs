@@ -0,0 +1,108 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::is_helper;
+use crate::rule::Rule;
+
+const DIRECTORIES: &str = "directories";
+const DIRECTORIES_DEFAULT: &str = "config";
+
+#[derive(Clone, Debug)]
+pub struct NoEnvOutsideConfigRule;
+
+impl Rule for NoEnvOutsideConfigRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Env Outside Config", Level::Warning)
+            .with_description(indoc! {"
+                Detects calls to `env()` outside of configuration files.
+
+                Once the configuration is cached using `php artisan config:cache`, the `.env` file is no longer
+                loaded, and `env()` returns `null` everywhere except in the configuration files.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: DIRECTORIES,
+                r#type: "array<string>",
+                description: "The directories containing the configuration files, relative to the root directory.",
+                default: Value::Array(vec![Value::String(DIRECTORIES_DEFAULT.to_string())]),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Reading a configuration value",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Services;
+
+                    final class PaymentGateway
+                    {
+                        public function getApiKey(): string
+                        {
+                            return config('services.stripe.key');
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Reading an environment variable in a service",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Services;
+
+                    final class PaymentGateway
+                    {
+                        public function getApiKey(): string
+                        {
+                            return env('STRIPE_KEY');
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoEnvOutsideConfigRule {
+    fn walk_in_function_call<'ast>(&self, function_call: &'ast FunctionCall, context: &mut LintContext<'a>) {
+        let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+            return;
+        };
+
+        if !is_helper(context, identifier, "env") {
+            return;
+        }
+
+        let file = context.lookup(&context.semantics.source.identifier.0).replace('\\', "/");
+        let is_config_file = match context.option(DIRECTORIES).and_then(|option| option.as_array()) {
+            Some(directories) => directories
+                .iter()
+                .filter_map(|directory| directory.as_str())
+                .any(|directory| is_within(&file, directory)),
+            None => is_within(&file, DIRECTORIES_DEFAULT),
+        };
+
+        if is_config_file {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Avoid calling `env()` outside of configuration files.")
+            .with_annotation(Annotation::primary(function_call.span()).with_message("`env()` is called here."))
+            .with_note("When the configuration is cached, `env()` returns `null` outside of configuration files.")
+            .with_help("Read the value in a configuration file, and access it using `config()` instead.");
+
+        context.report(issue);
+    }
+}
+
+fn is_within(file: &str, directory: &str) -> bool {
+    let directory = directory.trim_matches('/');
+
+    file.starts_with(&format!("{}/", directory)) || file.contains(&format!("/{}/", directory))
+}
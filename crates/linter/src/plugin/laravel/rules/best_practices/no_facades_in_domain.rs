@@ -0,0 +1,129 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleOptionDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::FACADES_NAMESPACE;
+use crate::plugin::laravel::rules::utils::REAL_TIME_FACADES_NAMESPACE;
+use crate::rule::Rule;
+
+const NAMESPACES: &str = "namespaces";
+const NAMESPACES_DEFAULT: &str = "App\\Domain";
+
+#[derive(Clone, Debug)]
+pub struct NoFacadesInDomainRule;
+
+impl Rule for NoFacadesInDomainRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Facades In Domain", Level::Warning)
+            .with_description(indoc! {"
+                Detects facades used inside domain classes.
+
+                Facades hide the dependencies of a class behind static calls, and tie the domain to the framework.
+                Domain classes should receive their dependencies through the constructor instead.
+            "})
+            .with_option(RuleOptionDefinition {
+                name: NAMESPACES,
+                r#type: "array<string>",
+                description: "The namespaces containing the domain classes.",
+                default: Value::Array(vec![Value::String(NAMESPACES_DEFAULT.to_string())]),
+            })
+            .with_example(RuleUsageExample::valid(
+                "Injecting the dependency",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Domain\Billing;
+
+                    use Psr\Log\LoggerInterface;
+
+                    final class InvoiceService
+                    {
+                        public function __construct(private LoggerInterface $logger) {}
+
+                        public function issue(Invoice $invoice): void
+                        {
+                            $this->logger->info('Invoice issued.');
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using a facade in a domain class",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Domain\Billing;
+
+                    use Illuminate\Support\Facades\Log;
+
+                    final class InvoiceService
+                    {
+                        public function issue(Invoice $invoice): void
+                        {
+                            Log::info('Invoice issued.');
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoFacadesInDomainRule {
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Expression::Identifier(identifier) = static_method_call.class.as_ref() else {
+            return;
+        };
+
+        let facade = context.lookup_name(identifier);
+        if !facade.starts_with(FACADES_NAMESPACE) && !facade.starts_with(REAL_TIME_FACADES_NAMESPACE) {
+            return;
+        }
+
+        let Some(class_like) = context.codebase.get_enclosing_class_like(&static_method_call.span().start) else {
+            return;
+        };
+
+        let class_name = class_like.name.get_key(context.interner);
+        let is_domain_class = match context.option(NAMESPACES).and_then(|option| option.as_array()) {
+            Some(namespaces) => namespaces
+                .iter()
+                .filter_map(|namespace| namespace.as_str())
+                .any(|namespace| is_within(&class_name, namespace)),
+            None => is_within(&class_name, NAMESPACES_DEFAULT),
+        };
+
+        if !is_domain_class {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), format!("Facade `{}` should not be used in domain classes.", facade))
+            .with_annotation(Annotation::primary(static_method_call.span()).with_message("The facade is used here."))
+            .with_annotation(
+                Annotation::secondary(class_like.span)
+                    .with_message(format!("Domain class `{}` is declared here.", class_name)),
+            )
+            .with_help("Inject the underlying service through the constructor instead.");
+
+        context.report(issue);
+    }
+}
+
+fn is_within(class_name: &str, namespace: &str) -> bool {
+    let namespace = namespace.trim_matches('\\');
+
+    class_name.len() > namespace.len()
+        && class_name[..namespace.len()].eq_ignore_ascii_case(namespace)
+        && class_name[namespace.len()..].starts_with('\\')
+}
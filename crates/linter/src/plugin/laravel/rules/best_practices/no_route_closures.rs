@@ -0,0 +1,91 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::get_method_name;
+use crate::plugin::laravel::rules::utils::is_facade;
+use crate::rule::Rule;
+
+const ROUTE_METHODS: [&str; 9] = ["get", "post", "put", "patch", "delete", "options", "any", "match", "fallback"];
+
+#[derive(Clone, Debug)]
+pub struct NoRouteClosuresRule;
+
+impl Rule for NoRouteClosuresRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Route Closures", Level::Warning)
+            .with_description(indoc! {"
+                Detects routes handled by closures.
+
+                Routes using closures cannot be cached using `php artisan route:cache`, which slows down every request.
+                Routes should be handled by controller actions instead.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Handling a route using a controller action",
+                indoc! {r#"
+                    <?php
+
+                    use App\Http\Controllers\UserController;
+                    use Illuminate\Support\Facades\Route;
+
+                    Route::get('/users', [UserController::class, 'index']);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Handling a route using a closure",
+                indoc! {r#"
+                    <?php
+
+                    use Illuminate\Support\Facades\Route;
+
+                    Route::get('/users', function () {
+                        return view('users.index');
+                    });
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoRouteClosuresRule {
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_method_name(context, &static_method_call.method) else {
+            return;
+        };
+
+        if !ROUTE_METHODS.iter().any(|route_method| route_method.eq_ignore_ascii_case(method))
+            || !is_facade(context, &static_method_call.class, "Route")
+        {
+            return;
+        }
+
+        for argument in static_method_call.argument_list.arguments.iter() {
+            let (Expression::Closure(_) | Expression::ArrowFunction(_)) = argument.value() else {
+                continue;
+            };
+
+            let issue = Issue::new(context.level(), "Routes should not be handled by closures.")
+                .with_annotation(
+                    Annotation::primary(argument.span()).with_message("This route is handled by a closure."),
+                )
+                .with_annotation(
+                    Annotation::secondary(static_method_call.span()).with_message("The route is declared here."),
+                )
+                .with_note(
+                    "Routes using closures prevent the routes from being cached using `php artisan route:cache`.",
+                )
+                .with_help("Move the closure body to a controller action.");
+
+            context.report(issue);
+        }
+    }
+}
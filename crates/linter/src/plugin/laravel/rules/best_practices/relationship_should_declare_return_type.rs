@@ -0,0 +1,162 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::get_method_name;
+use crate::plugin::laravel::rules::utils::is_model;
+use crate::rule::Rule;
+
+const RELATIONS_NAMESPACE: &str = "Illuminate\\Database\\Eloquent\\Relations\\";
+const RELATIONSHIPS: [(&str, &str); 11] = [
+    ("hasOne", "HasOne"),
+    ("hasMany", "HasMany"),
+    ("belongsTo", "BelongsTo"),
+    ("belongsToMany", "BelongsToMany"),
+    ("hasOneThrough", "HasOneThrough"),
+    ("hasManyThrough", "HasManyThrough"),
+    ("morphTo", "MorphTo"),
+    ("morphOne", "MorphOne"),
+    ("morphMany", "MorphMany"),
+    ("morphToMany", "MorphToMany"),
+    ("morphedByMany", "MorphToMany"),
+];
+
+#[derive(Clone, Debug)]
+pub struct RelationshipShouldDeclareReturnTypeRule;
+
+impl Rule for RelationshipShouldDeclareReturnTypeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Relationship Should Declare Return Type", Level::Warning)
+            .with_description(indoc! {"
+                Detects Eloquent relationship methods without a return type.
+
+                Declaring the relation type documents the relationship, and allows static analysis tools and IDEs
+                to understand the related model.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A relationship method with a return type",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Models;
+
+                    use Illuminate\Database\Eloquent\Model;
+                    use Illuminate\Database\Eloquent\Relations\HasMany;
+
+                    final class User extends Model
+                    {
+                        protected $fillable = ['name'];
+
+                        public function posts(): HasMany
+                        {
+                            return $this->hasMany(Post::class);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A relationship method without a return type",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Models;
+
+                    use Illuminate\Database\Eloquent\Model;
+
+                    final class User extends Model
+                    {
+                        protected $fillable = ['name'];
+
+                        public function posts()
+                        {
+                            return $this->hasMany(Post::class);
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RelationshipShouldDeclareReturnTypeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if !is_model(interner, class_like) {
+            return;
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            if method.return_type_hint.is_some() {
+                continue;
+            }
+
+            let MethodBody::Concrete(block) = &method.body else {
+                continue;
+            };
+
+            let Some(relation) = block.statements.iter().find_map(|statement| match statement {
+                Statement::Return(Return { value: Some(value), .. }) => get_relation(context, value),
+                _ => None,
+            }) else {
+                continue;
+            };
+
+            let method_name = context.lookup(&method.name.value);
+            let issue = Issue::new(
+                context.level(),
+                format!("Relationship method `{}()` should declare its return type.", method_name),
+            )
+            .with_annotation(
+                Annotation::primary(method.name.span())
+                    .with_message(format!("This method returns a `{}` relation.", relation)),
+            )
+            .with_help(format!("Add the `{}{}` return type to the method.", RELATIONS_NAMESPACE, relation));
+
+            context.report_with_fix(issue, |plan| {
+                plan.insert(
+                    method.parameter_list.span().end.offset,
+                    format!(": \\{}{}", RELATIONS_NAMESPACE, relation),
+                    SafetyClassification::PotentiallyUnsafe,
+                );
+            });
+        }
+    }
+}
+
+/// Returns the relation type of a `$this->relationship(...)` call, possibly followed by other method calls,
+/// such as `$this->hasMany(Post::class)->latest()`.
+fn get_relation(context: &LintContext<'_>, expression: &Expression) -> Option<&'static str> {
+    let Expression::Call(Call::Method(method_call)) = expression else {
+        return None;
+    };
+
+    if let Expression::Variable(Variable::Direct(variable)) = method_call.object.as_ref() {
+        if context.lookup(&variable.name) != "$this" {
+            return None;
+        }
+
+        let method = get_method_name(context, &method_call.method)?;
+
+        return RELATIONSHIPS
+            .iter()
+            .find(|(relationship, _)| relationship.eq_ignore_ascii_case(method))
+            .map(|(_, relation)| *relation);
+    }
+
+    get_relation(context, &method_call.object)
+}
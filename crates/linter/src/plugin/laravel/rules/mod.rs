@@ -1,3 +1,15 @@
+pub mod best_practices {
+    pub mod no_debug_helpers;
+    pub mod no_env_outside_config;
+    pub mod no_facades_in_domain;
+    pub mod no_route_closures;
+    pub mod relationship_should_declare_return_type;
+}
+
 pub mod safety {
+    pub mod model_should_guard_attributes;
+    pub mod no_raw_query_interpolation;
     pub mod no_request_all;
 }
+
+pub mod utils;
@@ -0,0 +1,104 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::is_model;
+use crate::rule::Rule;
+
+const GUARD_PROPERTIES: [&str; 2] = ["$fillable", "$guarded"];
+
+#[derive(Clone, Debug)]
+pub struct ModelShouldGuardAttributesRule;
+
+impl Rule for ModelShouldGuardAttributesRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Model Should Guard Attributes", Level::Warning)
+            .with_description(indoc! {"
+                Detects Eloquent models declaring neither `$fillable` nor `$guarded`.
+
+                Declaring which attributes can be mass-assigned makes it explicit which input a model accepts,
+                and protects against mass-assignment vulnerabilities when the defaults are changed.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A model declaring its fillable attributes",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Models;
+
+                    use Illuminate\Database\Eloquent\Model;
+
+                    final class Post extends Model
+                    {
+                        protected $fillable = ['title', 'body'];
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A model without `$fillable` or `$guarded`",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Models;
+
+                    use Illuminate\Database\Eloquent\Model;
+
+                    final class Post extends Model
+                    {
+                        protected $table = 'posts';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ModelShouldGuardAttributesRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if class_like.is_abstract || !is_model(interner, class_like) || declares_guard(context, class_like) {
+            return;
+        }
+
+        // Guards declared by the user-defined parent models apply as well, the ones declared by Eloquent do not.
+        let is_guarded_by_parent = class_like.inheritance.all_extended_classes.iter().any(|parent| {
+            !interner.lookup(&parent.value).starts_with("Illuminate\\")
+                && context
+                    .codebase
+                    .get_class(interner, &parent.value)
+                    .is_some_and(|parent| declares_guard(context, parent))
+        });
+
+        if is_guarded_by_parent {
+            return;
+        }
+
+        let class_name = context.lookup(&class.name.value);
+        let issue =
+            Issue::new(context.level(), format!("Model `{}` declares neither `$fillable` nor `$guarded`.", class_name))
+                .with_annotation(
+                    Annotation::primary(class.name.span())
+                        .with_message("This model does not declare which attributes are mass-assignable."),
+                )
+                .with_help(
+                    "Declare the mass-assignable attributes using `$fillable`, or the protected ones using `$guarded`.",
+                );
+
+        context.report(issue);
+    }
+}
+
+fn declares_guard(context: &LintContext<'_>, class_like: &ClassLikeReflection) -> bool {
+    class_like.properties.members.keys().any(|property| GUARD_PROPERTIES.contains(&context.lookup(property)))
+}
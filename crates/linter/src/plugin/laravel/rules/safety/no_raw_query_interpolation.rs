@@ -0,0 +1,170 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::laravel::rules::utils::get_method_name;
+use crate::plugin::laravel::rules::utils::is_facade;
+use crate::rule::Rule;
+
+const RAW_METHODS: [&str; 10] = [
+    "whereRaw",
+    "orWhereRaw",
+    "havingRaw",
+    "orHavingRaw",
+    "orderByRaw",
+    "groupByRaw",
+    "selectRaw",
+    "fromRaw",
+    "joinRaw",
+    "raw",
+];
+
+const DB_RAW_METHODS: [&str; 2] = ["raw", "unprepared"];
+
+#[derive(Clone, Debug)]
+pub struct NoRawQueryInterpolationRule;
+
+impl Rule for NoRawQueryInterpolationRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Raw Query Interpolation", Level::Error)
+            .with_description(indoc! {"
+                Detects raw SQL expressions, such as `DB::raw()` or `whereRaw()`, built by interpolating or
+                concatenating variables.
+
+                Raw expressions are inserted into the query as-is, so variables must be passed as bindings
+                to avoid SQL injection.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Passing variables as bindings",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Repositories;
+
+                    use App\Models\User;
+
+                    final class UserRepository
+                    {
+                        public function findByEmail(string $email): ?User
+                        {
+                            return User::query()->whereRaw('LOWER(email) = ?', [strtolower($email)])->first();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Interpolating a variable in a raw expression",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Repositories;
+
+                    use App\Models\User;
+
+                    final class UserRepository
+                    {
+                        public function findByEmail(string $email): ?User
+                        {
+                            return User::query()->whereRaw("LOWER(email) = '{$email}'")->first();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Concatenating a variable in `DB::raw()`",
+                indoc! {r#"
+                    <?php
+
+                    use Illuminate\Support\Facades\DB;
+
+                    $users = DB::table('users')->select(DB::raw('count(*) as total, ' . $column))->get();
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoRawQueryInterpolationRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let Some(method) = get_method_name(context, &method_call.method) else {
+            return;
+        };
+
+        if RAW_METHODS.iter().any(|raw_method| raw_method.eq_ignore_ascii_case(method)) {
+            check(method, &method_call.argument_list, context);
+        }
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        let Some(method) = get_method_name(context, &static_method_call.method) else {
+            return;
+        };
+
+        let is_raw_method = if is_facade(context, &static_method_call.class, "DB") {
+            DB_RAW_METHODS.iter().chain(RAW_METHODS.iter()).any(|raw_method| raw_method.eq_ignore_ascii_case(method))
+        } else {
+            RAW_METHODS.iter().any(|raw_method| raw_method.eq_ignore_ascii_case(method))
+        };
+
+        if is_raw_method {
+            check(method, &static_method_call.argument_list, context);
+        }
+    }
+}
+
+fn check(method: &str, argument_list: &ArgumentList, context: &mut LintContext<'_>) {
+    let Some(argument) = argument_list.arguments.first() else {
+        return;
+    };
+
+    let Some(span) = find_dynamic_part(argument.value()) else {
+        return;
+    };
+
+    let issue =
+        Issue::new(context.level(), format!("Raw SQL expression passed to `{}()` is built from variables.", method))
+            .with_annotation(
+                Annotation::primary(argument.value().span())
+                    .with_message("This raw SQL expression is built dynamically."),
+            )
+            .with_annotation(Annotation::secondary(span).with_message("This value is inserted into the query as-is."))
+            .with_help("Use `?` placeholders in the expression, and pass the values as bindings.");
+
+    context.report(issue);
+}
+
+/// Returns the span of the first non-literal part of a string built by interpolation or concatenation.
+fn find_dynamic_part(expression: &Expression) -> Option<mago_span::Span> {
+    match expression {
+        Expression::Parenthesized(parenthesized) => find_dynamic_part(&parenthesized.expression),
+        Expression::CompositeString(composite_string) => composite_string.parts().iter().find_map(|part| match part {
+            StringPart::Literal(_) => None,
+            _ => Some(part.span()),
+        }),
+        Expression::Binary(Binary { lhs, operator: BinaryOperator::StringConcat(_), rhs }) => find_dynamic_part(lhs)
+            .or_else(|| find_dynamic_part(rhs))
+            .or_else(|| [lhs, rhs].into_iter().find(|operand| !is_constant(operand)).map(|operand| operand.span())),
+        _ => None,
+    }
+}
+
+fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Parenthesized(parenthesized) => is_constant(&parenthesized.expression),
+        Expression::Binary(Binary { lhs, operator: BinaryOperator::StringConcat(_), rhs }) => {
+            is_constant(lhs) && is_constant(rhs)
+        }
+        Expression::Literal(_) | Expression::ConstantAccess(_) | Expression::MagicConstant(_) => true,
+        Expression::Access(Access::ClassConstant(_)) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,59 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_span::HasPosition;
+
+use crate::context::LintContext;
+
+pub const MODEL: &str = "Illuminate\\Database\\Eloquent\\Model";
+pub const FACADES_NAMESPACE: &str = "Illuminate\\Support\\Facades\\";
+pub const REAL_TIME_FACADES_NAMESPACE: &str = "Facades\\";
+
+/// Returns whether the class-like is an Eloquent model.
+pub fn is_model(interner: &ThreadedInterner, class_like: &ClassLikeReflection) -> bool {
+    let inheritance = &class_like.inheritance;
+
+    inheritance
+        .direct_extended_class
+        .iter()
+        .chain(inheritance.all_extended_classes.iter())
+        .any(|name| interner.lookup(&name.value).eq_ignore_ascii_case(MODEL))
+}
+
+/// Returns whether the expression is a static reference to the given facade, either through its fully
+/// qualified name, or through its global alias, e.g. `DB` for `Illuminate\Support\Facades\DB`.
+pub fn is_facade(context: &LintContext<'_>, class: &Expression, facade: &str) -> bool {
+    let Expression::Identifier(identifier) = class else {
+        return false;
+    };
+
+    let name = context.lookup_name(identifier);
+
+    name.eq_ignore_ascii_case(&format!("{}{}", FACADES_NAMESPACE, facade)) || name.eq_ignore_ascii_case(facade)
+}
+
+/// Returns the name of the method selected by the selector, if it is a plain identifier.
+pub fn get_method_name<'a>(context: &LintContext<'a>, selector: &ClassLikeMemberSelector) -> Option<&'a str> {
+    match selector {
+        ClassLikeMemberSelector::Identifier(identifier) => Some(context.interner.lookup(&identifier.value)),
+        _ => None,
+    }
+}
+
+/// Returns whether the identifier refers to the given global helper function.
+///
+/// Unqualified calls to functions unknown to the codebase are assumed to refer to the global function, as
+/// helpers defined by Laravel are not reflected unless the vendor directory is scanned.
+pub fn is_helper(context: &LintContext<'_>, identifier: &Identifier, helper: &str) -> bool {
+    let name = context.resolve_function_name(identifier);
+    if name.eq_ignore_ascii_case(helper) {
+        return true;
+    }
+
+    let Identifier::Local(local) = identifier else {
+        return false;
+    };
+
+    context.lookup(&local.value).eq_ignore_ascii_case(helper)
+        && !context.codebase.function_exists(context.interner, context.semantics.names.get(&identifier.position()))
+}
@@ -3,6 +3,8 @@ use toml::Value;
 
 use mago_ast::ast::*;
 use mago_ast::Node;
+use mago_metrics::complexity::get_class_like_decision_points;
+use mago_metrics::complexity::get_decision_points;
 use mago_reporting::*;
 use mago_span::HasSpan;
 use mago_walker::Walker;
@@ -10,7 +12,6 @@ use mago_walker::Walker;
 use crate::context::LintContext;
 use crate::definition::RuleDefinition;
 use crate::definition::RuleOptionDefinition;
-use crate::plugin::maintainability::rules::utils::is_method_setter_or_getter;
 use crate::rule::Rule;
 
 const THRESHOLD: &str = "threshold";
@@ -77,7 +78,7 @@ fn check_class_like(
 ) {
     let threshold = context.option(THRESHOLD).and_then(|o| o.as_integer()).unwrap_or(THRESHOLD_DEFAULT as i64);
 
-    let class_like_cyclomatic_complexity = get_cyclomatic_complexity_of_class_like_members(members, context);
+    let class_like_cyclomatic_complexity = get_class_like_decision_points(context.interner, members);
     if class_like_cyclomatic_complexity > threshold as usize {
         let issue = Issue::new(context.level(), format!("{kind} has high complexity.")).with_annotation(
             Annotation::primary(class_like.span()).with_message(format!(
@@ -94,7 +95,7 @@ fn check_class_like(
 fn check_function_like(kind: &'static str, function_like: impl HasSpan, body: &Block, context: &mut LintContext<'_>) {
     let threshold = context.option(THRESHOLD).and_then(|o| o.as_integer()).unwrap_or(THRESHOLD_DEFAULT as i64);
 
-    let block_cyclomatic_complexity = get_cyclomatic_complexity_of_node(Node::Block(body));
+    let block_cyclomatic_complexity = get_decision_points(Node::Block(body));
     if block_cyclomatic_complexity > threshold as usize {
         let issue = Issue::new(context.level(), format!("{kind} has high complexity.")).with_annotation(
             Annotation::primary(function_like.span()).with_message(format!(
@@ -106,62 +107,3 @@ fn check_function_like(kind: &'static str, function_like: impl HasSpan, body: &B
         context.report(issue);
     }
 }
-
-fn get_cyclomatic_complexity_of_class_like_members(
-    class_like_members: &[ClassLikeMember],
-    context: &LintContext<'_>,
-) -> usize {
-    let mut cyclomatic_complexity = 0;
-    for member in class_like_members {
-        let ClassLikeMember::Method(method) = member else {
-            continue;
-        };
-
-        let Some(method_cyclomatic_complexity) = get_cyclomatic_complexity_of_method(method, context) else {
-            continue;
-        };
-
-        cyclomatic_complexity += method_cyclomatic_complexity - 1;
-    }
-
-    cyclomatic_complexity
-}
-
-fn get_cyclomatic_complexity_of_method(method: &Method, context: &LintContext<'_>) -> Option<usize> {
-    if is_method_setter_or_getter(method, context) {
-        return None;
-    }
-
-    Some(if method.is_abstract() { 1 } else { get_cyclomatic_complexity_of_node(Node::Method(method)) + 1 })
-}
-
-fn get_cyclomatic_complexity_of_node(node: Node<'_>) -> usize {
-    let mut number = 0;
-
-    for child in node.children() {
-        number += get_cyclomatic_complexity_of_node(child);
-    }
-
-    match node {
-        Node::If(_)
-        | Node::IfStatementBodyElseIfClause(_)
-        | Node::IfColonDelimitedBodyElseIfClause(_)
-        | Node::For(_)
-        | Node::Foreach(_)
-        | Node::While(_)
-        | Node::DoWhile(_)
-        | Node::TryCatchClause(_)
-        | Node::Conditional(_) => number += 1,
-        Node::Binary(operation) => match operation.operator {
-            operator if operator.is_logical() || operator.is_null_coalesce() => number += 1,
-            BinaryOperator::Spaceship(_) => number += 2,
-            _ => (),
-        },
-        Node::SwitchCase(case) if case.is_default() => {
-            number += 1;
-        }
-        _ => (),
-    }
-
-    number
-}
@@ -1,5 +1,3 @@
-mod utils;
-
 pub mod cyclomatic_complexity;
 pub mod excessive_parameter_list;
 pub mod halstead;
@@ -3,6 +3,7 @@ use toml::Value;
 
 use mago_ast::ast::*;
 use mago_ast::Node;
+use mago_metrics::complexity::is_method_setter_or_getter;
 use mago_reporting::*;
 use mago_span::HasSpan;
 use mago_walker::Walker;
@@ -12,8 +13,6 @@ use crate::definition::RuleDefinition;
 use crate::definition::RuleOptionDefinition;
 use crate::rule::Rule;
 
-use crate::plugin::maintainability::rules::utils::is_method_setter_or_getter;
-
 const THRESHOLD: &str = "threshold";
 const THRESHOLD_DEFAULT: i64 = 10;
 
@@ -91,7 +90,7 @@ fn check(kind: &'static str, node: Node<'_>, members: &[ClassLikeMember], contex
     for member in members {
         match member {
             ClassLikeMember::Method(method) => {
-                if !count_setters_and_getters && is_method_setter_or_getter(method, context) {
+                if !count_setters_and_getters && is_method_setter_or_getter(context.interner, method) {
                     continue;
                 }
 
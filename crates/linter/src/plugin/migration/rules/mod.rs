@@ -10,3 +10,13 @@ pub mod php81 {
 pub mod php82 {
     pub mod readonly_class_promotion;
 }
+
+pub mod php83 {
+    pub mod json_validate;
+    pub mod override_attribute;
+    pub mod typed_class_constant;
+}
+
+pub mod php84 {
+    pub mod new_without_parentheses;
+}
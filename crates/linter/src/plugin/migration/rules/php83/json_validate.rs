@@ -0,0 +1,172 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+const JSON_DECODE: &str = "json_decode";
+const JSON_LAST_ERROR: &str = "json_last_error";
+const JSON_ERROR_NONE: &str = "JSON_ERROR_NONE";
+const JSON_VALIDATE: &str = "json_validate";
+
+#[derive(Clone, Debug)]
+pub struct JsonValidateRule;
+
+impl Rule for JsonValidateRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Json Validate", Level::Warning)
+            .with_minimum_supported_php_version(PHPVersion::PHP83)
+            .with_description(indoc! {"
+                Detects `json_decode()` calls whose result is discarded, followed by a `json_last_error()` check,
+                and suggests replacing them with `json_validate()`.
+                `json_validate()` checks whether a string contains valid JSON without building the decoded value,
+                using less memory and time.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using `json_validate()`",
+                indoc! {r#"
+                    <?php
+
+                    if (json_validate($payload)) {
+                        echo 'Valid';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Decoding a string to check whether it contains valid JSON",
+                indoc! {r#"
+                    <?php
+
+                    json_decode($payload);
+                    if (json_last_error() === JSON_ERROR_NONE) {
+                        echo 'Valid';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for JsonValidateRule {
+    fn walk_in_program(&self, program: &Program, context: &mut LintContext<'a>) {
+        check(program.statements.as_slice(), context);
+    }
+
+    fn walk_in_block(&self, block: &Block, context: &mut LintContext<'a>) {
+        check(block.statements.as_slice(), context);
+    }
+}
+
+fn check(statements: &[Statement], context: &mut LintContext<'_>) {
+    for pair in statements.windows(2) {
+        let [decode_statement @ Statement::Expression(ExpressionStatement { expression, .. }), check_statement] = pair
+        else {
+            continue;
+        };
+
+        let Expression::Call(Call::Function(FunctionCall { function, argument_list })) = expression.as_ref() else {
+            continue;
+        };
+
+        // The depth and flags arguments of `json_decode()` affect the validation.
+        let Some(Argument::Positional(PositionalArgument { ellipsis: None, value: json })) =
+            argument_list.arguments.first()
+        else {
+            continue;
+        };
+
+        if argument_list.arguments.len() > 2 || !is_function(function, JSON_DECODE, context) {
+            continue;
+        }
+
+        let Some(comparison) = get_comparison(check_statement) else {
+            continue;
+        };
+
+        let Some(is_negated) = get_error_check(comparison, context) else {
+            continue;
+        };
+
+        let content = context.interner.lookup(&context.semantics.source.content);
+        let replacement =
+            format!("{}{}({})", if is_negated { "!" } else { "" }, JSON_VALIDATE, &content[json.span().to_range()]);
+
+        let issue = Issue::new(
+            context.level(),
+            "Consider using `json_validate()` to check whether a string contains valid JSON.",
+        )
+        .with_annotation(
+            Annotation::primary(comparison.span()).with_message("This checks whether the string is valid JSON."),
+        )
+        .with_annotation(Annotation::secondary(decode_statement.span()).with_message("The decoded value is discarded."))
+        .with_help(format!("Remove the `json_decode()` call, and replace the check with `{}`.", replacement))
+        .with_note("`json_validate()` does not build the decoded value, using less memory and time.");
+
+        context.report_with_fix(issue, |plan| {
+            plan.delete(
+                decode_statement.span().start.offset..check_statement.span().start.offset,
+                SafetyClassification::PotentiallyUnsafe,
+            );
+            plan.replace(comparison.span().to_range(), replacement, SafetyClassification::PotentiallyUnsafe);
+        });
+    }
+}
+
+/// Returns the comparison used as the condition of an `if` statement, the value of a `return` statement,
+/// or the value assigned by an expression statement.
+fn get_comparison(statement: &Statement) -> Option<&Binary> {
+    let expression = match statement {
+        Statement::If(If { condition, .. }) => condition.as_ref(),
+        Statement::Return(Return { value: Some(value), .. }) => value,
+        Statement::Expression(ExpressionStatement { expression, .. }) => match expression.as_ref() {
+            Expression::AssignmentOperation(Assignment { operator: AssignmentOperator::Assign(_), rhs, .. }) => rhs,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match expression {
+        Expression::Binary(binary) => Some(binary),
+        _ => None,
+    }
+}
+
+/// Returns whether the comparison checks for a JSON error, i.e. `json_last_error() !== JSON_ERROR_NONE`, or
+/// for its absence, i.e. `json_last_error() === JSON_ERROR_NONE`.
+fn get_error_check(comparison: &Binary, context: &LintContext<'_>) -> Option<bool> {
+    let is_negated = match comparison.operator {
+        BinaryOperator::Identical(_) | BinaryOperator::Equal(_) => false,
+        BinaryOperator::NotIdentical(_) | BinaryOperator::NotEqual(_) | BinaryOperator::AngledNotEqual(_) => true,
+        _ => return None,
+    };
+
+    let (call, constant) = match (comparison.lhs.as_ref(), comparison.rhs.as_ref()) {
+        (Expression::Call(Call::Function(call)), Expression::ConstantAccess(constant))
+        | (Expression::ConstantAccess(constant), Expression::Call(Call::Function(call))) => (call, constant),
+        _ => return None,
+    };
+
+    if !call.argument_list.arguments.is_empty()
+        || !is_function(&call.function, JSON_LAST_ERROR, context)
+        || context.resolve_constant_name(&constant.name) != JSON_ERROR_NONE
+    {
+        return None;
+    }
+
+    Some(is_negated)
+}
+
+fn is_function(function: &Expression, name: &str, context: &LintContext<'_>) -> bool {
+    let Expression::Identifier(identifier) = function else {
+        return false;
+    };
+
+    context.resolve_function_name(identifier).eq_ignore_ascii_case(name)
+}
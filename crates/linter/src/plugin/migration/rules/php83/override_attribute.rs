@@ -0,0 +1,136 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+const OVERRIDE_ATTRIBUTE: &str = "Override";
+
+#[derive(Clone, Debug)]
+pub struct OverrideAttributeRule;
+
+impl Rule for OverrideAttributeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Override Attribute", Level::Help)
+            .with_minimum_supported_php_version(PHPVersion::PHP83)
+            .with_description(indoc! {"
+                Detects methods overriding a parent method, or implementing an interface method, without
+                the `#[\\Override]` attribute.
+
+                The `#[\\Override]` attribute makes PHP verify that the overridden method exists, so renaming or removing
+                the parent method does not silently turn the method into a new one.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "An overriding method with the `#[\\Override]` attribute",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        public function bar(): void {}
+                    }
+
+                    final class Baz extends Foo {
+                        #[\Override]
+                        public function bar(): void {}
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An overriding method without the `#[\\Override]` attribute",
+                indoc! {r#"
+                    <?php
+
+                    class Foo {
+                        public function bar(): void {}
+                    }
+
+                    final class Baz extends Foo {
+                        public function bar(): void {}
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for OverrideAttributeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let name = context.semantics.names.get(&class.name);
+        if let Some(reflection) = context.codebase.get_class(context.interner, name) {
+            check(reflection, &class.members, context);
+        }
+    }
+
+    fn walk_in_enum(&self, r#enum: &Enum, context: &mut LintContext<'a>) {
+        let name = context.semantics.names.get(&r#enum.name);
+        if let Some(reflection) = context.codebase.get_enum(context.interner, name) {
+            check(reflection, &r#enum.members, context);
+        }
+    }
+}
+
+fn check(reflection: &ClassLikeReflection, members: &Sequence<ClassLikeMember>, context: &mut LintContext<'_>) {
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        let Some(method_reflection) = reflection.get_method(&method.name.value) else {
+            continue;
+        };
+
+        if !method_reflection.is_overriding || has_override_attribute(method, context) {
+            continue;
+        }
+
+        let name = context.lookup(&method.name.value);
+        if name.eq_ignore_ascii_case("__construct") {
+            continue;
+        }
+
+        // Methods coming from traits are not considered by `#[\Override]`.
+        let overrides_parent = reflection.methods.overriden_members.get(&method.name.value).is_some_and(|parents| {
+            parents
+                .iter()
+                .any(|parent| context.codebase.get_class_like(*parent).is_some_and(|parent| !parent.is_trait()))
+        });
+
+        if !overrides_parent {
+            continue;
+        }
+
+        let issue = Issue::new(
+            context.level(),
+            format!("Method `{}` overrides a parent method, but is missing the `#[\\Override]` attribute.", name),
+        )
+        .with_annotation(Annotation::primary(method.name.span()).with_message("This method overrides a parent method."))
+        .with_help("Add the `#[\\Override]` attribute to the method.")
+        .with_note("The `#[\\Override]` attribute is available since PHP 8.3.");
+
+        let content = context.interner.lookup(&context.semantics.source.content);
+        let start = method.span().start.offset;
+        let line_start = content[..start].rfind('\n').map_or(0, |offset| offset + 1);
+        let indentation = &content[line_start..start];
+        let indentation = if indentation.trim().is_empty() { indentation } else { "" };
+
+        context.report_with_fix(issue, |plan| {
+            plan.insert(start, format!("#[\\Override]\n{}", indentation), SafetyClassification::Safe);
+        });
+    }
+}
+
+fn has_override_attribute(method: &Method, context: &LintContext<'_>) -> bool {
+    method
+        .attribute_lists
+        .iter()
+        .flat_map(|attribute_list| attribute_list.attributes.iter())
+        .any(|attribute| context.lookup_name(&attribute.name).eq_ignore_ascii_case(OVERRIDE_ATTRIBUTE))
+}
@@ -0,0 +1,148 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_interner::ThreadedInterner;
+use mago_php_version::PHPVersion;
+use mago_reflection::r#type::kind::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct TypedClassConstantRule;
+
+impl Rule for TypedClassConstantRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Typed Class Constant", Level::Warning)
+            .with_minimum_supported_php_version(PHPVersion::PHP83)
+            .with_description(indoc! {"
+                Detects class constants declared without a type, and suggests the type inferred from their value.
+                Typed class constants prevent child classes, and implementations, from changing the type of the constant.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A typed class constant",
+                indoc! {r#"
+                    <?php
+
+                    final class Foo {
+                        public const string BAR = 'bar';
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A class constant without a type",
+                indoc! {r#"
+                    <?php
+
+                    final class Foo {
+                        public const BAR = 'bar';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for TypedClassConstantRule {
+    fn walk_in_class_like_constant(&self, class_like_constant: &ClassLikeConstant, context: &mut LintContext<'a>) {
+        if class_like_constant.hint.is_some() {
+            return;
+        }
+
+        let semantics = context.semantics;
+        let mut constant_type = None;
+        for item in class_like_constant.items.iter() {
+            let kind = mago_typing::resolve_kind(
+                context.interner,
+                &semantics.source,
+                &semantics.names,
+                context.codebase,
+                &item.value,
+            );
+
+            // All the constants declared together share the same type.
+            match (get_declarable_type(context.interner, &kind), &constant_type) {
+                (Some(item_type), None) => constant_type = Some(item_type),
+                (Some(item_type), Some(constant_type)) if &item_type == constant_type => {}
+                _ => return,
+            }
+        }
+
+        let Some(constant_type) = constant_type else {
+            return;
+        };
+
+        let item = class_like_constant.first_item();
+        let name = context.lookup(&item.name.value);
+        let issue = Issue::new(context.level(), format!("Class constant `{}` should declare its type.", name))
+            .with_annotation(
+                Annotation::primary(class_like_constant.span())
+                    .with_message(format!("The value of this constant is of type `{}`.", constant_type)),
+            )
+            .with_help(format!("Declare the constant as `const {} {}`.", constant_type, name))
+            .with_note("Typed class constants are available since PHP 8.3.");
+
+        // Declaring the type of a constant that can be overridden breaks child classes overriding it
+        // with a value of a different type.
+        let modifiers = &class_like_constant.modifiers;
+        let can_be_overridden = !modifiers.contains_final()
+            && !modifiers.contains_private()
+            && context
+                .codebase
+                .get_enclosing_class_like(&class_like_constant.span().start)
+                .is_none_or(|class_like| !class_like.is_final && !class_like.is_enum());
+
+        context.report_with_fix(issue, |plan| {
+            plan.insert(
+                item.name.span().start.offset,
+                format!("{} ", constant_type),
+                if can_be_overridden { SafetyClassification::PotentiallyUnsafe } else { SafetyClassification::Safe },
+            );
+        });
+    }
+}
+
+/// Returns the type that can be declared for a constant holding a value of the given kind, if any.
+fn get_declarable_type(interner: &ThreadedInterner, kind: &TypeKind) -> Option<String> {
+    Some(
+        match kind {
+            TypeKind::Scalar(ScalarTypeKind::Bool)
+            | TypeKind::Value(ValueTypeKind::True)
+            | TypeKind::Value(ValueTypeKind::False) => "bool",
+            TypeKind::Scalar(ScalarTypeKind::Integer { .. })
+            | TypeKind::Scalar(ScalarTypeKind::IntegerMask(_))
+            | TypeKind::Scalar(ScalarTypeKind::LiteralInt)
+            | TypeKind::Value(ValueTypeKind::Integer { .. }) => "int",
+            TypeKind::Scalar(ScalarTypeKind::Float) | TypeKind::Value(ValueTypeKind::Float { .. }) => "float",
+            TypeKind::Scalar(
+                ScalarTypeKind::String
+                | ScalarTypeKind::ClassString(_)
+                | ScalarTypeKind::TraitString
+                | ScalarTypeKind::EnumString
+                | ScalarTypeKind::CallableString
+                | ScalarTypeKind::NumericString
+                | ScalarTypeKind::LiteralString
+                | ScalarTypeKind::NonEmptyString,
+            )
+            | TypeKind::Value(ValueTypeKind::String { .. }) => "string",
+            TypeKind::Value(ValueTypeKind::Null) => "null",
+            TypeKind::Array(_) => "array",
+            TypeKind::Object(ObjectTypeKind::EnumCase { enum_name, .. }) => {
+                return Some(format!("\\{}", interner.lookup(enum_name)));
+            }
+            TypeKind::Union { kinds } => {
+                let mut types = kinds.iter().map(|kind| get_declarable_type(interner, kind));
+                let first = types.next()??;
+
+                return types.all(|item_type| item_type.as_ref() == Some(&first)).then_some(first);
+            }
+            _ => return None,
+        }
+        .to_string(),
+    )
+}
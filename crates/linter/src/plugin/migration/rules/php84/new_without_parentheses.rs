@@ -0,0 +1,100 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NewWithoutParenthesesRule;
+
+impl Rule for NewWithoutParenthesesRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("New Without Parentheses", Level::Help)
+            .with_minimum_supported_php_version(PHPVersion::PHP84)
+            .with_description(indoc! {"
+                Detects `new` expressions wrapped in parentheses to access a member of the created object,
+                such as `(new Foo())->bar()`.
+                Since PHP 8.4, members can be accessed directly on the created object, such as `new Foo()->bar()`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Calling a method on a new object without wrapping parentheses",
+                indoc! {r#"
+                    <?php
+
+                    $response = new Response()->withStatus(404);
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Calling a method on a new object with wrapping parentheses",
+                indoc! {r#"
+                    <?php
+
+                    $response = (new Response())->withStatus(404);
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NewWithoutParenthesesRule {
+    fn walk_in_call(&self, call: &Call, context: &mut LintContext<'a>) {
+        let object = match call {
+            Call::Function(function_call) => &function_call.function,
+            Call::Method(method_call) => &method_call.object,
+            Call::NullSafeMethod(null_safe_method_call) => &null_safe_method_call.object,
+            Call::StaticMethod(static_method_call) => &static_method_call.class,
+        };
+
+        check(object, context);
+    }
+
+    fn walk_in_access(&self, access: &Access, context: &mut LintContext<'a>) {
+        let object = match access {
+            Access::Property(property_access) => &property_access.object,
+            Access::NullSafeProperty(null_safe_property_access) => &null_safe_property_access.object,
+            Access::StaticProperty(static_property_access) => &static_property_access.class,
+            Access::ClassConstant(class_constant_access) => &class_constant_access.class,
+        };
+
+        check(object, context);
+    }
+
+    fn walk_in_array_access(&self, array_access: &ArrayAccess, context: &mut LintContext<'a>) {
+        check(&array_access.array, context);
+    }
+}
+
+fn check(expression: &Expression, context: &mut LintContext<'_>) {
+    let Expression::Parenthesized(Parenthesized { left_parenthesis, expression, right_parenthesis }) = expression
+    else {
+        return;
+    };
+
+    // The parentheses can only be omitted when the class name is followed by an argument list.
+    let instantiation = match expression.as_ref() {
+        Expression::Instantiation(instantiation @ Instantiation { arguments: Some(_), .. }) => instantiation.span(),
+        Expression::AnonymousClass(anonymous_class) => anonymous_class.span(),
+        _ => return,
+    };
+
+    let issue = Issue::new(context.level(), "Parentheses around `new` expressions are no longer required.")
+        .with_annotation(
+            Annotation::primary(left_parenthesis.join(*right_parenthesis))
+                .with_message("These parentheses are redundant."),
+        )
+        .with_annotation(Annotation::secondary(instantiation).with_message("The object is created here."))
+        .with_help("Remove the parentheses wrapping the `new` expression.")
+        .with_note("Members of newly created objects can be accessed without wrapping parentheses since PHP 8.4.");
+
+    context.report_with_fix(issue, |plan| {
+        plan.delete(left_parenthesis.to_range(), SafetyClassification::Safe);
+        plan.delete(right_parenthesis.to_range(), SafetyClassification::Safe);
+    });
+}
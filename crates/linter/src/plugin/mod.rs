@@ -18,6 +18,7 @@ pub mod redundancy;
 pub mod safety;
 pub mod strictness;
 pub mod symfony;
+pub mod taint;
 
 #[macro_export]
 macro_rules! foreach_plugin {
@@ -3,8 +3,12 @@ use crate::plugin::naming::rules::class::ClassRule;
 use crate::plugin::naming::rules::constant::ConstantRule;
 use crate::plugin::naming::rules::function::FunctionRule;
 use crate::plugin::naming::rules::interface::InterfaceRule;
+use crate::plugin::naming::rules::method::MethodRule;
+use crate::plugin::naming::rules::parameter::ParameterRule;
+use crate::plugin::naming::rules::property::PropertyRule;
 use crate::plugin::naming::rules::r#enum::EnumRule;
 use crate::plugin::naming::rules::r#trait::TraitRule;
+use crate::plugin::naming::rules::variable::VariableRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -30,7 +34,11 @@ impl Plugin for NamingPlugin {
             Box::new(EnumRule),
             Box::new(FunctionRule),
             Box::new(InterfaceRule),
+            Box::new(MethodRule),
+            Box::new(ParameterRule),
+            Box::new(PropertyRule),
             Box::new(TraitRule),
+            Box::new(VariableRule),
         ]
     }
 }
@@ -24,12 +24,15 @@ impl Rule for ClassRule {
             .with_description(indoc! {"
                 Detects class declarations that do not follow class naming convention.
                 Class names should be in class case, also known as PascalCase.
+
+                With the PSR option enabled, abstract class names should be prefixed with `Abstract`, and
+                names of classes implementing `Throwable` should be suffixed with `Exception`.
             "})
             .with_option(RuleOptionDefinition {
                 name: PSR,
                 r#type: "boolean",
                 description:
-                    "Enforce PSR naming convention, which requires abstract classes to be prefixed with `Abstract`.",
+                    "Enforce PSR naming convention, which requires abstract classes to be prefixed with `Abstract`, and exception classes to be suffixed with `Exception`.",
                 default: Value::Boolean(PSR_DEFAULT),
             })
             .with_example(RuleUsageExample::valid(
@@ -72,6 +75,17 @@ impl Rule for ClassRule {
                 )
                 .with_option(PSR, Value::Boolean(true)),
             )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "An exception class name not suffixed with `Exception`, with PSR option enabled",
+                    indoc! {r#"
+                    <?php
+
+                    final class InvalidInput extends \Exception {}
+                "#},
+                )
+                .with_option(PSR, Value::Boolean(true)),
+            )
     }
 }
 
@@ -114,8 +128,46 @@ impl<'a> Walker<LintContext<'a>> for ClassRule {
             );
         }
 
+        if context.option(PSR).and_then(|o| o.as_bool()).unwrap_or(PSR_DEFAULT)
+            && !name.ends_with("Exception")
+            && is_throwable(class, context)
+        {
+            let suggested_name = format!("{}Exception", mago_casing::to_class_case(name));
+
+            issues.push(
+                Issue::new(
+                    context.level(),
+                    format!("Exception class name `{}` should be suffixed with `Exception`.", name),
+                )
+                .with_annotations([
+                    Annotation::primary(class.name.span()).with_message(format!("Class `{}` is declared here.", name))
+                ])
+                .with_note(format!("The exception class name `{}` does not follow PSR naming convention.", name))
+                .with_help(format!("Consider renaming it to `{}` to adhere to the naming convention.", suggested_name)),
+            );
+        }
+
         for issue in issues {
             context.report(issue);
         }
     }
 }
+
+/// Determine if the class implements `Throwable`, either directly or through one of its parents.
+fn is_throwable(class: &Class, context: &LintContext<'_>) -> bool {
+    let Some(reflection) =
+        context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&class.name))
+    else {
+        return false;
+    };
+
+    let inheritance = &reflection.inheritance;
+
+    inheritance.all_implemented_interfaces.iter().chain(inheritance.all_extended_classes.iter()).any(|name| {
+        let name = context.lookup(&name.value);
+
+        name.eq_ignore_ascii_case("Throwable")
+            || name.eq_ignore_ascii_case("Exception")
+            || name.eq_ignore_ascii_case("Error")
+    })
+}
@@ -0,0 +1,197 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::naming::rules::utils::case_option;
+use crate::plugin::naming::rules::utils::get_member_rename_edits;
+use crate::plugin::naming::rules::utils::pattern_option;
+use crate::plugin::naming::rules::utils::Convention;
+use crate::plugin::naming::rules::utils::CASE;
+use crate::plugin::naming::rules::utils::PATTERN;
+use crate::rule::Rule;
+
+const CASE_DEFAULT: &str = "camel";
+
+#[derive(Clone, Copy, Debug)]
+pub struct MethodRule;
+
+impl Rule for MethodRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Method", Level::Help)
+            .with_description(indoc! {"
+                Detects method declarations that do not follow the configured naming convention.
+                Method names should be in camel case, snake case, or match a custom pattern, depending on the configuration.
+
+                Magic methods, and methods overriding a parent method, are ignored.
+            "})
+            .with_option(case_option(CASE_DEFAULT))
+            .with_option(pattern_option())
+            .with_example(RuleUsageExample::valid(
+                "A method name in camel case",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function getName(): string
+                        {
+                            return 'Alice';
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A method name not in camel case",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function get_name(): string
+                        {
+                            return 'Alice';
+                        }
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A method name in snake case, with snake case enabled",
+                    indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        public function get_name(): string
+                        {
+                            return 'Alice';
+                        }
+                    }
+                "#},
+                )
+                .with_option(CASE, Value::String("snake".to_string())),
+            )
+            .with_example(
+                RuleUsageExample::invalid(
+                    "A method name not matching a custom pattern",
+                    indoc! {r#"
+                    <?php
+
+                    final class UserTest
+                    {
+                        public function checksName(): void
+                        {
+                        }
+                    }
+                "#},
+                )
+                .with_option(PATTERN, Value::String("^(test|provide)[A-Z][a-zA-Z0-9]*$".to_string())),
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for MethodRule {
+    fn walk_in_class<'ast>(&self, class: &'ast Class, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&class.name));
+
+        check(&class.members, reflection, true, context);
+    }
+
+    fn walk_in_enum<'ast>(&self, r#enum: &'ast Enum, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&r#enum.name));
+
+        check(&r#enum.members, reflection, true, context);
+    }
+
+    fn walk_in_anonymous_class<'ast>(&self, anonymous_class: &'ast AnonymousClass, context: &mut LintContext<'a>) {
+        let reflection = context.codebase.get_anonymous_class(anonymous_class);
+
+        check(&anonymous_class.members, reflection, true, context);
+    }
+
+    fn walk_in_trait<'ast>(&self, r#trait: &'ast Trait, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&r#trait.name));
+
+        // Private trait methods may be called from the classes using the trait, so they can not be renamed safely.
+        check(&r#trait.members, reflection, false, context);
+    }
+
+    fn walk_in_interface<'ast>(&self, interface: &'ast Interface, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&interface.name));
+
+        check(&interface.members, reflection, false, context);
+    }
+}
+
+fn check(
+    members: &Sequence<ClassLikeMember>,
+    reflection: Option<&ClassLikeReflection>,
+    can_rename: bool,
+    context: &mut LintContext<'_>,
+) {
+    let convention = Convention::get(context, CASE_DEFAULT);
+
+    for member in members.iter() {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        let name = context.lookup(&method.name.value);
+        if name.starts_with("__") || convention.matches(name) {
+            continue;
+        }
+
+        let method_reflection = reflection.and_then(|reflection| reflection.get_method(&method.name.value));
+        if method_reflection.is_some_and(|method_reflection| method_reflection.is_overriding) {
+            // The name of the method is dictated by the parent method.
+            continue;
+        }
+
+        let suggestion = convention.suggest(name);
+        let issue = Issue::new(context.level(), format!("Method name `{}` should {}.", name, convention.describe()))
+            .with_annotation(
+                Annotation::primary(method.name.span()).with_message(format!("Method `{}` is declared here.", name)),
+            )
+            .with_note(format!("The method name `{}` does not follow the configured naming convention.", name))
+            .with_help(match &suggestion {
+                Some(suggestion) => {
+                    format!("Consider renaming it to `{}` to adhere to the naming convention.", suggestion)
+                }
+                None => "Consider renaming it to adhere to the naming convention.".to_string(),
+            });
+
+        let edits = match &suggestion {
+            Some(suggestion) if can_rename && method.modifiers.contains_private() => {
+                get_member_rename_edits(members, true, name, suggestion, context)
+            }
+            _ => None,
+        };
+
+        let (Some(suggestion), Some(edits)) = (suggestion, edits) else {
+            context.report(issue);
+
+            continue;
+        };
+
+        context.report_with_fix(issue, |plan| {
+            plan.replace(method.name.span().to_range(), suggestion, SafetyClassification::PotentiallyUnsafe);
+
+            for (span, replacement) in edits {
+                plan.replace(span.to_range(), replacement, SafetyClassification::PotentiallyUnsafe);
+            }
+        });
+    }
+}
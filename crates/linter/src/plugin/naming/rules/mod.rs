@@ -3,4 +3,9 @@ pub mod constant;
 pub mod r#enum;
 pub mod function;
 pub mod interface;
+pub mod method;
+pub mod parameter;
+pub mod property;
 pub mod r#trait;
+pub mod utils;
+pub mod variable;
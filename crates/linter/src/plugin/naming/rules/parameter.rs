@@ -0,0 +1,182 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::feature::Feature;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::naming::rules::utils::case_option;
+use crate::plugin::naming::rules::utils::get_block_scope;
+use crate::plugin::naming::rules::utils::get_expression_scope;
+use crate::plugin::naming::rules::utils::pattern_option;
+use crate::plugin::naming::rules::utils::Convention;
+use crate::plugin::naming::rules::utils::Scope;
+use crate::plugin::naming::rules::utils::CASE;
+use crate::rule::Rule;
+
+const CASE_DEFAULT: &str = "camel";
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParameterRule;
+
+impl Rule for ParameterRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Parameter", Level::Help)
+            .with_description(indoc! {"
+                Detects parameters that do not follow the configured naming convention.
+                Parameter names should be in camel case, snake case, or match a custom pattern, depending on the configuration.
+
+                Promoted properties, and parameters of methods overriding a parent method, are ignored.
+            "})
+            .with_option(case_option(CASE_DEFAULT))
+            .with_option(pattern_option())
+            .with_example(RuleUsageExample::valid(
+                "Parameter names in camel case",
+                indoc! {r#"
+                    <?php
+
+                    function greet(string $firstName, string $lastName): string
+                    {
+                        return 'Hello, ' . $firstName . ' ' . $lastName;
+                    }
+
+                    $greet = fn(string $firstName): string => 'Hello, ' . $firstName;
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Parameter names not in camel case",
+                indoc! {r#"
+                    <?php
+
+                    function greet(string $first_name, string $LastName): string
+                    {
+                        return 'Hello, ' . $first_name . ' ' . $LastName;
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "Parameter names in snake case, with snake case enabled",
+                    indoc! {r#"
+                    <?php
+
+                    function greet(string $first_name, string $last_name): string
+                    {
+                        return 'Hello, ' . $first_name . ' ' . $last_name;
+                    }
+                "#},
+                )
+                .with_option(CASE, Value::String("snake".to_string())),
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ParameterRule {
+    fn walk_in_function<'ast>(&self, function: &'ast Function, context: &mut LintContext<'a>) {
+        let scope = get_block_scope(&function.body, context);
+
+        check(&function.parameter_list, Some(&scope), context);
+    }
+
+    fn walk_in_method<'ast>(&self, method: &'ast Method, context: &mut LintContext<'a>) {
+        let method_reflection = context
+            .codebase
+            .get_enclosing_class_like(method)
+            .and_then(|reflection| reflection.get_method(&method.name.value));
+
+        if method_reflection.is_some_and(|method_reflection| method_reflection.is_overriding) {
+            // The names of the parameters may be dictated by the parent method.
+            return;
+        }
+
+        let scope = match &method.body {
+            MethodBody::Concrete(block) => Some(get_block_scope(block, context)),
+            MethodBody::Abstract(_) => None,
+        };
+
+        check(&method.parameter_list, scope.as_ref(), context);
+    }
+
+    fn walk_in_closure<'ast>(&self, closure: &'ast Closure, context: &mut LintContext<'a>) {
+        let scope = get_block_scope(&closure.body, context);
+
+        check(&closure.parameter_list, Some(&scope), context);
+    }
+
+    fn walk_in_arrow_function<'ast>(&self, arrow_function: &'ast ArrowFunction, context: &mut LintContext<'a>) {
+        let scope = get_expression_scope(&arrow_function.expression, context);
+
+        check(&arrow_function.parameter_list, Some(&scope), context);
+    }
+}
+
+fn check(parameter_list: &FunctionLikeParameterList, scope: Option<&Scope>, context: &mut LintContext<'_>) {
+    let convention = Convention::get(context, CASE_DEFAULT);
+
+    // Parameters may be passed as named arguments since PHP 8.0, in which case renaming them
+    // would break the callers.
+    let scope = scope.filter(|_| !context.php_version.is_supported(Feature::NamedArguments));
+
+    for parameter in parameter_list.parameters.iter() {
+        if parameter.is_promoted_property() {
+            continue;
+        }
+
+        let name = &context.lookup(&parameter.variable.name)[1..];
+        if convention.matches(name) {
+            continue;
+        }
+
+        let suggestion = convention.suggest(name);
+        let issue =
+            Issue::new(context.level(), format!("Parameter name `${}` should {}.", name, convention.describe()))
+                .with_annotation(
+                    Annotation::primary(parameter.variable.span())
+                        .with_message(format!("Parameter `${}` is declared here.", name)),
+                )
+                .with_note(format!("The parameter name `${}` does not follow the configured naming convention.", name))
+                .with_help(match &suggestion {
+                    Some(suggestion) => {
+                        format!("Consider renaming it to `${}` to adhere to the naming convention.", suggestion)
+                    }
+                    None => "Consider renaming it to adhere to the naming convention.".to_string(),
+                });
+
+        let spans = match (&suggestion, scope) {
+            (Some(suggestion), Some(scope)) => {
+                let is_conflicting = parameter_list
+                    .parameters
+                    .iter()
+                    .any(|other| context.lookup(&other.variable.name)[1..] == **suggestion);
+
+                if is_conflicting {
+                    None
+                } else {
+                    scope.get_rename_spans(parameter.variable.name, suggestion, context)
+                }
+            }
+            _ => None,
+        };
+
+        let (Some(suggestion), Some(spans)) = (suggestion, spans) else {
+            context.report(issue);
+
+            continue;
+        };
+
+        context.report_with_fix(issue, |plan| {
+            let replacement = format!("${}", suggestion);
+
+            plan.replace(parameter.variable.span().to_range(), replacement.clone(), SafetyClassification::Safe);
+            for span in spans {
+                plan.replace(span.to_range(), replacement.clone(), SafetyClassification::Safe);
+            }
+        });
+    }
+}
@@ -0,0 +1,197 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::naming::rules::utils::case_option;
+use crate::plugin::naming::rules::utils::get_member_rename_edits;
+use crate::plugin::naming::rules::utils::pattern_option;
+use crate::plugin::naming::rules::utils::Convention;
+use crate::plugin::naming::rules::utils::CASE;
+use crate::rule::Rule;
+
+const CASE_DEFAULT: &str = "camel";
+
+#[derive(Clone, Copy, Debug)]
+pub struct PropertyRule;
+
+impl Rule for PropertyRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Property", Level::Help)
+            .with_description(indoc! {"
+                Detects property declarations, including promoted properties, that do not follow the configured naming convention.
+                Property names should be in camel case, snake case, or match a custom pattern, depending on the configuration.
+
+                Properties overriding a parent property are ignored.
+            "})
+            .with_option(case_option(CASE_DEFAULT))
+            .with_option(pattern_option())
+            .with_example(RuleUsageExample::valid(
+                "Property names in camel case",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        private string $firstName = 'Alice';
+
+                        public function __construct(
+                            public readonly string $lastName,
+                        ) {
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Property names not in camel case",
+                indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        private string $first_name = 'Alice';
+
+                        public function __construct(
+                            public readonly string $LastName,
+                        ) {
+                        }
+
+                        public function getFirstName(): string
+                        {
+                            return $this->first_name;
+                        }
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A property name in snake case, with snake case enabled",
+                    indoc! {r#"
+                    <?php
+
+                    final class User
+                    {
+                        private string $first_name = 'Alice';
+                    }
+                "#},
+                )
+                .with_option(CASE, Value::String("snake".to_string())),
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for PropertyRule {
+    fn walk_in_class<'ast>(&self, class: &'ast Class, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&class.name));
+
+        check(&class.members, reflection, true, context);
+    }
+
+    fn walk_in_anonymous_class<'ast>(&self, anonymous_class: &'ast AnonymousClass, context: &mut LintContext<'a>) {
+        let reflection = context.codebase.get_anonymous_class(anonymous_class);
+
+        check(&anonymous_class.members, reflection, true, context);
+    }
+
+    fn walk_in_trait<'ast>(&self, r#trait: &'ast Trait, context: &mut LintContext<'a>) {
+        let reflection =
+            context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&r#trait.name));
+
+        // Private trait properties may be accessed from the classes using the trait, so they can not be renamed safely.
+        check(&r#trait.members, reflection, false, context);
+    }
+}
+
+fn check(
+    members: &Sequence<ClassLikeMember>,
+    reflection: Option<&ClassLikeReflection>,
+    can_rename: bool,
+    context: &mut LintContext<'_>,
+) {
+    let convention = Convention::get(context, CASE_DEFAULT);
+
+    for member in members.iter() {
+        match member {
+            ClassLikeMember::Property(property) => {
+                let is_private = property.modifiers().contains_private();
+
+                for variable in property.variables() {
+                    check_property(members, variable, reflection, &convention, can_rename && is_private, context);
+                }
+            }
+            ClassLikeMember::Method(method)
+                if context.lookup(&method.name.value).eq_ignore_ascii_case("__construct") =>
+            {
+                for parameter in method.parameter_list.parameters.iter() {
+                    if parameter.is_promoted_property() {
+                        // Renaming a promoted property would rename the constructor parameter as well,
+                        // which may be passed as a named argument.
+                        check_property(members, &parameter.variable, reflection, &convention, false, context);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_property(
+    members: &Sequence<ClassLikeMember>,
+    variable: &DirectVariable,
+    reflection: Option<&ClassLikeReflection>,
+    convention: &Convention,
+    can_rename: bool,
+    context: &mut LintContext<'_>,
+) {
+    let name = &context.lookup(&variable.name)[1..];
+    if convention.matches(name) {
+        return;
+    }
+
+    let property_reflection = reflection.and_then(|reflection| reflection.get_property(&variable.name));
+    if property_reflection.is_some_and(|property_reflection| property_reflection.is_overriding) {
+        // The name of the property is dictated by the parent property.
+        return;
+    }
+
+    let suggestion = convention.suggest(name);
+    let issue = Issue::new(context.level(), format!("Property name `${}` should {}.", name, convention.describe()))
+        .with_annotation(
+            Annotation::primary(variable.span()).with_message(format!("Property `${}` is declared here.", name)),
+        )
+        .with_note(format!("The property name `${}` does not follow the configured naming convention.", name))
+        .with_help(match &suggestion {
+            Some(suggestion) => {
+                format!("Consider renaming it to `${}` to adhere to the naming convention.", suggestion)
+            }
+            None => "Consider renaming it to adhere to the naming convention.".to_string(),
+        });
+
+    let edits = match &suggestion {
+        Some(suggestion) if can_rename => get_member_rename_edits(members, false, name, suggestion, context),
+        _ => None,
+    };
+
+    let (Some(suggestion), Some(edits)) = (suggestion, edits) else {
+        context.report(issue);
+
+        return;
+    };
+
+    context.report_with_fix(issue, |plan| {
+        plan.replace(variable.span().to_range(), format!("${}", suggestion), SafetyClassification::PotentiallyUnsafe);
+
+        for (span, replacement) in edits {
+            plan.replace(span.to_range(), replacement, SafetyClassification::PotentiallyUnsafe);
+        }
+    });
+}
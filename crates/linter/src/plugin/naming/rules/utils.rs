@@ -0,0 +1,588 @@
+use ahash::HashSet;
+use regex::Regex;
+use toml::Value;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleOptionDefinition;
+
+pub const CASE: &str = "case";
+pub const PATTERN: &str = "pattern";
+
+/// Variables that are defined by PHP, and whose names can therefore not be changed.
+const PREDEFINED_VARIABLES: [&str; 14] = [
+    "$this",
+    "$GLOBALS",
+    "$_SERVER",
+    "$_GET",
+    "$_POST",
+    "$_FILES",
+    "$_COOKIE",
+    "$_SESSION",
+    "$_REQUEST",
+    "$_ENV",
+    "$argc",
+    "$argv",
+    "$http_response_header",
+    "$php_errormsg",
+];
+
+/// Functions that access the variables of the calling scope by name.
+const SCOPE_INTROSPECTION_FUNCTIONS: [&str; 4] = ["compact", "extract", "get_defined_vars", "parse_str"];
+
+/// A naming convention, as configured using the `case` and `pattern` options of a rule.
+#[derive(Debug)]
+pub enum Convention {
+    Camel,
+    Snake,
+    Either,
+    Pattern(Regex),
+}
+
+impl Convention {
+    /// Returns the naming convention configured for the current rule.
+    ///
+    /// The `pattern` option takes precedence over the `case` option, unless it is not a valid regular expression.
+    pub fn get(context: &LintContext<'_>, default_case: &'static str) -> Self {
+        if let Some(pattern) = context.option(PATTERN).and_then(|o| o.as_str()).filter(|p| !p.is_empty()) {
+            match Regex::new(pattern) {
+                Ok(regex) => return Convention::Pattern(regex),
+                Err(error) => tracing::warn!("Invalid naming pattern `{pattern}`, falling back to case: {error}"),
+            }
+        }
+
+        match context.option(CASE).and_then(|o| o.as_str()).unwrap_or(default_case) {
+            case if case.eq_ignore_ascii_case("snake") => Convention::Snake,
+            case if case.eq_ignore_ascii_case("either") => Convention::Either,
+            _ => Convention::Camel,
+        }
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Convention::Camel => mago_casing::is_camel_case(name),
+            Convention::Snake => mago_casing::is_snake_case(name),
+            Convention::Either => mago_casing::is_camel_case(name) || mago_casing::is_snake_case(name),
+            Convention::Pattern(regex) => regex.is_match(name),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Convention::Camel => "be in camel case".to_string(),
+            Convention::Snake => "be in snake case".to_string(),
+            Convention::Either => "be in either camel case or snake case".to_string(),
+            Convention::Pattern(regex) => format!("match the pattern `{}`", regex.as_str()),
+        }
+    }
+
+    /// Returns a name following the convention, if one can be derived from the given name.
+    pub fn suggest(&self, name: &str) -> Option<String> {
+        let suggestion = match self {
+            Convention::Camel | Convention::Either => mago_casing::to_camel_case(name),
+            Convention::Snake => mago_casing::to_snake_case(name),
+            Convention::Pattern(_) => return None,
+        };
+
+        if suggestion.is_empty() || suggestion == name || suggestion.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(suggestion)
+    }
+}
+
+pub fn case_option(default_case: &'static str) -> RuleOptionDefinition {
+    RuleOptionDefinition {
+        name: CASE,
+        r#type: "string",
+        description: "The case names should be in, either `camel`, `snake`, or `either`.",
+        default: Value::String(default_case.to_string()),
+    }
+}
+
+pub fn pattern_option() -> RuleOptionDefinition {
+    RuleOptionDefinition {
+        name: PATTERN,
+        r#type: "string",
+        description: "A regular expression names should match, taking precedence over the `case` option when set.",
+        default: Value::String(String::new()),
+    }
+}
+
+/// Determine if a variable is defined by PHP, or is a placeholder made only of underscores.
+pub fn is_reserved_variable(name: &str) -> bool {
+    PREDEFINED_VARIABLES.contains(&name) || name[1..].chars().all(|c| c == '_')
+}
+
+/// The variables used within a function-like body, or within the global scope.
+#[derive(Debug, Default)]
+pub struct Scope {
+    /// The name and span of each variable occurrence, in order of appearance.
+    pub variables: Vec<(StringIdentifier, Span)>,
+    /// The variables declared using `global`, which are not local to the scope.
+    pub globals: HashSet<StringIdentifier>,
+    /// The variables captured by closures, which would have to be renamed within the closures as well.
+    pub captured: HashSet<StringIdentifier>,
+    /// Whether variables are accessed by name, e.g. using variable variables, `compact()`, or `include`.
+    pub is_dynamic: bool,
+    shadowed: Vec<StringIdentifier>,
+}
+
+impl Scope {
+    /// Returns the distinct variables of the scope, along with the span of their first occurrence.
+    pub fn get_distinct_variables(&self) -> Vec<(StringIdentifier, Span)> {
+        let mut seen = HashSet::default();
+
+        self.variables.iter().filter(|(name, _)| seen.insert(*name)).copied().collect()
+    }
+
+    /// Returns the spans of the occurrences of a variable, if it can be safely renamed to the given name.
+    ///
+    /// The spans include the leading `$`, while the new name is given without it.
+    pub fn get_rename_spans(
+        &self,
+        name: StringIdentifier,
+        new_name: &str,
+        context: &LintContext<'_>,
+    ) -> Option<Vec<Span>> {
+        if self.is_dynamic || self.globals.contains(&name) || self.captured.contains(&name) {
+            return None;
+        }
+
+        if self.variables.iter().any(|(variable, _)| context.lookup(variable)[1..] == *new_name) {
+            return None;
+        }
+
+        Some(self.variables.iter().filter(|(variable, _)| *variable == name).map(|(_, span)| *span).collect())
+    }
+}
+
+pub fn get_block_scope(block: &Block, context: &LintContext<'_>) -> Scope {
+    let mut context = (Scope::default(), context);
+
+    internal::ScopeWalker.walk_block(block, &mut context);
+
+    context.0
+}
+
+pub fn get_expression_scope(expression: &Expression, context: &LintContext<'_>) -> Scope {
+    let mut context = (Scope::default(), context);
+
+    internal::ScopeWalker.walk_expression(expression, &mut context);
+
+    context.0
+}
+
+pub fn get_statements_scope(statements: &Sequence<Statement>, context: &LintContext<'_>) -> Scope {
+    let mut context = (Scope::default(), context);
+
+    for statement in statements.iter() {
+        internal::ScopeWalker.walk_statement(statement, &mut context);
+    }
+
+    context.0
+}
+
+/// Returns the edits renaming references to a private member within the members of its class-like,
+/// if the member can be safely renamed to the given name.
+///
+/// The rename is considered safe when every reference to the member goes through `$this`, `self`, or `static`,
+/// the member is not referenced by name in a string, e.g. a callable or `__sleep()`, and the class-like neither
+/// uses traits nor handles inaccessible members using magic methods.
+pub fn get_member_rename_edits(
+    members: &Sequence<ClassLikeMember>,
+    is_method: bool,
+    name: &str,
+    new_name: &str,
+    context: &LintContext<'_>,
+) -> Option<Vec<(Span, String)>> {
+    let mut magic_methods: &[&str] = &["__get", "__set", "__isset", "__unset"];
+    if is_method {
+        magic_methods = &["__call", "__callstatic"];
+    }
+
+    for member in members.iter() {
+        let is_conflicting = match member {
+            ClassLikeMember::TraitUse(_) => return None,
+            ClassLikeMember::Method(method) => {
+                let method_name = context.lookup(&method.name.value);
+                if magic_methods.iter().any(|magic| magic.eq_ignore_ascii_case(method_name)) {
+                    return None;
+                }
+
+                is_method && method_name.eq_ignore_ascii_case(new_name)
+                    || !is_method
+                        && method.parameter_list.parameters.iter().any(|parameter| {
+                            parameter.is_promoted_property()
+                                && context.lookup(&parameter.variable.name)[1..] == *new_name
+                        })
+            }
+            ClassLikeMember::Property(property) => {
+                !is_method
+                    && property.variables().iter().any(|variable| context.lookup(&variable.name)[1..] == *new_name)
+            }
+            _ => false,
+        };
+
+        if is_conflicting {
+            return None;
+        }
+    }
+
+    let mut references = internal::MemberReferences::default();
+    let mut walker_context = (&mut references, context);
+    for member in members.iter() {
+        internal::MemberWalker.walk_class_like_member(member, &mut walker_context);
+    }
+
+    if references.is_dynamic || references.strings.iter().any(|string| string.eq_ignore_ascii_case(name)) {
+        return None;
+    }
+
+    let mut edits = vec![];
+    for reference in references.references.iter() {
+        if reference.is_method != is_method {
+            continue;
+        }
+
+        let reference_name = context.lookup(&reference.name);
+        let is_static_property = reference_name.starts_with('$');
+        let matches = if is_method {
+            reference_name.eq_ignore_ascii_case(name)
+        } else {
+            reference_name.trim_start_matches('$') == name
+        };
+
+        if !matches {
+            continue;
+        }
+
+        if !reference.is_own {
+            return None;
+        }
+
+        edits.push((reference.span, if is_static_property { format!("${}", new_name) } else { new_name.to_string() }));
+    }
+
+    Some(edits)
+}
+
+mod internal {
+    use ahash::HashSet;
+
+    use mago_ast::*;
+    use mago_interner::StringIdentifier;
+    use mago_span::HasSpan;
+    use mago_span::Span;
+    use mago_walker::Walker;
+
+    use super::is_reserved_variable;
+    use super::Scope;
+    use super::SCOPE_INTROSPECTION_FUNCTIONS;
+    use crate::context::LintContext;
+
+    #[derive(Debug)]
+    pub(super) struct ScopeWalker;
+
+    impl<'a> Walker<(Scope, &'a LintContext<'a>)> for ScopeWalker {
+        fn walk_in_direct_variable<'ast>(
+            &self,
+            direct_variable: &'ast DirectVariable,
+            context: &mut (Scope, &'a LintContext<'a>),
+        ) {
+            if context.0.shadowed.contains(&direct_variable.name) {
+                return;
+            }
+
+            let name = context.1.lookup(&direct_variable.name);
+            if is_reserved_variable(name) {
+                return;
+            }
+
+            context.0.variables.push((direct_variable.name, direct_variable.span));
+        }
+
+        fn walk_in_indirect_variable<'ast>(
+            &self,
+            _: &'ast IndirectVariable,
+            context: &mut (Scope, &'a LintContext<'a>),
+        ) {
+            context.0.is_dynamic = true;
+        }
+
+        fn walk_in_nested_variable<'ast>(&self, _: &'ast NestedVariable, context: &mut (Scope, &'a LintContext<'a>)) {
+            context.0.is_dynamic = true;
+        }
+
+        fn walk_in_construct<'ast>(&self, construct: &'ast Construct, context: &mut (Scope, &'a LintContext<'a>)) {
+            if !matches!(
+                construct,
+                Construct::Isset(_)
+                    | Construct::Empty(_)
+                    | Construct::Print(_)
+                    | Construct::Exit(_)
+                    | Construct::Die(_)
+            ) {
+                context.0.is_dynamic = true;
+            }
+        }
+
+        fn walk_in_function_call<'ast>(
+            &self,
+            function_call: &'ast FunctionCall,
+            context: &mut (Scope, &'a LintContext<'a>),
+        ) {
+            let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+                return;
+            };
+
+            let name = context.1.resolve_function_name(identifier);
+            if SCOPE_INTROSPECTION_FUNCTIONS.iter().any(|function| function.eq_ignore_ascii_case(name)) {
+                context.0.is_dynamic = true;
+            }
+        }
+
+        fn walk_in_global<'ast>(&self, global: &'ast Global, context: &mut (Scope, &'a LintContext<'a>)) {
+            for variable in global.variables.iter() {
+                if let Variable::Direct(variable) = variable {
+                    context.0.globals.insert(variable.name);
+                }
+            }
+        }
+
+        fn walk_closure<'ast>(&self, closure: &'ast Closure, context: &mut (Scope, &'a LintContext<'a>)) {
+            if let Some(use_clause) = &closure.use_clause {
+                for use_clause_variable in use_clause.variables.iter() {
+                    let variable = &use_clause_variable.variable;
+
+                    context.0.captured.insert(variable.name);
+                    self.walk_direct_variable(variable, context);
+                }
+            }
+        }
+
+        fn walk_arrow_function<'ast>(
+            &self,
+            arrow_function: &'ast ArrowFunction,
+            context: &mut (Scope, &'a LintContext<'a>),
+        ) {
+            let shadowed = context.0.shadowed.len();
+            for parameter in arrow_function.parameter_list.parameters.iter() {
+                if let Some(default_value) = &parameter.default_value {
+                    self.walk_expression(&default_value.value, context);
+                }
+
+                context.0.shadowed.push(parameter.variable.name);
+            }
+
+            self.walk_expression(&arrow_function.expression, context);
+
+            context.0.shadowed.truncate(shadowed);
+        }
+
+        #[inline(always)]
+        fn walk_anonymous_class<'ast>(
+            &self,
+            anonymous_class: &'ast AnonymousClass,
+            context: &mut (Scope, &'a LintContext<'a>),
+        ) {
+            if let Some(argument_list) = anonymous_class.arguments.as_ref() {
+                self.walk_argument_list(argument_list, context);
+            }
+        }
+
+        #[inline(always)]
+        fn walk_class<'ast>(&self, _: &'ast Class, _: &mut (Scope, &'a LintContext<'a>)) {}
+
+        #[inline(always)]
+        fn walk_interface<'ast>(&self, _: &'ast Interface, _: &mut (Scope, &'a LintContext<'a>)) {}
+
+        #[inline(always)]
+        fn walk_trait<'ast>(&self, _: &'ast Trait, _: &mut (Scope, &'a LintContext<'a>)) {}
+
+        #[inline(always)]
+        fn walk_enum<'ast>(&self, _: &'ast Enum, _: &mut (Scope, &'a LintContext<'a>)) {}
+
+        #[inline(always)]
+        fn walk_function<'ast>(&self, _: &'ast Function, _: &mut (Scope, &'a LintContext<'a>)) {}
+    }
+
+    #[derive(Debug)]
+    pub(super) struct MemberReference {
+        pub name: StringIdentifier,
+        pub span: Span,
+        pub is_method: bool,
+        pub is_own: bool,
+    }
+
+    #[derive(Debug, Default)]
+    pub(super) struct MemberReferences {
+        pub references: Vec<MemberReference>,
+        pub strings: HashSet<String>,
+        pub is_dynamic: bool,
+    }
+
+    #[derive(Debug)]
+    pub(super) struct MemberWalker;
+
+    impl MemberWalker {
+        fn add_object_reference(
+            &self,
+            object: &Expression,
+            selector: &ClassLikeMemberSelector,
+            is_method: bool,
+            context: &mut (&mut MemberReferences, &LintContext<'_>),
+        ) {
+            let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+                context.0.is_dynamic = true;
+
+                return;
+            };
+
+            let is_own = matches!(object, Expression::Variable(Variable::Direct(variable)) if context.1.lookup(&variable.name) == "$this");
+
+            context.0.references.push(MemberReference {
+                name: identifier.value,
+                span: identifier.span,
+                is_method,
+                is_own,
+            });
+        }
+
+        fn add_class_reference(
+            &self,
+            class: &Expression,
+            name: StringIdentifier,
+            span: Span,
+            is_method: bool,
+            context: &mut (&mut MemberReferences, &LintContext<'_>),
+        ) {
+            let is_own = matches!(class, Expression::Self_(_) | Expression::Static(_));
+
+            context.0.references.push(MemberReference { name, span, is_method, is_own });
+        }
+
+        fn add_class_method_reference(
+            &self,
+            class: &Expression,
+            selector: &ClassLikeMemberSelector,
+            context: &mut (&mut MemberReferences, &LintContext<'_>),
+        ) {
+            let ClassLikeMemberSelector::Identifier(identifier) = selector else {
+                context.0.is_dynamic = true;
+
+                return;
+            };
+
+            self.add_class_reference(class, identifier.value, identifier.span, true, context);
+        }
+    }
+
+    impl<'a, 'b> Walker<(&'b mut MemberReferences, &'a LintContext<'a>)> for MemberWalker {
+        fn walk_in_method_call<'ast>(
+            &self,
+            method_call: &'ast MethodCall,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_object_reference(&method_call.object, &method_call.method, true, context);
+        }
+
+        fn walk_in_null_safe_method_call<'ast>(
+            &self,
+            null_safe_method_call: &'ast NullSafeMethodCall,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_object_reference(&null_safe_method_call.object, &null_safe_method_call.method, true, context);
+        }
+
+        fn walk_in_method_closure_creation<'ast>(
+            &self,
+            method_closure_creation: &'ast MethodClosureCreation,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_object_reference(&method_closure_creation.object, &method_closure_creation.method, true, context);
+        }
+
+        fn walk_in_static_method_call<'ast>(
+            &self,
+            static_method_call: &'ast StaticMethodCall,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_class_method_reference(&static_method_call.class, &static_method_call.method, context);
+        }
+
+        fn walk_in_static_method_closure_creation<'ast>(
+            &self,
+            static_method_closure_creation: &'ast StaticMethodClosureCreation,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_class_method_reference(
+                &static_method_closure_creation.class,
+                &static_method_closure_creation.method,
+                context,
+            );
+        }
+
+        fn walk_in_property_access<'ast>(
+            &self,
+            property_access: &'ast PropertyAccess,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_object_reference(&property_access.object, &property_access.property, false, context);
+        }
+
+        fn walk_in_null_safe_property_access<'ast>(
+            &self,
+            null_safe_property_access: &'ast NullSafePropertyAccess,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            self.add_object_reference(
+                &null_safe_property_access.object,
+                &null_safe_property_access.property,
+                false,
+                context,
+            );
+        }
+
+        fn walk_in_static_property_access<'ast>(
+            &self,
+            static_property_access: &'ast StaticPropertyAccess,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            let Variable::Direct(variable) = &static_property_access.property else {
+                context.0.is_dynamic = true;
+
+                return;
+            };
+
+            self.add_class_reference(&static_property_access.class, variable.name, variable.span(), false, context);
+        }
+
+        fn walk_in_literal_string<'ast>(
+            &self,
+            literal_string: &'ast LiteralString,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            let value = context.1.lookup(&literal_string.value);
+            if value.len() >= 2 {
+                context.0.strings.insert(value[1..value.len() - 1].to_string());
+            }
+        }
+
+        #[inline(always)]
+        fn walk_anonymous_class<'ast>(
+            &self,
+            anonymous_class: &'ast AnonymousClass,
+            context: &mut (&'b mut MemberReferences, &'a LintContext<'a>),
+        ) {
+            if let Some(argument_list) = anonymous_class.arguments.as_ref() {
+                self.walk_argument_list(argument_list, context);
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use indoc::indoc;
+use toml::Value;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::naming::rules::utils::case_option;
+use crate::plugin::naming::rules::utils::get_block_scope;
+use crate::plugin::naming::rules::utils::get_statements_scope;
+use crate::plugin::naming::rules::utils::pattern_option;
+use crate::plugin::naming::rules::utils::Convention;
+use crate::plugin::naming::rules::utils::Scope;
+use crate::plugin::naming::rules::utils::CASE;
+use crate::rule::Rule;
+
+const CASE_DEFAULT: &str = "camel";
+
+#[derive(Clone, Copy, Debug)]
+pub struct VariableRule;
+
+impl Rule for VariableRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Variable", Level::Help)
+            .with_description(indoc! {"
+                Detects variables that do not follow the configured naming convention.
+                Variable names should be in camel case, snake case, or match a custom pattern, depending on the configuration.
+
+                Predefined variables, such as `$_SERVER` or `$argv`, and parameters are ignored.
+            "})
+            .with_option(case_option(CASE_DEFAULT))
+            .with_option(pattern_option())
+            .with_example(RuleUsageExample::valid(
+                "A variable name in camel case",
+                indoc! {r#"
+                    <?php
+
+                    function get_full_name(string $firstName, string $lastName): string
+                    {
+                        $fullName = $firstName . ' ' . $lastName;
+
+                        return $fullName;
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A variable name not in camel case",
+                indoc! {r#"
+                    <?php
+
+                    function get_full_name(string $firstName, string $lastName): string
+                    {
+                        $full_name = $firstName . ' ' . $lastName;
+
+                        return $full_name;
+                    }
+                "#},
+            ))
+            .with_example(
+                RuleUsageExample::valid(
+                    "A variable name in snake case, with snake case enabled",
+                    indoc! {r#"
+                    <?php
+
+                    function get_full_name(string $first_name, string $last_name): string
+                    {
+                        $full_name = $first_name . ' ' . $last_name;
+
+                        return $full_name;
+                    }
+                "#},
+                )
+                .with_option(CASE, Value::String("snake".to_string())),
+            )
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for VariableRule {
+    fn walk_in_program<'ast>(&self, program: &'ast Program, context: &mut LintContext<'a>) {
+        // Variables of the global scope may be used by included files, so they can not be renamed safely.
+        let scope = get_statements_scope(&program.statements, context);
+
+        check(&scope, &[], false, context);
+    }
+
+    fn walk_in_function<'ast>(&self, function: &'ast Function, context: &mut LintContext<'a>) {
+        let scope = get_block_scope(&function.body, context);
+        let parameters = get_parameter_names(&function.parameter_list);
+
+        check(&scope, &parameters, true, context);
+    }
+
+    fn walk_in_method<'ast>(&self, method: &'ast Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        let scope = get_block_scope(block, context);
+        let parameters = get_parameter_names(&method.parameter_list);
+
+        check(&scope, &parameters, true, context);
+    }
+
+    fn walk_in_closure<'ast>(&self, closure: &'ast Closure, context: &mut LintContext<'a>) {
+        let scope = get_block_scope(&closure.body, context);
+        let mut names = get_parameter_names(&closure.parameter_list);
+        if let Some(use_clause) = &closure.use_clause {
+            // Captured variables are checked within the enclosing scope.
+            names.extend(use_clause.variables.iter().map(|variable| variable.variable.name));
+        }
+
+        check(&scope, &names, true, context);
+    }
+}
+
+fn get_parameter_names(parameter_list: &FunctionLikeParameterList) -> Vec<StringIdentifier> {
+    parameter_list.parameters.iter().map(|parameter| parameter.variable.name).collect()
+}
+
+fn check(scope: &Scope, ignored: &[StringIdentifier], can_rename: bool, context: &mut LintContext<'_>) {
+    let convention = Convention::get(context, CASE_DEFAULT);
+
+    for (variable, span) in scope.get_distinct_variables() {
+        if ignored.contains(&variable) || scope.globals.contains(&variable) {
+            continue;
+        }
+
+        let name = &context.lookup(&variable)[1..];
+        if convention.matches(name) {
+            continue;
+        }
+
+        let suggestion = convention.suggest(name);
+        let issue = Issue::new(context.level(), format!("Variable name `${}` should {}.", name, convention.describe()))
+            .with_annotation(Annotation::primary(span).with_message(format!("Variable `${}` is used here.", name)))
+            .with_note(format!("The variable name `${}` does not follow the configured naming convention.", name))
+            .with_help(match &suggestion {
+                Some(suggestion) => {
+                    format!("Consider renaming it to `${}` to adhere to the naming convention.", suggestion)
+                }
+                None => "Consider renaming it to adhere to the naming convention.".to_string(),
+            });
+
+        let spans = match &suggestion {
+            Some(suggestion) if can_rename => {
+                if ignored.iter().any(|ignored| context.lookup(ignored)[1..] == **suggestion) {
+                    None
+                } else {
+                    scope.get_rename_spans(variable, suggestion, context)
+                }
+            }
+            _ => None,
+        };
+
+        let (Some(suggestion), Some(spans)) = (suggestion, spans) else {
+            context.report(issue);
+
+            continue;
+        };
+
+        context.report_with_fix(issue, |plan| {
+            for span in spans {
+                plan.replace(span.to_range(), format!("${}", suggestion), SafetyClassification::Safe);
+            }
+        });
+    }
+}
@@ -1,5 +1,11 @@
 use crate::definition::PluginDefinition;
 use crate::plugin::phpunit::rules::consistency::assertions_style::AssertionsStyleRule;
+use crate::plugin::phpunit::rules::consistency::set_up_should_return_void::SetUpShouldReturnVoidRule;
+use crate::plugin::phpunit::rules::correctness::data_provider_should_be_valid::DataProviderShouldBeValidRule;
+use crate::plugin::phpunit::rules::correctness::no_final_class_mock::NoFinalClassMockRule;
+use crate::plugin::phpunit::rules::correctness::test_should_perform_assertions::TestShouldPerformAssertionsRule;
+use crate::plugin::phpunit::rules::deprecation::no_set_methods::NoSetMethodsRule;
+use crate::plugin::phpunit::rules::migration::attributes_over_annotations::AttributesOverAnnotationsRule;
 use crate::plugin::phpunit::rules::redundancy::redundant_instanceof::RedundantInstanceOfRule;
 use crate::plugin::phpunit::rules::strictness::strict_assertions::StrictAssertionsRule;
 
@@ -21,6 +27,16 @@ impl Plugin for PHPUnitPlugin {
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(AssertionsStyleRule), Box::new(RedundantInstanceOfRule), Box::new(StrictAssertionsRule)]
+        vec![
+            Box::new(AssertionsStyleRule),
+            Box::new(AttributesOverAnnotationsRule),
+            Box::new(DataProviderShouldBeValidRule),
+            Box::new(NoFinalClassMockRule),
+            Box::new(NoSetMethodsRule),
+            Box::new(RedundantInstanceOfRule),
+            Box::new(SetUpShouldReturnVoidRule),
+            Box::new(StrictAssertionsRule),
+            Box::new(TestShouldPerformAssertionsRule),
+        ]
     }
 }
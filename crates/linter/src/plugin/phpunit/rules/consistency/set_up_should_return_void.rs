@@ -0,0 +1,114 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::phpunit::rules::utils::is_test_class;
+use crate::rule::Rule;
+
+const FIXTURE_METHODS: [&str; 4] = ["setUp", "tearDown", "setUpBeforeClass", "tearDownAfterClass"];
+
+#[derive(Clone, Debug)]
+pub struct SetUpShouldReturnVoidRule;
+
+impl Rule for SetUpShouldReturnVoidRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Set Up Should Return Void", Level::Error)
+            .with_description(indoc! {"
+                Detects `setUp`, `tearDown`, `setUpBeforeClass`, and `tearDownAfterClass` methods without a `void` return type.
+
+                Since PHPUnit 8, these methods are declared with a `void` return type in `TestCase`, and overriding
+                them without it results in a fatal error.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A `setUp` method returning `void`",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        private Calculator $calculator;
+
+                        protected function setUp(): void
+                        {
+                            $this->calculator = new Calculator();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A `setUp` method without a return type",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        private Calculator $calculator;
+
+                        protected function setUp()
+                        {
+                            $this->calculator = new Calculator();
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for SetUpShouldReturnVoidRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        if !is_test_class(class, context) {
+            return;
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let name = context.lookup(&method.name.value);
+            let Some(fixture) = FIXTURE_METHODS.iter().find(|fixture| fixture.eq_ignore_ascii_case(name)) else {
+                continue;
+            };
+
+            let return_type_hint = method.return_type_hint.as_ref();
+            if return_type_hint.is_some_and(|return_type_hint| matches!(return_type_hint.hint, Hint::Void(_))) {
+                continue;
+            }
+
+            let issue =
+                Issue::new(context.level(), format!("Method `{}` should declare a `void` return type.", fixture))
+                    .with_annotation(match return_type_hint {
+                        Some(return_type_hint) => Annotation::primary(return_type_hint.hint.span())
+                            .with_message(format!("`{}` is declared with a non-`void` return type.", fixture)),
+                        None => Annotation::primary(method.name.span())
+                            .with_message(format!("`{}` is declared without a return type.", fixture)),
+                    })
+                    .with_help("Declare the method with a `void` return type.");
+
+            if return_type_hint.is_some() {
+                context.report(issue);
+
+                continue;
+            }
+
+            context.report_with_fix(issue, |plan| {
+                plan.insert(method.parameter_list.span().end.offset, ": void", SafetyClassification::Safe);
+            });
+        }
+    }
+}
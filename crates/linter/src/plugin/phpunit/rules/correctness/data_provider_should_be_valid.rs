@@ -0,0 +1,344 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::phpunit::rules::utils::get_annotations;
+use crate::plugin::phpunit::rules::utils::get_attributes;
+use crate::plugin::phpunit::rules::utils::is_public;
+use crate::plugin::phpunit::rules::utils::TEST_CASE;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct DataProviderShouldBeValidRule;
+
+impl Rule for DataProviderShouldBeValidRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Data Provider Should Be Valid", Level::Error)
+            .with_description(indoc! {"
+                Detects data providers referenced using `#[DataProvider]` or `@dataProvider` that do not exist,
+                are not declared as `public static`, or provide data sets that do not match the parameters of the test.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A valid data provider",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideSums')]
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+
+                        public static function provideSums(): iterable
+                        {
+                            yield [1, 2, 3];
+                            yield 'negative' => [-1, -2, -3];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A data provider that does not exist",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        /**
+                         * @dataProvider provideSums
+                         */
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A data provider that is not static",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideSums')]
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+
+                        public function provideSums(): array
+                        {
+                            return [[1, 2, 3]];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A data set with missing arguments",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideSums')]
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+
+                        public static function provideSums(): array
+                        {
+                            return [
+                                [1, 2, 3],
+                                [1, 2],
+                            ];
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for DataProviderShouldBeValidRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let methods: Vec<&Method> = class
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassLikeMember::Method(method) => Some(method),
+                _ => None,
+            })
+            .collect();
+
+        for method in methods.iter() {
+            for (provider, span) in get_data_providers(method, context) {
+                let provider_method =
+                    methods.iter().find(|method| context.lookup(&method.name.value).eq_ignore_ascii_case(provider));
+
+                match provider_method {
+                    Some(provider_method) => check_data_provider(method, provider_method, provider, span, context),
+                    None if !is_inherited(class, provider, context) => {
+                        let issue =
+                            Issue::new(context.level(), format!("Data provider `{}` does not exist.", provider))
+                                .with_annotation(
+                                    Annotation::primary(span).with_message("This data provider is not declared."),
+                                )
+                                .with_annotation(
+                                    Annotation::secondary(method.name.span())
+                                        .with_message("The data provider is used by this test."),
+                                )
+                                .with_help(format!("Declare a `public static function {}()` method.", provider));
+
+                        context.report(issue);
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+/// Returns the names of the data providers declared in the same class, along with the span referencing them.
+fn get_data_providers<'a>(method: &Method, context: &LintContext<'a>) -> Vec<(&'a str, Span)> {
+    let mut providers = vec![];
+    for attribute in get_attributes(&method.attribute_lists, "DataProvider", context) {
+        let Some(argument) = attribute.arguments.as_ref().and_then(|arguments| arguments.arguments.first()) else {
+            continue;
+        };
+
+        if let Expression::Literal(Literal::String(string)) = argument.value() {
+            let value = context.interner.lookup(&string.value);
+
+            providers.push((&value[1..value.len() - 1], argument.span()));
+        }
+    }
+
+    for annotation in get_annotations(method, context) {
+        if annotation.name != "dataProvider" {
+            continue;
+        }
+
+        let provider = annotation.value.split_whitespace().next().unwrap_or_default();
+        if !provider.is_empty() && !provider.contains("::") {
+            providers.push((provider.trim_end_matches("()"), annotation.span));
+        }
+    }
+
+    providers
+}
+
+/// Returns whether the data provider may be inherited from a parent class.
+fn is_inherited(class: &Class, provider: &str, context: &LintContext<'_>) -> bool {
+    let Some(parent) = class.extends.as_ref().and_then(|extends| extends.types.first()) else {
+        return false;
+    };
+
+    if context.lookup_name(parent).eq_ignore_ascii_case(TEST_CASE) {
+        return false;
+    }
+
+    let Some(class_like) =
+        context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&class.name))
+    else {
+        return true;
+    };
+
+    let methods = &class_like.methods;
+    let is_declared = methods
+        .members
+        .keys()
+        .chain(methods.appering_members.keys())
+        .any(|method| context.lookup(method).eq_ignore_ascii_case(provider));
+
+    // When the parent class is unknown, e.g. because it is not part of the analyzed sources, the provider
+    // may be declared there.
+    is_declared
+        || context.codebase.get_named_class_like(context.interner, context.semantics.names.get(parent)).is_none()
+}
+
+fn check_data_provider(test: &Method, provider: &Method, name: &str, span: Span, context: &mut LintContext<'_>) {
+    if !is_public(provider) || !provider.modifiers.contains_static() {
+        let issue =
+            Issue::new(context.level(), format!("Data provider `{}` must be declared as `public static`.", name))
+                .with_annotation(
+                    Annotation::primary(provider.name.span()).with_message("The data provider is declared here."),
+                )
+                .with_annotation(Annotation::secondary(span).with_message("The data provider is used here."))
+                .with_help("Declare the data provider as a `public static` method.");
+
+        context.report(issue);
+    }
+
+    let MethodBody::Concrete(block) = &provider.body else {
+        return;
+    };
+
+    let parameters = &test.parameter_list.parameters;
+    let is_variadic = parameters.iter().any(|parameter| parameter.ellipsis.is_some());
+    let maximum = parameters.iter().filter(|parameter| parameter.ellipsis.is_none()).count();
+    let minimum =
+        parameters.iter().filter(|parameter| parameter.ellipsis.is_none() && parameter.default_value.is_none()).count();
+
+    for data_set in get_data_sets(block) {
+        let elements = match data_set {
+            Expression::Array(array) => &array.elements,
+            Expression::LegacyArray(array) => &array.elements,
+            _ => continue,
+        };
+
+        if elements.iter().any(|element| matches!(element, ArrayElement::Variadic(_))) {
+            continue;
+        }
+
+        // Data sets with string keys are passed as named arguments.
+        let mut arguments = 0;
+        let mut has_unknown_argument = false;
+        for element in elements.iter() {
+            match element {
+                ArrayElement::KeyValue(KeyValueArrayElement { key, .. }) => {
+                    if let Expression::Literal(Literal::String(string)) = key.as_ref() {
+                        let key = context.lookup(&string.value);
+                        let key = &key[1..key.len() - 1];
+
+                        has_unknown_argument |= !is_variadic
+                            && !parameters
+                                .iter()
+                                .any(|parameter| &context.lookup(&parameter.variable.name)[1..] == key);
+                    }
+
+                    arguments += 1;
+                }
+                ArrayElement::Value(_) => arguments += 1,
+                _ => {}
+            }
+        }
+
+        if !has_unknown_argument && arguments >= minimum && (is_variadic || arguments <= maximum) {
+            continue;
+        }
+
+        let expected = match (minimum == maximum, is_variadic) {
+            (_, true) => format!("at least {}", minimum),
+            (true, false) => minimum.to_string(),
+            (false, false) => format!("between {} and {}", minimum, maximum),
+        };
+
+        let issue = Issue::new(
+            context.level(),
+            format!(
+                "Data set provided by `{}` does not match the parameters of test `{}`.",
+                name,
+                context.lookup(&test.name.value)
+            ),
+        )
+        .with_annotation(Annotation::primary(data_set.span()).with_message(if has_unknown_argument {
+            "This data set contains an argument that does not match any parameter.".to_string()
+        } else {
+            format!("This data set contains {} argument(s), but the test expects {}.", arguments, expected)
+        }))
+        .with_annotation(
+            Annotation::secondary(test.parameter_list.span()).with_message("The test parameters are declared here."),
+        )
+        .with_help("Update the data set, or the parameters of the test, so they match.");
+
+        context.report(issue);
+    }
+}
+
+/// Returns the data sets returned, or yielded, directly in the body of a data provider.
+fn get_data_sets(block: &Block) -> Vec<&Expression> {
+    let mut data_sets = vec![];
+    for statement in block.statements.iter() {
+        match statement {
+            Statement::Return(Return { value: Some(Expression::Array(Array { elements, .. })), .. })
+            | Statement::Return(Return {
+                value: Some(Expression::LegacyArray(LegacyArray { elements, .. })), ..
+            }) => {
+                data_sets.extend(elements.iter().filter_map(|element| match element {
+                    ArrayElement::KeyValue(KeyValueArrayElement { value, .. }) => Some(value.as_ref()),
+                    ArrayElement::Value(ValueArrayElement { value }) => Some(value.as_ref()),
+                    _ => None,
+                }));
+            }
+            Statement::Expression(ExpressionStatement { expression, .. }) => match expression.as_ref() {
+                Expression::Yield(Yield::Value(YieldValue { value: Some(value), .. })) => {
+                    data_sets.push(value.as_ref())
+                }
+                Expression::Yield(Yield::Pair(YieldPair { value, .. })) => data_sets.push(value.as_ref()),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    data_sets
+}
@@ -0,0 +1,157 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+const MOCKING_METHODS: [&str; 8] = [
+    "createMock",
+    "createStub",
+    "createPartialMock",
+    "createConfiguredMock",
+    "createConfiguredStub",
+    "createTestProxy",
+    "getMockBuilder",
+    "getMockForAbstractClass",
+];
+
+#[derive(Clone, Debug)]
+pub struct NoFinalClassMockRule;
+
+impl Rule for NoFinalClassMockRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Final Class Mock", Level::Error)
+            .with_description(indoc! {"
+                Detects mocks and stubs of final classes.
+
+                PHPUnit creates test doubles by extending the doubled class, which is not possible for final classes,
+                so the test fails at runtime. Depend on an interface, and double it instead.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Mocking an interface",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    interface Mailer
+                    {
+                        public function send(string $message): void;
+                    }
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mailer = $this->createMock(Mailer::class);
+                            $mailer->expects($this->once())->method('send');
+
+                            (new Newsletter($mailer))->publish();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Mocking a final class",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class Mailer
+                    {
+                        public function send(string $message): void
+                        {
+                        }
+                    }
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mailer = $this->createMock(Mailer::class);
+                            $mailer->expects($this->once())->method('send');
+
+                            (new Newsletter($mailer))->publish();
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoFinalClassMockRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        check(&method_call.method, &method_call.argument_list, context);
+    }
+
+    fn walk_in_static_method_call<'ast>(
+        &self,
+        static_method_call: &'ast StaticMethodCall,
+        context: &mut LintContext<'a>,
+    ) {
+        check(&static_method_call.method, &static_method_call.argument_list, context);
+    }
+}
+
+fn check(selector: &ClassLikeMemberSelector, argument_list: &ArgumentList, context: &mut LintContext<'_>) {
+    let ClassLikeMemberSelector::Identifier(method) = selector else {
+        return;
+    };
+
+    let method = context.interner.lookup(&method.value);
+    if !MOCKING_METHODS.iter().any(|mocking_method| mocking_method.eq_ignore_ascii_case(method)) {
+        return;
+    }
+
+    let Some(argument) = argument_list.arguments.first() else {
+        return;
+    };
+
+    let Expression::Access(Access::ClassConstant(ClassConstantAccess {
+        class,
+        constant: ClassLikeConstantSelector::Identifier(constant),
+        ..
+    })) = argument.value()
+    else {
+        return;
+    };
+
+    let Expression::Identifier(identifier) = class.as_ref() else {
+        return;
+    };
+
+    if !context.lookup(&constant.value).eq_ignore_ascii_case("class") {
+        return;
+    }
+
+    let Some(class_like) =
+        context.codebase.get_named_class_like(context.interner, context.semantics.names.get(identifier))
+    else {
+        return;
+    };
+
+    if !class_like.is_final {
+        return;
+    }
+
+    let class_name = context.lookup_name(identifier);
+    let issue = Issue::new(context.level(), format!("Final class `{}` cannot be doubled.", class_name))
+        .with_annotation(Annotation::primary(argument.span()).with_message(format!("`{}` is final.", class_name)))
+        .with_annotation(
+            Annotation::secondary(class_like.span).with_message(format!("`{}` is declared here.", class_name)),
+        )
+        .with_help("Introduce an interface for the class, and double the interface instead.");
+
+    context.report(issue);
+}
@@ -0,0 +1,135 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_ast_utils::reference::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::phpunit::rules::utils::get_annotations;
+use crate::plugin::phpunit::rules::utils::get_attributes;
+use crate::plugin::phpunit::rules::utils::is_test_method;
+use crate::rule::Rule;
+
+const ASSERTING_METHODS: [&str; 4] = ["fail", "expects", "markTestIncomplete", "markTestSkipped"];
+
+#[derive(Clone, Debug)]
+pub struct TestShouldPerformAssertionsRule;
+
+impl Rule for TestShouldPerformAssertionsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Test Should Perform Assertions", Level::Warning)
+            .with_description(indoc! {"
+                Detects test methods that do not perform any assertion.
+
+                Tests without assertions are reported as risky by PHPUnit, and do not verify anything.
+                Tests that intentionally perform no assertion should be marked using `#[DoesNotPerformAssertions]`.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A test performing an assertion",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $this->assertSame(42, compute());
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "A test expecting an exception",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $this->expectException(InvalidArgumentException::class);
+
+                            compute(-1);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A test without assertions",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            compute();
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for TestShouldPerformAssertionsRule {
+    fn walk_in_method(&self, method: &Method, context: &mut LintContext<'a>) {
+        let MethodBody::Concrete(block) = &method.body else {
+            return;
+        };
+
+        if !is_test_method(method, context)
+            || get_attributes(&method.attribute_lists, "DoesNotPerformAssertions", context).next().is_some()
+            || get_annotations(method, context).iter().any(|annotation| annotation.name == "doesNotPerformAssertions")
+        {
+            return;
+        }
+
+        // Custom assertion helpers are expected to follow the `assert*` naming convention.
+        let performs_assertions = !find_method_references_in_block(block, &|reference| {
+            let ClassLikeMemberSelector::Identifier(identifier) = reference.get_selector() else {
+                return false;
+            };
+
+            let name = context.lookup(&identifier.value);
+
+            starts_with_ignore_ascii_case(name, "assert")
+                || starts_with_ignore_ascii_case(name, "expect")
+                || ASSERTING_METHODS.iter().any(|method| method.eq_ignore_ascii_case(name))
+        })
+        .is_empty();
+
+        if performs_assertions {
+            return;
+        }
+
+        let name = context.lookup(&method.name.value);
+        let issue = Issue::new(context.level(), format!("Test `{}` does not perform any assertion.", name))
+            .with_annotation(
+                Annotation::primary(method.name.span()).with_message("This test does not perform any assertion."),
+            )
+            .with_note("PHPUnit reports tests that do not perform any assertion as risky.")
+            .with_help("Add an assertion, or mark the test using `#[DoesNotPerformAssertions]` if this is intended.");
+
+        context.report(issue);
+    }
+}
+
+fn starts_with_ignore_ascii_case(name: &str, prefix: &str) -> bool {
+    name.get(..prefix.len()).is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
@@ -0,0 +1,84 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NoSetMethodsRule;
+
+impl Rule for NoSetMethodsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Set Methods", Level::Warning)
+            .with_description(indoc! {"
+                Detects calls to the `setMethods()` method of mock builders.
+
+                `setMethods()` was deprecated in PHPUnit 8 and removed in PHPUnit 10. Use `onlyMethods()` to configure
+                existing methods, or `addMethods()` to add methods that do not exist.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Using `onlyMethods()`",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mailer = $this->getMockBuilder(Mailer::class)->onlyMethods(['send'])->getMock();
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using `setMethods()`",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    final class SomeTest extends TestCase
+                    {
+                        public function testSomething(): void
+                        {
+                            $mailer = $this->getMockBuilder(Mailer::class)->setMethods(['send'])->getMock();
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoSetMethodsRule {
+    fn walk_in_method_call<'ast>(&self, method_call: &'ast MethodCall, context: &mut LintContext<'a>) {
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return;
+        };
+
+        if !context.lookup(&method.value).eq_ignore_ascii_case("setMethods") {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "`setMethods()` is deprecated, and removed in PHPUnit 10.")
+            .with_annotation(Annotation::primary(method_call.span()).with_message("`setMethods()` is called here."))
+            .with_note("`onlyMethods()` only accepts existing methods, methods that do not exist must be configured using `addMethods()`.")
+            .with_help("Use `onlyMethods()`, or `addMethods()`, instead.");
+
+        context.report_with_fix(issue, |plan| {
+            plan.replace(method.span().to_range(), "onlyMethods", SafetyClassification::PotentiallyUnsafe);
+        });
+    }
+}
@@ -0,0 +1,287 @@
+use std::ops::Range;
+
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_fixer::SafetyClassification;
+use mago_php_version::PHPVersion;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::phpunit::rules::utils::get_annotations;
+use crate::plugin::phpunit::rules::utils::get_docblock;
+use crate::plugin::phpunit::rules::utils::is_test_class;
+use crate::plugin::phpunit::rules::utils::DocblockAnnotation;
+use crate::plugin::phpunit::rules::utils::ATTRIBUTES_NAMESPACE;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct AttributesOverAnnotationsRule;
+
+impl Rule for AttributesOverAnnotationsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Attributes Over Annotations", Level::Note)
+            .with_description(indoc! {"
+                Detects PHPUnit docblock annotations, such as `@test` or `@dataProvider`, that can be replaced by
+                the attributes introduced in PHPUnit 10.
+
+                Annotations are deprecated since PHPUnit 11, and are no longer supported in PHPUnit 12.
+            "})
+            .with_minimum_supported_php_version(PHPVersion::PHP80)
+            .with_example(RuleUsageExample::valid(
+                "Using attributes",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\Attributes\DataProvider;
+                    use PHPUnit\Framework\Attributes\Group;
+                    use PHPUnit\Framework\TestCase;
+
+                    #[Group('math')]
+                    final class SomeTest extends TestCase
+                    {
+                        #[DataProvider('provideSums')]
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+
+                        public static function provideSums(): iterable
+                        {
+                            yield [1, 2, 3];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using annotations",
+                indoc! {r#"
+                    <?php
+
+                    declare(strict_types=1);
+
+                    use PHPUnit\Framework\TestCase;
+
+                    /**
+                     * @group math
+                     */
+                    final class SomeTest extends TestCase
+                    {
+                        /**
+                         * @dataProvider provideSums
+                         */
+                        public function testSum(int $a, int $b, int $expected): void
+                        {
+                            $this->assertSame($expected, $a + $b);
+                        }
+
+                        public static function provideSums(): iterable
+                        {
+                            yield [1, 2, 3];
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for AttributesOverAnnotationsRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        if !is_test_class(class, context) {
+            return;
+        }
+
+        check(class, true, context);
+
+        for member in class.members.iter() {
+            if let ClassLikeMember::Method(method) = member {
+                check(method, false, context);
+            }
+        }
+    }
+}
+
+fn check(node: &impl HasSpan, is_class: bool, context: &mut LintContext<'_>) {
+    let annotations = get_annotations(node, context);
+    let replacements: Vec<(DocblockAnnotation<'_>, String)> =
+        annotations.iter().filter_map(|annotation| Some((*annotation, get_attribute(annotation, is_class)?))).collect();
+
+    if replacements.is_empty() {
+        return;
+    }
+
+    let mut issue = Issue::new(context.level(), "PHPUnit annotations should be replaced by attributes.")
+        .with_note("Annotations are deprecated since PHPUnit 11, and are no longer supported in PHPUnit 12.")
+        .with_help("Replace the annotations by the equivalent attributes.");
+
+    for (annotation, attribute) in replacements.iter() {
+        issue = issue.with_annotation(
+            Annotation::primary(annotation.span)
+                .with_message(format!("`@{}` can be replaced by `{}`.", annotation.name, attribute)),
+        );
+    }
+
+    let Some(docblock) = get_docblock(node, context) else {
+        return;
+    };
+
+    let content = context.interner.lookup(&context.semantics.source.content);
+    let start = node.span().start.offset;
+    let line_start = content[..start].rfind('\n').map_or(0, |offset| offset + 1);
+    let indentation = &content[line_start..start];
+    let indentation = if indentation.trim().is_empty() { indentation } else { "" };
+
+    let attributes = replacements
+        .iter()
+        .map(|(_, attribute)| format!("{}\n{}", attribute, indentation))
+        .collect::<Vec<_>>()
+        .concat();
+
+    // When the docblock only contains the replaced annotations, it is removed entirely.
+    let docblock_content = context.interner.lookup(&docblock.value);
+    let docblock_offset = docblock.span.start.offset;
+    let mut remaining = docblock_content.to_string();
+    for (annotation, _) in replacements.iter().rev() {
+        remaining.replace_range(
+            annotation.span.start.offset - docblock_offset..annotation.span.end.offset - docblock_offset,
+            "",
+        );
+    }
+
+    if remaining.chars().all(|c| c.is_whitespace() || c == '*' || c == '/') {
+        context.report_with_fix(issue, |plan| {
+            plan.replace(docblock.span.start.offset..start, attributes, SafetyClassification::PotentiallyUnsafe);
+        });
+
+        return;
+    }
+
+    if replacements.iter().any(|(annotation, _)| annotation.line.is_none()) {
+        context.report(issue);
+
+        return;
+    }
+
+    let mut deleted_lines: Vec<Range<usize>> =
+        replacements.iter().filter_map(|(annotation, _)| annotation.line).map(|line| line.to_range()).collect();
+
+    // The blank lines left at the end of the docblock once its last annotations are removed are removed too.
+    let mut line_offset = docblock_offset;
+    let lines = docblock_content
+        .split_inclusive('\n')
+        .map(|line| {
+            line_offset += line.len();
+
+            (line_offset - line.len()..line_offset, line)
+        })
+        .collect::<Vec<_>>();
+
+    let mut blank_lines = vec![];
+    let mut has_trailing_annotations = false;
+    for (range, line) in lines.iter().skip(1).rev().skip(1) {
+        if deleted_lines.iter().any(|deleted| deleted.start == range.start) {
+            has_trailing_annotations = true;
+        } else if line.trim().trim_start_matches('*').trim().is_empty() {
+            blank_lines.push(range.clone());
+        } else {
+            break;
+        }
+    }
+
+    if has_trailing_annotations {
+        deleted_lines.extend(blank_lines);
+    }
+
+    context.report_with_fix(issue, |plan| {
+        for line in deleted_lines {
+            plan.delete(line, SafetyClassification::PotentiallyUnsafe);
+        }
+
+        plan.insert(start, attributes, SafetyClassification::PotentiallyUnsafe);
+    });
+}
+
+/// Returns the attribute equivalent to the given annotation, if any.
+fn get_attribute(annotation: &DocblockAnnotation<'_>, is_class: bool) -> Option<String> {
+    let value = annotation.value;
+    let mut words = value.split_whitespace();
+
+    let (name, arguments) = match annotation.name {
+        "test" if !is_class => ("Test", None),
+        "before" if !is_class => ("Before", None),
+        "after" if !is_class => ("After", None),
+        "beforeClass" if !is_class => ("BeforeClass", None),
+        "afterClass" if !is_class => ("AfterClass", None),
+        "runInSeparateProcess" if !is_class => ("RunInSeparateProcess", None),
+        "runTestsInSeparateProcesses" if is_class => ("RunTestsInSeparateProcesses", None),
+        "small" if is_class => ("Small", None),
+        "medium" if is_class => ("Medium", None),
+        "large" if is_class => ("Large", None),
+        "coversNothing" => ("CoversNothing", None),
+        "doesNotPerformAssertions" => ("DoesNotPerformAssertions", None),
+        "group" if !value.is_empty() => ("Group", Some(string(value))),
+        "ticket" if !value.is_empty() => ("Ticket", Some(string(value))),
+        "testdox" if !value.is_empty() => ("TestDox", Some(string(value))),
+        "covers" if is_class && is_class_name(value) => ("CoversClass", Some(class(value))),
+        "uses" if is_class && is_class_name(value) => ("UsesClass", Some(class(value))),
+        "dataProvider" | "depends" if !is_class && words.clone().count() == 1 => {
+            let is_data_provider = annotation.name == "dataProvider";
+            let target = value.trim_end_matches("()");
+
+            match target.split_once("::") {
+                Some((class_name, method)) if is_class_name(class_name) => (
+                    if is_data_provider { "DataProviderExternal" } else { "DependsExternal" },
+                    Some(format!("{}, {}", class(class_name), string(method))),
+                ),
+                Some(_) => return None,
+                None => (if is_data_provider { "DataProvider" } else { "Depends" }, Some(string(target))),
+            }
+        }
+        "backupGlobals" | "preserveGlobalState" => {
+            let enabled = match value {
+                "enabled" => "true",
+                "disabled" => "false",
+                _ => return None,
+            };
+
+            (
+                if annotation.name == "backupGlobals" { "BackupGlobals" } else { "PreserveGlobalState" },
+                Some(enabled.to_string()),
+            )
+        }
+        "requires" => match (words.next(), words.next(), words.next()) {
+            (Some("PHP"), Some(version), None) => ("RequiresPhp", Some(string(version))),
+            (Some("extension"), Some(extension), None) => ("RequiresPhpExtension", Some(string(extension))),
+            (Some("function"), Some(function), None) => ("RequiresFunction", Some(string(function))),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(match arguments {
+        Some(arguments) => format!("#[\\{}{}({})]", ATTRIBUTES_NAMESPACE, name, arguments),
+        None => format!("#[\\{}{}]", ATTRIBUTES_NAMESPACE, name),
+    })
+}
+
+fn is_class_name(value: &str) -> bool {
+    !value.is_empty()
+        && value.trim_start_matches('\\').split('\\').all(|part| {
+            part.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        })
+}
+
+fn class(value: &str) -> String {
+    format!("\\{}::class", value.trim_start_matches('\\'))
+}
+
+fn string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -2,6 +2,21 @@ mod utils;
 
 pub mod consistency {
     pub mod assertions_style;
+    pub mod set_up_should_return_void;
+}
+
+pub mod correctness {
+    pub mod data_provider_should_be_valid;
+    pub mod no_final_class_mock;
+    pub mod test_should_perform_assertions;
+}
+
+pub mod deprecation {
+    pub mod no_set_methods;
+}
+
+pub mod migration {
+    pub mod attributes_over_annotations;
 }
 
 pub mod redundancy {
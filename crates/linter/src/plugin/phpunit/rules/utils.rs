@@ -1,8 +1,13 @@
 use mago_ast::*;
 use mago_ast_utils::reference::*;
+use mago_span::HasSpan;
+use mago_span::Span;
 
 use crate::context::LintContext;
 
+pub const TEST_CASE: &str = "PHPUnit\\Framework\\TestCase";
+pub const ATTRIBUTES_NAMESPACE: &str = "PHPUnit\\Framework\\Attributes\\";
+
 pub const TESTING_METHODS: [&str; 57] = [
     "anything",
     "arrayHasKey",
@@ -289,3 +294,135 @@ pub fn find_assertion_references_in_method<'a>(
         method_names.iter().any(|&method_name| name.eq_ignore_ascii_case(method_name))
     })
 }
+
+/// An annotation found in a docblock, such as `@dataProvider provideValues`.
+#[derive(Debug, Clone, Copy)]
+pub struct DocblockAnnotation<'a> {
+    /// The name of the annotation, without the `@`.
+    pub name: &'a str,
+    /// The value following the name of the annotation, if any.
+    pub value: &'a str,
+    /// The span of the annotation, from the `@` to the end of its value.
+    pub span: Span,
+    /// The span of the line containing the annotation, including its line break, if the annotation is
+    /// the only content on its line.
+    pub line: Option<Span>,
+}
+
+/// Returns whether the method is a test, either by its name, the `#[Test]` attribute, or the `@test` annotation.
+pub fn is_test_method(method: &Method, context: &LintContext<'_>) -> bool {
+    let name = context.lookup(&method.name.value);
+    if name.starts_with("test") && name.chars().nth(4).is_some_and(|c| c == '_' || c.is_uppercase()) {
+        return true;
+    }
+
+    get_attributes(&method.attribute_lists, "Test", context).next().is_some()
+        || get_annotations(method, context).iter().any(|annotation| annotation.name == "test")
+}
+
+/// Returns the PHPUnit attributes with the given name, e.g. `DataProvider` for `#[DataProvider(...)]`.
+pub fn get_attributes<'a, 'b>(
+    attribute_lists: &'a Sequence<AttributeList>,
+    name: &'b str,
+    context: &'b LintContext<'_>,
+) -> impl Iterator<Item = &'a Attribute> + 'b
+where
+    'a: 'b,
+{
+    attribute_lists.iter().flat_map(|attribute_list| attribute_list.attributes.iter()).filter(move |attribute| {
+        context
+            .lookup_name(&attribute.name)
+            .strip_prefix(ATTRIBUTES_NAMESPACE)
+            .is_some_and(|attribute_name| attribute_name.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Returns the docblock directly preceding the given node, if any.
+pub fn get_docblock<'a>(node: &impl HasSpan, context: &LintContext<'a>) -> Option<&'a Trivia> {
+    let start = node.span().start.offset;
+    let trivia = context.semantics.program.trivia.as_slice();
+    let index = trivia.partition_point(|trivia| trivia.span.end.offset <= start);
+    let docblock = trivia[..index].iter().rev().find(|trivia| trivia.kind.is_comment())?;
+    if docblock.kind != TriviaKind::DocBlockComment {
+        return None;
+    }
+
+    let content = context.interner.lookup(&context.semantics.source.content);
+    if !content[docblock.span.end.offset..start].trim().is_empty() {
+        return None;
+    }
+
+    Some(docblock)
+}
+
+/// Returns the annotations of the docblock directly preceding the given node.
+pub fn get_annotations<'a>(node: &impl HasSpan, context: &LintContext<'a>) -> Vec<DocblockAnnotation<'a>> {
+    let Some(docblock) = get_docblock(node, context) else {
+        return vec![];
+    };
+
+    let content = context.interner.lookup(&docblock.value);
+    let mut annotations = vec![];
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+
+        let text = line.trim_end();
+        let is_first_line = text.trim_start().starts_with("/**");
+        let is_last_line = text.ends_with("*/");
+
+        let mut body = text.trim_start();
+        body = body.strip_prefix("/**").or_else(|| body.strip_prefix('*')).unwrap_or(body);
+        body = body.strip_suffix("*/").unwrap_or(body).trim();
+
+        let Some(annotation) = body.strip_prefix('@') else {
+            continue;
+        };
+
+        let name_length = annotation.find(|c: char| c.is_whitespace() || c == '(').unwrap_or(annotation.len());
+        let body_start = line_start + (body.as_ptr() as usize - line.as_ptr() as usize);
+
+        annotations.push(DocblockAnnotation {
+            name: &annotation[..name_length],
+            value: annotation[name_length..].trim(),
+            span: docblock.span.subspan(body_start, body_start + body.len()),
+            line: (!is_first_line && !is_last_line).then(|| docblock.span.subspan(line_start, offset)),
+        });
+    }
+
+    annotations
+}
+
+/// Returns whether the method is declared as public, either explicitly or implicitly.
+pub fn is_public(method: &Method) -> bool {
+    !method.modifiers.contains_visibility() || method.modifiers.contains_public()
+}
+
+/// Returns whether the class is a test case, i.e. it extends `PHPUnit\Framework\TestCase`, or it extends another
+/// class and follows the `*Test` or `*TestCase` naming convention.
+pub fn is_test_class(class: &Class, context: &LintContext<'_>) -> bool {
+    let Some(parent) = class.extends.as_ref().and_then(|extends| extends.types.first()) else {
+        return false;
+    };
+
+    let parent = context.lookup_name(parent);
+    if parent.eq_ignore_ascii_case(TEST_CASE) || parent.ends_with("TestCase") {
+        return true;
+    }
+
+    let name = context.lookup(&class.name.value);
+    if name.ends_with("Test") || name.ends_with("TestCase") {
+        return true;
+    }
+
+    context.codebase.get_named_class_like(context.interner, context.semantics.names.get(&class.name)).is_some_and(
+        |class_like| {
+            class_like
+                .inheritance
+                .all_extended_classes
+                .iter()
+                .any(|parent| context.lookup(&parent.value).eq_ignore_ascii_case(TEST_CASE))
+        },
+    )
+}
@@ -1,5 +1,12 @@
 use crate::definition::PluginDefinition;
+use crate::plugin::symfony::rules::correctness::route_name_should_be_unique::RouteNameShouldBeUniqueRule;
+use crate::plugin::symfony::rules::correctness::route_parameters_should_match_arguments::RouteParametersShouldMatchArgumentsRule;
+use crate::plugin::symfony::rules::correctness::subscribed_event_method_should_exist::SubscribedEventMethodShouldExistRule;
+use crate::plugin::symfony::rules::quality::command_should_use_as_command_attribute::CommandShouldUseAsCommandAttributeRule;
+use crate::plugin::symfony::rules::quality::controller_action_should_return_response::ControllerActionShouldReturnResponseRule;
 use crate::plugin::symfony::rules::quality::interface_should_be_used::InterfaceShouldBeUsed;
+use crate::plugin::symfony::rules::quality::no_container_get_in_controller::NoContainerGetInControllerRule;
+use crate::plugin::symfony::rules::quality::no_service_locator::NoServiceLocatorRule;
 
 use crate::plugin::Plugin;
 use crate::rule::Rule;
@@ -19,6 +26,15 @@ impl Plugin for SymfonyPlugin {
     }
 
     fn get_rules(&self) -> Vec<Box<dyn Rule>> {
-        vec![Box::new(InterfaceShouldBeUsed)]
+        vec![
+            Box::new(CommandShouldUseAsCommandAttributeRule),
+            Box::new(ControllerActionShouldReturnResponseRule),
+            Box::new(InterfaceShouldBeUsed),
+            Box::new(NoContainerGetInControllerRule),
+            Box::new(NoServiceLocatorRule),
+            Box::new(RouteNameShouldBeUniqueRule::default()),
+            Box::new(RouteParametersShouldMatchArgumentsRule),
+            Box::new(SubscribedEventMethodShouldExistRule),
+        ]
     }
 }
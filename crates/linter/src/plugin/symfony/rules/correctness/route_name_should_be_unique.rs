@@ -0,0 +1,184 @@
+use std::sync::OnceLock;
+
+use ahash::HashMap;
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::CodebaseReflection;
+use mago_reflection::Reflection;
+use mago_reporting::*;
+use mago_span::Span;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::get_routes;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug, Default)]
+pub struct RouteNameShouldBeUniqueRule {
+    /// The declarations of each route name of the codebase, in the order Symfony loads them, built on first use.
+    declarations: OnceLock<HashMap<String, Vec<Span>>>,
+}
+
+impl Rule for RouteNameShouldBeUniqueRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Route Name Should Be Unique", Level::Error)
+            .with_description(indoc! {"
+                Detects `#[Route]` attributes declaring a route name that is already used by another route.
+
+                Symfony silently overrides the earlier routes with the last one, making the earlier ones unreachable.
+                Name prefixes declared on the controller class are taken into account.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Routes with distinct names",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Response;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    #[Route('/blog', name: 'blog_')]
+                    final class BlogController extends AbstractController
+                    {
+                        #[Route('/', name: 'index')]
+                        public function index(): Response
+                        {
+                            return $this->render('blog/index.html.twig');
+                        }
+
+                        #[Route('/{slug}', name: 'show')]
+                        public function show(string $slug): Response
+                        {
+                            return $this->render('blog/show.html.twig');
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Routes sharing the same name",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Response;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    final class BlogController extends AbstractController
+                    {
+                        #[Route('/blog', name: 'blog')]
+                        public function index(): Response
+                        {
+                            return $this->render('blog/index.html.twig');
+                        }
+
+                        #[Route('/blog/{slug}', name: 'blog')]
+                        public function show(string $slug): Response
+                        {
+                            return $this->render('blog/show.html.twig');
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RouteNameShouldBeUniqueRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        let routes = get_route_names(interner, class_like);
+        if routes.is_empty() {
+            return;
+        }
+
+        let declarations = self.declarations.get_or_init(|| get_route_declarations(interner, context.codebase));
+
+        for (route_name, span) in routes {
+            let Some(spans) = declarations.get(&route_name) else {
+                continue;
+            };
+
+            // Every declaration but the last one is overridden.
+            let Some((last_span, overridden)) = spans.split_last() else {
+                continue;
+            };
+
+            if !overridden.contains(&span) {
+                continue;
+            }
+
+            let issue =
+                Issue::new(context.level(), format!("Route name `{}` is declared again by another route.", route_name))
+                    .with_annotation(Annotation::primary(span).with_message("This route is overridden."))
+                    .with_annotation(
+                        Annotation::secondary(*last_span).with_message("The route declared here is kept instead."),
+                    )
+                    .with_note(
+                        "Symfony only keeps the last route declared with a given name, the other routes are ignored.",
+                    )
+                    .with_help("Give each route a unique name.");
+
+            context.report(issue);
+        }
+    }
+}
+
+/// Returns the declarations of each route name of the user-defined class-likes, ordered by source name, then offset.
+fn get_route_declarations(interner: &ThreadedInterner, codebase: &CodebaseReflection) -> HashMap<String, Vec<Span>> {
+    let mut declarations: HashMap<String, Vec<Span>> = HashMap::default();
+    for class_like in codebase.class_like_reflections.values().filter(|class_like| class_like.is_user_defined()) {
+        for (route_name, span) in get_route_names(interner, class_like) {
+            declarations.entry(route_name).or_default().push(span);
+        }
+    }
+
+    for spans in declarations.values_mut() {
+        spans.sort_by(|a, b| {
+            interner
+                .lookup(&a.start.source.0)
+                .cmp(interner.lookup(&b.start.source.0))
+                .then(a.start.offset.cmp(&b.start.offset))
+        });
+        spans.dedup();
+    }
+
+    declarations
+}
+
+/// Returns the full names of the routes declared by the methods of the class-like, along with the span of their attribute.
+fn get_route_names(interner: &ThreadedInterner, class_like: &ClassLikeReflection) -> Vec<(String, Span)> {
+    let class_routes = get_routes(interner, &class_like.attribute_reflections);
+    let prefixes: Vec<&str> = if class_routes.is_empty() {
+        vec![""]
+    } else {
+        class_routes.iter().map(|route| route.name.map(|(name, _)| name).unwrap_or("")).collect()
+    };
+
+    let mut names = vec![];
+    for method in class_like.methods.members.values() {
+        for route in get_routes(interner, &method.attribute_reflections) {
+            let Some((name, _)) = route.name else {
+                continue;
+            };
+
+            for prefix in &prefixes {
+                names.push((format!("{}{}", prefix, name), route.attribute.span));
+            }
+        }
+    }
+
+    names
+}
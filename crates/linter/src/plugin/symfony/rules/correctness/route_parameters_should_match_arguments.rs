@@ -0,0 +1,209 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::function_like::parameter::FunctionLikeParameterReflection;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reporting::*;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::get_path_parameters;
+use crate::plugin::symfony::rules::utils::get_routes;
+use crate::plugin::symfony::rules::utils::has_argument;
+use crate::rule::Rule;
+
+/// The position of the `defaults` argument of the `#[Route]` attribute.
+const DEFAULTS_POSITION: usize = 4;
+
+#[derive(Clone, Debug)]
+pub struct RouteParametersShouldMatchArgumentsRule;
+
+impl Rule for RouteParametersShouldMatchArgumentsRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Route Parameters Should Match Arguments", Level::Error)
+            .with_description(indoc! {"
+                Detects mismatches between the parameters of a `#[Route]` path and the arguments of the controller action.
+
+                A scalar argument without a default value must be provided by a route parameter with the same name,
+                otherwise Symfony fails to call the action. A route parameter that no argument uses is reported as well,
+                unless the action has arguments that could be resolved from it, such as entities.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Route parameters matching the arguments",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Request;
+                    use Symfony\Component\HttpFoundation\Response;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    #[Route('/users/{userId<\d+>}')]
+                    final class PostController extends AbstractController
+                    {
+                        #[Route('/posts/{slug}/{page?}', name: 'user_post')]
+                        public function show(Request $request, int $userId, string $slug, int $page = 1): Response
+                        {
+                            return $this->render('post/show.html.twig');
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An argument missing from the route path",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Response;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    final class PostController extends AbstractController
+                    {
+                        #[Route('/posts/{slug}', name: 'post')]
+                        public function show(string $id): Response
+                        {
+                            return $this->render('post/show.html.twig');
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for RouteParametersShouldMatchArgumentsRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        let class_routes = get_routes(interner, &class_like.attribute_reflections);
+        let prefix_parameters: Vec<&str> = class_routes
+            .iter()
+            .filter_map(|route| route.path)
+            .flat_map(|(path, _)| get_path_parameters(path))
+            .collect();
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let Some(reflection) = class_like.get_method(&method.name.value) else {
+                continue;
+            };
+
+            let method_name = interner.lookup(&method.name.value);
+            // Object arguments, e.g. entities, may be resolved using route parameters that no argument is named after.
+            let has_object_arguments = reflection.parameters.iter().any(|parameter| {
+                !parameter.attribute_reflections.is_empty()
+                    || parameter
+                        .type_reflection
+                        .as_ref()
+                        .is_some_and(|type_reflection| may_be_object(&type_reflection.kind))
+            });
+
+            for route in get_routes(interner, &reflection.attribute_reflections) {
+                let Some((path, path_span)) = route.path else {
+                    continue;
+                };
+
+                let mut route_parameters = get_path_parameters(path);
+                route_parameters.extend(prefix_parameters.iter().copied());
+
+                if !has_argument(interner, route.attribute, DEFAULTS_POSITION, "defaults") {
+                    for parameter in &reflection.parameters {
+                        let parameter_name = interner.lookup(&parameter.name);
+                        if is_resolved_without_route(parameter)
+                            || route_parameters.contains(&parameter_name.trim_start_matches('$'))
+                        {
+                            continue;
+                        }
+
+                        let issue = Issue::new(
+                            context.level(),
+                            format!(
+                                "Argument `{}` of controller action `{}` is not provided by the route path `{}`.",
+                                parameter_name, method_name, path
+                            ),
+                        )
+                        .with_annotation(Annotation::primary(parameter.span).with_message("This argument has no matching route parameter."))
+                        .with_annotation(Annotation::secondary(path_span).with_message("The route path is declared here."))
+                        .with_help(format!(
+                            "Add a `{{{}}}` parameter to the route path, give the argument a default value, or rename it to match a route parameter.",
+                            parameter_name.trim_start_matches('$')
+                        ));
+
+                        context.report(issue);
+                    }
+                }
+
+                if has_object_arguments {
+                    continue;
+                }
+
+                for route_parameter in get_path_parameters(path) {
+                    if route_parameter.starts_with('_') {
+                        continue;
+                    }
+
+                    let is_used = reflection
+                        .parameters
+                        .iter()
+                        .any(|parameter| interner.lookup(&parameter.name).trim_start_matches('$') == route_parameter);
+
+                    if is_used {
+                        continue;
+                    }
+
+                    let issue = Issue::new(
+                        context.level(),
+                        format!(
+                            "Route parameter `{{{}}}` is not used by any argument of controller action `{}`.",
+                            route_parameter, method_name
+                        ),
+                    )
+                    .with_annotation(
+                        Annotation::primary(path_span)
+                            .with_message(format!("`{{{}}}` is declared here.", route_parameter)),
+                    )
+                    .with_annotation(
+                        Annotation::secondary(method.name.span).with_message("This action does not use it."),
+                    )
+                    .with_help(format!(
+                        "Add a `${}` argument to the action, or remove the parameter from the route path.",
+                        route_parameter
+                    ));
+
+                    context.report(issue);
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether Symfony resolves the argument without a route parameter, e.g. the request, services, entities,
+/// or arguments with a default value.
+fn is_resolved_without_route(parameter: &FunctionLikeParameterReflection) -> bool {
+    parameter.is_variadic
+        || parameter.default.is_some()
+        || !parameter.attribute_reflections.is_empty()
+        || parameter.type_reflection.as_ref().is_some_and(|type_reflection| {
+            may_be_object(&type_reflection.kind) || type_reflection.kind.is_nullable().is_true()
+        })
+}
+
+fn may_be_object(kind: &TypeKind) -> bool {
+    match kind {
+        TypeKind::Union { kinds } => kinds.iter().any(may_be_object),
+        _ => kind.is_object(),
+    }
+}
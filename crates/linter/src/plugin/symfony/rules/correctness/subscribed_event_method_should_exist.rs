@@ -0,0 +1,183 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::extends_or_implements;
+use crate::plugin::symfony::rules::utils::has_method;
+use crate::rule::Rule;
+
+const EVENT_SUBSCRIBER: &str = "Symfony\\Component\\EventDispatcher\\EventSubscriberInterface";
+
+#[derive(Clone, Debug)]
+pub struct SubscribedEventMethodShouldExistRule;
+
+impl Rule for SubscribedEventMethodShouldExistRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Subscribed Event Method Should Exist", Level::Error)
+            .with_description(indoc! {"
+                Detects event subscribers whose `getSubscribedEvents()` method references methods that do not exist.
+
+                Symfony only calls the listener when the event is dispatched, so a misspelled method name
+                fails at runtime, possibly long after the subscriber was registered.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A subscriber referencing existing methods",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\EventSubscriber;
+
+                    use Symfony\Component\EventDispatcher\EventSubscriberInterface;
+                    use Symfony\Component\HttpKernel\Event\RequestEvent;
+                    use Symfony\Component\HttpKernel\KernelEvents;
+
+                    final class LocaleSubscriber implements EventSubscriberInterface
+                    {
+                        public static function getSubscribedEvents(): array
+                        {
+                            return [
+                                KernelEvents::REQUEST => [['onKernelRequest', 20], ['logRequest']],
+                            ];
+                        }
+
+                        public function onKernelRequest(RequestEvent $event): void
+                        {
+                        }
+
+                        public function logRequest(RequestEvent $event): void
+                        {
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A subscriber referencing a missing method",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\EventSubscriber;
+
+                    use Symfony\Component\EventDispatcher\EventSubscriberInterface;
+                    use Symfony\Component\HttpKernel\Event\RequestEvent;
+                    use Symfony\Component\HttpKernel\KernelEvents;
+
+                    final class LocaleSubscriber implements EventSubscriberInterface
+                    {
+                        public static function getSubscribedEvents(): array
+                        {
+                            return [KernelEvents::REQUEST => 'onRequest'];
+                        }
+
+                        public function onKernelRequest(RequestEvent $event): void
+                        {
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for SubscribedEventMethodShouldExistRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if !extends_or_implements(interner, class_like, EVENT_SUBSCRIBER) {
+            return;
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBody::Concrete(body) = &method.body else {
+                continue;
+            };
+
+            if !context.lookup(&method.name.value).eq_ignore_ascii_case("getSubscribedEvents") {
+                continue;
+            }
+
+            for statement in body.statements.iter() {
+                let Statement::Return(Return { value: Some(value), .. }) = statement else {
+                    continue;
+                };
+
+                for element in get_array_elements(value) {
+                    let listener = match element {
+                        ArrayElement::KeyValue(element) => &element.value,
+                        ArrayElement::Value(element) => &element.value,
+                        _ => continue,
+                    };
+
+                    check_listener(class_like, listener, true, context);
+                }
+            }
+        }
+    }
+}
+
+/// Checks a listener declaration, either a method name, a `[method, priority]` pair, or a list of such pairs.
+fn check_listener(
+    class_like: &ClassLikeReflection,
+    listener: &Expression,
+    allow_list: bool,
+    context: &mut LintContext<'_>,
+) {
+    match listener {
+        Expression::Literal(Literal::String(string)) => {
+            let value = context.interner.lookup(&string.value);
+            let method = &value[1..value.len() - 1];
+            if method.is_empty() || has_method(context.interner, class_like, method) {
+                return;
+            }
+
+            let class_name = class_like.name.get_key(context.interner);
+            let issue = Issue::new(
+                context.level(),
+                format!("Method `{}::{}` referenced by `getSubscribedEvents()` does not exist.", class_name, method),
+            )
+            .with_annotation(Annotation::primary(string.span()).with_message("This method does not exist."))
+            .with_help("Fix the method name, or add the listener method to the subscriber.");
+
+            context.report(issue);
+        }
+        Expression::Array(_) | Expression::LegacyArray(_) => {
+            let elements = get_array_elements(listener);
+            let Some(ArrayElement::Value(first)) = elements.first() else {
+                return;
+            };
+
+            match first.value.as_ref() {
+                Expression::Array(_) | Expression::LegacyArray(_) if allow_list => {
+                    for element in elements {
+                        if let ArrayElement::Value(element) = element {
+                            check_listener(class_like, &element.value, false, context);
+                        }
+                    }
+                }
+                value => check_listener(class_like, value, false, context),
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_array_elements(expression: &Expression) -> &[ArrayElement] {
+    match expression {
+        Expression::Array(array) => array.elements.as_slice(),
+        Expression::LegacyArray(array) => array.elements.as_slice(),
+        _ => &[],
+    }
+}
@@ -1,3 +1,15 @@
+pub mod correctness {
+    pub mod route_name_should_be_unique;
+    pub mod route_parameters_should_match_arguments;
+    pub mod subscribed_event_method_should_exist;
+}
+
 pub mod quality {
+    pub mod command_should_use_as_command_attribute;
+    pub mod controller_action_should_return_response;
     pub mod interface_should_be_used;
+    pub mod no_container_get_in_controller;
+    pub mod no_service_locator;
 }
+
+pub mod utils;
@@ -0,0 +1,111 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::extends_or_implements;
+use crate::plugin::symfony::rules::utils::has_attribute;
+use crate::rule::Rule;
+
+const COMMAND: &str = "Symfony\\Component\\Console\\Command\\Command";
+const AS_COMMAND_ATTRIBUTE: &str = "Symfony\\Component\\Console\\Attribute\\AsCommand";
+const STATIC_METADATA_PROPERTIES: [&str; 2] = ["$defaultName", "$defaultDescription"];
+
+#[derive(Clone, Debug)]
+pub struct CommandShouldUseAsCommandAttributeRule;
+
+impl Rule for CommandShouldUseAsCommandAttributeRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Command Should Use As Command Attribute", Level::Warning)
+            .with_description(indoc! {"
+                Detects console commands without the `#[AsCommand]` attribute.
+
+                The attribute lets Symfony register commands lazily, without instantiating them, and replaces the
+                `$defaultName` and `$defaultDescription` static properties, which were deprecated in Symfony 6.1.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "A command using the `#[AsCommand]` attribute",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Command;
+
+                    use Symfony\Component\Console\Attribute\AsCommand;
+                    use Symfony\Component\Console\Command\Command;
+
+                    #[AsCommand(name: 'app:create-user', description: 'Creates a new user.')]
+                    final class CreateUserCommand extends Command
+                    {
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "A command using the `$defaultName` property",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Command;
+
+                    use Symfony\Component\Console\Command\Command;
+
+                    final class CreateUserCommand extends Command
+                    {
+                        protected static $defaultName = 'app:create-user';
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for CommandShouldUseAsCommandAttributeRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if class_like.is_abstract
+            || !extends_or_implements(interner, class_like, COMMAND)
+            || has_attribute(interner, &class_like.attribute_reflections, AS_COMMAND_ATTRIBUTE)
+        {
+            return;
+        }
+
+        let class_name = context.lookup(&class.name.value);
+        let mut issue = Issue::new(
+            context.level(),
+            format!("Command `{}` should declare its metadata using the `#[AsCommand]` attribute.", class_name),
+        )
+        .with_annotation(
+            Annotation::primary(class.name.span()).with_message("This command has no `#[AsCommand]` attribute."),
+        );
+
+        let mut properties: Vec<_> = class_like
+            .properties
+            .members
+            .iter()
+            .map(|(property_name, property)| (property.item_span, context.lookup(property_name)))
+            .filter(|(_, property_name)| STATIC_METADATA_PROPERTIES.contains(property_name))
+            .collect();
+
+        properties.sort();
+        for (span, property_name) in properties {
+            issue = issue.with_annotation(
+                Annotation::secondary(span)
+                    .with_message(format!("`{}` is deprecated in favor of the attribute.", property_name)),
+            );
+        }
+
+        context.report(
+            issue
+                .with_note("Without the attribute, Symfony has to instantiate the command to know its name.")
+                .with_help("Add `#[AsCommand(name: '...', description: '...')]` to the class."),
+        );
+    }
+}
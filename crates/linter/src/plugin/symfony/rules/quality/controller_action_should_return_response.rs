@@ -0,0 +1,179 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::extends_or_implements;
+use crate::plugin::symfony::rules::utils::get_routes;
+use crate::plugin::symfony::rules::utils::has_attribute;
+use crate::plugin::symfony::rules::utils::is_controller;
+use crate::rule::Rule;
+
+const RESPONSE: &str = "Symfony\\Component\\HttpFoundation\\Response";
+const TEMPLATE_ATTRIBUTE: &str = "Symfony\\Bridge\\Twig\\Attribute\\Template";
+
+#[derive(Clone, Debug)]
+pub struct ControllerActionShouldReturnResponseRule;
+
+impl Rule for ControllerActionShouldReturnResponseRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("Controller Action Should Return Response", Level::Warning)
+            .with_description(indoc! {"
+                Detects controller actions declared to return something other than a `Response`.
+
+                Symfony requires controller actions to return a `Response`, unless the action uses the
+                `#[Template]` attribute, or a `kernel.view` listener converts the returned value.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "An action returning a response",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\JsonResponse;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    final class UserController extends AbstractController
+                    {
+                        #[Route('/users', name: 'user_list')]
+                        public function list(): JsonResponse
+                        {
+                            return $this->json([]);
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::valid(
+                "An action rendering the returned array using `#[Template]`",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bridge\Twig\Attribute\Template;
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    final class UserController extends AbstractController
+                    {
+                        #[Route('/users', name: 'user_list')]
+                        #[Template('user/list.html.twig')]
+                        public function list(): array
+                        {
+                            return ['users' => []];
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "An action returning an array",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\Routing\Attribute\Route;
+
+                    final class UserController extends AbstractController
+                    {
+                        #[Route('/users', name: 'user_list')]
+                        public function list(): array
+                        {
+                            return [];
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for ControllerActionShouldReturnResponseRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if !is_controller(interner, class_like) {
+            return;
+        }
+
+        let has_class_route = !get_routes(interner, &class_like.attribute_reflections).is_empty();
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let Some(return_type_hint) = &method.return_type_hint else {
+                continue;
+            };
+
+            let Some(reflection) = class_like.get_method(&method.name.value) else {
+                continue;
+            };
+
+            let method_name = context.lookup(&method.name.value);
+            let is_action = !get_routes(interner, &reflection.attribute_reflections).is_empty()
+                || (has_class_route && method_name.eq_ignore_ascii_case("__invoke"));
+
+            if !is_action || has_attribute(interner, &reflection.attribute_reflections, TEMPLATE_ATTRIBUTE) {
+                continue;
+            }
+
+            if is_response_hint(&return_type_hint.hint, context) {
+                continue;
+            }
+
+            let issue = Issue::new(
+                context.level(),
+                format!(
+                    "Controller action `{}` should return a `Response`, but is declared to return `{}`.",
+                    method_name,
+                    context.get_readable_hint(&return_type_hint.hint)
+                ),
+            )
+            .with_annotation(
+                Annotation::primary(return_type_hint.hint.span()).with_message("This type is not a `Response`."),
+            )
+            .with_annotation(Annotation::secondary(method.name.span()).with_message("This method is a controller action."))
+            .with_help("Return a `Response`, e.g. using `$this->render()` or `$this->json()`, or render the returned value using the `#[Template]` attribute.");
+
+            context.report(issue);
+        }
+    }
+}
+
+/// Returns whether the hint only allows responses.
+///
+/// Classes that are not part of the codebase are considered responses when their name ends with `Response`.
+fn is_response_hint(hint: &Hint, context: &LintContext<'_>) -> bool {
+    match hint {
+        Hint::Identifier(identifier) => {
+            let fqcn = context.lookup_name(identifier);
+            if fqcn.eq_ignore_ascii_case(RESPONSE) {
+                return true;
+            }
+
+            match context.codebase.get_named_class_like(context.interner, context.semantics.names.get(identifier)) {
+                Some(class_like) => extends_or_implements(context.interner, class_like, RESPONSE),
+                None => fqcn.to_ascii_lowercase().ends_with("response"),
+            }
+        }
+        Hint::Parenthesized(parenthesized) => is_response_hint(&parenthesized.hint, context),
+        Hint::Union(union) => is_response_hint(&union.left, context) && is_response_hint(&union.right, context),
+        Hint::Intersection(intersection) => {
+            is_response_hint(&intersection.left, context) || is_response_hint(&intersection.right, context)
+        }
+        Hint::Static(_) | Hint::Self_(_) | Hint::Parent(_) | Hint::Object(_) | Hint::Mixed(_) | Hint::Never(_) => true,
+        _ => false,
+    }
+}
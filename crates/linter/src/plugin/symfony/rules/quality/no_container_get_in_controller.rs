@@ -0,0 +1,116 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::extends_or_implements;
+use crate::plugin::symfony::rules::utils::CONTROLLER_CLASSES;
+use crate::rule::Rule;
+
+#[derive(Clone, Debug)]
+pub struct NoContainerGetInControllerRule;
+
+impl Rule for NoContainerGetInControllerRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Container Get In Controller", Level::Warning)
+            .with_description(indoc! {"
+                Detects services fetched from the container in controllers, using `$this->get()` or `$this->container->get()`.
+
+                `AbstractController::get()` was deprecated in Symfony 5.4 and removed in 6.0, and the container of
+                a controller only contains the services it subscribes to. Services should be injected instead.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Injecting a service into the action",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Psr\Log\LoggerInterface;
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Response;
+
+                    final class HomeController extends AbstractController
+                    {
+                        public function index(LoggerInterface $logger): Response
+                        {
+                            $logger->info('Home page visited.');
+
+                            return $this->render('home.html.twig');
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Fetching a service from the container",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Controller;
+
+                    use Symfony\Bundle\FrameworkBundle\Controller\AbstractController;
+                    use Symfony\Component\HttpFoundation\Response;
+
+                    final class HomeController extends AbstractController
+                    {
+                        public function index(): Response
+                        {
+                            $this->get('logger')->info('Home page visited.');
+
+                            return $this->render('home.html.twig');
+                        }
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoContainerGetInControllerRule {
+    fn walk_in_method_call(&self, method_call: &MethodCall, context: &mut LintContext<'a>) {
+        let ClassLikeMemberSelector::Identifier(method) = &method_call.method else {
+            return;
+        };
+
+        if !context.lookup(&method.value).eq_ignore_ascii_case("get") {
+            return;
+        }
+
+        let is_container_get = match method_call.object.as_ref() {
+            Expression::Variable(Variable::Direct(variable)) => context.lookup(&variable.name) == "$this",
+            Expression::Access(Access::Property(PropertyAccess {
+                object,
+                property: ClassLikeMemberSelector::Identifier(property),
+                ..
+            })) => {
+                matches!(object.as_ref(), Expression::Variable(Variable::Direct(variable)) if context.lookup(&variable.name) == "$this")
+                    && context.lookup(&property.value) == "container"
+            }
+            _ => false,
+        };
+
+        if !is_container_get {
+            return;
+        }
+
+        let Some(class_like) = context.codebase.get_enclosing_class_like(&method_call.span().start) else {
+            return;
+        };
+
+        if !CONTROLLER_CLASSES.iter().any(|controller| extends_or_implements(context.interner, class_like, controller))
+        {
+            return;
+        }
+
+        let issue = Issue::new(context.level(), "Avoid fetching services from the container in controllers.")
+            .with_annotation(Annotation::primary(method_call.span()).with_message("A service is fetched from the container here."))
+            .with_note("`AbstractController::get()` was deprecated in Symfony 5.4, and the controller container only contains the services it subscribes to.")
+            .with_help("Inject the service into the constructor, or as an argument of the action.");
+
+        context.report(issue);
+    }
+}
@@ -0,0 +1,161 @@
+use indoc::indoc;
+
+use mago_ast::*;
+use mago_reporting::*;
+use mago_span::HasSpan;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::plugin::symfony::rules::utils::extends_or_implements;
+use crate::plugin::symfony::rules::utils::is_one_of;
+use crate::plugin::symfony::rules::utils::CONTAINER_INTERFACES;
+use crate::rule::Rule;
+
+const CONTAINER_AWARE: [&str; 2] = [
+    "Symfony\\Component\\DependencyInjection\\ContainerAwareInterface",
+    "Symfony\\Bundle\\FrameworkBundle\\Command\\ContainerAwareCommand",
+];
+
+const CONTAINER_AWARE_TRAIT: &str = "Symfony\\Component\\DependencyInjection\\ContainerAwareTrait";
+
+const SERVICE_SUBSCRIBERS: [&str; 2] = [
+    "Symfony\\Contracts\\Service\\ServiceSubscriberInterface",
+    "Symfony\\Component\\DependencyInjection\\ServiceSubscriberInterface",
+];
+
+#[derive(Clone, Debug)]
+pub struct NoServiceLocatorRule;
+
+impl Rule for NoServiceLocatorRule {
+    fn get_definition(&self) -> RuleDefinition {
+        RuleDefinition::enabled("No Service Locator", Level::Warning)
+            .with_description(indoc! {"
+                Detects services using the whole container as a service locator, by injecting the container,
+                or by being container-aware.
+
+                Fetching services from the container hides the dependencies of a class, and prevents the container
+                from removing unused services. Service subscribers, which declare the services they fetch, are allowed.
+            "})
+            .with_example(RuleUsageExample::valid(
+                "Injecting the dependencies",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Mailer;
+
+                    use Symfony\Component\Mailer\MailerInterface;
+
+                    final class NewsletterSender
+                    {
+                        public function __construct(private MailerInterface $mailer)
+                        {
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Injecting the container",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Mailer;
+
+                    use Psr\Container\ContainerInterface;
+
+                    final class NewsletterSender
+                    {
+                        public function __construct(private ContainerInterface $container)
+                        {
+                        }
+
+                        public function send(): void
+                        {
+                            $this->container->get('mailer')->send($this->createMessage());
+                        }
+                    }
+                "#},
+            ))
+            .with_example(RuleUsageExample::invalid(
+                "Using the container-aware trait",
+                indoc! {r#"
+                    <?php
+
+                    namespace App\Mailer;
+
+                    use Symfony\Component\DependencyInjection\ContainerAwareInterface;
+                    use Symfony\Component\DependencyInjection\ContainerAwareTrait;
+
+                    final class NewsletterSender implements ContainerAwareInterface
+                    {
+                        use ContainerAwareTrait;
+                    }
+                "#},
+            ))
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for NoServiceLocatorRule {
+    fn walk_in_class(&self, class: &Class, context: &mut LintContext<'a>) {
+        let interner = context.interner;
+        let name = context.semantics.names.get(&class.name);
+        let Some(class_like) = context.codebase.get_named_class_like(interner, name) else {
+            return;
+        };
+
+        if SERVICE_SUBSCRIBERS.iter().any(|subscriber| extends_or_implements(interner, class_like, subscriber)) {
+            return;
+        }
+
+        let class_name = interner.lookup(&class.name.value);
+        let is_container_aware = CONTAINER_AWARE.iter().any(|fqcn| extends_or_implements(interner, class_like, fqcn))
+            || class_like
+                .used_traits
+                .iter()
+                .any(|used_trait| is_one_of(interner, used_trait, &[CONTAINER_AWARE_TRAIT]));
+
+        if is_container_aware {
+            let issue = Issue::new(context.level(), format!("Class `{}` is container-aware.", class_name))
+                .with_annotation(
+                    Annotation::primary(class.name.span())
+                        .with_message("This class has access to the whole container."),
+                )
+                .with_note("Container-aware classes were deprecated in Symfony 6.4.")
+                .with_help("Inject the services the class needs into its constructor instead.");
+
+            context.report(issue);
+        }
+
+        for member in class.members.iter() {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            if !interner.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+                continue;
+            }
+
+            for parameter in method.parameter_list.parameters.iter() {
+                let Some(Hint::Identifier(identifier)) = &parameter.hint else {
+                    continue;
+                };
+
+                if !CONTAINER_INTERFACES
+                    .iter()
+                    .any(|container| context.lookup_name(identifier).eq_ignore_ascii_case(container))
+                {
+                    continue;
+                }
+
+                let issue = Issue::new(context.level(), format!("Class `{}` uses the container as a service locator.", class_name))
+                    .with_annotation(Annotation::primary(parameter.span()).with_message("The container is injected here."))
+                    .with_annotation(Annotation::secondary(class.name.span()).with_message(format!("Class `{}` is declared here.", class_name)))
+                    .with_note("Fetching services from the container hides the dependencies of the class.")
+                    .with_help("Inject the services the class needs directly, or implement `ServiceSubscriberInterface` to declare them.");
+
+                context.report(issue);
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::attribute::AttributeArgumentReflection;
+use mago_reflection::attribute::AttributeReflection;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::r#type::kind::ValueTypeKind;
+use mago_span::Span;
+
+pub const ROUTE_ATTRIBUTES: [&str; 2] =
+    ["Symfony\\Component\\Routing\\Attribute\\Route", "Symfony\\Component\\Routing\\Annotation\\Route"];
+
+pub const CONTROLLER_CLASSES: [&str; 2] = [
+    "Symfony\\Bundle\\FrameworkBundle\\Controller\\AbstractController",
+    "Symfony\\Bundle\\FrameworkBundle\\Controller\\Controller",
+];
+
+pub const AS_CONTROLLER_ATTRIBUTE: &str = "Symfony\\Component\\HttpKernel\\Attribute\\AsController";
+
+pub const CONTAINER_INTERFACES: [&str; 2] =
+    ["Psr\\Container\\ContainerInterface", "Symfony\\Component\\DependencyInjection\\ContainerInterface"];
+
+/// A `#[Route]` attribute, with the literal values of its path and name arguments.
+#[derive(Debug, Clone, Copy)]
+pub struct Route<'a> {
+    pub attribute: &'a AttributeReflection,
+    pub path: Option<(&'a str, Span)>,
+    pub name: Option<(&'a str, Span)>,
+}
+
+/// Returns the `#[Route]` attributes among the given attributes.
+pub fn get_routes<'a>(interner: &'a ThreadedInterner, attributes: &'a [AttributeReflection]) -> Vec<Route<'a>> {
+    attributes
+        .iter()
+        .filter(|attribute| is_one_of(interner, &attribute.name.value, &ROUTE_ATTRIBUTES))
+        .map(|attribute| Route {
+            attribute,
+            path: get_string_argument(interner, attribute, 0, "path"),
+            name: get_string_argument(interner, attribute, 1, "name"),
+        })
+        .collect()
+}
+
+/// Returns whether the given attributes contain an attribute with the given fully qualified name.
+pub fn has_attribute(interner: &ThreadedInterner, attributes: &[AttributeReflection], fqcn: &str) -> bool {
+    attributes.iter().any(|attribute| interner.lookup(&attribute.name.value).eq_ignore_ascii_case(fqcn))
+}
+
+/// Returns whether the given attribute has an argument with the given name, or at the given position.
+pub fn has_argument(interner: &ThreadedInterner, attribute: &AttributeReflection, position: usize, name: &str) -> bool {
+    get_argument(interner, attribute, position, name).is_some()
+}
+
+/// Returns the literal string value of an attribute argument, passed either by name, or at the given position.
+pub fn get_string_argument<'a>(
+    interner: &'a ThreadedInterner,
+    attribute: &'a AttributeReflection,
+    position: usize,
+    name: &str,
+) -> Option<(&'a str, Span)> {
+    let (kind, span) = get_argument(interner, attribute, position, name)?;
+
+    match kind {
+        TypeKind::Value(ValueTypeKind::String { value, .. }) => Some((interner.lookup(value), span)),
+        _ => None,
+    }
+}
+
+/// Returns the parameter names used in a route path, e.g. `["id", "slug"]` for `/posts/{id<\d+>}/{slug?}`.
+pub fn get_path_parameters(path: &str) -> Vec<&str> {
+    let mut parameters = vec![];
+    let mut remaining = path;
+    while let Some(start) = remaining.find('{') {
+        let Some(end) = remaining[start..].find('}') else {
+            break;
+        };
+
+        let placeholder = remaining[start + 1..start + end].trim_start_matches('!');
+        let name = placeholder.split(['<', '?']).next().unwrap_or(placeholder);
+        if !name.is_empty() {
+            parameters.push(name);
+        }
+
+        remaining = &remaining[start + end + 1..];
+    }
+
+    parameters
+}
+
+/// Returns whether the class-like is a controller: it extends one of Symfony's base controllers, has
+/// the `#[AsController]` attribute, or declares routes.
+pub fn is_controller(interner: &ThreadedInterner, class_like: &ClassLikeReflection) -> bool {
+    CONTROLLER_CLASSES.iter().any(|controller| extends_or_implements(interner, class_like, controller))
+        || has_attribute(interner, &class_like.attribute_reflections, AS_CONTROLLER_ATTRIBUTE)
+        || !get_routes(interner, &class_like.attribute_reflections).is_empty()
+        || class_like
+            .methods
+            .members
+            .values()
+            .any(|method| !get_routes(interner, &method.attribute_reflections).is_empty())
+}
+
+/// Returns whether the class-like extends, or implements, the class-like with the given fully qualified name.
+pub fn extends_or_implements(interner: &ThreadedInterner, class_like: &ClassLikeReflection, fqcn: &str) -> bool {
+    let inheritance = &class_like.inheritance;
+
+    inheritance
+        .direct_extended_class
+        .iter()
+        .chain(inheritance.all_extended_classes.iter())
+        .chain(inheritance.direct_implemented_interfaces.iter())
+        .chain(inheritance.all_implemented_interfaces.iter())
+        .any(|name| interner.lookup(&name.value).eq_ignore_ascii_case(fqcn))
+}
+
+/// Returns whether the class-like declares, or inherits, a method with the given name.
+pub fn has_method(interner: &ThreadedInterner, class_like: &ClassLikeReflection, name: &str) -> bool {
+    let methods = &class_like.methods;
+
+    methods
+        .members
+        .keys()
+        .chain(methods.appering_members.keys())
+        .any(|method| interner.lookup(method).eq_ignore_ascii_case(name))
+}
+
+pub fn is_one_of(interner: &ThreadedInterner, name: &StringIdentifier, fqcns: &[&str]) -> bool {
+    let name = interner.lookup(name);
+
+    fqcns.iter().any(|fqcn| name.eq_ignore_ascii_case(fqcn))
+}
+
+fn get_argument<'a>(
+    interner: &ThreadedInterner,
+    attribute: &'a AttributeReflection,
+    position: usize,
+    name: &str,
+) -> Option<(&'a TypeKind, Span)> {
+    let arguments = &attribute.arguments.as_ref()?.arguments;
+
+    for (index, argument) in arguments.iter().enumerate() {
+        match argument {
+            AttributeArgumentReflection::Positional { value_type_reflection, span } if index == position => {
+                return Some((&value_type_reflection.kind, *span));
+            }
+            AttributeArgumentReflection::Named { name: argument_name, value_type_reflection, span }
+                if interner.lookup(&argument_name.value) == name =>
+            {
+                return Some((&value_type_reflection.kind, *span));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -0,0 +1,57 @@
+//! Rules reporting tainted data reaching dangerous sinks.
+//!
+//! The rules are backed by a [`TaintAnalyzer`], whose summaries of the codebase's functions and methods
+//! are computed before linting, one rule is provided for each kind of sink.
+
+use std::sync::Arc;
+
+use mago_taint::configuration::SinkKind;
+use mago_taint::TaintAnalyzer;
+
+use crate::definition::PluginDefinition;
+use crate::plugin::taint::rule::TaintRule;
+use crate::plugin::Plugin;
+use crate::rule::Rule;
+
+pub mod rule;
+
+/// A plugin reporting tainted data, such as user input, reaching dangerous sinks.
+#[derive(Debug, Clone)]
+pub struct TaintPlugin {
+    analyzer: Arc<TaintAnalyzer>,
+}
+
+impl TaintPlugin {
+    /// Creates a new plugin using the given analyzer, which should already have summarized the codebase.
+    pub fn new(analyzer: Arc<TaintAnalyzer>) -> Self {
+        Self { analyzer }
+    }
+
+    /// Returns the definition of the plugin, which is known before the analyzer is prepared.
+    ///
+    /// Summarizing the codebase is costly, so it should only be done when [`Linter::is_plugin_enabled`]
+    /// returns `true` for this definition.
+    ///
+    /// [`Linter::is_plugin_enabled`]: crate::Linter::is_plugin_enabled
+    pub fn definition() -> PluginDefinition {
+        PluginDefinition {
+            name: "Taint",
+            description:
+                "Provides rules that track user input through the codebase to detect injection vulnerabilities.",
+            enabled_by_default: false,
+        }
+    }
+}
+
+impl Plugin for TaintPlugin {
+    fn get_definition(&self) -> PluginDefinition {
+        Self::definition()
+    }
+
+    fn get_rules(&self) -> Vec<Box<dyn Rule>> {
+        [SinkKind::Sql, SinkKind::Shell, SinkKind::Include, SinkKind::Html, SinkKind::Unserialize, SinkKind::Header]
+            .into_iter()
+            .map(|kind| Box::new(TaintRule::new(kind, self.analyzer.clone())) as Box<dyn Rule>)
+            .collect()
+    }
+}
@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use indoc::indoc;
+
+use mago_ast::Program;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_taint::configuration::SinkKind;
+use mago_taint::TaintAnalyzer;
+use mago_walker::Walker;
+
+use crate::context::LintContext;
+use crate::definition::RuleDefinition;
+use crate::definition::RuleUsageExample;
+use crate::rule::Rule;
+
+/// A rule reporting tainted data reaching sinks of a single kind.
+#[derive(Debug, Clone)]
+pub struct TaintRule {
+    kind: SinkKind,
+    analyzer: Arc<TaintAnalyzer>,
+}
+
+impl TaintRule {
+    /// Creates a new rule reporting tainted data reaching sinks of the given kind.
+    pub fn new(kind: SinkKind, analyzer: Arc<TaintAnalyzer>) -> Self {
+        Self { kind, analyzer }
+    }
+
+    fn get_help(&self) -> &'static str {
+        match self.kind {
+            SinkKind::Sql => "Use prepared statements with bound parameters instead of building queries from input.",
+            SinkKind::Shell => "Escape each argument using `escapeshellarg()`, or avoid invoking a shell.",
+            SinkKind::Include => "Only include files from a fixed list of known paths.",
+            SinkKind::Html => "Escape the data using `htmlspecialchars()` before outputting it.",
+            SinkKind::Unserialize => {
+                "Use `json_decode()` instead, or restrict the classes using the `allowed_classes` option."
+            }
+            SinkKind::Header => "Validate the data, or encode it using `urlencode()`, before using it in a header.",
+        }
+    }
+}
+
+impl Rule for TaintRule {
+    fn get_definition(&self) -> RuleDefinition {
+        match self.kind {
+            SinkKind::Sql => RuleDefinition::enabled("SQL Injection", Level::Error)
+                .with_description("Reports user input reaching an SQL query without being escaped.")
+                .with_example(RuleUsageExample::valid(
+                    "Binding user input as a parameter",
+                    indoc! {r#"
+                        <?php
+
+                        $statement = $pdo->prepare('SELECT * FROM users WHERE id = ?');
+                        $statement->execute([$_GET['id']]);
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Concatenating user input into a query",
+                    indoc! {r#"
+                        <?php
+
+                        $pdo->query('SELECT * FROM users WHERE id = ' . $_GET['id']);
+                    "#},
+                )),
+            SinkKind::Shell => RuleDefinition::enabled("Command Injection", Level::Error)
+                .with_description("Reports user input reaching a shell command without being escaped.")
+                .with_example(RuleUsageExample::valid(
+                    "Escaping user input",
+                    indoc! {r#"
+                        <?php
+
+                        system('ls ' . escapeshellarg($_GET['directory']));
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Passing user input to a shell command",
+                    indoc! {r#"
+                        <?php
+
+                        system('ls ' . $_GET['directory']);
+                    "#},
+                )),
+            SinkKind::Include => RuleDefinition::enabled("File Inclusion", Level::Error)
+                .with_description("Reports user input used to choose a file to include.")
+                .with_example(RuleUsageExample::valid(
+                    "Including a fixed file",
+                    indoc! {r#"
+                        <?php
+
+                        include __DIR__ . '/pages/home.php';
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Including a file chosen by the user",
+                    indoc! {r#"
+                        <?php
+
+                        include __DIR__ . '/pages/' . $_GET['page'] . '.php';
+                    "#},
+                )),
+            SinkKind::Html => RuleDefinition::enabled("Cross-Site Scripting", Level::Error)
+                .with_description("Reports user input written to the HTML output without being escaped.")
+                .with_example(RuleUsageExample::valid(
+                    "Escaping user input",
+                    indoc! {r#"
+                        <?php
+
+                        echo htmlspecialchars($_GET['name']);
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Echoing user input",
+                    indoc! {r#"
+                        <?php
+
+                        echo $_GET['name'];
+                    "#},
+                )),
+            SinkKind::Unserialize => RuleDefinition::enabled("Unsafe Deserialization", Level::Error)
+                .with_description(
+                    "Reports user input passed to `unserialize()`, which can instantiate arbitrary objects.",
+                )
+                .with_example(RuleUsageExample::valid(
+                    "Decoding user input as JSON",
+                    indoc! {r#"
+                        <?php
+
+                        $data = json_decode($_COOKIE['data'], true);
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Unserializing user input",
+                    indoc! {r#"
+                        <?php
+
+                        $data = unserialize($_COOKIE['data']);
+                    "#},
+                )),
+            SinkKind::Header => RuleDefinition::enabled("Header Injection", Level::Error)
+                .with_description("Reports user input used in an HTTP header, or a mail header, without being encoded.")
+                .with_example(RuleUsageExample::valid(
+                    "Encoding user input",
+                    indoc! {r#"
+                        <?php
+
+                        header('Location: /search?q=' . urlencode($_GET['q']));
+                    "#},
+                ))
+                .with_example(RuleUsageExample::invalid(
+                    "Using user input in a header",
+                    indoc! {r#"
+                        <?php
+
+                        header('Location: ' . $_GET['next']);
+                    "#},
+                )),
+        }
+    }
+
+    fn lint(&self, program: &Program, context: &mut LintContext<'_>) {
+        let findings = self.analyzer.analyze(
+            context.interner,
+            context.codebase,
+            &context.semantics.source,
+            program,
+            &context.semantics.names,
+            &[self.kind],
+        );
+
+        for finding in findings {
+            context.report(
+                Issue::new(context.level(), finding.message)
+                    .with_annotations(finding.annotations)
+                    .with_help(self.get_help()),
+            );
+        }
+    }
+}
+
+impl<'a> Walker<LintContext<'a>> for TaintRule {}
@@ -5,34 +5,81 @@ use mago_linter::settings::RuleSettings;
 use mago_linter::settings::Settings;
 use mago_linter::Linter;
 use mago_php_version::PHPVersion;
+use mago_reporting::Level;
 use mago_semantics::Semantics;
+use mago_source::SourceCategory::BuiltIn;
 use mago_source::SourceCategory::UserDefined;
 use mago_source::SourceManager;
 
 pub mod plugins;
 
+/// The PHP stubs reflected alongside each example, so rules relying on built-in functions and classes can be tested.
+const STUBS: [(&str, &str); 5] = [
+    ("stubs/Core/Core.php", include_str!("../../../stubs/Core/Core.php")),
+    ("stubs/Core/Core_c.php", include_str!("../../../stubs/Core/Core_c.php")),
+    ("stubs/SPL/SPL.php", include_str!("../../../stubs/SPL/SPL.php")),
+    ("stubs/standard/basic.php", include_str!("../../../stubs/standard/basic.php")),
+    ("stubs/standard/standard_2.php", include_str!("../../../stubs/standard/standard_2.php")),
+];
+
+/// The setup of a rule test, for rules that need more than their default settings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestOptions {
+    /// The PHP version to lint the examples with, instead of the one derived from the supported PHP versions of the rule.
+    pub php_version: Option<PHPVersion>,
+    /// The level to enable the rule with, for rules that are disabled by default.
+    pub level: Option<Level>,
+    /// Whether to reflect the PHP stubs alongside the examples, for rules relying on built-in functions and classes.
+    pub stubs: bool,
+}
+
+impl TestOptions {
+    pub fn php_version(php_version: PHPVersion) -> Self {
+        Self { php_version: Some(php_version), ..Default::default() }
+    }
+
+    pub fn level(level: Level) -> Self {
+        Self { level: Some(level), ..Default::default() }
+    }
+
+    pub fn stubs() -> Self {
+        Self { stubs: true, ..Default::default() }
+    }
+
+    pub fn with_php_version(self, php_version: PHPVersion) -> Self {
+        Self { php_version: Some(php_version), ..self }
+    }
+
+    pub fn with_stubs(self) -> Self {
+        Self { stubs: true, ..self }
+    }
+}
+
 #[macro_export]
 macro_rules! rule_test {
     ($name:ident, $rule:expr) => {
+        $crate::rule_test!($name, $rule, $crate::TestOptions::default());
+    };
+    ($name:ident, $rule:expr, $options:expr) => {
         #[test]
         fn $name() {
             use mago_linter::rule::Rule;
 
             let rule = $rule;
             for usage_example in rule.get_definition().examples {
-                $crate::test_rule_usage_example(Box::new($rule), &usage_example);
+                $crate::test_rule_usage_example(Box::new($rule), &usage_example, $options);
             }
         }
     };
 }
 
-pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExample) {
+pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExample, options: TestOptions) {
     let definition = rule.get_definition();
 
     let interner = ThreadedInterner::new();
     let source_manager = SourceManager::new(interner.clone());
 
-    let mut rule_settings = RuleSettings::enabled();
+    let mut rule_settings = RuleSettings::from_level(options.level);
     for (option, value) in usage_example.options.iter() {
         rule_settings.options.insert(option.to_string(), value.clone());
     }
@@ -43,7 +90,21 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
 
     let semantics = Semantics::build(&interner, source);
     let source = source_manager.load(&source_id).unwrap();
-    let reflection = mago_reflector::reflect(&interner, &source, &semantics.program, &semantics.names);
+    let mut reflection = mago_reflector::reflect(&interner, &source, &semantics.program, &semantics.names);
+    for (stub_name, stub_content) in if options.stubs { &STUBS[..] } else { &[] } {
+        let stub_id = source_manager.insert_content(stub_name.to_string(), stub_content.to_string(), BuiltIn);
+        let stub = source_manager.load(&stub_id).unwrap();
+        let stub_semantics = Semantics::build(&interner, stub);
+        let stub = source_manager.load(&stub_id).unwrap();
+
+        reflection = mago_reflector::merge(
+            &interner,
+            reflection,
+            mago_reflector::reflect(&interner, &stub, &stub_semantics.program, &stub_semantics.names),
+        );
+    }
+
+    mago_reflector::populate(&interner, &mut reflection);
 
     let mut php_version = PHPVersion::PHP84;
     if let Some(version) = rule.get_definition().maximum_supported_php_version {
@@ -52,7 +113,9 @@ pub fn test_rule_usage_example(rule: Box<dyn Rule>, usage_example: &RuleUsageExa
     if let Some(version) = rule.get_definition().minimum_supported_php_version {
         php_version = version;
     }
-
+    if let Some(version) = options.php_version {
+        php_version = version;
+    }
     let settings = Settings::new(php_version).with_rule(format!("test/{}", definition.get_slug()), rule_settings);
     let mut linter = Linter::new(settings, interner.clone(), reflection);
 
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use mago_interner::ThreadedInterner;
+use mago_linter::external::ExternalPlugin;
+use mago_linter::plugin::Plugin;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_reporting::Level;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+const DEFINITION: &str = r#"{"name":"House Rules","description":"Rules we cannot upstream.","rules":[{"name":"No Foo","level":"Warning","description":"Disallows the `Foo` class."}]}"#;
+const QUERY: &str = r#"{"query":"class-like","name":"Foo"}"#;
+const ISSUES: &str = r#"[{"message":"Class `Foo` is not allowed.","annotations":[{"start":6,"end":15}],"fix":{"operations":[{"type":"Delete","value":{"range":{"start":6,"end":15},"safety_classification":{"type":"Safe"}}}]}}]"#;
+const INVALID_ISSUES: &str = r#"[{"message":"Class `Foo` is not allowed.","annotations":[{"start":6,"end":15},{"start":15,"end":6},{"start":6,"end":500}],"fix":{"operations":[{"type":"Delete","value":{"range":{"start":6,"end":500},"safety_classification":{"type":"Safe"}}}]}}]"#;
+
+/// Builds a plugin that reports the given issues whenever the `Foo` class exists in the codebase.
+fn plugin_module(issues: &str) -> Vec<u8> {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let wat = format!(
+        r#"
+        (module
+          (import "mago" "query" (func $query (param i32 i32) (result i64)))
+          (memory (export "memory") 32)
+          (global $heap (mut i32) (i32.const 8192))
+          (data (i32.const 0) "{definition}")
+          (data (i32.const 1024) "{query}")
+          (data (i32.const 2048) "{issues}")
+          (data (i32.const 4096) "[]")
+          (func (export "mago_alloc") (param $size i32) (result i32)
+            (local $pointer i32)
+            (local.set $pointer (global.get $heap))
+            (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+            (local.get $pointer))
+          (func (export "mago_plugin_definition") (result i64)
+            (i64.const {definition_length}))
+          (func (export "mago_lint") (param $pointer i32) (param $length i32) (result i64)
+            (if (result i64)
+              (i64.eq
+                (i64.and (call $query (i32.const 1024) (i32.const {query_length})) (i64.const 0xffffffff))
+                (i64.const 4))
+              (then (i64.const {empty}))
+              (else (i64.const {issues_packed})))))
+        "#,
+        definition = escape(DEFINITION),
+        query = escape(QUERY),
+        issues = escape(issues),
+        definition_length = DEFINITION.len(),
+        query_length = QUERY.len(),
+        empty = (4096_i64 << 32) | 2,
+        issues_packed = (2048_i64 << 32) | issues.len() as i64,
+    );
+
+    wat::parse_str(wat).unwrap()
+}
+
+/// Builds a plugin that reports an issue on every call to `mago_lint` but the first one made by an instance.
+fn counting_plugin_module() -> Vec<u8> {
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let wat = format!(
+        r#"
+        (module
+          (memory (export "memory") 32)
+          (global $heap (mut i32) (i32.const 8192))
+          (global $calls (mut i32) (i32.const 0))
+          (data (i32.const 0) "{definition}")
+          (data (i32.const 2048) "{issues}")
+          (data (i32.const 4096) "[]")
+          (func (export "mago_alloc") (param $size i32) (result i32)
+            (local $pointer i32)
+            (local.set $pointer (global.get $heap))
+            (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+            (local.get $pointer))
+          (func (export "mago_plugin_definition") (result i64)
+            (i64.const {definition_length}))
+          (func (export "mago_lint") (param $pointer i32) (param $length i32) (result i64)
+            (global.set $heap (i32.const 8192))
+            (global.set $calls (i32.add (global.get $calls) (i32.const 1)))
+            (if (result i64)
+              (i32.eq (global.get $calls) (i32.const 1))
+              (then (i64.const {empty}))
+              (else (i64.const {issues_packed})))))
+        "#,
+        definition = escape(DEFINITION),
+        issues = escape(ISSUES),
+        definition_length = DEFINITION.len(),
+        empty = (4096_i64 << 32) | 2,
+        issues_packed = (2048_i64 << 32) | ISSUES.len() as i64,
+    );
+
+    wat::parse_str(wat).unwrap()
+}
+
+fn lint(code: &'static str, module: &[u8]) -> mago_reporting::IssueCollection {
+    let interner = ThreadedInterner::new();
+    let source_manager = SourceManager::new(interner.clone());
+    let source_id =
+        source_manager.insert_content("test.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = source_manager.load(&source_id).unwrap();
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics.source, &semantics.program, &semantics.names);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP84), interner.clone(), codebase);
+    let plugin = ExternalPlugin::from_bytes(module, linter.get_codebase().clone()).unwrap();
+    linter.add_plugin(plugin);
+
+    linter.lint(&semantics)
+}
+
+#[test]
+fn test_external_plugin_definition() {
+    let plugin = ExternalPlugin::from_bytes(&plugin_module(ISSUES), Arc::default()).unwrap();
+    let definition = plugin.get_definition();
+
+    assert_eq!(definition.get_slug(), "house-rules");
+    assert_eq!(definition.description, "Rules we cannot upstream.");
+
+    let rules = plugin.get_rules();
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].get_definition().get_slug(), "no-foo");
+    assert_eq!(rules[0].get_definition().level, Some(Level::Warning));
+}
+
+#[test]
+fn test_external_plugin_reports_issues() {
+    let issues = lint("<?php class Foo {}", &plugin_module(ISSUES));
+    let issues = issues.into_iter().collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].code.as_deref(), Some("house-rules/no-foo"));
+    assert_eq!(issues[0].level, Level::Warning);
+    assert_eq!(issues[0].annotations[0].span.start.offset, 6);
+    assert_eq!(issues[0].suggestions.len(), 1);
+}
+
+#[test]
+fn test_external_plugin_queries_codebase() {
+    let issues = lint("<?php class Bar {}", &plugin_module(ISSUES));
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_external_plugin_drops_invalid_offsets() {
+    let issues = lint("<?php class Foo {}", &plugin_module(INVALID_ISSUES));
+    let issues = issues.into_iter().collect::<Vec<_>>();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].annotations.len(), 1);
+    assert_eq!(issues[0].annotations[0].span.end.offset, 15);
+    assert!(issues[0].suggestions.is_empty());
+}
+
+#[test]
+fn test_external_plugin_reuses_instances() {
+    let interner = ThreadedInterner::new();
+    let source_manager = SourceManager::new(interner.clone());
+    let source_id = source_manager.insert_content(
+        "test.php".to_string(),
+        "<?php class Foo {}".to_string(),
+        SourceCategory::UserDefined,
+    );
+    let source = source_manager.load(&source_id).unwrap();
+
+    let semantics = Semantics::build(&interner, source);
+    let codebase = mago_reflector::reflect(&interner, &semantics.source, &semantics.program, &semantics.names);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP84), interner.clone(), codebase);
+    let plugin = ExternalPlugin::from_bytes(&counting_plugin_module(), linter.get_codebase().clone()).unwrap();
+    linter.add_plugin(plugin);
+
+    // The instance that provided the plugin definition is reused, so it counts every call made by the linter.
+    assert!(linter.lint(&semantics).is_empty());
+    assert_eq!(linter.lint(&semantics).len(), 1);
+}
+
+#[test]
+fn test_external_plugin_names_are_shared_between_loads() {
+    let first = ExternalPlugin::from_bytes(&plugin_module(ISSUES), Arc::default()).unwrap();
+    let second = ExternalPlugin::from_bytes(&plugin_module(ISSUES), Arc::default()).unwrap();
+
+    assert!(std::ptr::eq(first.get_definition().name, second.get_definition().name));
+    assert!(std::ptr::eq(first.get_rules()[0].get_definition().name, second.get_rules()[0].get_definition().name));
+}
+
+#[test]
+fn test_external_plugin_rejects_invalid_module() {
+    assert!(ExternalPlugin::from_bytes(b"not a module", Arc::default()).is_err());
+}
@@ -0,0 +1,129 @@
+use indoc::indoc;
+
+use mago_interner::ThreadedInterner;
+use mago_linter::pattern::PatternPlugin;
+use mago_linter::pattern::PatternRuleDefinition;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_reporting::Issue;
+use mago_reporting::Level;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+fn lint(definition: PatternRuleDefinition, code: &'static str) -> Vec<Issue> {
+    let interner = ThreadedInterner::new();
+    let source_manager = SourceManager::new(interner.clone());
+    let source_id =
+        source_manager.insert_content("test.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let source = source_manager.load(&source_id).unwrap();
+
+    let semantics = Semantics::build(&interner, source);
+    let mut codebase = mago_reflector::reflect(&interner, &semantics.source, &semantics.program, &semantics.names);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP84), interner.clone(), codebase);
+    linter.add_plugin(PatternPlugin::new(&interner, [definition]).unwrap());
+
+    linter.lint(&semantics).into_iter().collect()
+}
+
+fn set_up_definition() -> PatternRuleDefinition {
+    PatternRuleDefinition {
+        name: "No Direct Set Up Call".to_string(),
+        pattern: "$x->setUp($$$args)".to_string(),
+        message: "Do not call `setUp()` directly.".to_string(),
+        level: Level::Error,
+        help: Some("Let PHPUnit call `setUp()` for you.".to_string()),
+        rewrite: Some("$x->initialize($$$args)".to_string()),
+        extends: Some("\\PHPUnit\\Framework\\TestCase".to_string()),
+    }
+}
+
+#[test]
+fn test_pattern_rule_reports_matches_with_fix() {
+    let code = indoc! {r#"
+        <?php
+
+        namespace App\Tests;
+
+        use PHPUnit\Framework\TestCase;
+
+        final class FooTest extends TestCase
+        {
+            public function testFoo(): void
+            {
+                $this->setUp(1, 2);
+            }
+        }
+    "#};
+
+    let issues = lint(set_up_definition(), code);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].level, Level::Error);
+    assert_eq!(issues[0].code.as_deref(), Some("patterns/no-direct-set-up-call"));
+    assert_eq!(issues[0].message, "Do not call `setUp()` directly.");
+
+    let (_, plan) = &issues[0].suggestions[0];
+    let fixed = plan.execute(code).get_fixed();
+    assert!(fixed.contains("$this->initialize(1, 2);"));
+    assert!(!fixed.contains("setUp"));
+}
+
+#[test]
+fn test_pattern_rule_respects_extends() {
+    let issues = lint(
+        set_up_definition(),
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Foo
+            {
+                public function bar(): void
+                {
+                    $this->setUp();
+                }
+            }
+        "#},
+    );
+
+    assert!(issues.is_empty());
+}
+
+#[test]
+fn test_pattern_rule_without_scope() {
+    let issues = lint(
+        PatternRuleDefinition {
+            name: "No Var Dump".to_string(),
+            pattern: "var_dump($$$args)".to_string(),
+            message: "Do not use `var_dump()`.".to_string(),
+            level: Level::Warning,
+            help: None,
+            rewrite: None,
+            extends: None,
+        },
+        indoc! {r#"
+            <?php
+
+            var_dump($a);
+            VAR_DUMP($a, $b);
+            print_r($a);
+        "#},
+    );
+
+    assert_eq!(issues.len(), 2);
+    assert!(issues.iter().all(|issue| issue.suggestions.is_empty()));
+}
+
+#[test]
+fn test_pattern_plugin_rejects_invalid_pattern() {
+    let interner = ThreadedInterner::new();
+    let mut definition = set_up_definition();
+    definition.pattern = "$x->setUp(".to_string();
+
+    assert!(PatternPlugin::new(&interner, [definition]).is_err());
+}
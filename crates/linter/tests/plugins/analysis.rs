@@ -2,10 +2,18 @@ use mago_linter::plugin::analysis::rules::inheritance::InheritanceRule;
 use mago_linter::plugin::analysis::rules::instantiation::InstantiationRule;
 use mago_linter::plugin::analysis::rules::undefined_constant::UndefinedConstantRule;
 use mago_linter::plugin::analysis::rules::undefined_function::UndefinedFunctionRule;
+use mago_linter::plugin::analysis::rules::unhandled_exception::UnhandledExceptionRule;
+use mago_linter::plugin::analysis::rules::unnecessary_throws_tag::UnnecessaryThrowsTagRule;
+use mago_linter::plugin::analysis::rules::unreachable_catch_clause::UnreachableCatchClauseRule;
+use mago_reporting::Level;
 
 use crate::rule_test;
+use crate::TestOptions;
 
 rule_test!(test_inheritance, InheritanceRule);
 rule_test!(test_instantiation, InstantiationRule);
 rule_test!(test_undefined_constant, UndefinedConstantRule);
 rule_test!(test_undefined_function, UndefinedFunctionRule);
+rule_test!(test_unhandled_exception, UnhandledExceptionRule, TestOptions::level(Level::Warning).with_stubs());
+rule_test!(test_unnecessary_throws_tag, UnnecessaryThrowsTagRule, TestOptions::stubs());
+rule_test!(test_unreachable_catch_clause, UnreachableCatchClauseRule, TestOptions::stubs());
@@ -1,15 +1,210 @@
+use mago_linter::plugin::compatibility::rules::internal::incompatible_internal_function_call::IncompatibleInternalFunctionCallRule;
+use mago_linter::plugin::compatibility::rules::internal::unavailable_internal_class::UnavailableInternalClassRule;
+use mago_linter::plugin::compatibility::rules::internal::unavailable_internal_function::UnavailableInternalFunctionRule;
+use mago_linter::plugin::compatibility::rules::php73::flexible_heredocs_feature::FlexibleHeredocsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php74::arrow_functions_feature::ArrowFunctionsFeatureRule;
 use mago_linter::plugin::compatibility::rules::php74::null_coalesce_assignment_feature::NullCoalesceAssignmentFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::abstract_trait_methods_feature::AbstractTraitMethodsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::case_insensitive_constant_names_feature::CaseInsensitiveConstantNamesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::class_constant_on_expression_feature::ClassConstantOnExpressionFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::legacy_constructor_feature::LegacyConstructorFeatureRule;
 use mago_linter::plugin::compatibility::rules::php80::named_arguments_feature::NamedArgumentsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::non_capturing_catches_feature::NonCapturingCatchesFeatureRule;
 use mago_linter::plugin::compatibility::rules::php80::promoted_properties_feature::PromotedPropertiesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::throw_expression_feature::ThrowExpressionFeatureRule;
 use mago_linter::plugin::compatibility::rules::php80::union_type_hint_feature::UnionTypeHintFeatureRule;
+use mago_linter::plugin::compatibility::rules::php80::unset_cast_feature::UnsetCastFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::array_unpacking_with_string_keys_feature::ArrayUnpackingWithStringKeysFeatureRule;
 use mago_linter::plugin::compatibility::rules::php81::closure_creation_feature::ClosureCreationFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::enums_feature::EnumsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::final_constants_feature::FinalConstantsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::never_return_type_feature::NeverReturnTypeFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::new_in_initializers_feature::NewInInitializersFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::pure_intersection_types_feature::PureIntersectionTypesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php81::readonly_properties_feature::ReadonlyPropertiesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php82::constants_in_traits_feature::ConstantsInTraitsFeatureRule;
+use mago_linter::plugin::compatibility::rules::php82::disjunctive_normal_form_types_feature::DisjunctiveNormalFormTypesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php82::never_return_type_in_arrow_function_feature::NeverReturnTypeInArrowFunctionFeatureRule;
+use mago_linter::plugin::compatibility::rules::php82::readonly_classes_feature::ReadonlyClassesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php83::dynamic_class_constant_access_feature::DynamicClassConstantAccessFeatureRule;
+use mago_linter::plugin::compatibility::rules::php83::readonly_anonymous_classes_feature::ReadonlyAnonymousClassesFeatureRule;
+use mago_linter::plugin::compatibility::rules::php83::typed_class_constants_feature::TypedClassConstantsFeatureRule;
 use mago_linter::plugin::compatibility::rules::php84::asymmetric_visibility_feature::AsymmetricVisibilityFeatureRule;
+use mago_linter::plugin::compatibility::rules::php84::property_hooks_feature::PropertyHooksFeatureRule;
+use mago_linter::plugin::compatibility::rules::php85::closure_in_constant_expressions_feature::ClosureInConstantExpressionsFeatureRule;
+
+use mago_interner::ThreadedInterner;
+use mago_linter::definition::RuleUsageExample;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
 
 use crate::rule_test;
+use crate::test_rule_usage_example;
+use crate::TestOptions;
+
+rule_test!(test_incompatible_internal_function_call, IncompatibleInternalFunctionCallRule, TestOptions::stubs());
+rule_test!(test_unavailable_internal_class, UnavailableInternalClassRule, TestOptions::stubs());
+rule_test!(test_unavailable_internal_function, UnavailableInternalFunctionRule, TestOptions::stubs());
+rule_test!(test_flexible_heredocs_feature, FlexibleHeredocsFeatureRule, TestOptions::php_version(PHPVersion::PHP72));
+rule_test!(test_arrow_functions_feature, ArrowFunctionsFeatureRule, TestOptions::php_version(PHPVersion::PHP73));
+rule_test!(
+    test_null_coalesce_assignment_feature,
+    NullCoalesceAssignmentFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP73)
+);
+rule_test!(
+    test_abstract_trait_methods_feature,
+    AbstractTraitMethodsFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP74)
+);
+rule_test!(test_case_insensitive_constant_names_feature, CaseInsensitiveConstantNamesFeatureRule);
+rule_test!(test_legacy_constructor_feature, LegacyConstructorFeatureRule);
+rule_test!(
+    test_class_constant_on_expression_feature,
+    ClassConstantOnExpressionFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP74)
+);
+rule_test!(test_named_arguments_feature, NamedArgumentsFeatureRule, TestOptions::php_version(PHPVersion::PHP74));
+rule_test!(
+    test_non_capturing_catches_feature,
+    NonCapturingCatchesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP74)
+);
+rule_test!(
+    test_promoted_properties_feature,
+    PromotedPropertiesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP74)
+);
+rule_test!(test_throw_expression_feature, ThrowExpressionFeatureRule, TestOptions::php_version(PHPVersion::PHP74));
+rule_test!(test_union_type_hint_feature, UnionTypeHintFeatureRule, TestOptions::php_version(PHPVersion::PHP74));
+rule_test!(test_unset_cast_feature, UnsetCastFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(
+    test_array_unpacking_with_string_keys_feature,
+    ArrayUnpackingWithStringKeysFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP80)
+);
+rule_test!(test_closure_creation_feature, ClosureCreationFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(test_enums_feature, EnumsFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(test_final_constants_feature, FinalConstantsFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(test_never_return_type_feature, NeverReturnTypeFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(test_new_in_initializers_feature, NewInInitializersFeatureRule, TestOptions::php_version(PHPVersion::PHP80));
+rule_test!(
+    test_pure_intersection_types_feature,
+    PureIntersectionTypesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP80)
+);
+rule_test!(
+    test_readonly_properties_feature,
+    ReadonlyPropertiesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP80)
+);
+rule_test!(test_constants_in_traits_feature, ConstantsInTraitsFeatureRule, TestOptions::php_version(PHPVersion::PHP81));
+rule_test!(
+    test_disjunctive_normal_form_types_feature,
+    DisjunctiveNormalFormTypesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP81)
+);
+rule_test!(
+    test_never_return_type_in_arrow_function_feature,
+    NeverReturnTypeInArrowFunctionFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP81)
+);
+rule_test!(test_readonly_classes_feature, ReadonlyClassesFeatureRule, TestOptions::php_version(PHPVersion::PHP81));
+rule_test!(
+    test_dynamic_class_constant_access_feature,
+    DynamicClassConstantAccessFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP82)
+);
+rule_test!(
+    test_readonly_anonymous_classes_feature,
+    ReadonlyAnonymousClassesFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP82)
+);
+rule_test!(
+    test_typed_class_constants_feature,
+    TypedClassConstantsFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP82)
+);
+rule_test!(
+    test_asymmetric_visibility_feature,
+    AsymmetricVisibilityFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP83)
+);
+rule_test!(test_property_hooks_feature, PropertyHooksFeatureRule, TestOptions::php_version(PHPVersion::PHP83));
+rule_test!(
+    test_closure_in_constant_expressions_feature,
+    ClosureInConstantExpressionsFeatureRule,
+    TestOptions::php_version(PHPVersion::PHP84)
+);
+
+#[test]
+fn test_incompatible_internal_function_call_signatures() {
+    let examples = [
+        (PHPVersion::PHP74, RuleUsageExample::valid("Omitting the optional result array", "<?php parse_str($query);")),
+        (
+            PHPVersion::PHP80,
+            RuleUsageExample::valid(
+                "Passing the result array by name",
+                "<?php parse_str(string: $query, result: $result);",
+            ),
+        ),
+        (
+            PHPVersion::PHP80,
+            RuleUsageExample::invalid("Passing too many arguments", "<?php parse_str($query, $result, $extra);"),
+        ),
+        (PHPVersion::PHP84, RuleUsageExample::valid("Passing variadic values", "<?php sprintf('%s %s', $a, $b);")),
+        (PHPVersion::new(5, 6, 0), RuleUsageExample::invalid("Omitting the required values", "<?php sprintf('%s');")),
+    ];
+
+    for (php_version, example) in examples {
+        test_rule_usage_example(
+            Box::new(IncompatibleInternalFunctionCallRule),
+            &example,
+            TestOptions::stubs().with_php_version(php_version),
+        );
+    }
+}
+
+#[test]
+fn test_unavailable_internal_function_ignores_extension_versions() {
+    let stubs = [
+        ("stubs/standard/standard_9.php", "<?php\n\n/**\n * @since 8.0\n */\nfunction core_function() {}\n"),
+        ("stubs/redis/Redis.php", "<?php\n\n/**\n * @since 8.1\n */\nfunction extension_function() {}\n"),
+    ];
+
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let source_id = manager.insert_content(
+        "test.php".to_string(),
+        "<?php\n\ncore_function();\nextension_function();\n".to_string(),
+        SourceCategory::UserDefined,
+    );
+    let semantics = Semantics::build(&interner, manager.load(&source_id).unwrap());
+    let mut reflection = mago_reflector::reflect(&interner, &semantics.source, &semantics.program, &semantics.names);
+    for (name, content) in stubs {
+        let stub_id = manager.insert_content(name.to_string(), content.to_string(), SourceCategory::BuiltIn);
+        let stub_semantics = Semantics::build(&interner, manager.load(&stub_id).unwrap());
+
+        reflection = mago_reflector::merge(
+            &interner,
+            reflection,
+            mago_reflector::reflect(&interner, &stub_semantics.source, &stub_semantics.program, &stub_semantics.names),
+        );
+    }
+
+    mago_reflector::populate(&interner, &mut reflection);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP74), interner.clone(), reflection);
+    linter.add_rule("compatibility", Box::new(UnavailableInternalFunctionRule));
+
+    let issues = linter.lint(&semantics).into_iter().collect::<Vec<_>>();
 
-rule_test!(test_null_coalesce_assignment_feature, NullCoalesceAssignmentFeatureRule);
-rule_test!(test_named_arguments_feature, NamedArgumentsFeatureRule);
-rule_test!(test_promoted_properties_feature, PromotedPropertiesFeatureRule);
-rule_test!(test_union_type_hint_feature, UnionTypeHintFeatureRule);
-rule_test!(test_closure_creation_feature, ClosureCreationFeatureRule);
-rule_test!(test_asymmetric_visibility_feature, AsymmetricVisibilityFeatureRule);
+    // `@since 8.1` in the stubs of the redis extension is a version of the extension, not of PHP.
+    assert_eq!(issues.len(), 1, "Only the core function should be reported, but got: {:?}", issues);
+    assert!(issues[0].message.contains("core_function"));
+}
@@ -1,5 +1,19 @@
+use mago_linter::plugin::laravel::rules::best_practices::no_debug_helpers::NoDebugHelpersRule;
+use mago_linter::plugin::laravel::rules::best_practices::no_env_outside_config::NoEnvOutsideConfigRule;
+use mago_linter::plugin::laravel::rules::best_practices::no_facades_in_domain::NoFacadesInDomainRule;
+use mago_linter::plugin::laravel::rules::best_practices::no_route_closures::NoRouteClosuresRule;
+use mago_linter::plugin::laravel::rules::best_practices::relationship_should_declare_return_type::RelationshipShouldDeclareReturnTypeRule;
+use mago_linter::plugin::laravel::rules::safety::model_should_guard_attributes::ModelShouldGuardAttributesRule;
+use mago_linter::plugin::laravel::rules::safety::no_raw_query_interpolation::NoRawQueryInterpolationRule;
 use mago_linter::plugin::laravel::rules::safety::no_request_all::NoRequestAllRule;
 
 use crate::rule_test;
 
+rule_test!(test_model_should_guard_attributes, ModelShouldGuardAttributesRule);
+rule_test!(test_no_debug_helpers, NoDebugHelpersRule);
+rule_test!(test_no_env_outside_config, NoEnvOutsideConfigRule);
+rule_test!(test_no_facades_in_domain, NoFacadesInDomainRule);
+rule_test!(test_no_raw_query_interpolation, NoRawQueryInterpolationRule);
 rule_test!(test_no_request_all, NoRequestAllRule);
+rule_test!(test_no_route_closures, NoRouteClosuresRule);
+rule_test!(test_relationship_should_declare_return_type, RelationshipShouldDeclareReturnTypeRule);
@@ -2,6 +2,10 @@ use mago_linter::plugin::migration::rules::php80::str_contains::StrContainsRule;
 use mago_linter::plugin::migration::rules::php80::str_starts_with::StrStartsWithRule;
 use mago_linter::plugin::migration::rules::php81::explicit_octal_notation::ExplicitOctalNotationRule;
 use mago_linter::plugin::migration::rules::php82::readonly_class_promotion::ReadonlyClassPromotionRule;
+use mago_linter::plugin::migration::rules::php83::json_validate::JsonValidateRule;
+use mago_linter::plugin::migration::rules::php83::override_attribute::OverrideAttributeRule;
+use mago_linter::plugin::migration::rules::php83::typed_class_constant::TypedClassConstantRule;
+use mago_linter::plugin::migration::rules::php84::new_without_parentheses::NewWithoutParenthesesRule;
 
 use crate::rule_test;
 
@@ -9,3 +13,7 @@ rule_test!(test_str_starts_with, StrStartsWithRule);
 rule_test!(test_str_contains, StrContainsRule);
 rule_test!(test_explicit_octal_notation, ExplicitOctalNotationRule);
 rule_test!(test_readonly_class_promotion, ReadonlyClassPromotionRule);
+rule_test!(test_json_validate, JsonValidateRule);
+rule_test!(test_override_attribute, OverrideAttributeRule);
+rule_test!(test_typed_class_constant, TypedClassConstantRule);
+rule_test!(test_new_without_parentheses, NewWithoutParenthesesRule);
@@ -13,3 +13,4 @@ pub mod redundancy;
 pub mod safety;
 pub mod strictness;
 pub mod symfony;
+pub mod taint;
@@ -2,8 +2,12 @@ use mago_linter::plugin::naming::rules::class::ClassRule;
 use mago_linter::plugin::naming::rules::constant::ConstantRule;
 use mago_linter::plugin::naming::rules::function::FunctionRule;
 use mago_linter::plugin::naming::rules::interface::InterfaceRule;
+use mago_linter::plugin::naming::rules::method::MethodRule;
+use mago_linter::plugin::naming::rules::parameter::ParameterRule;
+use mago_linter::plugin::naming::rules::property::PropertyRule;
 use mago_linter::plugin::naming::rules::r#enum::EnumRule;
 use mago_linter::plugin::naming::rules::r#trait::TraitRule;
+use mago_linter::plugin::naming::rules::variable::VariableRule;
 
 use crate::rule_test;
 
@@ -12,4 +16,8 @@ rule_test!(test_constant, ConstantRule);
 rule_test!(test_enum, EnumRule);
 rule_test!(test_function, FunctionRule);
 rule_test!(test_interface, InterfaceRule);
+rule_test!(test_method, MethodRule);
+rule_test!(test_parameter, ParameterRule);
+rule_test!(test_property, PropertyRule);
 rule_test!(test_trait, TraitRule);
+rule_test!(test_variable, VariableRule);
@@ -1,9 +1,187 @@
+use indoc::indoc;
+
+use mago_fixer::FixPlan;
+use mago_interner::ThreadedInterner;
 use mago_linter::plugin::phpunit::rules::consistency::assertions_style::AssertionsStyleRule;
+use mago_linter::plugin::phpunit::rules::consistency::set_up_should_return_void::SetUpShouldReturnVoidRule;
+use mago_linter::plugin::phpunit::rules::correctness::data_provider_should_be_valid::DataProviderShouldBeValidRule;
+use mago_linter::plugin::phpunit::rules::correctness::no_final_class_mock::NoFinalClassMockRule;
+use mago_linter::plugin::phpunit::rules::correctness::test_should_perform_assertions::TestShouldPerformAssertionsRule;
+use mago_linter::plugin::phpunit::rules::deprecation::no_set_methods::NoSetMethodsRule;
+use mago_linter::plugin::phpunit::rules::migration::attributes_over_annotations::AttributesOverAnnotationsRule;
 use mago_linter::plugin::phpunit::rules::redundancy::redundant_instanceof::RedundantInstanceOfRule;
 use mago_linter::plugin::phpunit::rules::strictness::strict_assertions::StrictAssertionsRule;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
 
 use crate::rule_test;
 
 rule_test!(test_assertions_style, AssertionsStyleRule);
 rule_test!(test_strict_assertions, StrictAssertionsRule);
 rule_test!(test_redundant_instanceof, RedundantInstanceOfRule);
+rule_test!(test_set_up_should_return_void, SetUpShouldReturnVoidRule);
+rule_test!(test_data_provider_should_be_valid, DataProviderShouldBeValidRule);
+rule_test!(test_no_final_class_mock, NoFinalClassMockRule);
+rule_test!(test_test_should_perform_assertions, TestShouldPerformAssertionsRule);
+rule_test!(test_no_set_methods, NoSetMethodsRule);
+rule_test!(test_attributes_over_annotations, AttributesOverAnnotationsRule);
+
+/// Lints the given code with the attributes over annotations rule, and applies the fixes of all reported issues.
+fn fix_annotations(code: &str) -> String {
+    let interner = ThreadedInterner::new();
+    let source_manager = SourceManager::new(interner.clone());
+    let source_id =
+        source_manager.insert_content("test.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+    let semantics = Semantics::build(&interner, source_manager.load(&source_id).unwrap());
+    let mut codebase = mago_reflector::reflect(&interner, &semantics.source, &semantics.program, &semantics.names);
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP84), interner.clone(), codebase);
+    linter.add_rule("phpunit", Box::new(AttributesOverAnnotationsRule));
+
+    let mut plan = FixPlan::new();
+    for mut issue in linter.lint(&semantics) {
+        for (_, suggestion) in issue.take_suggestions() {
+            plan.merge(suggestion);
+        }
+    }
+
+    plan.execute(code).get_fixed()
+}
+
+#[test]
+fn test_attributes_over_annotations_fix_replaces_tags() {
+    let code = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\TestCase;
+
+        /**
+         * Tests the calculator.
+         *
+         * @covers \App\Calculator
+         * @group math
+         */
+        final class CalculatorTest extends TestCase
+        {
+            /**
+             * Checks the sum.
+             *
+             * @dataProvider provideSums
+             */
+            public function testSum(int $a, int $b, int $expected): void
+            {
+                $this->assertSame($expected, $a + $b);
+            }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\TestCase;
+
+        /**
+         * Tests the calculator.
+         */
+        #[\PHPUnit\Framework\Attributes\CoversClass(\App\Calculator::class)]
+        #[\PHPUnit\Framework\Attributes\Group('math')]
+        final class CalculatorTest extends TestCase
+        {
+            /**
+             * Checks the sum.
+             */
+            #[\PHPUnit\Framework\Attributes\DataProvider('provideSums')]
+            public function testSum(int $a, int $b, int $expected): void
+            {
+                $this->assertSame($expected, $a + $b);
+            }
+        }
+    "#};
+
+    assert_eq!(fix_annotations(code), expected);
+}
+
+#[test]
+fn test_attributes_over_annotations_fix_removes_empty_docblock() {
+    let code = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\TestCase;
+
+        final class CalculatorTest extends TestCase
+        {
+            /**
+             * @dataProvider provideSums
+             * @group math
+             */
+            public function testSum(int $a, int $b, int $expected): void
+            {
+                $this->assertSame($expected, $a + $b);
+            }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\TestCase;
+
+        final class CalculatorTest extends TestCase
+        {
+            #[\PHPUnit\Framework\Attributes\DataProvider('provideSums')]
+            #[\PHPUnit\Framework\Attributes\Group('math')]
+            public function testSum(int $a, int $b, int $expected): void
+            {
+                $this->assertSame($expected, $a + $b);
+            }
+        }
+    "#};
+
+    assert_eq!(fix_annotations(code), expected);
+}
+
+#[test]
+fn test_attributes_over_annotations_fix_keeps_existing_attributes() {
+    let code = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\Attributes\TestDox;
+        use PHPUnit\Framework\TestCase;
+
+        final class CalculatorTest extends TestCase
+        {
+            /**
+             * @group math
+             */
+            #[TestDox('adds numbers')]
+            public function testSum(): void
+            {
+                $this->assertSame(3, 1 + 2);
+            }
+        }
+    "#};
+
+    let expected = indoc! {r#"
+        <?php
+
+        use PHPUnit\Framework\Attributes\TestDox;
+        use PHPUnit\Framework\TestCase;
+
+        final class CalculatorTest extends TestCase
+        {
+            #[\PHPUnit\Framework\Attributes\Group('math')]
+            #[TestDox('adds numbers')]
+            public function testSum(): void
+            {
+                $this->assertSame(3, 1 + 2);
+            }
+        }
+    "#};
+
+    assert_eq!(fix_annotations(code), expected);
+}
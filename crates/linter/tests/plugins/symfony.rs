@@ -1,5 +1,91 @@
+use indoc::indoc;
+
+use mago_interner::ThreadedInterner;
+use mago_linter::plugin::symfony::rules::correctness::route_name_should_be_unique::RouteNameShouldBeUniqueRule;
+use mago_linter::plugin::symfony::rules::correctness::route_parameters_should_match_arguments::RouteParametersShouldMatchArgumentsRule;
+use mago_linter::plugin::symfony::rules::correctness::subscribed_event_method_should_exist::SubscribedEventMethodShouldExistRule;
+use mago_linter::plugin::symfony::rules::quality::command_should_use_as_command_attribute::CommandShouldUseAsCommandAttributeRule;
+use mago_linter::plugin::symfony::rules::quality::controller_action_should_return_response::ControllerActionShouldReturnResponseRule;
 use mago_linter::plugin::symfony::rules::quality::interface_should_be_used::InterfaceShouldBeUsed;
+use mago_linter::plugin::symfony::rules::quality::no_container_get_in_controller::NoContainerGetInControllerRule;
+use mago_linter::plugin::symfony::rules::quality::no_service_locator::NoServiceLocatorRule;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_semantics::Semantics;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
 
 use crate::rule_test;
 
+rule_test!(test_command_should_use_as_command_attribute, CommandShouldUseAsCommandAttributeRule);
+rule_test!(test_controller_action_should_return_response, ControllerActionShouldReturnResponseRule);
 rule_test!(test_interface_should_be_used, InterfaceShouldBeUsed);
+rule_test!(test_no_container_get_in_controller, NoContainerGetInControllerRule);
+rule_test!(test_no_service_locator, NoServiceLocatorRule);
+rule_test!(test_route_name_should_be_unique, RouteNameShouldBeUniqueRule::default());
+rule_test!(test_route_parameters_should_match_arguments, RouteParametersShouldMatchArgumentsRule);
+rule_test!(test_subscribed_event_method_should_exist, SubscribedEventMethodShouldExistRule);
+
+#[test]
+fn test_route_name_should_be_unique_reports_overridden_routes_across_files() {
+    let controller = |class: &str, path: &str| {
+        format!(
+            indoc! {r#"
+                <?php
+
+                use Symfony\Component\Routing\Attribute\Route;
+
+                final class {}
+                {{
+                    #[Route('{}', name: 'blog')]
+                    public function index(): void
+                    {{
+                    }}
+                }}
+            "#},
+            class, path
+        )
+    };
+
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    // The sources are inserted out of order, Symfony loads `a.php` first, so its route is the one overridden.
+    let sources = [
+        ("c.php", controller("ThirdController", "/third")),
+        ("a.php", controller("FirstController", "/first")),
+        ("b.php", controller("SecondController", "/second")),
+    ];
+
+    let mut semantics = vec![];
+    let mut reflection = None;
+    for (name, content) in sources {
+        let source_id = manager.insert_content(name.to_string(), content, SourceCategory::UserDefined);
+        let source_semantics = Semantics::build(&interner, manager.load(&source_id).unwrap());
+        let source = manager.load(&source_id).unwrap();
+        let source_reflection =
+            mago_reflector::reflect(&interner, &source, &source_semantics.program, &source_semantics.names);
+
+        reflection = Some(match reflection {
+            Some(reflection) => mago_reflector::merge(&interner, reflection, source_reflection),
+            None => source_reflection,
+        });
+        semantics.push((name, source_semantics));
+    }
+
+    let mut reflection = reflection.unwrap();
+    mago_reflector::populate(&interner, &mut reflection);
+
+    let mut linter = Linter::new(Settings::new(PHPVersion::PHP84), interner.clone(), reflection);
+    linter.add_rule("symfony", Box::new(RouteNameShouldBeUniqueRule::default()));
+
+    for (name, semantics) in semantics {
+        let issues = linter.lint(&semantics);
+
+        match name {
+            "c.php" => assert!(issues.is_empty(), "The last route should be kept, but got: {:?}", issues),
+            _ => assert_eq!(issues.len(), 1, "The route in `{}` should be reported as overridden.", name),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use mago_interner::ThreadedInterner;
+use mago_linter::plugin::taint::TaintPlugin;
+use mago_linter::settings::Settings;
+use mago_linter::Linter;
+use mago_php_version::PHPVersion;
+use mago_reflection::CodebaseReflection;
+use mago_taint::configuration::TaintConfiguration;
+use mago_taint::TaintAnalyzer;
+
+fn create_linter(settings: Settings) -> Linter {
+    let mut linter = Linter::new(settings, ThreadedInterner::new(), CodebaseReflection::new());
+    linter.add_plugin(TaintPlugin::new(Arc::new(TaintAnalyzer::new(&TaintConfiguration::default()))));
+
+    linter
+}
+
+#[test]
+fn test_taint_plugin_is_disabled_by_default() {
+    let linter = create_linter(Settings::new(PHPVersion::PHP84));
+
+    assert!(!linter.is_plugin_enabled(&TaintPlugin::definition()));
+    assert!(linter.get_configured_rules().iter().all(|rule| !rule.slug.starts_with("taint/")));
+}
+
+#[test]
+fn test_taint_plugin_is_enabled_through_the_plugins_setting() {
+    let linter = create_linter(Settings::new(PHPVersion::PHP84).with_plugins(vec!["taint".to_string()]));
+
+    assert!(linter.is_plugin_enabled(&TaintPlugin::definition()));
+    assert_eq!(linter.get_configured_rules().iter().filter(|rule| rule.slug.starts_with("taint/")).count(), 6);
+}
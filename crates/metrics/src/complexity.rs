@@ -0,0 +1,217 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+
+/// Returns the number of decision points under the given node.
+///
+/// The cyclomatic complexity of a function-like is one more than the number of its decision points.
+pub fn get_decision_points(node: Node<'_>) -> usize {
+    let mut number = 0;
+
+    for child in node.children() {
+        number += get_decision_points(child);
+    }
+
+    match node {
+        Node::If(_)
+        | Node::IfStatementBodyElseIfClause(_)
+        | Node::IfColonDelimitedBodyElseIfClause(_)
+        | Node::For(_)
+        | Node::Foreach(_)
+        | Node::While(_)
+        | Node::DoWhile(_)
+        | Node::TryCatchClause(_)
+        | Node::Conditional(_) => number += 1,
+        Node::Binary(operation) => match operation.operator {
+            operator if operator.is_logical() || operator.is_null_coalesce() => number += 1,
+            BinaryOperator::Spaceship(_) => number += 2,
+            _ => (),
+        },
+        Node::SwitchCase(case) if case.is_default() => {
+            number += 1;
+        }
+        _ => (),
+    }
+
+    number
+}
+
+/// Returns the cyclomatic complexity of a method, or `None` if the method is a getter or a setter.
+///
+/// Abstract methods have a complexity of 1.
+pub fn get_method_cyclomatic_complexity(interner: &ThreadedInterner, method: &Method) -> Option<usize> {
+    if is_method_setter_or_getter(interner, method) {
+        return None;
+    }
+
+    Some(if method.is_abstract() { 1 } else { get_decision_points(Node::Method(method)) + 1 })
+}
+
+/// Returns the decision points of the methods of a class-like, excluding getters and setters.
+pub fn get_class_like_decision_points(interner: &ThreadedInterner, members: &[ClassLikeMember]) -> usize {
+    let mut decision_points = 0;
+    for member in members {
+        let ClassLikeMember::Method(method) = member else {
+            continue;
+        };
+
+        let Some(method_cyclomatic_complexity) = get_method_cyclomatic_complexity(interner, method) else {
+            continue;
+        };
+
+        decision_points += method_cyclomatic_complexity - 1;
+    }
+
+    decision_points
+}
+
+/// Returns the *Kan Defect* value for a given AST node by counting
+/// the number of select statements, loop statements, and `if` statements,
+/// then applying the **Kan Defect** formula.
+pub fn get_kan_defect(node: Node<'_>) -> f64 {
+    let (select_count, while_count, if_count) = collect_defect_factors(node);
+
+    calculate_kan_defect(select_count, while_count, if_count)
+}
+
+/// Returns whether the given method is a simple getter or setter of a property of `$this`.
+pub fn is_method_setter_or_getter(interner: &ThreadedInterner, method: &Method) -> bool {
+    let MethodBody::Concrete(block) = &method.body else {
+        return false;
+    };
+
+    let statements_len = block.statements.len();
+    if statements_len > 2 {
+        return false;
+    }
+
+    let Some(statement) = block.statements.first() else {
+        return false;
+    };
+
+    match statement {
+        Statement::Return(return_statement) if method.parameter_list.parameters.is_empty() => {
+            let Some(expression) = &return_statement.value else {
+                return false;
+            };
+
+            if !is_accessing_property_of_this(interner, expression) {
+                return false;
+            }
+
+            statements_len == 1
+        }
+        Statement::Expression(expression_statement) if method.parameter_list.parameters.len() == 1 => {
+            let Expression::AssignmentOperation(assignment) = expression_statement.expression.as_ref() else {
+                return false;
+            };
+
+            if !is_accessing_property_of_this(interner, assignment.lhs.as_ref()) {
+                return false;
+            }
+
+            match block.statements.last() {
+                Some(statement) => match statement {
+                    Statement::Return(return_statement) => {
+                        let Some(expression) = &return_statement.value else {
+                            return false;
+                        };
+
+                        is_variable_named(interner, expression, "$this")
+                    }
+                    _ => false,
+                },
+                None => true,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Computes the final *Kan Defect* value given the counts of select
+/// statements (`switch`/`match`), loop statements (`do…while`, `while`,
+/// `foreach`), and `if` statements.
+///
+/// This formula is taken from the phpmetrics “Kan Defect” metric:
+///
+/// ```text
+/// defect = 0.15
+///        + 0.23 * (number of loops)
+///        + 0.22 * (number of selects)
+///        + 0.07 * (number of ifs)
+/// ```
+///
+/// Note that these coefficients (0.15, 0.23, 0.22, 0.07) are an *approximation*
+/// and are not part of a standard software metric outside phpmetrics.
+///
+/// See: https://github.com/phpmetrics/PhpMetrics/blob/c43217cd7783bbd54d0b8c1dd43f697bc36ef79d/src/Hal/Metric/Class_/Complexity/KanDefectVisitor.php#L60C13-L60C76
+fn calculate_kan_defect(select: usize, r#while: usize, r#if: usize) -> f64 {
+    let select = select as f64;
+    let r#while = r#while as f64;
+    let r#if = r#if as f64;
+
+    0.15 + 0.23 * r#while + 0.22 * select + 0.07 * r#if
+}
+
+/// Recursively traverses the given AST node, counting:
+///
+/// 1. **Select statements** (i.e., `switch` or `match`)
+/// 2. **Loop statements** (i.e., `do…while`, `while`, `foreach`)
+/// 3. **If statements** (`if`)
+///
+/// Returns a tuple `(select_count, while_count, if_count)` representing
+/// how many of each type of statement appear under the given node and all
+/// its descendants.
+fn collect_defect_factors(node: Node<'_>) -> (usize, usize, usize) {
+    let mut select_count = 0;
+    let mut while_count = 0;
+    let mut if_count = 0;
+
+    // Recurse through child nodes
+    for child in node.children() {
+        let (child_select, child_while, child_if) = collect_defect_factors(child);
+
+        select_count += child_select;
+        while_count += child_while;
+        if_count += child_if;
+    }
+
+    // Check the current node's type
+    match node {
+        Node::Switch(_) | Node::Match(_) => {
+            select_count += 1;
+        }
+        Node::DoWhile(_) | Node::While(_) | Node::Foreach(_) => {
+            while_count += 1;
+        }
+        Node::If(_) => {
+            if_count += 1;
+        }
+        _ => (),
+    }
+
+    (select_count, while_count, if_count)
+}
+
+fn is_accessing_property_of_this(interner: &ThreadedInterner, expression: &Expression) -> bool {
+    let Expression::Access(access) = expression else {
+        return false;
+    };
+
+    let Access::Property(property_access) = access else {
+        return false;
+    };
+
+    is_variable_named(interner, &property_access.object, "$this")
+}
+
+fn is_variable_named(interner: &ThreadedInterner, expression: &Expression, name: &str) -> bool {
+    let Expression::Variable(variable) = expression else {
+        return false;
+    };
+
+    let Variable::Direct(direct_variable) = variable else {
+        return false;
+    };
+
+    interner.lookup(&direct_variable.name) == name
+}
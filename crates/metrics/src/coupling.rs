@@ -0,0 +1,184 @@
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::r#type::kind::ArrayTypeKind;
+use mago_reflection::r#type::kind::CallableTypeKind;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::r#type::TypeReflection;
+use mago_walker::MutWalker;
+
+/// Returns the lowercased names of the class-likes that the given class-like depends on.
+///
+/// The dependencies are the class-likes it extends, implements, or uses, the class-likes appearing
+/// in the types of its properties, and in the signatures of its methods, and the given class-likes
+/// referenced in the bodies of its members, see [`get_referenced_class_likes`].
+pub(crate) fn get_dependencies(
+    interner: &ThreadedInterner,
+    class_like: &ClassLikeReflection,
+    references: &HashSet<StringIdentifier>,
+) -> HashSet<StringIdentifier> {
+    let mut names = references.clone();
+
+    let inheritance = &class_like.inheritance;
+    names.extend(inheritance.direct_extended_class.iter().map(|name| name.value));
+    names.extend(inheritance.direct_implemented_interfaces.iter().map(|name| name.value));
+    names.extend(inheritance.direct_extended_interfaces.iter().map(|name| name.value));
+    names.extend(class_like.used_traits.iter().copied());
+
+    let mut types: Vec<&TypeReflection> = vec![];
+    for property in class_like.properties.members.values() {
+        types.extend(&property.type_reflection);
+    }
+
+    for method in class_like.methods.members.values() {
+        types.extend(method.parameters.iter().filter_map(|parameter| parameter.type_reflection.as_ref()));
+        types.extend(method.return_type_reflection.as_ref().map(|r#return| &r#return.type_reflection));
+    }
+
+    for type_reflection in types {
+        collect_class_names(&type_reflection.kind, &mut names);
+    }
+
+    let mut dependencies: HashSet<_> = names.iter().map(|name| interner.lowered(name)).collect();
+    if let Some(name) = class_like.name.inner() {
+        dependencies.remove(&interner.lowered(&name.value));
+    }
+
+    dependencies
+}
+
+/// Returns the names of the class-likes referenced in the bodies of the given members.
+///
+/// A class-like is referenced when it is instantiated, when one of its static methods, static properties,
+/// or constants is accessed, when it is the right-hand side of `instanceof`, or when it is caught.
+pub(crate) fn get_referenced_class_likes(names: &Names, members: &[ClassLikeMember]) -> HashSet<StringIdentifier> {
+    let mut collector = ReferenceCollector { names, references: HashSet::default() };
+    for member in members {
+        collector.walk_class_like_member(member, &mut ());
+    }
+
+    collector.references
+}
+
+struct ReferenceCollector<'a> {
+    names: &'a Names,
+    references: HashSet<StringIdentifier>,
+}
+
+impl ReferenceCollector<'_> {
+    fn collect_expression(&mut self, expression: &Expression) {
+        if let Expression::Identifier(identifier) = expression {
+            self.references.insert(*self.names.get(identifier));
+        }
+    }
+
+    fn collect_hint(&mut self, hint: &Hint) {
+        match hint {
+            Hint::Identifier(identifier) => {
+                self.references.insert(*self.names.get(identifier));
+            }
+            Hint::Parenthesized(parenthesized) => self.collect_hint(&parenthesized.hint),
+            Hint::Nullable(nullable) => self.collect_hint(&nullable.hint),
+            Hint::Union(union) => {
+                self.collect_hint(&union.left);
+                self.collect_hint(&union.right);
+            }
+            Hint::Intersection(intersection) => {
+                self.collect_hint(&intersection.left);
+                self.collect_hint(&intersection.right);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl MutWalker<()> for ReferenceCollector<'_> {
+    fn walk_in_instantiation(&mut self, instantiation: &Instantiation, _context: &mut ()) {
+        self.collect_expression(&instantiation.class);
+    }
+
+    fn walk_in_static_method_call(&mut self, static_method_call: &StaticMethodCall, _context: &mut ()) {
+        self.collect_expression(&static_method_call.class);
+    }
+
+    fn walk_in_static_method_closure_creation(
+        &mut self,
+        static_method_closure_creation: &StaticMethodClosureCreation,
+        _context: &mut (),
+    ) {
+        self.collect_expression(&static_method_closure_creation.class);
+    }
+
+    fn walk_in_static_property_access(&mut self, static_property_access: &StaticPropertyAccess, _context: &mut ()) {
+        self.collect_expression(&static_property_access.class);
+    }
+
+    fn walk_in_class_constant_access(&mut self, class_constant_access: &ClassConstantAccess, _context: &mut ()) {
+        self.collect_expression(&class_constant_access.class);
+    }
+
+    fn walk_in_binary(&mut self, binary: &Binary, _context: &mut ()) {
+        if let BinaryOperator::Instanceof(_) = binary.operator {
+            self.collect_expression(&binary.rhs);
+        }
+    }
+
+    fn walk_in_try_catch_clause(&mut self, try_catch_clause: &TryCatchClause, _context: &mut ()) {
+        self.collect_hint(&try_catch_clause.hint);
+    }
+}
+
+fn collect_class_names(kind: &TypeKind, names: &mut HashSet<StringIdentifier>) {
+    match kind {
+        TypeKind::Union { kinds } | TypeKind::Intersection { kinds } => {
+            for kind in kinds {
+                collect_class_names(kind, names);
+            }
+        }
+        TypeKind::Object(object) => match object {
+            ObjectTypeKind::NamedObject { name, type_parameters } => {
+                names.insert(*name);
+
+                for kind in type_parameters {
+                    collect_class_names(kind, names);
+                }
+            }
+            ObjectTypeKind::EnumCase { enum_name, .. } => {
+                names.insert(*enum_name);
+            }
+            ObjectTypeKind::Generator { key, value, send, r#return } => {
+                for kind in [key, value, send, r#return] {
+                    collect_class_names(kind, names);
+                }
+            }
+            _ => {}
+        },
+        TypeKind::Array(ArrayTypeKind::Array { key, value, .. }) => {
+            collect_class_names(key, names);
+            collect_class_names(value, names);
+        }
+        TypeKind::Array(ArrayTypeKind::List { value, .. }) => {
+            collect_class_names(value, names);
+        }
+        TypeKind::Iterable { key, value } => {
+            collect_class_names(key, names);
+            collect_class_names(value, names);
+        }
+        TypeKind::Callable(
+            CallableTypeKind::Callable { parameters, return_kind, .. }
+            | CallableTypeKind::Closure { parameters, return_kind, .. },
+        ) => {
+            for parameter in parameters {
+                collect_class_names(&parameter.kind, names);
+            }
+
+            collect_class_names(return_kind, names);
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,132 @@
+use ahash::HashSet;
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_ast::node::NodeKind;
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+
+/// The Halstead measures of a piece of code, computed from the operators and operands it contains.
+///
+/// See: https://en.wikipedia.org/wiki/Halstead_complexity_measures
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Halstead {
+    /// The volume (V), estimating the size of the code.
+    pub volume: f64,
+    /// The difficulty (D), reflecting how hard the code is to write or understand.
+    pub difficulty: f64,
+    /// The effort (E), estimating the mental effort required to develop or maintain the code.
+    pub effort: f64,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct Operator(NodeKind);
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+struct Operand(StringIdentifier);
+
+impl Halstead {
+    /// Computes the Halstead measures of the code under the given node, excluding nested declarations.
+    pub fn measure(node: Node<'_>) -> Self {
+        let (operators, operands) = gather_operators_and_operands(node);
+
+        compute_halstead_metrics(&operators, &operands)
+    }
+}
+
+fn gather_operators_and_operands(node: Node<'_>) -> (Vec<Operator>, Vec<Operand>) {
+    let mut operators = Vec::new();
+    let mut operands = Vec::new();
+
+    fn recurse(n: Node<'_>, ops: &mut Vec<Operator>, rands: &mut Vec<Operand>) {
+        if n.is_declaration() {
+            return;
+        }
+
+        for child in n.children() {
+            recurse(child, ops, rands);
+        }
+
+        categorize_node(n, ops, rands);
+    }
+
+    for child in node.children() {
+        recurse(child, &mut operators, &mut operands);
+    }
+
+    (operators, operands)
+}
+
+/// Check if the node is considered an operator or operand in Halstead terms
+/// and record a textual representation.
+fn categorize_node(node: Node<'_>, operators: &mut Vec<Operator>, operands: &mut Vec<Operand>) {
+    match node {
+        Node::Binary(_)
+        | Node::AssignmentOperation(_)
+        | Node::If(_)
+        | Node::IfStatementBodyElseIfClause(_)
+        | Node::IfColonDelimitedBodyElseIfClause(_)
+        | Node::For(_)
+        | Node::Switch(_)
+        | Node::TryCatchClause(_)
+        | Node::Return(_)
+        | Node::While(_)
+        | Node::DoWhile(_) => {
+            operators.push(Operator(node.kind()));
+        }
+        Node::UnaryPrefix(unary) if unary.operator.is_cast() => {
+            operators.push(Operator(node.kind()));
+        }
+        Node::DirectVariable(variable) => {
+            operands.push(Operand(variable.name));
+        }
+        Node::LiteralString(literal) => {
+            operands.push(Operand(literal.value));
+        }
+        Node::LiteralInteger(literal) => {
+            operands.push(Operand(literal.raw));
+        }
+        Node::LiteralFloat(literal) => {
+            operands.push(Operand(literal.raw));
+        }
+        _ => (),
+    }
+}
+
+/// Computes the Halstead metrics from the given operators and operands.
+///
+/// **Important**: if `n2 == 0` or `N2 == 0`, we set all metrics to 0
+/// (mirroring the original phpmetrics approach).
+fn compute_halstead_metrics(operators: &[Operator], operands: &[Operand]) -> Halstead {
+    let unique_ops: HashSet<_> = operators.iter().collect();
+    let unique_operands: HashSet<_> = operands.iter().collect();
+
+    let n1 = unique_ops.len();
+    let n2 = unique_operands.len();
+    let total_n1 = operators.len();
+    let total_n2 = operands.len();
+
+    if n2 == 0 || total_n2 == 0 {
+        return Halstead::default();
+    }
+
+    let n1_f = n1 as f64;
+    let n2_f = n2 as f64;
+    let total_n1_f = total_n1 as f64;
+    let total_n2_f = total_n2 as f64;
+
+    let n = n1_f + n2_f;
+    let total_n = total_n1_f + total_n2_f;
+
+    let volume = if n > 0.0 { total_n * n.log2() } else { 0.0 };
+    let difficulty = (n1_f / 2.0) * (total_n2_f / n2_f.max(1.0));
+    let effort = volume * difficulty;
+
+    Halstead { volume: round2(volume), difficulty: round2(difficulty), effort: round2(effort) }
+}
+
+/// Utility to round a floating-point number to two decimal places.
+#[inline]
+pub(crate) fn round2(val: f64) -> f64 {
+    (val * 100.0).round() / 100.0
+}
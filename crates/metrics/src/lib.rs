@@ -0,0 +1,210 @@
+//! Code metrics for PHP.
+//!
+//! Metrics are measured for every file, and for every function, class-like, and method it declares:
+//! line counts, cyclomatic complexity, Halstead measures, Kan defect, and the maintainability index.
+//!
+//! Coupling and depth of inheritance depend on the rest of the codebase, and are measured afterwards
+//! from its reflection, using [`CodebaseMetrics::measure_coupling`].
+
+use ahash::HashMap;
+use ahash::HashSet;
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+
+use mago_ast::Program;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::CodebaseReflection;
+use mago_source::Source;
+
+use crate::halstead::Halstead;
+use crate::lines::Lines;
+
+pub mod complexity;
+pub mod halstead;
+pub mod lines;
+pub mod maintainability;
+pub mod output;
+
+mod coupling;
+mod walker;
+
+/// The kind of a measured function-like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum FunctionLikeKind {
+    Function,
+    Method,
+}
+
+/// The kind of a measured class-like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ClassLikeKind {
+    Class,
+    Interface,
+    Trait,
+    Enum,
+}
+
+/// The metrics of a function or a method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionLikeMetrics {
+    /// The fully qualified name of the function, or `Class::method` for methods.
+    pub name: String,
+    /// Whether this is a function or a method.
+    pub kind: FunctionLikeKind,
+    /// The line on which the function-like starts, starting from 1.
+    pub line: usize,
+    /// The line counts of the function-like.
+    pub lines: Lines,
+    /// The number of parameters.
+    pub parameters: usize,
+    /// The cyclomatic complexity, i.e. one more than the number of decision points.
+    pub cyclomatic_complexity: usize,
+    /// The Halstead measures of the body.
+    pub halstead: Halstead,
+    /// The Kan defect density.
+    pub kan_defect: f64,
+    /// The maintainability index.
+    pub maintainability_index: f64,
+}
+
+/// The metrics of a class, an interface, a trait, or an enum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassLikeMetrics {
+    /// The fully qualified name of the class-like.
+    pub name: String,
+    /// Whether this is a class, an interface, a trait, or an enum.
+    pub kind: ClassLikeKind,
+    /// The line on which the class-like starts, starting from 1.
+    pub line: usize,
+    /// The line counts of the class-like.
+    pub lines: Lines,
+    /// The number of properties, where each property of a declaration listing several counts.
+    pub properties: usize,
+    /// The cyclomatic complexity, i.e. one more than the decision points of its methods, excluding getters and setters.
+    pub cyclomatic_complexity: usize,
+    /// The Kan defect density.
+    pub kan_defect: f64,
+    /// The maintainability index, computed from the Halstead volume of all its methods.
+    pub maintainability_index: f64,
+    /// The number of user-defined class-likes depending on this class-like (Ca).
+    pub afferent_coupling: usize,
+    /// The number of class-likes this class-like depends on (Ce), through its declaration or the bodies of its members.
+    pub efferent_coupling: usize,
+    /// The instability, i.e. `Ce / (Ca + Ce)`, or 0 if the class-like is not coupled at all.
+    pub instability: f64,
+    /// The number of classes this class extends, directly or through its parents.
+    pub depth_of_inheritance: usize,
+    /// The metrics of the methods.
+    pub methods: Vec<FunctionLikeMetrics>,
+    /// The class-likes referenced in the bodies of the members, used to measure the coupling.
+    #[serde(skip)]
+    pub(crate) references: HashSet<StringIdentifier>,
+}
+
+/// The metrics of a source file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileMetrics {
+    /// The name of the source file.
+    pub name: String,
+    /// The line counts of the file.
+    pub lines: Lines,
+    /// The cyclomatic complexity, i.e. one more than the decision points of all the code in the file.
+    pub cyclomatic_complexity: usize,
+    /// The Kan defect density.
+    pub kan_defect: f64,
+    /// The maintainability index, computed from the Halstead volume of all the code in the file.
+    pub maintainability_index: f64,
+    /// The metrics of the functions declared in the file.
+    pub functions: Vec<FunctionLikeMetrics>,
+    /// The metrics of the class-likes declared in the file.
+    pub class_likes: Vec<ClassLikeMetrics>,
+}
+
+/// The metrics of all the source files of a codebase.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CodebaseMetrics {
+    /// The metrics of each source file, sorted by name.
+    pub files: Vec<FileMetrics>,
+}
+
+impl FileMetrics {
+    /// Measures the metrics of the given source file.
+    ///
+    /// The coupling and depth of inheritance of its class-likes are left at 0, see [`CodebaseMetrics::measure_coupling`].
+    pub fn measure(interner: &ThreadedInterner, source: &Source, program: &Program, names: &Names) -> Self {
+        walker::measure(interner, source, program, names)
+    }
+}
+
+impl CodebaseMetrics {
+    /// Creates the metrics of a codebase from the metrics of its source files.
+    pub fn new(mut files: Vec<FileMetrics>) -> Self {
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self { files }
+    }
+
+    /// Returns an iterator over the metrics of all the class-likes of the codebase.
+    pub fn class_likes(&self) -> impl Iterator<Item = &ClassLikeMetrics> {
+        self.files.iter().flat_map(|file| file.class_likes.iter())
+    }
+
+    /// Returns an iterator over the metrics of all the functions and methods of the codebase.
+    pub fn function_likes(&self) -> impl Iterator<Item = &FunctionLikeMetrics> {
+        self.files.iter().flat_map(|file| {
+            file.functions.iter().chain(file.class_likes.iter().flat_map(|class_like| class_like.methods.iter()))
+        })
+    }
+
+    /// Measures the coupling and depth of inheritance of all class-likes, using the reflection of the codebase.
+    ///
+    /// A class-like depends on the class-likes it extends, implements, uses, or mentions in the types of its
+    /// properties and method signatures, and on the class-likes its members instantiate, access statically,
+    /// check with `instanceof`, or catch.
+    ///
+    /// Afferent coupling only counts the class-likes measured in this codebase, while efferent coupling
+    /// counts any class-like, including built-in and external ones.
+    pub fn measure_coupling(&mut self, interner: &ThreadedInterner, codebase: &CodebaseReflection) {
+        let mut dependencies = HashMap::default();
+        for class_like in self.class_likes() {
+            let name = interner.intern(&class_like.name);
+            if let Some(reflection) = codebase.get_named_class_like(interner, &name) {
+                dependencies.insert(
+                    interner.lowered(&name),
+                    coupling::get_dependencies(interner, reflection, &class_like.references),
+                );
+            }
+        }
+
+        let mut afferent = HashMap::default();
+        for names in dependencies.values() {
+            for name in names {
+                *afferent.entry(*name).or_insert(0) += 1;
+            }
+        }
+
+        let empty = HashSet::default();
+        for class_like in self.files.iter_mut().flat_map(|file| file.class_likes.iter_mut()) {
+            let name = interner.lowered(&interner.intern(&class_like.name));
+
+            class_like.afferent_coupling = afferent.get(&name).copied().unwrap_or(0);
+            class_like.efferent_coupling = dependencies.get(&name).unwrap_or(&empty).len();
+            class_like.instability = match class_like.afferent_coupling + class_like.efferent_coupling {
+                0 => 0.0,
+                total => halstead::round2(class_like.efferent_coupling as f64 / total as f64),
+            };
+
+            class_like.depth_of_inheritance = codebase
+                .get_named_class_like(interner, &name)
+                .map(|reflection| reflection.inheritance.all_extended_classes.len())
+                .unwrap_or(0);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_span::Span;
+
+/// The line counts of a piece of code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lines {
+    /// The number of lines (LOC), including blank lines and comments.
+    pub loc: usize,
+    /// The number of logical lines (LLOC), i.e. the lines containing code.
+    pub lloc: usize,
+    /// The number of comment lines (CLOC), i.e. the lines containing a comment.
+    ///
+    /// A line with both code and a trailing comment counts as both a logical line and a comment line.
+    pub cloc: usize,
+}
+
+/// An index of which lines of a source contain code, and which contain comments.
+#[derive(Debug)]
+pub(crate) struct LineIndex<'a> {
+    starts: &'a [usize],
+    code: Vec<bool>,
+    comment: Vec<bool>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Creates an index of the lines of the given content, starting at the given offsets.
+    pub fn new(content: &str, starts: &'a [usize], comments: impl Iterator<Item = Span>) -> Self {
+        let mut in_comment = vec![false; content.len()];
+        for span in comments {
+            in_comment[span.start.offset..span.end.offset.min(content.len())].fill(true);
+        }
+
+        let mut code = vec![false; starts.len()];
+        let mut comment = vec![false; starts.len()];
+        let mut line = 0;
+        for (offset, byte) in content.bytes().enumerate() {
+            while line + 1 < starts.len() && starts[line + 1] <= offset {
+                line += 1;
+            }
+
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+
+            if in_comment[offset] {
+                comment[line] = true;
+            } else {
+                code[line] = true;
+            }
+        }
+
+        Self { starts, code, comment }
+    }
+
+    /// Counts the lines spanned by the given span.
+    pub fn count(&self, span: Span) -> Lines {
+        if self.starts.is_empty() {
+            return Lines::default();
+        }
+
+        let first = self.line_of(span.start.offset);
+        let last = self.line_of(span.end.offset.saturating_sub(1).max(span.start.offset));
+
+        Lines {
+            loc: last - first + 1,
+            lloc: self.code[first..=last].iter().filter(|code| **code).count(),
+            cloc: self.comment[first..=last].iter().filter(|comment| **comment).count(),
+        }
+    }
+
+    /// Returns the zero-based line containing the given offset.
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.starts.binary_search(&offset).unwrap_or_else(|next_line| next_line - 1)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::halstead::round2;
+use crate::lines::Lines;
+
+/// Computes the maintainability index of a piece of code, the way phpmetrics does.
+///
+/// The index combines the Halstead volume, the cyclomatic complexity, and the logical lines of code
+/// into a score between 0 and 100, to which up to 50 points are added for the proportion of comment lines:
+///
+/// ```text
+/// MIwoC = max((171 - 5.2 * ln(V) - 0.23 * CC - 16.2 * ln(LLOC)) * 100 / 171, 0)
+/// MIcw  = 50 * sin(sqrt(2.4 * CLOC / LOC))
+/// MI    = MIwoC + MIcw
+/// ```
+///
+/// A volume or a number of logical lines lower than one counts as one, so that code without any
+/// operands, or without any logical lines, has the maximum index.
+///
+/// See: https://github.com/phpmetrics/PhpMetrics/blob/c43217cd7783bbd54d0b8c1dd43f697bc36ef79d/src/Hal/Metric/Class_/Structural/MaintainabilityIndexVisitor.php
+pub fn get_maintainability_index(volume: f64, cyclomatic_complexity: usize, lines: Lines) -> f64 {
+    let volume = volume.max(1.0);
+    let lloc = (lines.lloc as f64).max(1.0);
+
+    let without_comments =
+        ((171.0 - 5.2 * volume.ln() - 0.23 * cyclomatic_complexity as f64 - 16.2 * lloc.ln()) * 100.0 / 171.0).max(0.0);
+
+    let comment_weight =
+        if lines.loc > 0 { 50.0 * (2.4 * lines.cloc as f64 / lines.loc as f64).sqrt().sin() } else { 0.0 };
+
+    round2(without_comments + comment_weight)
+}
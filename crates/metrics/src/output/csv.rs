@@ -0,0 +1,142 @@
+use std::io;
+use std::io::Write;
+
+use crate::CodebaseMetrics;
+use crate::FunctionLikeMetrics;
+
+const HEADER: &[&str] = &[
+    "type",
+    "file",
+    "name",
+    "line",
+    "loc",
+    "lloc",
+    "cloc",
+    "cyclomatic_complexity",
+    "halstead_volume",
+    "halstead_difficulty",
+    "halstead_effort",
+    "kan_defect",
+    "maintainability_index",
+    "parameters",
+    "methods",
+    "properties",
+    "afferent_coupling",
+    "efferent_coupling",
+    "instability",
+    "depth_of_inheritance",
+];
+
+/// Writes one row per file, function, class-like, and method, leaving the columns that do not apply empty.
+pub(super) fn write(writer: &mut impl Write, metrics: &CodebaseMetrics) -> io::Result<()> {
+    write_row(writer, HEADER.iter().map(|column| column.to_string()))?;
+
+    for file in &metrics.files {
+        write_row(
+            writer,
+            [
+                "file".to_string(),
+                file.name.clone(),
+                String::new(),
+                String::new(),
+                file.lines.loc.to_string(),
+                file.lines.lloc.to_string(),
+                file.lines.cloc.to_string(),
+                file.cyclomatic_complexity.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                file.kan_defect.to_string(),
+                file.maintainability_index.to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ],
+        )?;
+
+        for function in &file.functions {
+            write_function_like(writer, &file.name, function)?;
+        }
+
+        for class_like in &file.class_likes {
+            write_row(
+                writer,
+                [
+                    class_like.kind.to_string(),
+                    file.name.clone(),
+                    class_like.name.clone(),
+                    class_like.line.to_string(),
+                    class_like.lines.loc.to_string(),
+                    class_like.lines.lloc.to_string(),
+                    class_like.lines.cloc.to_string(),
+                    class_like.cyclomatic_complexity.to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    class_like.kan_defect.to_string(),
+                    class_like.maintainability_index.to_string(),
+                    String::new(),
+                    class_like.methods.len().to_string(),
+                    class_like.properties.to_string(),
+                    class_like.afferent_coupling.to_string(),
+                    class_like.efferent_coupling.to_string(),
+                    class_like.instability.to_string(),
+                    class_like.depth_of_inheritance.to_string(),
+                ],
+            )?;
+
+            for method in &class_like.methods {
+                write_function_like(writer, &file.name, method)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_function_like(writer: &mut impl Write, file: &str, function_like: &FunctionLikeMetrics) -> io::Result<()> {
+    write_row(
+        writer,
+        [
+            function_like.kind.to_string(),
+            file.to_string(),
+            function_like.name.clone(),
+            function_like.line.to_string(),
+            function_like.lines.loc.to_string(),
+            function_like.lines.lloc.to_string(),
+            function_like.lines.cloc.to_string(),
+            function_like.cyclomatic_complexity.to_string(),
+            function_like.halstead.volume.to_string(),
+            function_like.halstead.difficulty.to_string(),
+            function_like.halstead.effort.to_string(),
+            function_like.kan_defect.to_string(),
+            function_like.maintainability_index.to_string(),
+            function_like.parameters.to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ],
+    )
+}
+
+fn write_row(writer: &mut impl Write, cells: impl IntoIterator<Item = String>) -> io::Result<()> {
+    let cells: Vec<_> = cells.into_iter().map(|cell| escape(&cell)).collect();
+
+    writeln!(writer, "{}", cells.join(","))
+}
+
+/// Quotes a cell if it contains a separator, a quote, or a line break, as described in RFC 4180.
+fn escape(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
@@ -0,0 +1,190 @@
+use std::io;
+use std::io::Write;
+
+use crate::CodebaseMetrics;
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1f2328; }
+h1, h2 { font-weight: 600; }
+table { border-collapse: collapse; margin-bottom: 2rem; font-size: 0.9rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.3rem 0.6rem; text-align: right; }
+th { background: #f6f8fa; }
+td:first-child, th:first-child { text-align: left; }
+.summary td { font-weight: 600; }
+.poor { background: #ffebe9; }
+.fair { background: #fff8c5; }
+"#;
+
+/// A maintainability index below this is considered poor.
+const POOR_MAINTAINABILITY_INDEX: f64 = 65.0;
+
+/// A maintainability index below this is considered fair.
+const FAIR_MAINTAINABILITY_INDEX: f64 = 85.0;
+
+/// Writes a standalone HTML page with the totals of the codebase, and a table for files, class-likes, and function-likes.
+///
+/// Class-likes are sorted by ascending maintainability index, and function-likes by descending cyclomatic
+/// complexity, so that the code most in need of attention comes first.
+pub(super) fn write(writer: &mut impl Write, metrics: &CodebaseMetrics) -> io::Result<()> {
+    let mut class_likes: Vec<_> = metrics.class_likes().collect();
+    class_likes.sort_by(|a, b| a.maintainability_index.total_cmp(&b.maintainability_index));
+
+    let mut function_likes: Vec<_> = metrics.function_likes().collect();
+    function_likes.sort_by_key(|function_like| std::cmp::Reverse(function_like.cyclomatic_complexity));
+
+    let loc: usize = metrics.files.iter().map(|file| file.lines.loc).sum();
+    let lloc: usize = metrics.files.iter().map(|file| file.lines.lloc).sum();
+    let cloc: usize = metrics.files.iter().map(|file| file.lines.cloc).sum();
+
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"en\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<title>Mago Metrics</title>")?;
+    writeln!(writer, "<style>{}</style>", STYLE)?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+    writeln!(writer, "<h1>Metrics</h1>")?;
+
+    writeln!(writer, "<h2>Summary</h2>")?;
+    writeln!(writer, "<table class=\"summary\">")?;
+    write_header(writer, &["Files", "Class-likes", "Functions & methods", "LOC", "LLOC", "CLOC"])?;
+    write_cells(
+        writer,
+        None,
+        &[
+            metrics.files.len().to_string(),
+            class_likes.len().to_string(),
+            function_likes.len().to_string(),
+            loc.to_string(),
+            lloc.to_string(),
+            cloc.to_string(),
+        ],
+    )?;
+    write_header(writer, &["Average CC", "Maximum CC", "Average MI", "Minimum MI", "Average Ce", "Maximum DIT"])?;
+    write_cells(
+        writer,
+        None,
+        &[
+            format_float(average(
+                function_likes.iter().map(|function_like| function_like.cyclomatic_complexity as f64),
+            )),
+            function_likes.first().map(|function_like| function_like.cyclomatic_complexity).unwrap_or(0).to_string(),
+            format_float(average(class_likes.iter().map(|class_like| class_like.maintainability_index))),
+            class_likes.first().map(|class_like| format_float(class_like.maintainability_index)).unwrap_or_default(),
+            format_float(average(class_likes.iter().map(|class_like| class_like.efferent_coupling as f64))),
+            class_likes.iter().map(|class_like| class_like.depth_of_inheritance).max().unwrap_or(0).to_string(),
+        ],
+    )?;
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "<h2>Files</h2>")?;
+    writeln!(writer, "<table>")?;
+    write_header(writer, &["File", "LOC", "LLOC", "CLOC", "CC", "Kan defect", "MI"])?;
+    for file in &metrics.files {
+        write_cells(
+            writer,
+            Some(file.maintainability_index),
+            &[
+                escape(&file.name),
+                file.lines.loc.to_string(),
+                file.lines.lloc.to_string(),
+                file.lines.cloc.to_string(),
+                file.cyclomatic_complexity.to_string(),
+                format_float(file.kan_defect),
+                format_float(file.maintainability_index),
+            ],
+        )?;
+    }
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "<h2>Class-likes</h2>")?;
+    writeln!(writer, "<table>")?;
+    write_header(
+        writer,
+        &["Name", "Kind", "LOC", "Methods", "Properties", "CC", "Kan defect", "MI", "Ca", "Ce", "Instability", "DIT"],
+    )?;
+    for class_like in &class_likes {
+        write_cells(
+            writer,
+            Some(class_like.maintainability_index),
+            &[
+                escape(&class_like.name),
+                class_like.kind.to_string(),
+                class_like.lines.loc.to_string(),
+                class_like.methods.len().to_string(),
+                class_like.properties.to_string(),
+                class_like.cyclomatic_complexity.to_string(),
+                format_float(class_like.kan_defect),
+                format_float(class_like.maintainability_index),
+                class_like.afferent_coupling.to_string(),
+                class_like.efferent_coupling.to_string(),
+                format_float(class_like.instability),
+                class_like.depth_of_inheritance.to_string(),
+            ],
+        )?;
+    }
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "<h2>Functions &amp; methods</h2>")?;
+    writeln!(writer, "<table>")?;
+    write_header(writer, &["Name", "LOC", "Parameters", "CC", "Volume", "Difficulty", "Effort", "Kan defect", "MI"])?;
+    for function_like in &function_likes {
+        write_cells(
+            writer,
+            Some(function_like.maintainability_index),
+            &[
+                escape(&function_like.name),
+                function_like.lines.loc.to_string(),
+                function_like.parameters.to_string(),
+                function_like.cyclomatic_complexity.to_string(),
+                format_float(function_like.halstead.volume),
+                format_float(function_like.halstead.difficulty),
+                format_float(function_like.halstead.effort),
+                format_float(function_like.kan_defect),
+                format_float(function_like.maintainability_index),
+            ],
+        )?;
+    }
+    writeln!(writer, "</table>")?;
+
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")
+}
+
+fn write_header(writer: &mut impl Write, columns: &[&str]) -> io::Result<()> {
+    let columns: Vec<_> = columns.iter().map(|column| format!("<th>{}</th>", escape(column))).collect();
+
+    writeln!(writer, "<tr>{}</tr>", columns.join(""))
+}
+
+/// Writes a row of cells, highlighted according to the given maintainability index, if any.
+fn write_cells(writer: &mut impl Write, maintainability_index: Option<f64>, cells: &[String]) -> io::Result<()> {
+    let class = match maintainability_index {
+        Some(index) if index < POOR_MAINTAINABILITY_INDEX => " class=\"poor\"",
+        Some(index) if index < FAIR_MAINTAINABILITY_INDEX => " class=\"fair\"",
+        _ => "",
+    };
+
+    let cells: Vec<_> = cells.iter().map(|cell| format!("<td>{}</td>", cell)).collect();
+
+    writeln!(writer, "<tr{}>{}</tr>", class, cells.join(""))
+}
+
+fn average(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+fn format_float(value: f64) -> String {
+    format!("{:.2}", value)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
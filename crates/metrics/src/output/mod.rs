@@ -0,0 +1,55 @@
+use std::io;
+use std::io::Write;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+use strum::VariantNames;
+
+use crate::CodebaseMetrics;
+
+mod csv;
+mod html;
+
+/// The format to write metrics in.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, VariantNames)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum MetricsFormat {
+    /// The metrics of every file, with the metrics of its functions and class-likes nested in it.
+    #[default]
+    Json,
+    /// One row per file, function, class-like, and method.
+    Csv,
+    /// A standalone HTML page summarizing the metrics.
+    Html,
+}
+
+impl MetricsFormat {
+    /// Writes the given metrics to the given writer, in this format.
+    pub fn write(&self, writer: &mut impl Write, metrics: &CodebaseMetrics) -> io::Result<()> {
+        match self {
+            MetricsFormat::Json => {
+                serde_json::to_writer_pretty(&mut *writer, metrics)?;
+
+                writeln!(writer)
+            }
+            MetricsFormat::Csv => csv::write(writer, metrics),
+            MetricsFormat::Html => html::write(writer, metrics),
+        }
+    }
+}
+
+impl FromStr for MetricsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "html" => Ok(Self::Html),
+            _ => Err(format!("Invalid metrics format: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use mago_ast::*;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_source::Source;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::complexity::get_class_like_decision_points;
+use crate::complexity::get_decision_points;
+use crate::complexity::get_kan_defect;
+use crate::coupling::get_referenced_class_likes;
+use crate::halstead::round2;
+use crate::halstead::Halstead;
+use crate::lines::LineIndex;
+use crate::maintainability::get_maintainability_index;
+use crate::ClassLikeKind;
+use crate::ClassLikeMetrics;
+use crate::FileMetrics;
+use crate::FunctionLikeKind;
+use crate::FunctionLikeMetrics;
+
+/// Measures the metrics of the given source file.
+pub(crate) fn measure(interner: &ThreadedInterner, source: &Source, program: &Program, names: &Names) -> FileMetrics {
+    let content = interner.lookup(&source.content);
+    let index = LineIndex::new(
+        content,
+        &source.lines,
+        program.trivia.iter().filter(|trivia| trivia.kind.is_comment()).map(|trivia| trivia.span),
+    );
+
+    let docblocks = program
+        .trivia
+        .iter()
+        .filter(|trivia| matches!(trivia.kind, TriviaKind::DocBlockComment))
+        .map(|trivia| trivia.span)
+        .collect();
+
+    let mut context = MetricsContext { interner, names, content, index, docblocks };
+    let mut walker = MetricsWalker::default();
+    walker.walk_program(program, &mut context);
+
+    // The top-level code is measured without the declarations, which are measured on their own.
+    let mut volume = Halstead::measure(Node::Program(program)).volume;
+    for function_like in walker.functions.iter().chain(walker.class_likes.iter().flat_map(|c| c.methods.iter())) {
+        volume += function_like.halstead.volume;
+    }
+
+    let lines = context.index.count(program.span());
+    let cyclomatic_complexity = get_decision_points(Node::Program(program)) + 1;
+
+    FileMetrics {
+        name: interner.lookup(&source.identifier.0).to_string(),
+        lines,
+        cyclomatic_complexity,
+        kan_defect: round2(get_kan_defect(Node::Program(program))),
+        maintainability_index: get_maintainability_index(volume, cyclomatic_complexity, lines),
+        functions: walker.functions,
+        class_likes: walker.class_likes,
+    }
+}
+
+#[derive(Debug)]
+struct MetricsContext<'a> {
+    interner: &'a ThreadedInterner,
+    names: &'a Names,
+    content: &'a str,
+    index: LineIndex<'a>,
+    docblocks: Vec<Span>,
+}
+
+impl MetricsContext<'_> {
+    /// Returns the span of the given declaration, including the docblock preceding it, if any.
+    fn get_declaration_span(&self, node: Node<'_>) -> Span {
+        let span = node.span();
+        let index = self.docblocks.partition_point(|docblock| docblock.end.offset <= span.start.offset);
+        if index == 0 {
+            return span;
+        }
+
+        let docblock = self.docblocks[index - 1];
+        if self.content[docblock.end.offset..span.start.offset].trim().is_empty() {
+            Span::new(docblock.start, span.end)
+        } else {
+            span
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct MetricsWalker {
+    functions: Vec<FunctionLikeMetrics>,
+    class_likes: Vec<ClassLikeMetrics>,
+}
+
+impl MetricsWalker {
+    fn measure_class_like(
+        &mut self,
+        context: &MetricsContext<'_>,
+        kind: ClassLikeKind,
+        name: &LocalIdentifier,
+        node: Node<'_>,
+        members: &[ClassLikeMember],
+    ) {
+        let name = context.interner.lookup(context.names.get(name)).to_string();
+
+        let mut properties = 0;
+        let mut methods = vec![];
+        for member in members {
+            match member {
+                ClassLikeMember::Property(Property::Plain(plain_property)) => {
+                    properties += plain_property.items.len();
+                }
+                ClassLikeMember::Property(Property::Hooked(_)) => {
+                    properties += 1;
+                }
+                ClassLikeMember::Method(method) => {
+                    let method_name = format!("{}::{}", name, context.interner.lookup(&method.name.value));
+                    let cyclomatic_complexity =
+                        if method.is_abstract() { 1 } else { get_decision_points(Node::Method(method)) + 1 };
+
+                    methods.push(measure_function_like(
+                        context,
+                        method_name,
+                        FunctionLikeKind::Method,
+                        Node::Method(method),
+                        method.parameter_list.parameters.len(),
+                        cyclomatic_complexity,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let span = context.get_declaration_span(node);
+        let lines = context.index.count(span);
+        let volume = methods.iter().map(|method| method.halstead.volume).sum();
+        let cyclomatic_complexity = get_class_like_decision_points(context.interner, members) + 1;
+
+        self.class_likes.push(ClassLikeMetrics {
+            name,
+            kind,
+            line: context.index.line_of(node.span().start.offset) + 1,
+            lines,
+            properties,
+            cyclomatic_complexity,
+            kan_defect: round2(get_kan_defect(node)),
+            maintainability_index: get_maintainability_index(volume, cyclomatic_complexity, lines),
+            afferent_coupling: 0,
+            efferent_coupling: 0,
+            instability: 0.0,
+            depth_of_inheritance: 0,
+            methods,
+            references: get_referenced_class_likes(context.names, members),
+        });
+    }
+}
+
+impl<'a> MutWalker<MetricsContext<'a>> for MetricsWalker {
+    fn walk_in_function(&mut self, function: &Function, context: &mut MetricsContext<'a>) {
+        let name = context.interner.lookup(context.names.get(&function.name)).to_string();
+
+        self.functions.push(measure_function_like(
+            context,
+            name,
+            FunctionLikeKind::Function,
+            Node::Function(function),
+            function.parameter_list.parameters.len(),
+            get_decision_points(Node::Function(function)) + 1,
+        ));
+    }
+
+    fn walk_in_class(&mut self, class: &Class, context: &mut MetricsContext<'a>) {
+        self.measure_class_like(
+            context,
+            ClassLikeKind::Class,
+            &class.name,
+            Node::Class(class),
+            class.members.as_slice(),
+        );
+    }
+
+    fn walk_in_interface(&mut self, interface: &Interface, context: &mut MetricsContext<'a>) {
+        let node = Node::Interface(interface);
+
+        self.measure_class_like(context, ClassLikeKind::Interface, &interface.name, node, interface.members.as_slice());
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut MetricsContext<'a>) {
+        self.measure_class_like(
+            context,
+            ClassLikeKind::Trait,
+            &r#trait.name,
+            Node::Trait(r#trait),
+            r#trait.members.as_slice(),
+        );
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut MetricsContext<'a>) {
+        self.measure_class_like(
+            context,
+            ClassLikeKind::Enum,
+            &r#enum.name,
+            Node::Enum(r#enum),
+            r#enum.members.as_slice(),
+        );
+    }
+}
+
+fn measure_function_like(
+    context: &MetricsContext<'_>,
+    name: String,
+    kind: FunctionLikeKind,
+    node: Node<'_>,
+    parameters: usize,
+    cyclomatic_complexity: usize,
+) -> FunctionLikeMetrics {
+    let span = context.get_declaration_span(node);
+    let lines = context.index.count(span);
+    let halstead = Halstead::measure(node);
+
+    FunctionLikeMetrics {
+        name,
+        kind,
+        line: context.index.line_of(node.span().start.offset) + 1,
+        lines,
+        parameters,
+        cyclomatic_complexity,
+        halstead,
+        kan_defect: round2(get_kan_defect(node)),
+        maintainability_index: get_maintainability_index(halstead.volume, cyclomatic_complexity, lines),
+    }
+}
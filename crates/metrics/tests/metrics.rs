@@ -0,0 +1,261 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use mago_interner::ThreadedInterner;
+use mago_metrics::lines::Lines;
+use mago_metrics::maintainability::get_maintainability_index;
+use mago_metrics::output::MetricsFormat;
+use mago_metrics::ClassLikeKind;
+use mago_metrics::CodebaseMetrics;
+use mago_metrics::FileMetrics;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_reflection::CodebaseReflection;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+fn measure(files: &[&str]) -> CodebaseMetrics {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let mut metrics = vec![];
+    let mut codebase = CodebaseReflection::new();
+    for (i, code) in files.iter().enumerate() {
+        let source_id = manager.insert_content(format!("{}.php", i), code.to_string(), SourceCategory::UserDefined);
+        let source = manager.load(&source_id).unwrap();
+        let (program, error) = parse_source(&interner, &source);
+        assert!(error.is_none(), "failed to parse source {}: {:?}", i, error);
+
+        let names = Names::resolve(&interner, &program);
+        let reflection = mago_reflector::reflect(&interner, &source, &program, &names);
+        codebase = mago_reflector::merge(&interner, codebase, reflection);
+
+        metrics.push(FileMetrics::measure(&interner, &source, &program, &names));
+    }
+
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mut metrics = CodebaseMetrics::new(metrics);
+    metrics.measure_coupling(&interner, &codebase);
+
+    metrics
+}
+
+#[test]
+fn test_function_metrics() {
+    let metrics = measure(&[indoc! {r#"
+        <?php
+
+        namespace App;
+
+        /**
+         * Sums the positive numbers.
+         */
+        function sum(array $numbers, int $limit): int
+        {
+            $total = 0;
+            foreach ($numbers as $number) {
+                // Skip negative numbers.
+                if ($number > 0 && $total < $limit) {
+                    $total += $number;
+                }
+            }
+
+            return $total;
+        }
+    "#}]);
+
+    let function = &metrics.files[0].functions[0];
+
+    assert_eq!(function.name, "App\\sum");
+    assert_eq!(function.line, 8);
+    assert_eq!(function.lines, Lines { loc: 15, lloc: 10, cloc: 4 });
+    assert_eq!(function.parameters, 2);
+    assert_eq!(function.cyclomatic_complexity, 4);
+    assert_eq!(function.kan_defect, 0.45);
+    assert!(function.halstead.volume > 0.0);
+    assert!(function.maintainability_index > 0.0);
+}
+
+#[test]
+fn test_file_lines() {
+    let metrics = measure(&[indoc! {r#"
+        <?php
+
+        // A comment.
+        $a = 1; # A trailing comment.
+
+        /*
+         * A block comment.
+         */
+        echo $a;
+    "#}]);
+
+    assert_eq!(metrics.files[0].lines, Lines { loc: 9, lloc: 3, cloc: 5 });
+}
+
+#[test]
+fn test_class_like_cyclomatic_complexity_excludes_accessors() {
+    let metrics = measure(&[indoc! {r#"
+        <?php
+
+        final class Counter
+        {
+            private int $count = 0;
+            private int $step, $limit;
+
+            public function getCount(): int
+            {
+                return $this->count;
+            }
+
+            public function increment(): void
+            {
+                if ($this->count < $this->limit) {
+                    $this->count += $this->step;
+                }
+            }
+        }
+    "#}]);
+
+    let class = &metrics.files[0].class_likes[0];
+
+    assert_eq!(class.name, "Counter");
+    assert_eq!(class.kind, ClassLikeKind::Class);
+    assert_eq!(class.properties, 3);
+    assert_eq!(class.methods.len(), 2);
+    assert_eq!(class.methods[0].cyclomatic_complexity, 1);
+    assert_eq!(class.methods[1].cyclomatic_complexity, 2);
+    assert_eq!(class.cyclomatic_complexity, 2);
+}
+
+#[test]
+fn test_coupling_and_depth_of_inheritance() {
+    let metrics = measure(&[
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            interface Repository {}
+
+            class Model {}
+
+            class User extends Model {}
+        "#},
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Admin extends User implements Repository
+            {
+                public function __construct(private Logger $logger) {}
+
+                public function find(int $id): ?Model
+                {
+                    return null;
+                }
+            }
+        "#},
+    ]);
+
+    let class_likes: Vec<_> = metrics
+        .class_likes()
+        .map(|class_like| {
+            (
+                class_like.name.as_str(),
+                class_like.afferent_coupling,
+                class_like.efferent_coupling,
+                class_like.instability,
+                class_like.depth_of_inheritance,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        class_likes,
+        vec![
+            ("App\\Repository", 1, 0, 0.0, 0),
+            ("App\\Model", 2, 0, 0.0, 0),
+            ("App\\User", 1, 1, 0.5, 1),
+            ("App\\Admin", 0, 4, 1.0, 2),
+        ]
+    );
+}
+
+#[test]
+fn test_coupling_includes_references_in_method_bodies() {
+    let metrics = measure(&[indoc! {r#"
+        <?php
+
+        namespace App;
+
+        final class Handler
+        {
+            public function handle(mixed $message): void
+            {
+                if ($message instanceof Command) {
+                    $bus = new Bus(Config::DEFAULT);
+                    $bus->dispatch(Registry::get($message), Clock::$now);
+                }
+
+                try {
+                    self::log();
+                } catch (Failure|\RuntimeException $exception) {
+                }
+            }
+
+            private static function log(): void
+            {
+            }
+        }
+    "#}]);
+
+    let handler = metrics.class_likes().next().unwrap();
+
+    // `Command`, `Bus`, `Config`, `Registry`, `Clock`, `Failure`, and `RuntimeException`, but not `self`.
+    assert_eq!(handler.efferent_coupling, 7);
+    assert_eq!(handler.afferent_coupling, 0);
+}
+
+#[test]
+fn test_maintainability_index() {
+    assert_eq!(get_maintainability_index(0.0, 1, Lines { loc: 1, lloc: 1, cloc: 0 }), 99.87);
+    assert_eq!(get_maintainability_index(1e30, 100, Lines { loc: 10000, lloc: 10000, cloc: 0 }), 0.0);
+
+    let without_comments = get_maintainability_index(100.0, 5, Lines { loc: 20, lloc: 20, cloc: 0 });
+    let with_comments = get_maintainability_index(100.0, 5, Lines { loc: 20, lloc: 20, cloc: 5 });
+
+    assert!(with_comments > without_comments);
+}
+
+#[test]
+fn test_csv_output() {
+    let metrics = measure(&[indoc! {r#"
+        <?php
+
+        class Foo
+        {
+            public function bar(): void {}
+        }
+
+        function baz(): void {}
+    "#}]);
+
+    let mut output = vec![];
+    MetricsFormat::Csv.write(&mut output, &metrics).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let rows: Vec<_> = output.lines().map(|line| line.split(',').take(4).collect::<Vec<_>>().join(",")).collect();
+
+    assert_eq!(
+        rows,
+        vec![
+            "type,file,name,line",
+            "file,0.php,,",
+            "function,0.php,baz,8",
+            "class,0.php,Foo,3",
+            "method,0.php,Foo::bar,5",
+        ]
+    );
+}
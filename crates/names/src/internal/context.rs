@@ -27,6 +27,14 @@ pub struct NameResolutionContext {
     constant_aliases: HashMap<String, String, RandomState>,
 }
 
+/// A snapshot of the name resolution state, starting at the given offset.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NameScope {
+    pub offset: usize,
+    namespace_name: Option<String>,
+    context: NameResolutionContext,
+}
+
 #[derive(Debug)]
 pub struct NameContext<'a> {
     pub interner: &'a ThreadedInterner,
@@ -43,6 +51,28 @@ impl<'a> NameContext<'a> {
         }
     }
 
+    /// Creates a context that resolves names within the given scope.
+    pub fn from_scope(interner: &'a ThreadedInterner, scope: &NameScope) -> Self {
+        NameContext {
+            interner,
+            name_resolution_contexts: vec![scope.context.clone()],
+            namespace_name: scope.namespace_name.clone(),
+        }
+    }
+
+    /// Captures the current name resolution state, so that names can be resolved within it later.
+    pub fn get_scope(&self, offset: usize) -> NameScope {
+        NameScope {
+            offset,
+            namespace_name: self.namespace_name.clone(),
+            context: self
+                .name_resolution_contexts
+                .last()
+                .cloned()
+                .expect("expected there to be at least one name resolution context"),
+        }
+    }
+
     pub fn get_namespace_name(&self) -> Option<String> {
         self.namespace_name.clone()
     }
@@ -15,7 +15,7 @@ pub struct NameResolver {
 
 impl NameResolver {
     pub fn new() -> Self {
-        NameResolver { resolved_names: Names { names: HashMap::default() } }
+        NameResolver { resolved_names: Names { names: HashMap::default(), scopes: Vec::new() } }
     }
 }
 
@@ -27,6 +27,8 @@ impl<'a> MutWalker<NameContext<'a>> for NameResolver {
         };
 
         context.enter_namespace(name);
+
+        self.resolved_names.scopes.push(context.get_scope(namespace.span().start.offset));
     }
 
     fn walk_in_use<'ast>(&mut self, r#use: &'ast Use, context: &mut NameContext<'a>) {
@@ -275,7 +277,13 @@ impl<'a> MutWalker<NameContext<'a>> for NameResolver {
         }
     }
 
-    fn walk_out_namespace<'ast>(&mut self, _namespace: &'ast Namespace, context: &mut NameContext<'a>) {
+    fn walk_out_use<'ast>(&mut self, r#use: &'ast Use, context: &mut NameContext<'a>) {
+        self.resolved_names.scopes.push(context.get_scope(r#use.span().end.offset));
+    }
+
+    fn walk_out_namespace<'ast>(&mut self, namespace: &'ast Namespace, context: &mut NameContext<'a>) {
         context.exit_namespace();
+
+        self.resolved_names.scopes.push(context.get_scope(namespace.span().end.offset));
     }
 }
@@ -12,6 +12,8 @@ use mago_span::Position;
 use mago_walker::MutWalker;
 
 use crate::internal::context::NameContext;
+use crate::internal::context::NameKind;
+use crate::internal::context::NameScope;
 use crate::internal::resolver::NameResolver;
 
 mod internal;
@@ -23,6 +25,7 @@ mod internal;
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Names {
     names: HashMap<usize, (StringIdentifier, bool)>,
+    scopes: Vec<NameScope>,
 }
 
 impl Names {
@@ -43,6 +46,7 @@ impl Names {
         let mut resolver: NameResolver = NameResolver::new();
         let mut context: NameContext = NameContext::new(interner);
 
+        resolver.resolved_names.scopes.push(context.get_scope(0));
         resolver.walk_program(program, &mut context);
 
         resolver.resolved_names
@@ -101,6 +105,38 @@ impl Names {
         self.names.get(&position.position().offset).map(|(_, imported)| *imported).unwrap_or(false)
     }
 
+    /// Resolves a class name that is not part of the AST, such as a type found in a docblock,
+    /// as if it appeared at the given position.
+    ///
+    /// The name is resolved against the namespace and `use` imports in effect at that position,
+    /// following the same rules as the class names within the AST.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - A `ThreadedInterner` used for string interning.
+    /// * `position` - A reference to a type that implements `HasPosition`.
+    /// * `name` - The class name, as written in the source code.
+    ///
+    /// # Returns
+    ///
+    /// The `StringIdentifier` of the resolved name.
+    pub fn resolve_class_name(
+        &self,
+        interner: &ThreadedInterner,
+        position: &impl HasPosition,
+        name: &str,
+    ) -> StringIdentifier {
+        let offset = position.position().offset;
+        let index = self.scopes.partition_point(|scope| scope.offset <= offset);
+
+        let name = interner.intern(name);
+        let Some(scope) = index.checked_sub(1).and_then(|index| self.scopes.get(index)) else {
+            return NameContext::new(interner).resolve_name(NameKind::Default, name).0;
+        };
+
+        NameContext::from_scope(interner, scope).resolve_name(NameKind::Default, name).0
+    }
+
     /// Inserts a resolved name at the given position.
     ///
     /// This method is intended for internal use within the crate.
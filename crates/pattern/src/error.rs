@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    Empty,
+    Invalid(String),
+}
+
+impl std::fmt::Display for PatternError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "pattern is empty"),
+            Self::Invalid(pattern) => {
+                write!(f, "pattern `{}` is not a valid PHP expression or statement", pattern)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
@@ -0,0 +1,285 @@
+use ahash::HashMap;
+
+use mago_ast::node::NodeKind;
+use mago_ast::Node;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::take_identifier;
+use crate::Binding;
+
+/// Matches pattern nodes against target nodes, collecting metavariable bindings.
+pub(crate) struct Matcher<'a, 't> {
+    pattern: &'a str,
+    target: &'a str,
+    bindings: HashMap<String, Binding>,
+    /// The target nodes bound to each metavariable, used to compare repeated metavariables.
+    bound: HashMap<String, Vec<Node<'t>>>,
+    /// The kinds of the target nodes enclosing the node being matched.
+    ancestors: Vec<NodeKind>,
+}
+
+/// The text surrounding the children of a node, normalized for comparison.
+struct Gaps {
+    leading: String,
+    inner: Vec<String>,
+    trailing: String,
+}
+
+impl<'a, 't> Matcher<'a, 't> {
+    pub fn new(pattern: &'a str, target: &'a str) -> Self {
+        Self { pattern, target, bindings: HashMap::default(), bound: HashMap::default(), ancestors: vec![] }
+    }
+
+    pub fn take_bindings(self) -> HashMap<String, Binding> {
+        self.bindings
+    }
+
+    pub fn match_node(&mut self, pattern: Node<'_>, target: Node<'t>) -> bool {
+        if pattern.kind() != target.kind() {
+            return false;
+        }
+
+        if let Some(name) = get_metavariable(slice(self.pattern, pattern.span())) {
+            return self.bind(name, &[target], false);
+        }
+
+        let pattern_children = pattern.children();
+        let target_children = target.children();
+
+        if pattern_children.is_empty() && target_children.is_empty() {
+            return leaves_are_equal(
+                pattern.kind(),
+                &self.ancestors,
+                slice(self.pattern, pattern.span()),
+                slice(self.target, target.span()),
+            );
+        }
+
+        let pattern_gaps = get_gaps(self.pattern, pattern.span(), &pattern_children);
+        let target_gaps = get_gaps(self.target, target.span(), &target_children);
+        if pattern_children.is_empty() || target_children.is_empty() {
+            // One side is empty, which only matches if the pattern consists of sequence metavariables,
+            // in which case the surrounding text is compared as a whole.
+            if pattern_gaps.leading + &pattern_gaps.trailing != target_gaps.leading + &target_gaps.trailing {
+                return false;
+            }
+        } else if pattern_gaps.leading != target_gaps.leading || pattern_gaps.trailing != target_gaps.trailing {
+            return false;
+        }
+
+        let has_sequence =
+            pattern_children.iter().any(|child| get_sequence_metavariable(slice(self.pattern, child.span())).is_some());
+
+        if !has_sequence && pattern_gaps.inner != target_gaps.inner {
+            return false;
+        }
+
+        self.ancestors.push(target.kind());
+        let matched = self.match_sequence(&pattern_children, &target_children);
+        self.ancestors.pop();
+
+        matched
+    }
+
+    fn match_sequence(&mut self, pattern: &[Node<'_>], target: &[Node<'t>]) -> bool {
+        let Some((first, rest)) = pattern.split_first() else {
+            return target.is_empty();
+        };
+
+        if let Some(name) = get_sequence_metavariable(slice(self.pattern, first.span())) {
+            for length in 0..=target.len() {
+                let snapshot = (self.bindings.clone(), self.bound.clone());
+                if self.bind(name, &target[..length], true) && self.match_sequence(rest, &target[length..]) {
+                    return true;
+                }
+
+                (self.bindings, self.bound) = snapshot;
+            }
+
+            return false;
+        }
+
+        let Some((first_target, rest_target)) = target.split_first() else {
+            return false;
+        };
+
+        let snapshot = (self.bindings.clone(), self.bound.clone());
+        if self.match_node(*first, *first_target) && self.match_sequence(rest, rest_target) {
+            return true;
+        }
+
+        (self.bindings, self.bound) = snapshot;
+
+        false
+    }
+
+    /// Binds the given target nodes to a metavariable.
+    ///
+    /// A metavariable that is already bound only matches nodes that are structurally equal to the
+    /// ones it is bound to, see [`Matcher::sequences_are_equal`].
+    fn bind(&mut self, name: &str, nodes: &[Node<'t>], is_sequence: bool) -> bool {
+        if let Some(existing) = self.bindings.get(name) {
+            if existing.is_sequence != is_sequence {
+                return false;
+            }
+
+            let bound = self.bound.get(name).cloned().unwrap_or_default();
+
+            return self.sequences_are_equal(&bound, nodes);
+        }
+
+        let span = match (nodes.first(), nodes.last()) {
+            (Some(first), Some(last)) => Some(first.span().join(last.span())),
+            _ => None,
+        };
+
+        let text = span.map(|span| slice(self.target, span).to_string()).unwrap_or_default();
+
+        self.bindings.insert(name.to_string(), Binding { span, text, is_sequence });
+        self.bound.insert(name.to_string(), nodes.to_vec());
+
+        true
+    }
+
+    /// Checks whether two sequences of target nodes are structurally equal.
+    ///
+    /// Whitespace and comments are ignored, and so is the case of keywords, and of the identifiers
+    /// that PHP treats case-insensitively, see [`leaves_are_equal`].
+    fn sequences_are_equal(&mut self, a: &[Node<'t>], b: &[Node<'t>]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(a, b)| self.nodes_are_equal(*a, *b))
+    }
+
+    fn nodes_are_equal(&mut self, a: Node<'t>, b: Node<'t>) -> bool {
+        if a.kind() != b.kind() {
+            return false;
+        }
+
+        let a_children = a.children();
+        let b_children = b.children();
+
+        if a_children.is_empty() && b_children.is_empty() {
+            return leaves_are_equal(
+                a.kind(),
+                &self.ancestors,
+                slice(self.target, a.span()),
+                slice(self.target, b.span()),
+            );
+        }
+
+        let a_gaps = get_gaps(self.target, a.span(), &a_children);
+        let b_gaps = get_gaps(self.target, b.span(), &b_children);
+        if a_gaps.leading != b_gaps.leading || a_gaps.inner != b_gaps.inner || a_gaps.trailing != b_gaps.trailing {
+            return false;
+        }
+
+        self.ancestors.push(a.kind());
+        let equal = self.sequences_are_equal(&a_children, &b_children);
+        self.ancestors.pop();
+
+        equal
+    }
+}
+
+fn get_metavariable(text: &str) -> Option<&str> {
+    let name = text.strip_prefix('$')?;
+    if name.starts_with('$') || name == "this" {
+        return None;
+    }
+
+    let identifier = take_identifier(name);
+    if identifier.is_empty() || identifier.len() != name.len() {
+        return None;
+    }
+
+    Some(identifier)
+}
+
+fn get_sequence_metavariable(text: &str) -> Option<&str> {
+    let name = text.strip_prefix("$$$")?;
+
+    let identifier = take_identifier(name);
+    if identifier.is_empty() || identifier.len() != name.len() {
+        return None;
+    }
+
+    Some(identifier)
+}
+
+/// Compares two leaf nodes of the given kind, enclosed by nodes of the given kinds.
+///
+/// Keywords and modifiers are compared case-insensitively, and so are identifiers, except the names of
+/// constants, properties, named arguments, and labels, which PHP treats case-sensitively.
+/// Everything else, such as variables and string contents, is compared case-sensitively.
+fn leaves_are_equal(kind: NodeKind, ancestors: &[NodeKind], pattern: &str, target: &str) -> bool {
+    match kind {
+        NodeKind::Keyword | NodeKind::Modifier => pattern.eq_ignore_ascii_case(target),
+        NodeKind::LocalIdentifier | NodeKind::QualifiedIdentifier | NodeKind::FullyQualifiedIdentifier => {
+            let owner = ancestors.iter().rev().find(|kind| {
+                !matches!(
+                    kind,
+                    NodeKind::Identifier | NodeKind::ClassLikeMemberSelector | NodeKind::ClassLikeConstantSelector
+                )
+            });
+
+            match owner {
+                Some(
+                    NodeKind::ConstantAccess
+                    | NodeKind::ClassConstantAccess
+                    | NodeKind::PropertyAccess
+                    | NodeKind::NullSafePropertyAccess
+                    | NodeKind::NamedArgument
+                    | NodeKind::Goto
+                    | NodeKind::Label,
+                ) => pattern == target,
+                _ => pattern.eq_ignore_ascii_case(target),
+            }
+        }
+        _ => pattern == target,
+    }
+}
+
+fn get_gaps(content: &str, span: Span, children: &[Node<'_>]) -> Gaps {
+    let mut cursor = span.start.offset;
+    let mut gaps = vec![];
+
+    for child in children {
+        let child_span = child.span();
+        if child_span.start.offset >= cursor {
+            gaps.push(normalize(&content[cursor..child_span.start.offset]));
+        }
+
+        cursor = cursor.max(child_span.end.offset);
+    }
+
+    let trailing = normalize(&content[cursor.min(span.end.offset)..span.end.offset]);
+    let leading = if gaps.is_empty() { String::new() } else { gaps.remove(0) };
+
+    Gaps { leading, inner: gaps, trailing }
+}
+
+/// Removes whitespace and comments, and lowercases the given code.
+fn normalize(code: &str) -> String {
+    let mut output = String::with_capacity(code.len());
+    let mut rest = code;
+
+    while let Some(character) = rest.chars().next() {
+        if rest.starts_with("/*") {
+            rest = rest[2..].find("*/").map(|end| &rest[end + 4..]).unwrap_or("");
+        } else if rest.starts_with("//") || (rest.starts_with('#') && !rest.starts_with("#[")) {
+            rest = rest.find('\n').map(|end| &rest[end..]).unwrap_or("");
+        } else {
+            if !character.is_whitespace() {
+                output.extend(character.to_lowercase());
+            }
+
+            rest = &rest[character.len_utf8()..];
+        }
+    }
+
+    output
+}
+
+fn slice(content: &str, span: Span) -> &str {
+    &content[span.start.offset..span.end.offset]
+}
@@ -0,0 +1,432 @@
+//! Structural matching and rewriting of PHP code.
+//!
+//! A [`Pattern`] is a fragment of PHP code, either an expression or a single statement, in which
+//! variables act as metavariables:
+//!
+//! - `$name` matches any single node, e.g. an expression, an argument, or an array element.
+//! - `$$$name` matches a sequence of zero or more sibling nodes, e.g. the arguments of a call.
+//!
+//! `$this` is never treated as a metavariable. When the same metavariable is used more than once
+//! in a pattern, all of its occurrences must match structurally equal code.
+//!
+//! Everything else is matched structurally, ignoring whitespace, comments, and the case of keywords
+//! and of the names PHP treats case-insensitively, i.e. the names of functions, classes, and methods,
+//! so `$x->setUp($$$args)` matches `$this -> SetUp( 1, 2 )` as well. Variables, strings, and the
+//! names of constants and properties are case-sensitive.
+//!
+//! A [`Template`] is a fragment of code in which metavariables are replaced by the code they matched,
+//! and is used to rewrite matches.
+
+use ahash::HashMap;
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_ast::Node;
+use mago_ast::Program;
+use mago_ast::Statement;
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_source::SourceIdentifier;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::error::PatternError;
+use crate::internal::matcher::Matcher;
+
+pub mod error;
+
+mod internal;
+
+/// A structural code pattern with metavariables.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    source: String,
+    code: String,
+    program: Program,
+    is_expression: bool,
+}
+
+/// A single match of a [`Pattern`] in a program.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Match {
+    /// The span of the matched node.
+    pub span: Span,
+
+    /// The code captured by each metavariable, keyed by the metavariable name without the `$` prefix.
+    pub bindings: HashMap<String, Binding>,
+}
+
+/// The code captured by a metavariable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Binding {
+    /// The span of the captured code, or `None` if a sequence metavariable matched nothing.
+    pub span: Option<Span>,
+
+    /// The captured code.
+    pub text: String,
+
+    /// Whether the binding was captured by a sequence metavariable (`$$$name`).
+    pub is_sequence: bool,
+}
+
+/// A code template used to rewrite the matches of a [`Pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Text(String),
+    Metavariable(String),
+    Sequence(String),
+}
+
+impl Pattern {
+    /// Parses a pattern from the given PHP code fragment.
+    ///
+    /// The fragment must contain a single expression (e.g. `$x->setUp($$$args)`) or a single
+    /// statement (e.g. `return $x;`), without an opening tag.
+    ///
+    /// # Parameters
+    ///
+    /// - `interner`: The interner to use while parsing the pattern.
+    /// - `pattern`: The code of the pattern.
+    ///
+    /// # Returns
+    ///
+    /// The parsed pattern, or an error if the code is not a valid expression or statement.
+    pub fn new(interner: &ThreadedInterner, pattern: &str) -> Result<Self, PatternError> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err(PatternError::Empty);
+        }
+
+        if let Some(parsed) = Self::parse(interner, pattern, format!("<?php {}", pattern)) {
+            return Ok(parsed);
+        }
+
+        if let Some(parsed) = Self::parse(interner, pattern, format!("<?php {};", pattern)) {
+            if parsed.is_expression {
+                return Ok(parsed);
+            }
+        }
+
+        Err(PatternError::Invalid(pattern.to_string()))
+    }
+
+    /// Returns the code of this pattern, as written by the user.
+    pub fn get_code(&self) -> &str {
+        &self.source
+    }
+
+    /// Checks whether this pattern matches the given node.
+    ///
+    /// # Parameters
+    ///
+    /// - `content`: The content of the source the node belongs to.
+    /// - `node`: The node to match.
+    ///
+    /// # Returns
+    ///
+    /// The match, or `None` if the node does not match.
+    pub fn matches(&self, content: &str, node: Node<'_>) -> Option<Match> {
+        let mut matcher = Matcher::new(&self.code, content);
+
+        if matcher.match_node(self.root(), node) {
+            Some(Match { span: node.span(), bindings: matcher.take_bindings() })
+        } else {
+            None
+        }
+    }
+
+    /// Finds all the matches of this pattern in the given program.
+    ///
+    /// Matches are returned in source order, nested matches are included.
+    ///
+    /// # Parameters
+    ///
+    /// - `content`: The content of the source the program was parsed from.
+    /// - `program`: The program to search.
+    ///
+    /// # Returns
+    ///
+    /// A list of all matches.
+    pub fn find_all(&self, content: &str, program: &Program) -> Vec<Match> {
+        let mut matches = vec![];
+        self.find_in(content, Node::Program(program), &mut matches);
+
+        matches
+    }
+
+    fn find_in(&self, content: &str, node: Node<'_>, matches: &mut Vec<Match>) {
+        if let Some(result) = self.matches(content, node) {
+            matches.push(result);
+        }
+
+        for child in node.children() {
+            self.find_in(content, child, matches);
+        }
+    }
+
+    fn parse(interner: &ThreadedInterner, source: &str, code: String) -> Option<Self> {
+        let (program, error) = mago_parser::parse(interner, Input::new(SourceIdentifier::dummy(), code.as_bytes()));
+        if error.is_some() {
+            return None;
+        }
+
+        let mut statements = program.statements.iter().filter(|statement| is_pattern_statement(statement));
+        let statement = statements.next()?;
+        if statements.next().is_some() {
+            return None;
+        }
+
+        let is_expression = matches!(statement, Statement::Expression(_));
+
+        Some(Self { source: source.to_string(), code, program, is_expression })
+    }
+
+    fn root(&self) -> Node<'_> {
+        let statement = self
+            .program
+            .statements
+            .iter()
+            .find(|statement| is_pattern_statement(statement))
+            .expect("pattern must contain a statement");
+
+        match statement {
+            Statement::Expression(statement) if self.is_expression => Node::Expression(&statement.expression),
+            statement => Node::Statement(statement),
+        }
+    }
+}
+
+impl Template {
+    /// Parses a template from the given code.
+    ///
+    /// # Parameters
+    ///
+    /// - `template`: The code of the template, where `$name` and `$$$name` refer to metavariables.
+    ///
+    /// # Returns
+    ///
+    /// The parsed template.
+    pub fn new(template: &str) -> Self {
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut characters = template.char_indices().peekable();
+
+        while let Some((index, character)) = characters.next() {
+            if character != '$' {
+                text.push(character);
+
+                continue;
+            }
+
+            let rest = &template[index..];
+            let is_sequence = rest.starts_with("$$$");
+            let prefix = if is_sequence { 3 } else { 1 };
+            let name = take_identifier(&rest[prefix..]);
+
+            if name.is_empty() || (!is_sequence && name == "this") {
+                text.push(character);
+
+                continue;
+            }
+
+            if !text.is_empty() {
+                parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+            }
+
+            parts.push(if is_sequence {
+                TemplatePart::Sequence(name.to_string())
+            } else {
+                TemplatePart::Metavariable(name.to_string())
+            });
+
+            for _ in 1..(prefix + name.len()) {
+                characters.next();
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+
+        Self { parts }
+    }
+
+    /// Renders this template using the bindings of the given match.
+    ///
+    /// Metavariables that are not bound by the match are rendered as-is.
+    ///
+    /// # Parameters
+    ///
+    /// - `result`: The match to take bindings from.
+    ///
+    /// # Returns
+    ///
+    /// The rendered code.
+    pub fn render(&self, result: &Match) -> String {
+        let mut output = String::new();
+        for part in &self.parts {
+            match part {
+                TemplatePart::Text(text) => output.push_str(text),
+                TemplatePart::Metavariable(name) => match result.bindings.get(name) {
+                    Some(binding) => output.push_str(&binding.text),
+                    None => {
+                        output.push('$');
+                        output.push_str(name);
+                    }
+                },
+                TemplatePart::Sequence(name) => match result.bindings.get(name) {
+                    Some(binding) => output.push_str(&binding.text),
+                    None => {
+                        output.push_str("$$$");
+                        output.push_str(name);
+                    }
+                },
+            }
+        }
+
+        output
+    }
+}
+
+fn is_pattern_statement(statement: &Statement) -> bool {
+    !matches!(statement, Statement::OpeningTag(_) | Statement::Noop(_))
+}
+
+pub(crate) fn take_identifier(input: &str) -> &str {
+    let end = input
+        .char_indices()
+        .find(|(index, character)| {
+            !(character.is_ascii_alphabetic() || *character == '_' || (*index > 0 && character.is_ascii_digit()))
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(input.len());
+
+    &input[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn find(pattern: &str, code: &str) -> Vec<(String, Match)> {
+        let interner = ThreadedInterner::new();
+        let pattern = Pattern::new(&interner, pattern).unwrap();
+        let (program, error) = mago_parser::parse(&interner, Input::new(SourceIdentifier::dummy(), code.as_bytes()));
+        assert!(error.is_none(), "failed to parse `{}`", code);
+
+        pattern
+            .find_all(code, &program)
+            .into_iter()
+            .map(|result| (code[result.span.start.offset..result.span.end.offset].to_string(), result))
+            .collect()
+    }
+
+    fn texts(pattern: &str, code: &str) -> Vec<String> {
+        find(pattern, code).into_iter().map(|(text, _)| text).collect()
+    }
+
+    #[test]
+    fn test_metavariables() {
+        let matches = find("$x->setUp($$$args)", "<?php $this->setUp(); $foo->bar->SETUP( 1, 2 ); $a->setDown();");
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].0, "$this->setUp()");
+        assert_eq!(matches[0].1.bindings["x"].text, "$this");
+        assert_eq!(matches[0].1.bindings["args"].text, "");
+        assert_eq!(matches[1].1.bindings["x"].text, "$foo->bar");
+        assert_eq!(matches[1].1.bindings["args"].text, "1, 2");
+    }
+
+    #[test]
+    fn test_sequence_metavariable_with_fixed_arguments() {
+        assert_eq!(texts("foo($$$before, 1)", "<?php foo(1); foo(2, 1); foo(1, 2);"), vec!["foo(1)", "foo(2, 1)"]);
+    }
+
+    #[test]
+    fn test_repeated_metavariable() {
+        assert_eq!(
+            texts("$x === $x", "<?php $a === $a; $a === $b; $a->b === $a -> b;"),
+            vec!["$a === $a", "$a->b === $a -> b"]
+        );
+    }
+
+    #[test]
+    fn test_repeated_metavariable_is_case_sensitive_for_variables() {
+        assert_eq!(texts("$x === $x", "<?php $a === $A; $a->b === $a->B; FOO === foo;"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_repeated_metavariable_is_case_sensitive_for_strings() {
+        assert_eq!(
+            texts("$x === $x", "<?php 'A' === 'a'; \"$a\" === \"$A\"; foo('X') === FOO('x');"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_repeated_metavariable_ignores_case_of_names() {
+        assert_eq!(
+            texts("$x === $x", "<?php foo('x') === FOO('x'); new Foo === NEW foo; $a->b() === $a->B();"),
+            vec!["foo('x') === FOO('x')", "new Foo === NEW foo", "$a->b() === $a->B()"]
+        );
+    }
+
+    #[test]
+    fn test_literals_and_operators() {
+        assert_eq!(
+            texts("$x + 1", "<?php $a + 1; $a + 2; $a - 1; ($b + 1) + 1;"),
+            vec!["$a + 1", "($b + 1) + 1", "$b + 1"]
+        );
+        assert_eq!(texts("'a'", "<?php echo 'a', \"a\", 'b';"), vec!["'a'"]);
+    }
+
+    #[test]
+    fn test_this_is_not_a_metavariable() {
+        assert_eq!(texts("$this->foo()", "<?php $this->foo(); $that->foo();"), vec!["$this->foo()"]);
+    }
+
+    #[test]
+    fn test_comments_are_ignored() {
+        assert_eq!(texts("foo($x)", "<?php foo(/* a */ 1); foo(1, 2);"), vec!["foo(/* a */ 1)"]);
+    }
+
+    #[test]
+    fn test_statement_pattern() {
+        assert_eq!(
+            texts("return null;", "<?php function a() { return null; } function b() { return 1; }"),
+            vec!["return null;"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let interner = ThreadedInterner::new();
+
+        assert!(matches!(Pattern::new(&interner, "  "), Err(PatternError::Empty)));
+        assert!(Pattern::new(&interner, "foo(").is_err());
+        assert!(Pattern::new(&interner, "foo(); bar();").is_err());
+    }
+
+    #[test]
+    fn test_template() {
+        let matches = find("$x->setUp($$$args)", "<?php $foo->setUp(1, 2);");
+        let template = Template::new("$x->initialize($$$args, $this, $unknown)");
+
+        assert_eq!(template.render(&matches[0].1), "$foo->initialize(1, 2, $this, $unknown)");
+    }
+
+    #[test]
+    fn test_get_code() {
+        let interner = ThreadedInterner::new();
+
+        assert_eq!(Pattern::new(&interner, "foo($x)").unwrap().get_code(), "foo($x)");
+        assert_eq!(Pattern::new(&interner, "return $x;").unwrap().get_code(), "return $x;");
+    }
+}
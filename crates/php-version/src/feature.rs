@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 pub enum Feature {
     NullCoalesceAssign,
     ParameterContravariance,
@@ -35,6 +37,8 @@ pub enum Feature {
     FinalConstants,
     ReadonlyProperties,
     Enums,
+    NeverReturnType,
+    NewInInitializers,
     PureIntersectionTypes,
     TentativeReturnTypes,
     ClosureCreation,
@@ -64,4 +68,5 @@ pub enum Feature {
     JsonValidate,
     ClosureInConstantExpressions,
     ArrowFunctions,
+    FlexibleHeredocs,
 }
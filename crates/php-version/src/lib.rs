@@ -26,7 +26,7 @@ pub mod feature;
 /// assert_eq!(version.to_version_id(), 0x08_04_00);
 /// assert_eq!(version.to_string(), "8.4.0");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct PHPVersion(u32);
 
@@ -61,6 +61,9 @@ impl PHPVersion {
     /// The PHP 8.4 version.
     pub const PHP84: PHPVersion = PHPVersion::new(8, 4, 0);
 
+    /// The latest PHP version supported.
+    pub const LATEST: PHPVersion = PHPVersion::PHP84;
+
     /// Creates a new `PHPVersion` from the provided `major`, `minor`, and `patch` values.
     ///
     /// The internal representation packs these three components into a single `u32`
@@ -225,11 +228,14 @@ impl PHPVersion {
             | Feature::PregCaptureOnlyNamedGroups => self.0 >= 0x08_02_00,
             Feature::InterfaceConstantImplicitlyFinal => self.0 < 0x08_01_00,
             Feature::ParameterTypeWidening => self.0 >= 0x07_02_00,
+            Feature::FlexibleHeredocs => self.0 >= 0x07_03_00,
             Feature::AllUnicodeScalarCodePointsInMbSubstituteCharacter => self.0 >= 0x07_02_00,
             Feature::PassNoneEncodings => self.0 < 0x07_03_00,
             Feature::FinalConstants
             | Feature::ReadonlyProperties
             | Feature::Enums
+            | Feature::NeverReturnType
+            | Feature::NewInInitializers
             | Feature::PureIntersectionTypes
             | Feature::TentativeReturnTypes
             | Feature::ClosureCreation
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RefactorError {
+    SymbolNotFound(String),
+    InvalidName(String),
+    NameConflict(String),
+    NotUserDefined(String, String),
+    SharedSource(String),
+}
+
+impl std::fmt::Display for RefactorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SymbolNotFound(symbol) => write!(f, "symbol `{}` could not be found", symbol),
+            Self::InvalidName(name) => write!(f, "`{}` is not a valid identifier", name),
+            Self::NameConflict(name) => write!(f, "symbol `{}` already exists", name),
+            Self::NotUserDefined(symbol, declaration) => {
+                write!(
+                    f,
+                    "symbol `{}` cannot be refactored, it is also declared by `{}` outside of the project",
+                    symbol, declaration
+                )
+            }
+            Self::SharedSource(symbol) => {
+                write!(f, "symbol `{}` cannot be moved, its source also declares other symbols", symbol)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RefactorError {}
@@ -0,0 +1,31 @@
+use mago_ast::ast::UseType;
+
+pub mod move_class;
+pub mod rename;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    Function,
+    Constant,
+}
+
+pub fn reference_kind(r#type: &UseType) -> ReferenceKind {
+    match r#type {
+        UseType::Function(_) => ReferenceKind::Function,
+        UseType::Const(_) => ReferenceKind::Constant,
+    }
+}
+
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut characters = name.chars();
+
+    matches!(characters.next(), Some(c) if c == '_' || c.is_ascii_alphabetic() || !c.is_ascii())
+        && characters.all(|c| c == '_' || c.is_ascii_alphanumeric() || !c.is_ascii())
+}
+
+/// Returns the content of a single or double quoted string literal, with escaped backslashes unescaped.
+pub fn unquote(value: &str) -> String {
+    let value = value.strip_prefix(['\'', '"']).and_then(|value| value.strip_suffix(['\'', '"'])).unwrap_or(value);
+
+    value.replace("\\\\", "\\")
+}
@@ -0,0 +1,552 @@
+use std::collections::BTreeSet;
+
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::ast::*;
+use mago_ast::Program;
+use mago_ast::Sequence;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::internal::reference_kind;
+use crate::internal::unquote;
+use crate::internal::ReferenceKind;
+use crate::move_class::ClassMover;
+
+#[derive(Debug)]
+pub struct MoveContext<'a> {
+    pub interner: &'a ThreadedInterner,
+    pub codebase: &'a CodebaseReflection,
+    pub names: &'a Names,
+    pub mover: &'a ClassMover,
+    pub is_moved_source: bool,
+}
+
+/// A namespace, or the global scope of a program without namespaces, along with
+/// the imports that have to be added to it.
+#[derive(Debug)]
+struct NamespaceScope {
+    name: String,
+    insertion: usize,
+    has_imports: bool,
+    declaration: Option<String>,
+    aliases: HashMap<String, String>,
+    function_aliases: HashMap<String, String>,
+    constant_aliases: HashMap<String, String>,
+    imports: BTreeSet<String>,
+}
+
+#[derive(Debug)]
+pub struct MoveWalker {
+    pub plan: FixPlan,
+    pub issues: IssueCollection,
+    edited: HashSet<usize>,
+    reference_kinds: HashMap<usize, ReferenceKind>,
+    scopes: Vec<NamespaceScope>,
+}
+
+impl MoveWalker {
+    pub fn new() -> Self {
+        Self {
+            plan: FixPlan::new(),
+            issues: IssueCollection::new(),
+            edited: HashSet::default(),
+            reference_kinds: HashMap::default(),
+            scopes: vec![],
+        }
+    }
+
+    fn replace(&mut self, span: Span, text: impl Into<String>) {
+        if self.edited.insert(span.start.offset) {
+            self.plan.replace(span.to_range(), text, SafetyClassification::Safe);
+        }
+    }
+
+    fn report(&mut self, context: &MoveContext<'_>, span: Span, reason: &str) {
+        let mover = context.mover;
+
+        self.issues.push(
+            Issue::new(Level::Warning, format!("Possible reference to `{}` could not be updated.", mover.old_name))
+                .with_annotation(Annotation::primary(span).with_message(reason.to_string()))
+                .with_help(format!("Review this code, and update it to refer to `{}` if needed.", mover.new_name)),
+        );
+    }
+
+    fn enter_scope(
+        &mut self,
+        context: &MoveContext<'_>,
+        name: String,
+        statements: &Sequence<Statement>,
+        insertion: usize,
+        declaration: Option<String>,
+    ) {
+        let interner = context.interner;
+        let qualify = |short: &str| if name.is_empty() { short.to_string() } else { format!("{}\\{}", name, short) };
+
+        let mut scope = NamespaceScope {
+            insertion,
+            has_imports: false,
+            declaration,
+            aliases: HashMap::default(),
+            function_aliases: HashMap::default(),
+            constant_aliases: HashMap::default(),
+            imports: BTreeSet::new(),
+            name: name.clone(),
+        };
+
+        for statement in statements.iter() {
+            let declared = match statement {
+                Statement::Use(r#use) => {
+                    scope.has_imports = true;
+                    scope.insertion = r#use.span().end.offset;
+
+                    for (item, prefix, kind) in use_items(interner, r#use) {
+                        let imported = match prefix {
+                            Some(prefix) => format!("{}\\{}", prefix, interner.lookup(&item.name.value())),
+                            None => interner.lookup(&item.name.value()).to_string(),
+                        };
+
+                        let imported = imported.trim_start_matches('\\').to_string();
+                        let alias = match &item.alias {
+                            Some(alias) => interner.lookup(&alias.identifier.value).to_string(),
+                            None => imported.rsplit('\\').next().unwrap_or(&imported).to_string(),
+                        };
+
+                        let aliases = match kind {
+                            None => &mut scope.aliases,
+                            Some(ReferenceKind::Function) => &mut scope.function_aliases,
+                            Some(ReferenceKind::Constant) => &mut scope.constant_aliases,
+                        };
+
+                        aliases.insert(alias.to_ascii_lowercase(), imported.to_ascii_lowercase());
+                    }
+
+                    continue;
+                }
+                Statement::Class(class) => &class.name,
+                Statement::Interface(interface) => &interface.name,
+                Statement::Trait(r#trait) => &r#trait.name,
+                Statement::Enum(r#enum) => &r#enum.name,
+                _ => continue,
+            };
+
+            let declared = interner.lookup(&declared.value);
+
+            scope.aliases.insert(declared.to_ascii_lowercase(), qualify(declared).to_ascii_lowercase());
+        }
+
+        self.scopes.push(scope);
+    }
+
+    fn exit_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+
+        let mut text = scope.declaration.map(|namespace| format!("\n\nnamespace {};", namespace)).unwrap_or_default();
+        if !scope.imports.is_empty() {
+            text.push_str(if scope.has_imports { "\n" } else { "\n\n" });
+            text.push_str(
+                &scope.imports.iter().map(|import| format!("use {};", import)).collect::<Vec<_>>().join("\n"),
+            );
+        }
+
+        if !text.is_empty() {
+            self.plan.insert(scope.insertion, text, SafetyClassification::Safe);
+        }
+    }
+
+    /// Imports the given name in the current scope, unless the alias it would be imported as is already
+    /// taken by another symbol.
+    ///
+    /// Returns whether the name can be referred to by its alias.
+    fn import(&mut self, context: &MoveContext<'_>, kind: Option<ReferenceKind>, name: &str, alias: &str) -> bool {
+        let Some(scope) = self.scopes.last_mut() else {
+            return false;
+        };
+
+        let aliases = match kind {
+            None => &mut scope.aliases,
+            Some(ReferenceKind::Function) => &mut scope.function_aliases,
+            Some(ReferenceKind::Constant) => &mut scope.constant_aliases,
+        };
+
+        let lowered_name = name.to_ascii_lowercase();
+        let lowered_alias = alias.to_ascii_lowercase();
+        if let Some(existing) = aliases.get(&lowered_alias) {
+            return *existing == lowered_name;
+        }
+
+        if kind.is_none() {
+            let mover = context.mover;
+            if context.is_moved_source {
+                if lowered_alias == mover.get_new_short_name().to_ascii_lowercase() {
+                    return false;
+                }
+            } else {
+                let shadowed =
+                    if scope.name.is_empty() { alias.to_string() } else { format!("{}\\{}", scope.name, alias) };
+
+                if !shadowed.eq_ignore_ascii_case(&mover.old_name)
+                    && context
+                        .codebase
+                        .get_named_class_like(context.interner, &context.interner.intern(shadowed))
+                        .is_some()
+                {
+                    return false;
+                }
+            }
+        }
+
+        aliases.insert(lowered_alias, lowered_name);
+        scope.imports.insert(match kind {
+            None => name.to_string(),
+            Some(ReferenceKind::Function) => format!("function {}", name),
+            Some(ReferenceKind::Constant) => format!("const {}", name),
+        });
+
+        true
+    }
+
+    fn current_namespace(&self) -> &str {
+        self.scopes.last().map(|scope| scope.name.as_str()).unwrap_or_default()
+    }
+
+    /// Updates a reference to the moved class-like.
+    fn walk_moved_class_reference(&mut self, context: &MoveContext<'_>, span: Span, text: &str, imported: bool) {
+        let mover = context.mover;
+        let fully_qualified = format!("\\{}", mover.new_name);
+
+        if text.contains('\\') {
+            self.replace(span, fully_qualified);
+
+            return;
+        }
+
+        let is_renamed = !mover.get_old_short_name().eq_ignore_ascii_case(mover.get_new_short_name());
+        if imported {
+            // The `use` statement is updated, so only references through the short name need updating.
+            if is_renamed && text.eq_ignore_ascii_case(mover.get_old_short_name()) {
+                self.replace(span, mover.get_new_short_name());
+            }
+
+            return;
+        }
+
+        // References from the new namespace resolve to the moved class-like as is, while references
+        // from the old one need an import, unless its alias is already taken.
+        let is_resolvable = context.is_moved_source
+            || self.current_namespace().eq_ignore_ascii_case(mover.get_new_namespace())
+            || self.import(context, None, &mover.new_name, mover.get_new_short_name());
+
+        if !is_resolvable {
+            self.replace(span, fully_qualified);
+        } else if is_renamed {
+            self.replace(span, mover.get_new_short_name());
+        }
+    }
+
+    /// Updates a reference, made from within the moved source, to a symbol of the old namespace.
+    fn walk_old_namespace_reference(
+        &mut self,
+        context: &MoveContext<'_>,
+        span: Span,
+        text: &str,
+        resolved: &str,
+        kind: Option<ReferenceKind>,
+    ) {
+        let mover = context.mover;
+        if text.starts_with('\\') || !self.current_namespace().eq_ignore_ascii_case(mover.get_old_namespace()) {
+            return;
+        }
+
+        if text.contains('\\') {
+            self.replace(span, format!("\\{}", resolved));
+
+            return;
+        }
+
+        if let Some(kind) = kind {
+            // Unqualified functions and constants fall back to the global namespace, so they only
+            // need to be imported when they are declared in the old namespace.
+            let exists = match kind {
+                ReferenceKind::Function => {
+                    context.codebase.function_exists(context.interner, &context.interner.intern(resolved))
+                }
+                ReferenceKind::Constant => {
+                    context.codebase.constant_exists(context.interner, &context.interner.intern(resolved))
+                }
+            };
+
+            if mover.get_old_namespace().is_empty() || !exists {
+                return;
+            }
+        }
+
+        if !self.import(context, kind, resolved, text) {
+            self.replace(span, format!("\\{}", resolved));
+        }
+    }
+
+    fn walk_class_like_declaration(&mut self, context: &MoveContext<'_>, name: &LocalIdentifier) {
+        let mover = context.mover;
+        if !context.is_moved_source || !context.names.contains(&name.span.start) {
+            return;
+        }
+
+        if context.interner.lookup(context.names.get(&name.span)).eq_ignore_ascii_case(&mover.old_name)
+            && context.interner.lookup(&name.value) != mover.get_new_short_name()
+        {
+            self.replace(name.span, mover.get_new_short_name());
+        }
+    }
+
+    fn walk_use_item(
+        &mut self,
+        context: &MoveContext<'_>,
+        r#use: &Use,
+        item: &UseItem,
+        prefix: Option<&str>,
+        kind: Option<ReferenceKind>,
+    ) {
+        let interner = context.interner;
+        let mover = context.mover;
+        if kind.is_some() {
+            return;
+        }
+
+        let text = interner.lookup(&item.name.value());
+        let imported = match prefix {
+            Some(prefix) => format!("{}\\{}", prefix, text),
+            None => text.to_string(),
+        };
+
+        let is_renamed = !mover.get_old_short_name().eq_ignore_ascii_case(mover.get_new_short_name());
+        if !imported.trim_start_matches('\\').eq_ignore_ascii_case(&mover.old_name) {
+            let alias = match &item.alias {
+                Some(alias) => interner.lookup(&alias.identifier.value),
+                None => text.rsplit('\\').next().unwrap_or(text),
+            };
+
+            if is_renamed && alias.eq_ignore_ascii_case(mover.get_new_short_name()) {
+                self.report(context, item.span(), "This import conflicts with the new name.");
+            }
+
+            return;
+        }
+
+        // An alias matching the old short name is renamed along with the references using it.
+        let alias = item.alias.as_ref().map(|alias| {
+            let value = interner.lookup(&alias.identifier.value);
+            if is_renamed && value.eq_ignore_ascii_case(mover.get_old_short_name()) {
+                self.replace(alias.identifier.span, mover.get_new_short_name());
+
+                mover.get_new_short_name().to_string()
+            } else {
+                value.to_string()
+            }
+        });
+
+        let Some(prefix) = prefix else {
+            let leading = if text.starts_with('\\') { "\\" } else { "" };
+            self.replace(item.name.span(), format!("{}{}", leading, mover.new_name));
+
+            return;
+        };
+
+        let prefix = prefix.trim_start_matches('\\');
+        if let Some(remainder) = mover.new_name.strip_prefix(prefix).and_then(|name| name.strip_prefix('\\')) {
+            if mover.new_name[..prefix.len()].eq_ignore_ascii_case(prefix) {
+                self.replace(item.name.span(), remainder.to_string());
+
+                return;
+            }
+        }
+
+        // The new name is outside of the group prefix, so the item is removed from the group,
+        // and imported separately.
+        let replacement = match &alias {
+            Some(alias) => format!("use {} as {};", mover.new_name, alias),
+            None => format!("use {};", mover.new_name),
+        };
+
+        let UseItems::MixedList(list) = &r#use.items else {
+            return;
+        };
+
+        let position = list.items.iter().position(|other| other.item.span() == item.span()).unwrap_or_default();
+        if list.items.len() == 1 {
+            self.replace(r#use.span(), replacement);
+
+            return;
+        }
+
+        let removed = match list.items.get(position + 1) {
+            Some(next) => Span::new(item.span().start, next.span().start),
+            None => match list.items.tokens.get(position.wrapping_sub(1)) {
+                Some(separator) => Span::new(separator.span.start, item.span().end),
+                None => item.span(),
+            },
+        };
+
+        self.plan.delete(removed.to_range(), SafetyClassification::Safe);
+        self.plan.insert(r#use.span().end.offset, format!("\n{}", replacement), SafetyClassification::Safe);
+    }
+}
+
+impl<'a> MutWalker<MoveContext<'a>> for MoveWalker {
+    fn walk_in_program(&mut self, program: &Program, context: &mut MoveContext<'a>) {
+        if program.statements.iter().any(|statement| matches!(statement, Statement::Namespace(_))) {
+            return;
+        }
+
+        // Imports, and the namespace declaration of a class-like moved out of the global namespace,
+        // are inserted after the opening tag and the `declare` statements.
+        let insertion = program
+            .statements
+            .iter()
+            .take_while(|statement| matches!(statement, Statement::OpeningTag(_) | Statement::Declare(_)))
+            .last()
+            .map(|statement| statement.span().end.offset)
+            .unwrap_or_default();
+
+        let declaration = context.is_moved_source.then(|| context.mover.get_new_namespace().to_string());
+
+        self.enter_scope(context, String::new(), &program.statements, insertion, declaration);
+    }
+
+    fn walk_out_program(&mut self, _program: &Program, _context: &mut MoveContext<'a>) {
+        self.exit_scope();
+    }
+
+    fn walk_in_namespace(&mut self, namespace: &Namespace, context: &mut MoveContext<'a>) {
+        let name =
+            namespace.name.as_ref().map(|name| context.interner.lookup(&name.value()).to_string()).unwrap_or_default();
+
+        if context.is_moved_source && name.eq_ignore_ascii_case(context.mover.get_old_namespace()) {
+            if let Some(identifier) = &namespace.name {
+                self.replace(identifier.span(), context.mover.get_new_namespace());
+            }
+        }
+
+        let insertion = match &namespace.body {
+            NamespaceBody::Implicit(body) => body.terminator.span().end.offset,
+            NamespaceBody::BraceDelimited(block) => block.left_brace.end.offset,
+        };
+
+        self.enter_scope(context, name, namespace.statements(), insertion, None);
+    }
+
+    fn walk_out_namespace(&mut self, _namespace: &Namespace, _context: &mut MoveContext<'a>) {
+        self.exit_scope();
+    }
+
+    fn walk_in_use(&mut self, r#use: &Use, context: &mut MoveContext<'a>) {
+        for (item, prefix, kind) in use_items(context.interner, r#use) {
+            self.walk_use_item(context, r#use, item, prefix, kind);
+        }
+    }
+
+    fn walk_in_class(&mut self, class: &Class, context: &mut MoveContext<'a>) {
+        self.walk_class_like_declaration(context, &class.name);
+    }
+
+    fn walk_in_interface(&mut self, interface: &Interface, context: &mut MoveContext<'a>) {
+        self.walk_class_like_declaration(context, &interface.name);
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut MoveContext<'a>) {
+        self.walk_class_like_declaration(context, &r#trait.name);
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut MoveContext<'a>) {
+        self.walk_class_like_declaration(context, &r#enum.name);
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, _context: &mut MoveContext<'a>) {
+        if let Expression::Identifier(identifier) = function_call.function.as_ref() {
+            self.reference_kinds.insert(identifier.span().start.offset, ReferenceKind::Function);
+        }
+    }
+
+    fn walk_in_function_closure_creation(
+        &mut self,
+        creation: &FunctionClosureCreation,
+        _context: &mut MoveContext<'a>,
+    ) {
+        if let Expression::Identifier(identifier) = creation.function.as_ref() {
+            self.reference_kinds.insert(identifier.span().start.offset, ReferenceKind::Function);
+        }
+    }
+
+    fn walk_in_constant_access(&mut self, constant_access: &ConstantAccess, _context: &mut MoveContext<'a>) {
+        self.reference_kinds.insert(constant_access.name.span().start.offset, ReferenceKind::Constant);
+    }
+
+    fn walk_in_identifier(&mut self, identifier: &Identifier, context: &mut MoveContext<'a>) {
+        let span = identifier.span();
+        if !context.names.contains(&span.start) {
+            return;
+        }
+
+        let kind = self.reference_kinds.get(&span.start.offset).copied();
+        let text = context.interner.lookup(&identifier.value());
+        let resolved = context.interner.lookup(context.names.get(&span));
+        let imported = context.names.is_imported(&span);
+
+        if kind.is_none() && resolved.eq_ignore_ascii_case(&context.mover.old_name) {
+            self.walk_moved_class_reference(context, span, text, imported);
+        } else if context.is_moved_source
+            && !imported
+            && !context.mover.get_old_namespace().eq_ignore_ascii_case(context.mover.get_new_namespace())
+        {
+            self.walk_old_namespace_reference(context, span, text, resolved, kind);
+        }
+    }
+
+    fn walk_in_literal_string(&mut self, literal_string: &LiteralString, context: &mut MoveContext<'a>) {
+        let value = unquote(context.interner.lookup(&literal_string.value));
+
+        if value.trim_start_matches('\\').eq_ignore_ascii_case(&context.mover.old_name) {
+            self.report(context, literal_string.span, "This string may refer to the moved class.");
+        }
+    }
+}
+
+/// Returns the items of a `use` statement, along with the prefix of the group they belong to, if any,
+/// and their kind, which is `None` for class-like imports.
+fn use_items<'u>(
+    interner: &'u ThreadedInterner,
+    r#use: &'u Use,
+) -> Vec<(&'u UseItem, Option<&'u str>, Option<ReferenceKind>)> {
+    match &r#use.items {
+        UseItems::Sequence(sequence) => sequence.items.iter().map(|item| (item, None, None)).collect(),
+        UseItems::TypedSequence(sequence) => {
+            let kind = reference_kind(&sequence.r#type);
+
+            sequence.items.iter().map(|item| (item, None, Some(kind))).collect()
+        }
+        UseItems::TypedList(list) => {
+            let kind = reference_kind(&list.r#type);
+            let prefix = interner.lookup(&list.namespace.value());
+
+            list.items.iter().map(|item| (item, Some(prefix), Some(kind))).collect()
+        }
+        UseItems::MixedList(list) => {
+            let prefix = interner.lookup(&list.namespace.value());
+
+            list.items.iter().map(|item| (&item.item, Some(prefix), item.r#type.as_ref().map(reference_kind))).collect()
+        }
+    }
+}
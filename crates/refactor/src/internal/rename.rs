@@ -0,0 +1,1110 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::ast::*;
+use mago_ast::Program;
+use mago_ast::Trivia;
+use mago_ast::TriviaKind;
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+use mago_span::HasSpan;
+use mago_span::Position;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::internal::reference_kind;
+use crate::internal::unquote;
+use crate::internal::ReferenceKind;
+use crate::rename::Renamer;
+use crate::symbol::Symbol;
+
+#[derive(Debug)]
+pub struct RenameContext<'a> {
+    pub interner: &'a ThreadedInterner,
+    pub codebase: &'a CodebaseReflection,
+    pub names: &'a Names,
+    pub renamer: &'a Renamer,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClassLikeScope {
+    in_hierarchy: bool,
+    parent: Option<StringIdentifier>,
+}
+
+#[derive(Debug)]
+pub struct RenameWalker {
+    pub plan: FixPlan,
+    pub issues: IssueCollection,
+    edited: HashSet<usize>,
+    reference_kinds: HashMap<usize, ReferenceKind>,
+    scopes: Vec<ClassLikeScope>,
+    /// For each function-like being walked, the variables holding an object whose class-like is known to be,
+    /// or known not to be, in the hierarchy of the renamed member.
+    variables: Vec<HashMap<StringIdentifier, bool>>,
+    promoted_property_scope: Option<Span>,
+}
+
+impl RenameWalker {
+    pub fn new() -> Self {
+        Self {
+            plan: FixPlan::new(),
+            issues: IssueCollection::new(),
+            edited: HashSet::default(),
+            reference_kinds: HashMap::default(),
+            scopes: vec![],
+            variables: vec![],
+            promoted_property_scope: None,
+        }
+    }
+
+    fn replace(&mut self, span: Span, text: impl Into<String>) {
+        if self.edited.insert(span.start.offset) {
+            self.plan.replace(span.to_range(), text, SafetyClassification::Safe);
+        }
+    }
+
+    /// Replaces the last segment of a possibly qualified name, e.g. `Foo` in `App\Foo`.
+    ///
+    /// The name is left untouched if its last segment is an alias of the renamed symbol,
+    /// as the alias keeps referring to it once the `use` statement is updated.
+    fn replace_last_segment(&mut self, context: &RenameContext<'_>, span: Span, text: &str) {
+        let renamer = context.renamer;
+        let segment = text.rsplit('\\').next().unwrap_or(text);
+
+        let matches = match renamer.symbol {
+            Symbol::Constant(_) => segment == renamer.old_name,
+            _ => segment.eq_ignore_ascii_case(&renamer.old_name),
+        };
+
+        if matches {
+            let start = span.end.offset - segment.len();
+
+            self.replace(Span::new(Position::new(span.start.source, start), span.end), renamer.new_name.clone());
+        }
+    }
+
+    fn report(&mut self, context: &RenameContext<'_>, span: Span, reason: &str) {
+        let renamer = context.renamer;
+
+        self.issues.push(
+            Issue::new(
+                Level::Warning,
+                format!("Possible reference to `{}` could not be renamed.", renamer.fully_qualified_name),
+            )
+            .with_annotation(Annotation::primary(span).with_message(reason.to_string()))
+            .with_help(format!("Review this code, and rename it to `{}` if needed.", renamer.new_name)),
+        );
+    }
+
+    fn is_in_hierarchy(&self) -> bool {
+        self.scopes.last().is_some_and(|scope| scope.in_hierarchy)
+    }
+
+    /// Returns whether the given class expression, e.g. `self` in `self::foo()`, refers to a class-like
+    /// in the hierarchy of the renamed member, or `None` if it can not be determined.
+    fn resolve_class(&self, context: &RenameContext<'_>, class: &Expression) -> Option<bool> {
+        match class {
+            Expression::Self_(_) | Expression::Static(_) => Some(self.is_in_hierarchy()),
+            Expression::Parent(_) => Some(self.is_parent_in_hierarchy(context)),
+            Expression::Identifier(identifier) => Some(is_name_in_hierarchy(context, identifier.span())),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the given object, e.g. `$foo` in `$foo->bar()`, is an instance of a class-like in
+    /// the hierarchy of the renamed member, or `None` if it can not be determined.
+    ///
+    /// The class-like is known for `$this`, new instances, and parameters with a class-like type hint
+    /// that are never reassigned.
+    fn resolve_object(&self, context: &RenameContext<'_>, object: &Expression) -> Option<bool> {
+        match object {
+            Expression::Parenthesized(parenthesized) => self.resolve_object(context, &parenthesized.expression),
+            Expression::Instantiation(instantiation) => self.resolve_class(context, &instantiation.class),
+            Expression::Variable(Variable::Direct(variable)) => {
+                if context.interner.lookup(&variable.name) == "$this" {
+                    return Some(self.is_in_hierarchy());
+                }
+
+                self.variables.last().and_then(|variables| variables.get(&variable.name)).copied()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether every class-like in the given type hint is in the hierarchy of the renamed member,
+    /// or `None` if the hint is not made of class-likes only, or mixes class-likes in and out of the hierarchy.
+    fn resolve_hint(&self, context: &RenameContext<'_>, hint: &Hint) -> Option<bool> {
+        match hint {
+            Hint::Identifier(identifier) => Some(is_name_in_hierarchy(context, identifier.span())),
+            Hint::Self_(_) | Hint::Static(_) => Some(self.is_in_hierarchy()),
+            Hint::Parent(_) => Some(self.is_parent_in_hierarchy(context)),
+            Hint::Nullable(nullable) => self.resolve_hint(context, &nullable.hint),
+            Hint::Parenthesized(parenthesized) => self.resolve_hint(context, &parenthesized.hint),
+            Hint::Union(union) => match (union.left.as_ref(), union.right.as_ref()) {
+                (Hint::Null(_), hint) | (hint, Hint::Null(_)) => self.resolve_hint(context, hint),
+                (left, right) => {
+                    let left = self.resolve_hint(context, left)?;
+
+                    (self.resolve_hint(context, right)? == left).then_some(left)
+                }
+            },
+            _ => None,
+        }
+    }
+
+    fn is_parent_in_hierarchy(&self, context: &RenameContext<'_>) -> bool {
+        self.scopes
+            .last()
+            .and_then(|scope| scope.parent)
+            .is_some_and(|parent| context.renamer.hierarchy.contains(&parent))
+    }
+
+    /// Enters a function-like, tracking its parameters with a known class-like along with the given
+    /// inherited variables, except for the ones reassigned in its body.
+    fn enter_function_like(
+        &mut self,
+        context: &RenameContext<'_>,
+        parameter_list: &FunctionLikeParameterList,
+        mut variables: HashMap<StringIdentifier, bool>,
+        reassigned: HashSet<StringIdentifier>,
+    ) {
+        for parameter in parameter_list.parameters.iter() {
+            let in_hierarchy = match &parameter.hint {
+                Some(hint) if parameter.ellipsis.is_none() => self.resolve_hint(context, hint),
+                _ => None,
+            };
+
+            match in_hierarchy {
+                Some(in_hierarchy) => variables.insert(parameter.variable.name, in_hierarchy),
+                None => variables.remove(&parameter.variable.name),
+            };
+        }
+
+        variables.retain(|name, _| !reassigned.contains(name));
+
+        self.variables.push(variables);
+    }
+
+    fn matches_member(&self, context: &RenameContext<'_>, name: &str) -> bool {
+        match context.renamer.symbol {
+            Symbol::Method(..) => name.eq_ignore_ascii_case(&context.renamer.old_name),
+            _ => name == context.renamer.old_name,
+        }
+    }
+
+    fn enter_class_like(&mut self, context: &RenameContext<'_>, name: Option<&LocalIdentifier>, parents: &[Span]) {
+        let renamer = context.renamer;
+
+        if let (Some(name), Symbol::ClassLike(_)) = (name, renamer.symbol) {
+            if resolve_lowered(context, name.span).is_some_and(|name| renamer.hierarchy.contains(&name)) {
+                self.replace(name.span, renamer.new_name.clone());
+            }
+        }
+
+        let in_hierarchy = match name {
+            Some(name) => resolve_lowered(context, name.span).is_some_and(|name| renamer.hierarchy.contains(&name)),
+            None => parents
+                .iter()
+                .any(|parent| resolve_lowered(context, *parent).is_some_and(|name| renamer.hierarchy.contains(&name))),
+        };
+
+        self.scopes.push(ClassLikeScope { in_hierarchy, parent: None });
+    }
+
+    fn walk_instance_member(
+        &mut self,
+        context: &RenameContext<'_>,
+        object: &Expression,
+        selector: &ClassLikeMemberSelector,
+    ) {
+        let is_property = matches!(context.renamer.symbol, Symbol::Property(..));
+
+        match selector {
+            ClassLikeMemberSelector::Identifier(identifier) => {
+                if !self.matches_member(context, context.interner.lookup(&identifier.value)) {
+                    return;
+                }
+
+                match self.resolve_object(context, object) {
+                    Some(true) => self.replace(identifier.span, context.renamer.new_name.clone()),
+                    Some(false) => {}
+                    None => {
+                        let reason = if is_property {
+                            "The type of this object is unknown, it may not have the renamed property."
+                        } else {
+                            "The type of this object is unknown, it may not have the renamed method."
+                        };
+
+                        self.report(context, identifier.span, reason);
+                    }
+                }
+            }
+            ClassLikeMemberSelector::Variable(_) | ClassLikeMemberSelector::Expression(_) => {
+                self.report(context, selector.span(), "This member is accessed dynamically.");
+            }
+        }
+    }
+
+    fn walk_static_method(
+        &mut self,
+        context: &RenameContext<'_>,
+        class: &Expression,
+        selector: &ClassLikeMemberSelector,
+    ) {
+        match selector {
+            ClassLikeMemberSelector::Identifier(identifier) => {
+                if !self.matches_member(context, context.interner.lookup(&identifier.value)) {
+                    return;
+                }
+
+                match self.resolve_class(context, class) {
+                    Some(true) => self.replace(identifier.span, context.renamer.new_name.clone()),
+                    Some(false) => {}
+                    None => self.report(context, identifier.span, "The class of this call is resolved dynamically."),
+                }
+            }
+            ClassLikeMemberSelector::Variable(_) | ClassLikeMemberSelector::Expression(_) => {
+                self.report(context, selector.span(), "This method is called dynamically.");
+            }
+        }
+    }
+
+    fn walk_reference(&mut self, context: &RenameContext<'_>, identifier: &Identifier, kind: Option<ReferenceKind>) {
+        let renamer = context.renamer;
+        let span = identifier.span();
+        if !context.names.contains(&span.start) {
+            return;
+        }
+
+        let (target, expected_kind) = match renamer.symbol {
+            Symbol::ClassLike(name) => (name, None),
+            Symbol::Function(name) => (name, Some(ReferenceKind::Function)),
+            Symbol::Constant(name) => (name, Some(ReferenceKind::Constant)),
+            _ => return,
+        };
+
+        if kind != expected_kind {
+            return;
+        }
+
+        let text = context.interner.lookup(&identifier.value());
+        let resolved = *context.names.get(&span);
+
+        let is_reference = match kind {
+            None => context.interner.lowered(&resolved) == context.interner.lowered(&target),
+            Some(kind) => {
+                names_match(context.interner, kind, &resolved, &target)
+                    || (!text.contains('\\')
+                        && !context.names.is_imported(&span)
+                        && !context.interner.lookup(&target).contains('\\')
+                        && !symbol_exists(context, kind, &resolved)
+                        && names_match(context.interner, kind, &identifier.value(), &target))
+            }
+        };
+
+        if is_reference {
+            self.replace_last_segment(context, span, text);
+        }
+    }
+
+    fn walk_use_item(
+        &mut self,
+        context: &RenameContext<'_>,
+        item: &UseItem,
+        prefix: Option<&str>,
+        kind: Option<ReferenceKind>,
+    ) {
+        let renamer = context.renamer;
+        let (target, expected_kind) = match renamer.symbol {
+            Symbol::ClassLike(name) => (name, None),
+            Symbol::Function(name) => (name, Some(ReferenceKind::Function)),
+            Symbol::Constant(name) => (name, Some(ReferenceKind::Constant)),
+            _ => return,
+        };
+
+        if kind != expected_kind {
+            return;
+        }
+
+        let text = context.interner.lookup(&item.name.value());
+        let name = match prefix {
+            Some(prefix) => format!("{}\\{}", prefix.trim_start_matches('\\'), text),
+            None => text.trim_start_matches('\\').to_string(),
+        };
+
+        let name = context.interner.intern(name);
+        if match kind {
+            None => context.interner.lowered(&name) == context.interner.lowered(&target),
+            Some(kind) => names_match(context.interner, kind, &name, &target),
+        } {
+            self.replace_last_segment(context, item.name.span(), text);
+
+            return;
+        }
+
+        let alias = match &item.alias {
+            Some(alias) => context.interner.lookup(&alias.identifier.value),
+            None => text.rsplit('\\').next().unwrap_or(text),
+        };
+
+        if alias.eq_ignore_ascii_case(&renamer.new_name) {
+            self.report(context, item.span(), "This import conflicts with the new name.");
+        }
+    }
+}
+
+impl<'a> MutWalker<RenameContext<'a>> for RenameWalker {
+    fn walk_in_program(&mut self, program: &Program, context: &mut RenameContext<'a>) {
+        if !matches!(context.renamer.symbol, Symbol::ClassLike(_)) {
+            return;
+        }
+
+        for trivia in program.trivia.iter() {
+            if matches!(trivia.kind, TriviaKind::DocBlockComment) {
+                self.walk_docblock(context, trivia);
+            }
+        }
+    }
+
+    fn walk_in_class(&mut self, class: &Class, context: &mut RenameContext<'a>) {
+        self.enter_class_like(context, Some(&class.name), &[]);
+
+        if let Some(parent) = class.extends.as_ref().and_then(|extends| extends.types.first()) {
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.parent = resolve_lowered(context, parent.span());
+            }
+        }
+    }
+
+    fn walk_out_class(&mut self, _class: &Class, _context: &mut RenameContext<'a>) {
+        self.scopes.pop();
+    }
+
+    fn walk_in_interface(&mut self, interface: &Interface, context: &mut RenameContext<'a>) {
+        self.enter_class_like(context, Some(&interface.name), &[]);
+    }
+
+    fn walk_out_interface(&mut self, _interface: &Interface, _context: &mut RenameContext<'a>) {
+        self.scopes.pop();
+    }
+
+    fn walk_in_trait(&mut self, r#trait: &Trait, context: &mut RenameContext<'a>) {
+        self.enter_class_like(context, Some(&r#trait.name), &[]);
+    }
+
+    fn walk_out_trait(&mut self, _trait: &Trait, _context: &mut RenameContext<'a>) {
+        self.scopes.pop();
+    }
+
+    fn walk_in_enum(&mut self, r#enum: &Enum, context: &mut RenameContext<'a>) {
+        self.enter_class_like(context, Some(&r#enum.name), &[]);
+    }
+
+    fn walk_out_enum(&mut self, _enum: &Enum, _context: &mut RenameContext<'a>) {
+        self.scopes.pop();
+    }
+
+    fn walk_in_anonymous_class(&mut self, anonymous_class: &AnonymousClass, context: &mut RenameContext<'a>) {
+        let parents = anonymous_class
+            .extends
+            .iter()
+            .flat_map(|extends| extends.types.iter())
+            .chain(anonymous_class.implements.iter().flat_map(|implements| implements.types.iter()))
+            .map(|parent| parent.span())
+            .collect::<Vec<_>>();
+
+        self.enter_class_like(context, None, &parents);
+
+        if let Some(parent) = anonymous_class.extends.as_ref().and_then(|extends| extends.types.first()) {
+            if let Some(scope) = self.scopes.last_mut() {
+                scope.parent = resolve_lowered(context, parent.span());
+            }
+        }
+    }
+
+    fn walk_out_anonymous_class(&mut self, _anonymous_class: &AnonymousClass, _context: &mut RenameContext<'a>) {
+        self.scopes.pop();
+    }
+
+    fn walk_in_use(&mut self, r#use: &Use, context: &mut RenameContext<'a>) {
+        match &r#use.items {
+            UseItems::Sequence(sequence) => {
+                for item in sequence.items.iter() {
+                    self.walk_use_item(context, item, None, None);
+                }
+            }
+            UseItems::TypedSequence(sequence) => {
+                let kind = reference_kind(&sequence.r#type);
+
+                for item in sequence.items.iter() {
+                    self.walk_use_item(context, item, None, Some(kind));
+                }
+            }
+            UseItems::TypedList(list) => {
+                let kind = reference_kind(&list.r#type);
+                let prefix = context.interner.lookup(&list.namespace.value());
+
+                for item in list.items.iter() {
+                    self.walk_use_item(context, item, Some(prefix), Some(kind));
+                }
+            }
+            UseItems::MixedList(list) => {
+                let prefix = context.interner.lookup(&list.namespace.value());
+
+                for item in list.items.iter() {
+                    self.walk_use_item(context, &item.item, Some(prefix), item.r#type.as_ref().map(reference_kind));
+                }
+            }
+        }
+    }
+
+    fn walk_in_function(&mut self, function: &Function, context: &mut RenameContext<'a>) {
+        if let Symbol::Function(target) = context.renamer.symbol {
+            if resolve_lowered(context, function.name.span) == Some(context.interner.lowered(&target)) {
+                self.replace(function.name.span, context.renamer.new_name.clone());
+            }
+        }
+
+        let reassigned = ReassignedVariables::in_block(&function.body);
+
+        self.enter_function_like(context, &function.parameter_list, HashMap::default(), reassigned);
+    }
+
+    fn walk_out_function(&mut self, _function: &Function, _context: &mut RenameContext<'a>) {
+        self.variables.pop();
+    }
+
+    fn walk_in_closure(&mut self, closure: &Closure, context: &mut RenameContext<'a>) {
+        let mut variables = HashMap::default();
+        if let (Some(use_clause), Some(parent)) = (&closure.use_clause, self.variables.last()) {
+            for variable in use_clause.variables.iter() {
+                if let (None, Some(in_hierarchy)) = (variable.ampersand, parent.get(&variable.variable.name)) {
+                    variables.insert(variable.variable.name, *in_hierarchy);
+                }
+            }
+        }
+
+        let reassigned = ReassignedVariables::in_block(&closure.body);
+
+        self.enter_function_like(context, &closure.parameter_list, variables, reassigned);
+    }
+
+    fn walk_out_closure(&mut self, _closure: &Closure, _context: &mut RenameContext<'a>) {
+        self.variables.pop();
+    }
+
+    fn walk_in_arrow_function(&mut self, arrow_function: &ArrowFunction, context: &mut RenameContext<'a>) {
+        let variables = self.variables.last().cloned().unwrap_or_default();
+        let reassigned = ReassignedVariables::in_expression(&arrow_function.expression);
+
+        self.enter_function_like(context, &arrow_function.parameter_list, variables, reassigned);
+    }
+
+    fn walk_out_arrow_function(&mut self, _arrow_function: &ArrowFunction, _context: &mut RenameContext<'a>) {
+        self.variables.pop();
+    }
+
+    fn walk_in_constant_item(&mut self, constant_item: &ConstantItem, context: &mut RenameContext<'a>) {
+        if let Symbol::Constant(target) = context.renamer.symbol {
+            let span = constant_item.name.span;
+            if context.names.contains(&span.start)
+                && names_match(context.interner, ReferenceKind::Constant, context.names.get(&span), &target)
+            {
+                self.replace(span, context.renamer.new_name.clone());
+            }
+        }
+    }
+
+    fn walk_in_function_call(&mut self, function_call: &FunctionCall, _context: &mut RenameContext<'a>) {
+        if let Expression::Identifier(identifier) = function_call.function.as_ref() {
+            self.reference_kinds.insert(identifier.span().start.offset, ReferenceKind::Function);
+        }
+    }
+
+    fn walk_in_function_closure_creation(
+        &mut self,
+        creation: &FunctionClosureCreation,
+        _context: &mut RenameContext<'a>,
+    ) {
+        if let Expression::Identifier(identifier) = creation.function.as_ref() {
+            self.reference_kinds.insert(identifier.span().start.offset, ReferenceKind::Function);
+        }
+    }
+
+    fn walk_in_constant_access(&mut self, constant_access: &ConstantAccess, _context: &mut RenameContext<'a>) {
+        self.reference_kinds.insert(constant_access.name.span().start.offset, ReferenceKind::Constant);
+    }
+
+    fn walk_in_identifier(&mut self, identifier: &Identifier, context: &mut RenameContext<'a>) {
+        let kind = self.reference_kinds.get(&identifier.span().start.offset).copied();
+
+        self.walk_reference(context, identifier, kind);
+    }
+
+    fn walk_in_method(&mut self, method: &Method, context: &mut RenameContext<'a>) {
+        let reassigned = match &method.body {
+            MethodBody::Concrete(block) => ReassignedVariables::in_block(block),
+            MethodBody::Abstract(_) => HashSet::default(),
+        };
+
+        self.enter_function_like(context, &method.parameter_list, HashMap::default(), reassigned);
+
+        if !self.is_in_hierarchy() {
+            return;
+        }
+
+        let renamer = context.renamer;
+        match renamer.symbol {
+            Symbol::Method(..) if self.matches_member(context, context.interner.lookup(&method.name.value)) => {
+                self.replace(method.name.span, renamer.new_name.clone());
+            }
+            Symbol::Property(..) => {
+                if !context.interner.lookup(&method.name.value).eq_ignore_ascii_case("__construct") {
+                    return;
+                }
+
+                let is_promoted = method.parameter_list.parameters.iter().any(|parameter| {
+                    !parameter.modifiers.is_empty()
+                        && context.interner.lookup(&parameter.variable.name).trim_start_matches('$') == renamer.old_name
+                });
+
+                if is_promoted {
+                    self.promoted_property_scope = Some(method.span());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn walk_out_method(&mut self, _method: &Method, _context: &mut RenameContext<'a>) {
+        self.promoted_property_scope = None;
+        self.variables.pop();
+    }
+
+    fn walk_in_direct_variable(&mut self, direct_variable: &DirectVariable, context: &mut RenameContext<'a>) {
+        let Some(scope) = self.promoted_property_scope else {
+            return;
+        };
+
+        if scope.contains(&direct_variable.span.start)
+            && context.interner.lookup(&direct_variable.name).trim_start_matches('$') == context.renamer.old_name
+        {
+            self.replace(direct_variable.span, format!("${}", context.renamer.new_name));
+        }
+    }
+
+    fn walk_in_property_abstract_item(&mut self, item: &PropertyAbstractItem, context: &mut RenameContext<'a>) {
+        self.walk_property_declaration(context, &item.variable);
+    }
+
+    fn walk_in_property_concrete_item(&mut self, item: &PropertyConcreteItem, context: &mut RenameContext<'a>) {
+        self.walk_property_declaration(context, &item.variable);
+    }
+
+    fn walk_in_class_like_constant_item(&mut self, item: &ClassLikeConstantItem, context: &mut RenameContext<'a>) {
+        self.walk_constant_declaration(context, &item.name);
+    }
+
+    fn walk_in_enum_case_unit_item(&mut self, item: &EnumCaseUnitItem, context: &mut RenameContext<'a>) {
+        self.walk_constant_declaration(context, &item.name);
+    }
+
+    fn walk_in_enum_case_backed_item(&mut self, item: &EnumCaseBackedItem, context: &mut RenameContext<'a>) {
+        self.walk_constant_declaration(context, &item.name);
+    }
+
+    fn walk_in_trait_use_method_reference(
+        &mut self,
+        reference: &TraitUseMethodReference,
+        context: &mut RenameContext<'a>,
+    ) {
+        if !matches!(context.renamer.symbol, Symbol::Method(..)) {
+            return;
+        }
+
+        match reference {
+            TraitUseMethodReference::Identifier(identifier) => {
+                if self.is_in_hierarchy() && self.matches_member(context, context.interner.lookup(&identifier.value)) {
+                    self.replace(identifier.span, context.renamer.new_name.clone());
+                }
+            }
+            TraitUseMethodReference::Absolute(_) => {}
+        }
+    }
+
+    fn walk_in_trait_use_absolute_method_reference(
+        &mut self,
+        reference: &TraitUseAbsoluteMethodReference,
+        context: &mut RenameContext<'a>,
+    ) {
+        if matches!(context.renamer.symbol, Symbol::Method(..))
+            && self.matches_member(context, context.interner.lookup(&reference.method_name.value))
+        {
+            self.report(context, reference.method_name.span, "The trait of this method could not be resolved.");
+        }
+    }
+
+    fn walk_in_method_call(&mut self, method_call: &MethodCall, context: &mut RenameContext<'a>) {
+        if let Symbol::Method(..) = context.renamer.symbol {
+            self.walk_instance_member(context, &method_call.object, &method_call.method);
+        }
+    }
+
+    fn walk_in_null_safe_method_call(&mut self, method_call: &NullSafeMethodCall, context: &mut RenameContext<'a>) {
+        if let Symbol::Method(..) = context.renamer.symbol {
+            self.walk_instance_member(context, &method_call.object, &method_call.method);
+        }
+    }
+
+    fn walk_in_method_closure_creation(&mut self, creation: &MethodClosureCreation, context: &mut RenameContext<'a>) {
+        if let Symbol::Method(..) = context.renamer.symbol {
+            self.walk_instance_member(context, &creation.object, &creation.method);
+        }
+    }
+
+    fn walk_in_static_method_call(&mut self, method_call: &StaticMethodCall, context: &mut RenameContext<'a>) {
+        if let Symbol::Method(..) = context.renamer.symbol {
+            self.walk_static_method(context, &method_call.class, &method_call.method);
+        }
+    }
+
+    fn walk_in_static_method_closure_creation(
+        &mut self,
+        creation: &StaticMethodClosureCreation,
+        context: &mut RenameContext<'a>,
+    ) {
+        if let Symbol::Method(..) = context.renamer.symbol {
+            self.walk_static_method(context, &creation.class, &creation.method);
+        }
+    }
+
+    fn walk_in_property_access(&mut self, property_access: &PropertyAccess, context: &mut RenameContext<'a>) {
+        if let Symbol::Property(..) = context.renamer.symbol {
+            self.walk_instance_member(context, &property_access.object, &property_access.property);
+        }
+    }
+
+    fn walk_in_null_safe_property_access(
+        &mut self,
+        property_access: &NullSafePropertyAccess,
+        context: &mut RenameContext<'a>,
+    ) {
+        if let Symbol::Property(..) = context.renamer.symbol {
+            self.walk_instance_member(context, &property_access.object, &property_access.property);
+        }
+    }
+
+    fn walk_in_static_property_access(
+        &mut self,
+        property_access: &StaticPropertyAccess,
+        context: &mut RenameContext<'a>,
+    ) {
+        if !matches!(context.renamer.symbol, Symbol::Property(..)) {
+            return;
+        }
+
+        let Variable::Direct(variable) = &property_access.property else {
+            self.report(context, property_access.property.span(), "This property is accessed dynamically.");
+
+            return;
+        };
+
+        if context.interner.lookup(&variable.name).trim_start_matches('$') != context.renamer.old_name {
+            return;
+        }
+
+        match self.resolve_class(context, &property_access.class) {
+            Some(true) => self.replace(variable.span, format!("${}", context.renamer.new_name)),
+            Some(false) => {}
+            None => self.report(context, variable.span, "The class of this property is resolved dynamically."),
+        }
+    }
+
+    fn walk_in_class_constant_access(
+        &mut self,
+        constant_access: &ClassConstantAccess,
+        context: &mut RenameContext<'a>,
+    ) {
+        if !matches!(context.renamer.symbol, Symbol::ClassLikeConstant(..)) {
+            return;
+        }
+
+        let ClassLikeConstantSelector::Identifier(identifier) = &constant_access.constant else {
+            self.report(context, constant_access.constant.span(), "This constant is accessed dynamically.");
+
+            return;
+        };
+
+        if context.interner.lookup(&identifier.value) != context.renamer.old_name {
+            return;
+        }
+
+        match self.resolve_class(context, &constant_access.class) {
+            Some(true) => self.replace(identifier.span, context.renamer.new_name.clone()),
+            Some(false) => {}
+            None => self.report(context, identifier.span, "The class of this constant is resolved dynamically."),
+        }
+    }
+
+    fn walk_in_named_argument(&mut self, named_argument: &NamedArgument, context: &mut RenameContext<'a>) {
+        if context.renamer.is_promoted_property
+            && context.interner.lookup(&named_argument.name.value) == context.renamer.old_name
+        {
+            self.report(
+                context,
+                named_argument.name.span,
+                "This named argument may refer to the promoted constructor parameter of the renamed property.",
+            );
+        }
+    }
+
+    fn walk_in_literal_string(&mut self, literal_string: &LiteralString, context: &mut RenameContext<'a>) {
+        let renamer = context.renamer;
+        let value = unquote(context.interner.lookup(&literal_string.value));
+
+        let matches = match renamer.symbol {
+            Symbol::Property(..) | Symbol::ClassLikeConstant(..) | Symbol::Constant(..) => {
+                value == renamer.old_name || value == renamer.fully_qualified_name
+            }
+            _ => {
+                value.eq_ignore_ascii_case(&renamer.old_name)
+                    || value.eq_ignore_ascii_case(&renamer.fully_qualified_name)
+            }
+        };
+
+        if matches {
+            self.report(context, literal_string.span, "This string may refer to the renamed symbol.");
+        }
+    }
+}
+
+impl RenameWalker {
+    /// Renames the class-like references in the types of the tags of a docblock, e.g. `Foo` in `@param Foo $foo`.
+    fn walk_docblock(&mut self, context: &RenameContext<'_>, trivia: &Trivia) {
+        let Symbol::ClassLike(target) = context.renamer.symbol else {
+            return;
+        };
+
+        let target = context.interner.lowered(&target);
+        let comment = context.interner.lookup(&trivia.value);
+
+        let mut line_start = 0;
+        for line in comment.split_inclusive('\n') {
+            let offset = line_start;
+            line_start += line.len();
+
+            let content = line.trim_start_matches(|c: char| c.is_whitespace() || c == '/' || c == '*');
+            let Some(tag) = content.strip_prefix('@') else {
+                continue;
+            };
+
+            let tag_length = tag.find(char::is_whitespace).unwrap_or(tag.len());
+            let text = tag[tag_length..].trim_end();
+            let text = text.strip_suffix("*/").unwrap_or(text);
+            let Some((type_offset, r#type)) = find_docblock_type(&tag[..tag_length], text) else {
+                continue;
+            };
+
+            let type_offset = offset + (line.len() - content.len()) + 1 + tag_length + type_offset;
+            for (name_offset, name) in find_docblock_names(r#type) {
+                let start = type_offset + name_offset;
+                let span = trivia.span.subspan(start, start + name.len());
+                let resolved = context.names.resolve_class_name(context.interner, &span, name);
+
+                if context.interner.lowered(&resolved) == target {
+                    self.replace_last_segment(context, span, name);
+                }
+            }
+        }
+    }
+
+    fn walk_property_declaration(&mut self, context: &RenameContext<'_>, variable: &DirectVariable) {
+        if matches!(context.renamer.symbol, Symbol::Property(..))
+            && self.is_in_hierarchy()
+            && context.interner.lookup(&variable.name).trim_start_matches('$') == context.renamer.old_name
+        {
+            self.replace(variable.span, format!("${}", context.renamer.new_name));
+        }
+    }
+
+    fn walk_constant_declaration(&mut self, context: &RenameContext<'_>, name: &LocalIdentifier) {
+        if matches!(context.renamer.symbol, Symbol::ClassLikeConstant(..))
+            && self.is_in_hierarchy()
+            && context.interner.lookup(&name.value) == context.renamer.old_name
+        {
+            self.replace(name.span, context.renamer.new_name.clone());
+        }
+    }
+}
+
+/// Returns the lowered name resolved at the given span, if any.
+fn resolve_lowered(context: &RenameContext<'_>, span: Span) -> Option<StringIdentifier> {
+    if !context.names.contains(&span.start) {
+        return None;
+    }
+
+    Some(context.interner.lowered(context.names.get(&span)))
+}
+
+fn symbol_exists(context: &RenameContext<'_>, kind: ReferenceKind, name: &StringIdentifier) -> bool {
+    match kind {
+        ReferenceKind::Function => context.codebase.function_exists(context.interner, name),
+        ReferenceKind::Constant => context.codebase.constant_exists(context.interner, name),
+    }
+}
+
+/// Compares two function or constant names.
+///
+/// Namespaces and function names are case-insensitive, while constant names are case-sensitive.
+fn names_match(interner: &ThreadedInterner, kind: ReferenceKind, a: &StringIdentifier, b: &StringIdentifier) -> bool {
+    let a = interner.lookup(a).trim_start_matches('\\');
+    let b = interner.lookup(b).trim_start_matches('\\');
+
+    match kind {
+        ReferenceKind::Function => a.eq_ignore_ascii_case(b),
+        ReferenceKind::Constant => {
+            let (a_namespace, a_name) = a.rsplit_once('\\').unwrap_or(("", a));
+            let (b_namespace, b_name) = b.rsplit_once('\\').unwrap_or(("", b));
+
+            a_namespace.eq_ignore_ascii_case(b_namespace) && a_name == b_name
+        }
+    }
+}
+
+/// Returns whether the name resolved at the given span is a class-like in the hierarchy of the renamed symbol.
+fn is_name_in_hierarchy(context: &RenameContext<'_>, span: Span) -> bool {
+    resolve_lowered(context, span).is_some_and(|name| context.renamer.hierarchy.contains(&name))
+}
+
+/// Returns the type of a docblock tag along with its offset in the given text following the tag,
+/// e.g. `Foo` for `@param Foo $foo`, or `None` if the tag has no type.
+fn find_docblock_type<'t>(tag: &str, text: &'t str) -> Option<(usize, &'t str)> {
+    let tag = ["psalm-", "phpstan-", "phan-"].iter().find_map(|prefix| tag.strip_prefix(prefix)).unwrap_or(tag);
+
+    match tag {
+        "param"
+        | "return"
+        | "var"
+        | "throws"
+        | "property"
+        | "property-read"
+        | "property-write"
+        | "mixin"
+        | "extends"
+        | "implements"
+        | "use"
+        | "template-extends"
+        | "template-implements"
+        | "template-use"
+        | "see" => read_docblock_type(text, 0),
+        "method" => {
+            let (offset, r#type) = read_docblock_type(text, 0)?;
+            if r#type == "static" {
+                read_docblock_type(text, offset + r#type.len())
+            } else {
+                Some((offset, r#type))
+            }
+        }
+        "template" | "template-covariant" | "template-contravariant" => {
+            let (offset, name) = read_docblock_type(text, 0)?;
+            let (offset, keyword) = read_docblock_type(text, offset + name.len())?;
+
+            if keyword == "of" || keyword == "as" {
+                read_docblock_type(text, offset + keyword.len())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Reads the type starting at the given offset, ending at the first whitespace outside of brackets,
+/// unless it separates the parts of a union, intersection, or callable type.
+fn read_docblock_type(text: &str, offset: usize) -> Option<(usize, &str)> {
+    let start = offset + text[offset..].len() - text[offset..].trim_start().len();
+    if start == text.len() {
+        return None;
+    }
+
+    let mut depth = 0usize;
+    let mut end = text.len();
+    for (index, character) in text[start..].char_indices() {
+        let index = start + index;
+
+        match character {
+            '<' | '{' | '(' | '[' => depth += 1,
+            '>' | '}' | ')' | ']' => depth = depth.saturating_sub(1),
+            _ if character.is_whitespace() && depth == 0 => {
+                let before = text[..index].trim_end();
+                let after = text[index..].trim_start();
+
+                if !before.ends_with(['|', '&', ':']) && !after.starts_with(['|', '&']) {
+                    end = index;
+
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some((start, &text[start..end]))
+}
+
+/// Returns the names in a docblock type that may refer to class-likes, along with their offsets,
+/// skipping variables, string literals, numbers, array shape keys, constants, and callables.
+fn find_docblock_names(r#type: &str) -> Vec<(usize, &str)> {
+    let is_name_character = |byte: u8| byte == b'_' || byte == b'\\' || byte.is_ascii_alphanumeric() || byte >= 0x80;
+
+    let bytes = r#type.as_bytes();
+    let mut names = vec![];
+    let mut quote = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if let Some(delimiter) = quote {
+            if byte == delimiter {
+                quote = None;
+            }
+
+            index += 1;
+
+            continue;
+        }
+
+        if byte == b'\'' || byte == b'"' {
+            quote = Some(byte);
+            index += 1;
+
+            continue;
+        }
+
+        if !is_name_character(byte) {
+            index += 1;
+
+            continue;
+        }
+
+        let start = index;
+        while index < bytes.len() && is_name_character(bytes[index]) {
+            index += 1;
+        }
+
+        let before = &r#type[..start];
+        let after = r#type[index..].trim_start();
+        let is_name = !byte.is_ascii_digit()
+            && !before.ends_with(['$', '-'])
+            && !before.ends_with("::")
+            && !after.starts_with(['(', '-'])
+            && !after.starts_with("?:")
+            && (!after.starts_with(':') || after.starts_with("::"));
+
+        if is_name {
+            names.push((start, &r#type[start..index]));
+        }
+    }
+
+    names
+}
+
+/// Collects the variables that may be reassigned in a function-like body, including its nested function-likes.
+#[derive(Debug, Default)]
+struct ReassignedVariables {
+    names: HashSet<StringIdentifier>,
+}
+
+impl ReassignedVariables {
+    fn in_block(block: &Block) -> HashSet<StringIdentifier> {
+        let mut collector = Self::default();
+        collector.walk_block(block, &mut ());
+
+        collector.names
+    }
+
+    fn in_expression(expression: &Expression) -> HashSet<StringIdentifier> {
+        let mut collector = Self::default();
+        collector.walk_expression(expression, &mut ());
+
+        collector.names
+    }
+
+    fn add_target(&mut self, target: &Expression) {
+        match target {
+            Expression::Variable(Variable::Direct(variable)) => {
+                self.names.insert(variable.name);
+            }
+            Expression::Array(Array { elements, .. })
+            | Expression::LegacyArray(LegacyArray { elements, .. })
+            | Expression::List(List { elements, .. }) => {
+                for element in elements.iter() {
+                    match element {
+                        ArrayElement::KeyValue(element) => self.add_target(&element.value),
+                        ArrayElement::Value(element) => self.add_target(&element.value),
+                        ArrayElement::Variadic(_) | ArrayElement::Missing(_) => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl MutWalker<()> for ReassignedVariables {
+    fn walk_in_assignment(&mut self, assignment: &Assignment, _context: &mut ()) {
+        self.add_target(&assignment.lhs);
+    }
+
+    fn walk_in_foreach(&mut self, foreach: &Foreach, _context: &mut ()) {
+        match &foreach.target {
+            ForeachTarget::Value(target) => self.add_target(&target.value),
+            ForeachTarget::KeyValue(target) => {
+                self.add_target(&target.key);
+                self.add_target(&target.value);
+            }
+        }
+    }
+
+    fn walk_in_try_catch_clause(&mut self, try_catch_clause: &TryCatchClause, _context: &mut ()) {
+        if let Some(variable) = &try_catch_clause.variable {
+            self.names.insert(variable.name);
+        }
+    }
+
+    fn walk_in_global(&mut self, global: &Global, _context: &mut ()) {
+        for variable in global.variables.iter() {
+            if let Variable::Direct(variable) = variable {
+                self.names.insert(variable.name);
+            }
+        }
+    }
+
+    fn walk_in_static_abstract_item(&mut self, item: &StaticAbstractItem, _context: &mut ()) {
+        self.names.insert(item.variable.name);
+    }
+
+    fn walk_in_static_concrete_item(&mut self, item: &StaticConcreteItem, _context: &mut ()) {
+        self.names.insert(item.variable.name);
+    }
+
+    fn walk_in_closure_use_clause_variable(&mut self, variable: &ClosureUseClauseVariable, _context: &mut ()) {
+        if variable.ampersand.is_some() {
+            self.names.insert(variable.variable.name);
+        }
+    }
+
+    fn walk_in_unary_prefix(&mut self, unary_prefix: &UnaryPrefix, _context: &mut ()) {
+        if let UnaryPrefixOperator::Reference(_) = unary_prefix.operator {
+            self.add_target(&unary_prefix.operand);
+        }
+    }
+}
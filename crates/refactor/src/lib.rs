@@ -0,0 +1,17 @@
+//! Semantic refactorings of PHP code.
+//!
+//! Unlike textual search-and-replace, refactorings operate on resolved names and on the reflection
+//! of the whole codebase, so that, for example, renaming a method also renames the methods overriding
+//! it, and every call made through `$this`, `self`, `static`, `parent`, or the name of a class in its
+//! hierarchy.
+//!
+//! References that can not be resolved with certainty, such as a method called on an object of unknown
+//! type, or a string that looks like the renamed symbol, are never edited; they are reported as issues
+//! so that they can be reviewed.
+
+pub mod error;
+pub mod move_class;
+pub mod rename;
+pub mod symbol;
+
+mod internal;
@@ -0,0 +1,162 @@
+use mago_ast::Program;
+use mago_fixer::FixPlan;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::identifier::ClassLikeName;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::IssueCollection;
+use mago_source::SourceIdentifier;
+use mago_walker::MutWalker;
+
+use crate::error::RefactorError;
+use crate::internal::is_valid_identifier;
+use crate::internal::move_class::MoveContext;
+use crate::internal::move_class::MoveWalker;
+
+/// Moves a class-like to a new fully qualified name, usually in a different namespace.
+///
+/// The source declaring the class-like gets its namespace declaration rewritten, along with
+/// imports for the symbols of the old namespace it was relying on, while every other source gets
+/// its `use` statements and qualified references updated.
+///
+/// Moving the file itself, e.g. according to the PSR-4 mapping of the project, is left to the caller,
+/// see [`ClassMover::get_source`].
+#[derive(Debug, Clone)]
+pub struct ClassMover {
+    pub(crate) source: SourceIdentifier,
+    pub(crate) old_name: String,
+    pub(crate) new_name: String,
+}
+
+impl ClassMover {
+    /// Creates a new class mover.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - The interner used to look up names.
+    /// * `codebase` - The reflection of the whole codebase.
+    /// * `class_like` - The fully qualified name of the class-like to move, e.g. `App\Old\Foo`.
+    /// * `new_name` - The new fully qualified name of the class-like, e.g. `App\New\Foo`.
+    ///
+    /// # Returns
+    ///
+    /// The class mover, or an error if the class-like does not exist, is not user-defined, or shares
+    /// its source with other symbols, or if the new name is invalid or already taken.
+    pub fn new(
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        class_like: &str,
+        new_name: &str,
+    ) -> Result<Self, RefactorError> {
+        let old_name = class_like.trim().trim_start_matches('\\');
+        let new_name = new_name.trim().trim_start_matches('\\');
+
+        let Some(reflection) = codebase.get_named_class_like(interner, &interner.intern(old_name)) else {
+            return Err(RefactorError::SymbolNotFound(old_name.to_string()));
+        };
+
+        let Some(class_like) = reflection.name.inner().map(|name| name.value) else {
+            return Err(RefactorError::SymbolNotFound(old_name.to_string()));
+        };
+
+        let old_name = interner.lookup(&class_like).to_string();
+        if !new_name.contains('\\') || !new_name.split('\\').all(is_valid_identifier) {
+            return Err(RefactorError::InvalidName(new_name.to_string()));
+        }
+
+        if !old_name.eq_ignore_ascii_case(new_name)
+            && codebase.get_named_class_like(interner, &interner.intern(new_name)).is_some()
+        {
+            return Err(RefactorError::NameConflict(new_name.to_string()));
+        }
+
+        let source = reflection.span.start.source;
+        if !source.1.is_user_defined() {
+            return Err(RefactorError::NotUserDefined(old_name.clone(), old_name));
+        }
+
+        let declares_other_class_like = codebase.class_like_reflections.iter().any(|(name, other)| {
+            !matches!(name, ClassLikeName::AnonymousClass(_))
+                && other.span.start.source == source
+                && other.name.inner().is_some_and(|name| name.value != class_like)
+        });
+
+        let declares_function = codebase.function_like_reflections.iter().any(|(name, function)| {
+            matches!(name, FunctionLikeName::Function(_)) && function.span.start.source == source
+        });
+
+        let declares_constant =
+            codebase.constant_reflections.values().any(|constant| constant.item_span.start.source == source);
+
+        if declares_other_class_like || declares_function || declares_constant {
+            return Err(RefactorError::SharedSource(old_name));
+        }
+
+        Ok(Self { source, old_name, new_name: new_name.to_string() })
+    }
+
+    /// Returns the source declaring the moved class-like, i.e. the file to move.
+    pub fn get_source(&self) -> SourceIdentifier {
+        self.source
+    }
+
+    /// Returns the old fully qualified name of the class-like, as it was declared.
+    pub fn get_old_name(&self) -> &str {
+        &self.old_name
+    }
+
+    /// Returns the new fully qualified name of the class-like.
+    pub fn get_new_name(&self) -> &str {
+        &self.new_name
+    }
+
+    /// Returns the old namespace of the class-like, which is empty for the global namespace.
+    pub fn get_old_namespace(&self) -> &str {
+        self.old_name.rsplit_once('\\').map(|(namespace, _)| namespace).unwrap_or_default()
+    }
+
+    /// Returns the new namespace of the class-like.
+    pub fn get_new_namespace(&self) -> &str {
+        self.new_name.rsplit_once('\\').map(|(namespace, _)| namespace).unwrap_or_default()
+    }
+
+    /// Returns the old short name of the class-like, e.g. `Foo` for `App\Old\Foo`.
+    pub fn get_old_short_name(&self) -> &str {
+        self.old_name.rsplit('\\').next().unwrap_or(&self.old_name)
+    }
+
+    /// Returns the new short name of the class-like, e.g. `Bar` for `App\New\Bar`.
+    pub fn get_new_short_name(&self) -> &str {
+        self.new_name.rsplit('\\').next().unwrap_or(&self.new_name)
+    }
+
+    /// Moves the class-like in the given program.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - The interner used to look up names.
+    /// * `codebase` - The reflection of the whole codebase.
+    /// * `program` - The program to update.
+    /// * `names` - The resolved names of the program.
+    ///
+    /// # Returns
+    ///
+    /// The plan containing the edits to apply to the source of the program, and the
+    /// references that could not be updated with certainty.
+    pub fn move_class(
+        &self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        program: &Program,
+        names: &Names,
+    ) -> (FixPlan, IssueCollection) {
+        let mut context =
+            MoveContext { interner, codebase, names, mover: self, is_moved_source: program.source == self.source };
+        let mut walker = MoveWalker::new();
+
+        walker.walk_program(program, &mut context);
+
+        (walker.plan, walker.issues)
+    }
+}
@@ -0,0 +1,337 @@
+use ahash::HashSet;
+
+use mago_ast::Program;
+use mago_fixer::FixPlan;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::IssueCollection;
+use mago_source::SourceIdentifier;
+use mago_span::Span;
+use mago_walker::MutWalker;
+
+use crate::error::RefactorError;
+use crate::internal::is_valid_identifier;
+use crate::internal::rename::RenameContext;
+use crate::internal::rename::RenameWalker;
+use crate::symbol::find_member;
+use crate::symbol::Symbol;
+
+/// Renames a symbol along with all of its references.
+///
+/// A renamer is created once for the whole codebase, and then applied to each source separately,
+/// producing a [`FixPlan`] with the edits to apply, and an [`IssueCollection`] listing the references
+/// that could not be resolved with certainty, e.g. method calls on a receiver of an unknown type,
+/// or strings that look like the renamed symbol.
+#[derive(Debug, Clone)]
+pub struct Renamer {
+    pub(crate) symbol: Symbol,
+    pub(crate) old_name: String,
+    pub(crate) new_name: String,
+    pub(crate) fully_qualified_name: String,
+    pub(crate) hierarchy: HashSet<StringIdentifier>,
+    pub(crate) is_promoted_property: bool,
+    pub(crate) source: Option<SourceIdentifier>,
+}
+
+impl Renamer {
+    /// Creates a new renamer.
+    ///
+    /// For members, every class-like the member can be accessed through is collected from the
+    /// codebase, so that overriding and overridden members, as well as implementations of interface
+    /// methods, are renamed together.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - The interner used to look up names.
+    /// * `codebase` - The reflection of the whole codebase.
+    /// * `symbol` - The symbol to rename.
+    /// * `new_name` - The new short name of the symbol, e.g. `baz` to rename `App\Foo::bar` to `App\Foo::baz`.
+    ///
+    /// # Returns
+    ///
+    /// The renamer, or an error if the new name is invalid or already taken, or if the symbol
+    /// is also declared outside of the user-defined sources.
+    pub fn new(
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        symbol: Symbol,
+        new_name: &str,
+    ) -> Result<Self, RefactorError> {
+        let new_name = match symbol {
+            Symbol::Property(..) => new_name.strip_prefix('$').unwrap_or(new_name),
+            _ => new_name,
+        };
+
+        if !is_valid_identifier(new_name) {
+            return Err(RefactorError::InvalidName(new_name.to_string()));
+        }
+
+        let old_name = symbol.get_short_name(interner).to_string();
+        let fully_qualified_name = symbol.get_fully_qualified_name(interner);
+        let mut renamer = Self {
+            symbol,
+            old_name,
+            new_name: new_name.to_string(),
+            fully_qualified_name,
+            hierarchy: HashSet::default(),
+            is_promoted_property: false,
+            source: None,
+        };
+
+        match symbol {
+            Symbol::ClassLike(name) => {
+                let new_fully_qualified_name = interner.intern(renamer.get_new_fully_qualified_name(interner));
+                if !renamer.old_name.eq_ignore_ascii_case(new_name)
+                    && codebase.get_named_class_like(interner, &new_fully_qualified_name).is_some()
+                {
+                    return Err(RefactorError::NameConflict(interner.lookup(&new_fully_qualified_name).to_string()));
+                }
+
+                if let Some(class_like) = codebase.get_named_class_like(interner, &name) {
+                    ensure_user_defined(&renamer, class_like.span, &renamer.fully_qualified_name)?;
+
+                    renamer.source = Some(class_like.span.start.source);
+                }
+
+                renamer.hierarchy.insert(interner.lowered(&name));
+            }
+            Symbol::Function(name) => {
+                let new_fully_qualified_name = interner.intern(renamer.get_new_fully_qualified_name(interner));
+                if !renamer.old_name.eq_ignore_ascii_case(new_name)
+                    && codebase.function_exists(interner, &new_fully_qualified_name)
+                {
+                    return Err(RefactorError::NameConflict(interner.lookup(&new_fully_qualified_name).to_string()));
+                }
+
+                if let Some(function) = codebase.get_function(interner, &name) {
+                    ensure_user_defined(&renamer, function.span, &renamer.fully_qualified_name)?;
+                }
+            }
+            Symbol::Constant(name) => {
+                let new_fully_qualified_name = interner.intern(renamer.get_new_fully_qualified_name(interner));
+                if renamer.old_name != new_name && codebase.constant_exists(interner, &new_fully_qualified_name) {
+                    return Err(RefactorError::NameConflict(interner.lookup(&new_fully_qualified_name).to_string()));
+                }
+
+                if let Some(constant) = codebase.get_constant(interner, &name) {
+                    ensure_user_defined(&renamer, constant.item_span, &renamer.fully_qualified_name)?;
+                }
+            }
+            Symbol::Method(class_like, _)
+            | Symbol::Property(class_like, _)
+            | Symbol::ClassLikeConstant(class_like, _) => {
+                renamer.collect_hierarchy(interner, codebase, class_like)?;
+                renamer.ensure_member_is_available(interner, codebase)?;
+            }
+        }
+
+        Ok(renamer)
+    }
+
+    /// Returns the symbol being renamed.
+    pub fn get_symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    /// Returns the source declaring the renamed class-like, i.e. the file to rename along with it
+    /// when following PSR-4, or `None` if the symbol is not a class-like.
+    pub fn get_source(&self) -> Option<SourceIdentifier> {
+        self.source
+    }
+
+    /// Returns the new fully qualified name of the symbol, e.g. `App\Foo::baz`.
+    pub fn get_new_fully_qualified_name(&self, interner: &ThreadedInterner) -> String {
+        match self.symbol {
+            Symbol::ClassLike(name) | Symbol::Function(name) | Symbol::Constant(name) => {
+                match interner.lookup(&name).rsplit_once('\\') {
+                    Some((namespace, _)) => format!("{}\\{}", namespace, self.new_name),
+                    None => self.new_name.clone(),
+                }
+            }
+            Symbol::Property(class_like, _) => format!("{}::${}", interner.lookup(&class_like), self.new_name),
+            Symbol::Method(class_like, _) | Symbol::ClassLikeConstant(class_like, _) => {
+                format!("{}::{}", interner.lookup(&class_like), self.new_name)
+            }
+        }
+    }
+
+    /// Renames the symbol in the given program.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - The interner used to look up names.
+    /// * `codebase` - The reflection of the whole codebase.
+    /// * `program` - The program to rename the symbol in.
+    /// * `names` - The resolved names of the program.
+    ///
+    /// # Returns
+    ///
+    /// The plan containing the edits to apply to the source of the program, and the
+    /// references that could not be renamed with certainty.
+    pub fn rename(
+        &self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        program: &Program,
+        names: &Names,
+    ) -> (FixPlan, IssueCollection) {
+        let mut context = RenameContext { interner, codebase, names, renamer: self };
+        let mut walker = RenameWalker::new();
+
+        walker.walk_program(program, &mut context);
+
+        (walker.plan, walker.issues)
+    }
+
+    /// Collects every class-like a member can be accessed through.
+    ///
+    /// Starting from the given class-like, this walks up to the ancestors that also have the member,
+    /// and down to all the descendants, until no new class-like is found.
+    fn collect_hierarchy(
+        &mut self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        class_like: StringIdentifier,
+    ) -> Result<(), RefactorError> {
+        let mut queue = vec![interner.lowered(&class_like)];
+        while let Some(current) = queue.pop() {
+            if !self.hierarchy.insert(current) {
+                continue;
+            }
+
+            let Some(reflection) = codebase.get_named_class_like(interner, &current) else {
+                continue;
+            };
+
+            if self.declares_member(interner, reflection) {
+                let declaration = reflection.name.inner().map(|name| interner.lookup(&name.value)).unwrap_or_default();
+
+                ensure_user_defined(
+                    self,
+                    reflection.span,
+                    &format!("{}::{}", declaration, self.get_old_member_name()),
+                )?;
+
+                if let Symbol::Property(..) = self.symbol {
+                    self.is_promoted_property |=
+                        has_promoted_property(interner, reflection, &self.get_old_member_name());
+                }
+            }
+
+            let inheritance = &reflection.inheritance;
+            for ancestor in inheritance
+                .all_extended_classes
+                .iter()
+                .chain(inheritance.all_implemented_interfaces.iter())
+                .chain(inheritance.all_extended_interfaces.iter())
+                .map(|name| name.value)
+                .chain(reflection.used_traits.iter().copied())
+            {
+                let Some(ancestor) = codebase.get_named_class_like(interner, &ancestor) else {
+                    continue;
+                };
+
+                if self.has_member(interner, ancestor) {
+                    if let Some(name) = ancestor.name.inner() {
+                        queue.push(interner.lowered(&name.value));
+                    }
+                }
+            }
+
+            if let Some(descendants) = codebase.all_classlike_descendants.get(&current) {
+                queue.extend(descendants.iter().copied());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that no class-like in the hierarchy already has a member with the new name.
+    fn ensure_member_is_available(
+        &self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+    ) -> Result<(), RefactorError> {
+        if self.old_name.eq_ignore_ascii_case(&self.new_name) {
+            return Ok(());
+        }
+
+        for class_like in &self.hierarchy {
+            let Some(reflection) = codebase.get_named_class_like(interner, class_like) else {
+                continue;
+            };
+
+            let exists = match self.symbol {
+                Symbol::Method(..) => find_member(interner, reflection, &self.new_name, true).is_some(),
+                Symbol::Property(..) => {
+                    find_member(interner, reflection, &format!("${}", self.new_name), false).is_some()
+                }
+                _ => reflection
+                    .constants
+                    .keys()
+                    .chain(reflection.cases.members.keys())
+                    .any(|constant| interner.lookup(constant) == self.new_name),
+            };
+
+            if exists {
+                return Err(RefactorError::NameConflict(self.get_new_fully_qualified_name(interner)));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_old_member_name(&self) -> String {
+        match self.symbol {
+            Symbol::Property(..) => format!("${}", self.old_name),
+            _ => self.old_name.clone(),
+        }
+    }
+
+    fn declares_member(&self, interner: &ThreadedInterner, class_like: &ClassLikeReflection) -> bool {
+        match self.symbol {
+            Symbol::Method(..) => {
+                class_like.methods.members.keys().any(|name| interner.lookup(name).eq_ignore_ascii_case(&self.old_name))
+            }
+            Symbol::Property(..) => {
+                let name = self.get_old_member_name();
+
+                class_like.properties.members.keys().any(|member| interner.lookup(member) == name)
+                    || has_promoted_property(interner, class_like, &name)
+            }
+            Symbol::ClassLikeConstant(..) => class_like
+                .constants
+                .keys()
+                .chain(class_like.cases.members.keys())
+                .any(|member| interner.lookup(member) == self.old_name),
+            _ => false,
+        }
+    }
+
+    fn has_member(&self, interner: &ThreadedInterner, class_like: &ClassLikeReflection) -> bool {
+        match self.symbol {
+            Symbol::Method(..) => find_member(interner, class_like, &self.old_name, true).is_some(),
+            Symbol::Property(..) => find_member(interner, class_like, &self.get_old_member_name(), false).is_some(),
+            _ => self.declares_member(interner, class_like),
+        }
+    }
+}
+
+fn ensure_user_defined(renamer: &Renamer, span: Span, declaration: &str) -> Result<(), RefactorError> {
+    if span.start.source.1.is_user_defined() {
+        Ok(())
+    } else {
+        Err(RefactorError::NotUserDefined(renamer.fully_qualified_name.clone(), declaration.to_string()))
+    }
+}
+
+fn has_promoted_property(interner: &ThreadedInterner, class_like: &ClassLikeReflection, name: &str) -> bool {
+    class_like.methods.members.values().any(|method| {
+        matches!(method.name, FunctionLikeName::Method(_, method_name) if interner.lookup(&method_name.value).eq_ignore_ascii_case("__construct"))
+            && method.parameters.iter().any(|parameter| parameter.is_promoted_property && interner.lookup(&parameter.name) == name)
+    })
+}
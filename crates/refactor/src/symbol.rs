@@ -0,0 +1,179 @@
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_reflection::class_like::ClassLikeReflection;
+use mago_reflection::identifier::FunctionLikeName;
+use mago_reflection::CodebaseReflection;
+
+use crate::error::RefactorError;
+
+/// A symbol that can be refactored, identified by the name it was declared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    /// A class, interface, trait, or enum, e.g. `App\Foo`.
+    ClassLike(StringIdentifier),
+    /// A function, e.g. `App\foo`.
+    Function(StringIdentifier),
+    /// A constant, e.g. `App\FOO`.
+    Constant(StringIdentifier),
+    /// A method of a class-like, e.g. `App\Foo::bar`.
+    Method(StringIdentifier, StringIdentifier),
+    /// A property of a class-like, e.g. `App\Foo::$bar`, the name includes the `$` prefix.
+    Property(StringIdentifier, StringIdentifier),
+    /// A constant or an enum case of a class-like, e.g. `App\Foo::BAR`.
+    ClassLikeConstant(StringIdentifier, StringIdentifier),
+}
+
+impl Symbol {
+    /// Resolves a symbol from its fully qualified name.
+    ///
+    /// Members are written as `Class::method`, `Class::$property`, or `Class::CONSTANT`,
+    /// while other names are looked up as a class-like first, then as a function, and
+    /// finally as a constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `interner` - The interner used to look up names.
+    /// * `codebase` - The reflection of the codebase the symbol is declared in.
+    /// * `symbol` - The fully qualified name of the symbol, e.g. `App\Foo::bar`.
+    ///
+    /// # Returns
+    ///
+    /// The resolved symbol, or an error if no such symbol is declared in the codebase.
+    pub fn resolve(
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        symbol: &str,
+    ) -> Result<Self, RefactorError> {
+        let not_found = || RefactorError::SymbolNotFound(symbol.to_string());
+        let name = symbol.trim().trim_start_matches('\\');
+
+        if let Some((class_like_name, member_name)) = name.split_once("::") {
+            let class_like = codebase.get_named_class_like(interner, &interner.intern(class_like_name));
+            let Some(class_like) = class_like else {
+                return Err(not_found());
+            };
+
+            let class_like_name = class_like.name.inner().ok_or_else(not_found)?.value;
+
+            if member_name.starts_with('$') {
+                return find_member(interner, class_like, member_name, false)
+                    .map(|property| Symbol::Property(class_like_name, property))
+                    .ok_or_else(not_found);
+            }
+
+            if let Some(method) = find_member(interner, class_like, member_name, true) {
+                return Ok(Symbol::Method(class_like_name, method));
+            }
+
+            return class_like
+                .constants
+                .keys()
+                .chain(class_like.cases.members.keys())
+                .find(|constant| interner.lookup(constant) == member_name)
+                .map(|constant| Symbol::ClassLikeConstant(class_like_name, *constant))
+                .ok_or_else(not_found);
+        }
+
+        let id = interner.intern(name);
+        if let Some(class_like) = codebase.get_named_class_like(interner, &id) {
+            return class_like.name.inner().map(|name| Symbol::ClassLike(name.value)).ok_or_else(not_found);
+        }
+
+        if let Some(function) = codebase.get_function(interner, &id) {
+            if let FunctionLikeName::Function(name) = function.name {
+                return Ok(Symbol::Function(name.value));
+            }
+        }
+
+        if let Some(constant) = codebase.get_constant(interner, &id) {
+            return Ok(Symbol::Constant(constant.name.value));
+        }
+
+        Err(not_found())
+    }
+
+    /// Returns the short name of the symbol, i.e. the name written at its declaration.
+    ///
+    /// For namespaced symbols, this is the last segment of the name, and for properties,
+    /// the name does not include the `$` prefix.
+    pub fn get_short_name<'a>(&self, interner: &'a ThreadedInterner) -> &'a str {
+        match self {
+            Symbol::ClassLike(name) | Symbol::Function(name) | Symbol::Constant(name) => {
+                let name = interner.lookup(name);
+
+                name.rsplit('\\').next().unwrap_or(name)
+            }
+            Symbol::Method(_, name) | Symbol::ClassLikeConstant(_, name) => interner.lookup(name),
+            Symbol::Property(_, name) => interner.lookup(name).trim_start_matches('$'),
+        }
+    }
+
+    /// Returns the name of the class-like the symbol is a member of, if any.
+    pub fn get_class_like_name(&self) -> Option<StringIdentifier> {
+        match self {
+            Symbol::Method(class_like, _)
+            | Symbol::Property(class_like, _)
+            | Symbol::ClassLikeConstant(class_like, _) => Some(*class_like),
+            _ => None,
+        }
+    }
+
+    /// Formats the symbol as it would be written on the command line, e.g. `App\Foo::$bar`.
+    pub fn get_fully_qualified_name(&self, interner: &ThreadedInterner) -> String {
+        match self {
+            Symbol::ClassLike(name) | Symbol::Function(name) | Symbol::Constant(name) => {
+                interner.lookup(name).to_string()
+            }
+            Symbol::Method(class_like, name)
+            | Symbol::Property(class_like, name)
+            | Symbol::ClassLikeConstant(class_like, name) => {
+                format!("{}::{}", interner.lookup(class_like), interner.lookup(name))
+            }
+        }
+    }
+}
+
+/// Finds a method or a property that appears in the given class-like, either declared or inherited.
+///
+/// Methods are matched case-insensitively, while properties are case-sensitive, and also include
+/// the properties promoted by the constructor of the class-like.
+pub(crate) fn find_member(
+    interner: &ThreadedInterner,
+    class_like: &ClassLikeReflection,
+    name: &str,
+    is_method: bool,
+) -> Option<StringIdentifier> {
+    let mut members = if is_method {
+        Box::new(class_like.methods.members.keys().chain(class_like.methods.appering_members.keys()))
+            as Box<dyn Iterator<Item = &StringIdentifier>>
+    } else {
+        Box::new(class_like.properties.members.keys().chain(class_like.properties.appering_members.keys()))
+    };
+
+    let member = members
+        .find(|member| {
+            let member = interner.lookup(member);
+
+            if is_method {
+                member.eq_ignore_ascii_case(name)
+            } else {
+                member == name
+            }
+        })
+        .copied();
+
+    if member.is_some() || is_method {
+        return member;
+    }
+
+    class_like
+        .methods
+        .members
+        .values()
+        .filter(|method| {
+            matches!(method.name, FunctionLikeName::Method(_, method_name) if interner.lookup(&method_name.value).eq_ignore_ascii_case("__construct"))
+        })
+        .flat_map(|method| method.parameters.iter())
+        .find(|parameter| parameter.is_promoted_property && interner.lookup(&parameter.name) == name)
+        .map(|parameter| parameter.name)
+}
@@ -0,0 +1,350 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_refactor::error::RefactorError;
+use mago_refactor::move_class::ClassMover;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Issue;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+struct Outcome {
+    sources: Vec<String>,
+    issues: Vec<Issue>,
+}
+
+fn move_class(files: &[&str], class_like: &str, new_name: &str) -> Result<Outcome, RefactorError> {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let mut parsed = vec![];
+    let mut codebase = CodebaseReflection::new();
+    for (i, code) in files.iter().enumerate() {
+        let source_id = manager.insert_content(format!("{}.php", i), code.to_string(), SourceCategory::UserDefined);
+        let source = manager.load(&source_id).unwrap();
+        let (program, error) = parse_source(&interner, &source);
+        assert!(error.is_none(), "failed to parse source {}: {:?}", i, error);
+
+        let names = Names::resolve(&interner, &program);
+        let reflection = mago_reflector::reflect(&interner, &source, &program, &names);
+        codebase = mago_reflector::merge(&interner, codebase, reflection);
+
+        parsed.push((source, program, names));
+    }
+
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mover = ClassMover::new(&interner, &codebase, class_like, new_name)?;
+
+    let mut sources = vec![];
+    let mut issues = vec![];
+    for (source, program, names) in parsed {
+        let (plan, source_issues) = mover.move_class(&interner, &codebase, &program, &names);
+
+        sources.push(plan.execute(interner.lookup(&source.content)).get_fixed());
+        issues.extend(source_issues);
+    }
+
+    Ok(Outcome { sources, issues })
+}
+
+#[test]
+fn test_move_class_to_another_namespace() {
+    let outcome = move_class(
+        &[
+            indoc! {r#"
+                <?php
+
+                declare(strict_types=1);
+
+                namespace App\Old;
+
+                use App\Contracts\Handler;
+
+                final class Foo implements Handler
+                {
+                    public function handle(Bar $bar, Sub\Baz $baz): Foo
+                    {
+                        helper();
+                        strlen('x');
+
+                        return new Foo();
+                    }
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Old;
+
+                final class Bar
+                {
+                    public function make(): Foo
+                    {
+                        return new Foo();
+                    }
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Old\Sub;
+
+                final class Baz
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Old;
+
+                function helper(): void
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Contracts;
+
+                interface Handler
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Controller;
+
+                use App\Old\Foo;
+                use App\Old\{Bar, Foo as AliasedFoo};
+
+                function run(Foo $foo, AliasedFoo $aliased, Bar $bar): \App\Old\Foo
+                {
+                    $class = 'App\Old\Foo';
+
+                    return new \App\Old\Foo();
+                }
+            "#},
+        ],
+        "App\\Old\\Foo",
+        "App\\New\\Foo",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            declare(strict_types=1);
+
+            namespace App\New;
+
+            use App\Contracts\Handler;
+            use App\Old\Bar;
+            use function App\Old\helper;
+
+            final class Foo implements Handler
+            {
+                public function handle(Bar $bar, \App\Old\Sub\Baz $baz): Foo
+                {
+                    helper();
+                    strlen('x');
+
+                    return new Foo();
+                }
+            }
+        "#}
+    );
+
+    assert_eq!(
+        outcome.sources[1],
+        indoc! {r#"
+            <?php
+
+            namespace App\Old;
+
+            use App\New\Foo;
+
+            final class Bar
+            {
+                public function make(): Foo
+                {
+                    return new Foo();
+                }
+            }
+        "#}
+    );
+
+    assert!(outcome.sources[2].contains("namespace App\\Old\\Sub;"));
+    assert_eq!(
+        outcome.sources[5],
+        indoc! {r#"
+            <?php
+
+            namespace App\Controller;
+
+            use App\New\Foo;
+            use App\Old\{Bar};
+            use App\New\Foo as AliasedFoo;
+
+            function run(Foo $foo, AliasedFoo $aliased, Bar $bar): \App\New\Foo
+            {
+                $class = 'App\Old\Foo';
+
+                return new \App\New\Foo();
+            }
+        "#}
+    );
+
+    assert_eq!(outcome.issues.len(), 1);
+}
+
+#[test]
+fn test_move_class_with_new_short_name() {
+    let outcome = move_class(
+        &[
+            indoc! {r#"
+                <?php
+
+                namespace App\Old;
+
+                final class Foo
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Controller;
+
+                use App\Old\{Foo};
+
+                function run(Foo $foo): Foo
+                {
+                    return $foo;
+                }
+            "#},
+        ],
+        "App\\Old\\Foo",
+        "App\\Old\\Sub\\Qux",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            namespace App\Old\Sub;
+
+            final class Qux
+            {
+            }
+        "#}
+    );
+
+    assert_eq!(
+        outcome.sources[1],
+        indoc! {r#"
+            <?php
+
+            namespace App\Controller;
+
+            use App\Old\{Sub\Qux};
+
+            function run(Qux $foo): Qux
+            {
+                return $foo;
+            }
+        "#}
+    );
+}
+
+#[test]
+fn test_move_class_out_of_global_namespace() {
+    let outcome = move_class(
+        &[
+            indoc! {r#"
+                <?php
+
+                final class Legacy extends Exception
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                throw new Legacy();
+            "#},
+        ],
+        "Legacy",
+        "App\\Legacy",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            use Exception;
+
+            final class Legacy extends Exception
+            {
+            }
+        "#}
+    );
+
+    assert_eq!(
+        outcome.sources[1],
+        indoc! {r#"
+            <?php
+
+            use App\Legacy;
+
+            throw new Legacy();
+        "#}
+    );
+}
+
+#[test]
+fn test_move_class_errors() {
+    let files = [
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Foo
+            {
+            }
+
+            final class Bar
+            {
+            }
+        "#},
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Baz
+            {
+            }
+        "#},
+    ];
+
+    assert!(matches!(move_class(&files, "App\\Qux", "App\\Quux"), Err(RefactorError::SymbolNotFound(_))));
+    assert!(matches!(move_class(&files, "App\\Foo", "App\\Bar"), Err(RefactorError::NameConflict(_))));
+    assert!(matches!(move_class(&files, "App\\Foo", "App\\New\\Foo"), Err(RefactorError::SharedSource(_))));
+    assert!(matches!(move_class(&files, "App\\Baz", "Baz"), Err(RefactorError::InvalidName(_))));
+    assert!(matches!(move_class(&files, "App\\Baz", "App\\1Baz"), Err(RefactorError::InvalidName(_))));
+}
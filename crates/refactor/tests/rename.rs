@@ -0,0 +1,625 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_refactor::error::RefactorError;
+use mago_refactor::rename::Renamer;
+use mago_refactor::symbol::Symbol;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Issue;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+struct Outcome {
+    sources: Vec<String>,
+    issues: Vec<Issue>,
+    declaring_source: Option<usize>,
+}
+
+fn rename(files: &[&str], symbol: &str, new_name: &str) -> Result<Outcome, RefactorError> {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let mut parsed = vec![];
+    let mut codebase = CodebaseReflection::new();
+    for (i, code) in files.iter().enumerate() {
+        let source_id = manager.insert_content(format!("{}.php", i), code.to_string(), SourceCategory::UserDefined);
+        let source = manager.load(&source_id).unwrap();
+        let (program, error) = parse_source(&interner, &source);
+        assert!(error.is_none(), "failed to parse source {}: {:?}", i, error);
+
+        let names = Names::resolve(&interner, &program);
+        let reflection = mago_reflector::reflect(&interner, &source, &program, &names);
+        codebase = mago_reflector::merge(&interner, codebase, reflection);
+
+        parsed.push((source, program, names));
+    }
+
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let symbol = Symbol::resolve(&interner, &codebase, symbol)?;
+    let renamer = Renamer::new(&interner, &codebase, symbol, new_name)?;
+
+    let mut sources = vec![];
+    let mut issues = vec![];
+    let mut declaring_source = None;
+    for (i, (source, program, names)) in parsed.into_iter().enumerate() {
+        if renamer.get_source() == Some(source.identifier) {
+            declaring_source = Some(i);
+        }
+
+        let (plan, source_issues) = renamer.rename(&interner, &codebase, &program, &names);
+
+        sources.push(plan.execute(interner.lookup(&source.content)).get_fixed());
+        issues.extend(source_issues);
+    }
+
+    Ok(Outcome { sources, issues, declaring_source })
+}
+
+#[test]
+fn test_rename_class_like() {
+    let outcome = rename(
+        &[
+            indoc! {r#"
+                <?php
+
+                namespace App;
+
+                final class Foo
+                {
+                    public static function create(): Foo
+                    {
+                        return new Foo();
+                    }
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Controller;
+
+                use App\Foo;
+                use App\Foo as Aliased;
+
+                function run(Foo $foo, Aliased $aliased): \App\Foo
+                {
+                    $class = 'App\Foo';
+
+                    return $foo instanceof \App\Foo ? Foo::create() : new Aliased();
+                }
+            "#},
+        ],
+        "App\\Foo",
+        "Bar",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Bar
+            {
+                public static function create(): Bar
+                {
+                    return new Bar();
+                }
+            }
+        "#}
+    );
+
+    assert_eq!(
+        outcome.sources[1],
+        indoc! {r#"
+            <?php
+
+            namespace App\Controller;
+
+            use App\Bar;
+            use App\Bar as Aliased;
+
+            function run(Bar $foo, Aliased $aliased): \App\Bar
+            {
+                $class = 'App\Foo';
+
+                return $foo instanceof \App\Bar ? Bar::create() : new Aliased();
+            }
+        "#}
+    );
+
+    assert_eq!(outcome.issues.len(), 1);
+}
+
+#[test]
+fn test_rename_class_like_in_docblocks() {
+    let outcome = rename(
+        &[
+            indoc! {r#"
+                <?php
+
+                namespace App;
+
+                final class Foo
+                {
+                }
+
+                final class Foobar
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App\Service;
+
+                use App\Foo;
+                use App\Foo as Aliased;
+
+                /**
+                 * @method static Foo create()
+                 * @template T of Foo
+                 * @property-read list<Foo> $foos
+                 */
+                final class Service
+                {
+                    /**
+                     * Finds a foo, see {@see Foo}.
+                     *
+                     * @param Foo|null $foo The foo to find.
+                     * @param array{foo: Foo, 'Foo': int} $shape
+                     * @param \App\Foo $qualified
+                     * @param Aliased $aliased
+                     * @param \App\Foobar $other
+                     * @param \Other\Foo $unrelated
+                     * @psalm-param array<int, Foo> $foos
+                     *
+                     * @return (callable(Foo): Foo) Returns a Foo.
+                     *
+                     * @throws Foo
+                     */
+                    public function find($foo, $shape, $qualified, $aliased, $other, $unrelated, $foos)
+                    {
+                        /** @var Foo $result */
+                        $result = $foo;
+
+                        return $result;
+                    }
+                }
+            "#},
+        ],
+        "App\\Foo",
+        "Bar",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[1],
+        indoc! {r#"
+            <?php
+
+            namespace App\Service;
+
+            use App\Bar;
+            use App\Bar as Aliased;
+
+            /**
+             * @method static Bar create()
+             * @template T of Bar
+             * @property-read list<Bar> $foos
+             */
+            final class Service
+            {
+                /**
+                 * Finds a foo, see {@see Foo}.
+                 *
+                 * @param Bar|null $foo The foo to find.
+                 * @param array{foo: Bar, 'Foo': int} $shape
+                 * @param \App\Bar $qualified
+                 * @param Aliased $aliased
+                 * @param \App\Foobar $other
+                 * @param \Other\Foo $unrelated
+                 * @psalm-param array<int, Bar> $foos
+                 *
+                 * @return (callable(Bar): Bar) Returns a Foo.
+                 *
+                 * @throws Bar
+                 */
+                public function find($foo, $shape, $qualified, $aliased, $other, $unrelated, $foos)
+                {
+                    /** @var Bar $result */
+                    $result = $foo;
+
+                    return $result;
+                }
+            }
+        "#}
+    );
+}
+
+#[test]
+fn test_rename_class_like_reports_declaring_source() {
+    let files = [
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            function helper(): void
+            {
+            }
+        "#},
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            final class Foo
+            {
+                public function bar(): void
+                {
+                }
+            }
+        "#},
+    ];
+
+    assert_eq!(rename(&files, "App\\Foo", "Bar").unwrap().declaring_source, Some(1));
+    assert_eq!(rename(&files, "App\\Foo::bar", "baz").unwrap().declaring_source, None);
+    assert_eq!(rename(&files, "App\\helper", "run").unwrap().declaring_source, None);
+}
+
+#[test]
+fn test_rename_method_in_hierarchy() {
+    let outcome = rename(
+        &[indoc! {r#"
+            <?php
+
+            namespace App;
+
+            interface Handler
+            {
+                public function handle(): void;
+            }
+
+            class BaseHandler implements Handler
+            {
+                public function handle(): void
+                {
+                }
+
+                public function run(): void
+                {
+                    $this->handle();
+                    static::handle();
+                }
+            }
+
+            final class ChildHandler extends BaseHandler
+            {
+                public function handle(): void
+                {
+                    parent::handle();
+                }
+            }
+
+            final class Unrelated
+            {
+                public function handle(): void
+                {
+                    $this->handle();
+                }
+            }
+
+            function run(Handler $handler, array $handlers): void
+            {
+                ChildHandler::handle(...);
+                $handler->handle();
+                $handlers[0]->handle();
+            }
+        "#}],
+        "App\\BaseHandler::handle",
+        "process",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            interface Handler
+            {
+                public function process(): void;
+            }
+
+            class BaseHandler implements Handler
+            {
+                public function process(): void
+                {
+                }
+
+                public function run(): void
+                {
+                    $this->process();
+                    static::process();
+                }
+            }
+
+            final class ChildHandler extends BaseHandler
+            {
+                public function process(): void
+                {
+                    parent::process();
+                }
+            }
+
+            final class Unrelated
+            {
+                public function handle(): void
+                {
+                    $this->handle();
+                }
+            }
+
+            function run(Handler $handler, array $handlers): void
+            {
+                ChildHandler::process(...);
+                $handler->process();
+                $handlers[0]->handle();
+            }
+        "#}
+    );
+
+    assert_eq!(outcome.issues.len(), 1);
+}
+
+#[test]
+fn test_rename_method_on_known_receivers() {
+    let outcome = rename(
+        &[indoc! {r#"
+            <?php
+
+            namespace App;
+
+            class Foo
+            {
+                public function bar(): void
+                {
+                }
+            }
+
+            final class Unrelated
+            {
+                public function bar(): void
+                {
+                }
+            }
+
+            function run(?Foo $foo, Foo|null $nullable, Unrelated $unrelated, Foo|Unrelated $either, Foo $reassigned): void
+            {
+                (new Foo())->bar();
+                new Foo()->bar();
+                $foo?->bar();
+                $nullable->bar();
+                $unrelated->bar();
+                $either->bar();
+
+                $closure = function () use ($foo): void {
+                    $foo->bar();
+                };
+
+                $arrow = fn (Unrelated $foo) => $foo->bar() ?? $nullable->bar();
+
+                $reassigned = $unrelated;
+                $reassigned->bar();
+            }
+        "#}],
+        "App\\Foo::bar",
+        "baz",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            class Foo
+            {
+                public function baz(): void
+                {
+                }
+            }
+
+            final class Unrelated
+            {
+                public function bar(): void
+                {
+                }
+            }
+
+            function run(?Foo $foo, Foo|null $nullable, Unrelated $unrelated, Foo|Unrelated $either, Foo $reassigned): void
+            {
+                (new Foo())->baz();
+                new Foo()->baz();
+                $foo?->baz();
+                $nullable->baz();
+                $unrelated->bar();
+                $either->bar();
+
+                $closure = function () use ($foo): void {
+                    $foo->baz();
+                };
+
+                $arrow = fn (Unrelated $foo) => $foo->bar() ?? $nullable->baz();
+
+                $reassigned = $unrelated;
+                $reassigned->bar();
+            }
+        "#}
+    );
+
+    assert_eq!(outcome.issues.len(), 2);
+}
+
+#[test]
+fn test_rename_promoted_property() {
+    let outcome = rename(
+        &[indoc! {r#"
+            <?php
+
+            final class Point
+            {
+                private static int $count = 0;
+
+                public function __construct(
+                    public readonly int $x,
+                ) {
+                    self::$count++;
+                    echo $x;
+                }
+
+                public function getX(): int
+                {
+                    return $this->x;
+                }
+            }
+
+            $point = new Point(x: 1);
+        "#}],
+        "Point::$x",
+        "$abscissa",
+    )
+    .unwrap();
+
+    assert_eq!(
+        outcome.sources[0],
+        indoc! {r#"
+            <?php
+
+            final class Point
+            {
+                private static int $count = 0;
+
+                public function __construct(
+                    public readonly int $abscissa,
+                ) {
+                    self::$count++;
+                    echo $abscissa;
+                }
+
+                public function getX(): int
+                {
+                    return $this->abscissa;
+                }
+            }
+
+            $point = new Point(x: 1);
+        "#}
+    );
+
+    assert_eq!(outcome.issues.len(), 1);
+}
+
+#[test]
+fn test_rename_function_and_constant() {
+    let files = [
+        indoc! {r#"
+            <?php
+
+            namespace App\Util;
+
+            const VERSION = '1.0';
+
+            function format(string $value): string
+            {
+                return $value . VERSION;
+            }
+        "#},
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            use function App\Util\format;
+            use const App\Util\VERSION;
+
+            echo format(VERSION);
+            echo Util\format('x');
+            echo \strlen('x');
+        "#},
+    ];
+
+    let outcome = rename(&files, "App\\Util\\format", "render").unwrap();
+    assert_eq!(outcome.sources[0].contains("function render(string $value)"), true);
+    assert_eq!(outcome.sources[1].contains("use function App\\Util\\render;"), true);
+    assert_eq!(outcome.sources[1].contains("echo render(VERSION);"), true);
+    assert_eq!(outcome.sources[1].contains("echo Util\\render('x');"), true);
+    assert!(outcome.issues.is_empty());
+
+    let outcome = rename(&files, "App\\Util\\VERSION", "RELEASE").unwrap();
+    assert_eq!(outcome.sources[0].contains("const RELEASE = '1.0';"), true);
+    assert_eq!(outcome.sources[0].contains("return $value . RELEASE;"), true);
+    assert_eq!(outcome.sources[1].contains("use const App\\Util\\RELEASE;"), true);
+    assert_eq!(outcome.sources[1].contains("echo format(RELEASE);"), true);
+}
+
+#[test]
+fn test_rename_global_function_from_namespace() {
+    let outcome = rename(
+        &[
+            indoc! {r#"
+                <?php
+
+                function legacy_helper(): void
+                {
+                }
+            "#},
+            indoc! {r#"
+                <?php
+
+                namespace App;
+
+                legacy_helper();
+                \legacy_helper();
+            "#},
+        ],
+        "legacy_helper",
+        "helper",
+    )
+    .unwrap();
+
+    assert_eq!(outcome.sources[1].contains("\nhelper();\n\\helper();"), true);
+}
+
+#[test]
+fn test_rename_errors() {
+    let files = [indoc! {r#"
+        <?php
+
+        final class Foo
+        {
+            public function bar(): void
+            {
+            }
+
+            public function baz(): void
+            {
+            }
+        }
+    "#}];
+
+    assert!(matches!(rename(&files, "Foo::qux", "quux"), Err(RefactorError::SymbolNotFound(_))));
+    assert!(matches!(rename(&files, "Foo::bar", "1bar"), Err(RefactorError::InvalidName(_))));
+    assert!(matches!(rename(&files, "Foo", "Foo\\Bar"), Err(RefactorError::InvalidName(_))));
+    assert!(matches!(rename(&files, "Foo::bar", "BAZ"), Err(RefactorError::NameConflict(_))));
+}
@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use mago_php_version::PHPVersion;
+
+/// Represents the PHP versions in which a built-in entity, such as an internal function or class, is available.
+///
+/// This information is extracted from the `@since`, `@deprecated`, and `@removed` tags, and the
+/// `#[Deprecated(since: '...')]` and `#[PhpStormStubsElementAvailable(from: '...', to: '...')]` attributes,
+/// of the bundled PHP stubs. It is only reflected for built-in entities, as these tags refer to library
+/// versions, rather than PHP versions, in user-defined code.
+///
+/// Example:
+///
+/// ```php
+/// /**
+///  * @removed 8.0
+///  */
+/// #[Deprecated(since: '7.2')]
+/// function each(&$array): array {}
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct AvailabilityReflection {
+    /// The PHP version in which the entity was introduced, if known.
+    pub since: Option<PHPVersion>,
+
+    /// The PHP version in which the entity was deprecated, if known.
+    pub deprecated: Option<PHPVersion>,
+
+    /// The PHP version in which the entity was removed, if known.
+    pub removed: Option<PHPVersion>,
+}
+
+impl AvailabilityReflection {
+    /// Checks if the entity is available in the given PHP version.
+    pub fn is_available_in(&self, version: PHPVersion) -> bool {
+        self.since.is_none_or(|since| version >= since) && self.removed.is_none_or(|removed| version < removed)
+    }
+
+    /// Checks if the entity is available, but deprecated, in the given PHP version.
+    pub fn is_deprecated_in(&self, version: PHPVersion) -> bool {
+        self.is_available_in(version) && self.deprecated.is_some_and(|deprecated| version >= deprecated)
+    }
+
+    /// Checks if there is no PHP version in which both entities are available.
+    pub fn is_disjoint_from(&self, other: &Self) -> bool {
+        let is_before =
+            |a: &Self, b: &Self| matches!((a.removed, b.since), (Some(removed), Some(since)) if removed <= since);
+
+        is_before(self, other) || is_before(other, self)
+    }
+
+    /// Restricts the availability to the PHP versions in which the other entity is also available,
+    /// e.g. a parameter of a function declared for a range of PHP versions only.
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            since: self.since.max(other.since),
+            deprecated: self.deprecated.or(other.deprecated),
+            removed: match (self.removed, other.removed) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+
+    /// Extends the availability to the PHP versions in which the other entity is available,
+    /// e.g. a function declared once for each range of PHP versions with a different signature.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            since: self.since.zip(other.since).map(|(a, b)| a.min(b)),
+            deprecated: match (self.deprecated, other.deprecated) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+            removed: self.removed.zip(other.removed).map(|(a, b)| a.max(b)),
+        }
+    }
+}
@@ -11,6 +11,7 @@ use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::attribute::AttributeReflection;
+use crate::availability::AvailabilityReflection;
 use crate::class_like::constant::ClassLikeConstantReflection;
 use crate::class_like::enum_case::EnumCaseReflection;
 use crate::class_like::inheritance::InheritanceReflection;
@@ -70,6 +71,9 @@ pub struct ClassLikeReflection {
     /// Whether the entity is an anonymous class.
     pub is_anonymous: bool,
 
+    /// The PHP versions in which the class-like entity is available, if it is built-in.
+    pub availability_reflection: AvailabilityReflection,
+
     /// The span in the source code where the class-like entity is declared.
     pub span: Span,
 
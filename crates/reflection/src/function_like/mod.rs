@@ -1,6 +1,7 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use mago_php_version::PHPVersion;
 use mago_reporting::IssueCollection;
 use mago_source::HasSource;
 use mago_source::SourceIdentifier;
@@ -8,10 +9,12 @@ use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::attribute::AttributeReflection;
+use crate::availability::AvailabilityReflection;
 use crate::class_like::member::ClassLikeMemberVisibilityReflection;
 use crate::function_like::parameter::FunctionLikeParameterReflection;
 use crate::function_like::r#return::FunctionLikeReturnTypeReflection;
 use crate::identifier::FunctionLikeName;
+use crate::identifier::Name;
 use crate::r#type::kind::Template;
 use crate::Reflection;
 
@@ -51,6 +54,11 @@ pub struct FunctionLikeReflection {
     /// Flags if the function or method has the potential to throw an exception.
     pub has_throws: bool,
 
+    /// The fully qualified exception types declared by the `@throws` tags of this function or method.
+    ///
+    /// Always empty for closures, arrow functions, and property hooks.
+    pub thrown_types: Vec<Name>,
+
     /// Indicates if this function-like entity is anonymous (i.e., a closure or an anonymous function).
     ///
     /// For functions and methods, this is always `false`.
@@ -74,6 +82,9 @@ pub struct FunctionLikeReflection {
     /// Indicates if this function or method is pure.
     pub is_pure: bool,
 
+    /// The PHP versions in which this function or method is available, if it is built-in.
+    pub availability_reflection: AvailabilityReflection,
+
     /// Flags if this function or method overrides a method from a parent class.
     ///
     /// Always `false` for functions, arrow functions, and closures. For methods,
@@ -115,6 +126,17 @@ impl FunctionLikeReflection {
     pub fn is_arrow_function(&self) -> bool {
         matches!(self.name, FunctionLikeName::ArrowFunction(_))
     }
+
+    /// Checks if the signature of this function-like entity changed between PHP versions,
+    /// e.g. `parse_str()`, whose second parameter is required since PHP 8.0.
+    pub fn has_versioned_signature(&self) -> bool {
+        self.parameters.iter().any(|parameter| parameter.availability_reflection != AvailabilityReflection::default())
+    }
+
+    /// Returns the parameters of this function-like entity in the given PHP version.
+    pub fn get_parameters_in(&self, version: PHPVersion) -> Vec<&FunctionLikeParameterReflection> {
+        self.parameters.iter().filter(|parameter| parameter.availability_reflection.is_available_in(version)).collect()
+    }
 }
 
 impl HasSpan for FunctionLikeReflection {
@@ -8,6 +8,7 @@ use mago_span::HasSpan;
 use mago_span::Span;
 
 use crate::attribute::AttributeReflection;
+use crate::availability::AvailabilityReflection;
 use crate::r#type::TypeReflection;
 
 /// Represents a parameter in a function-like entity (such as a function or method),
@@ -35,6 +36,10 @@ pub struct FunctionLikeParameterReflection {
     /// The default value of the parameter, if any, including its type and span in the source code.
     pub default: Option<FunctionLikeParameterDefaultValueReflection>,
 
+    /// The PHP versions in which this parameter is available, for built-in functions and methods
+    /// whose signature changed between PHP versions.
+    pub availability_reflection: AvailabilityReflection,
+
     /// The span of the parameter in the source code.
     pub span: Span,
 }
@@ -22,6 +22,7 @@ use crate::identifier::Name;
 
 pub mod assertion;
 pub mod attribute;
+pub mod availability;
 pub mod class_like;
 pub mod constant;
 pub mod function_like;
@@ -178,7 +179,8 @@ impl CodebaseReflection {
     ///
     /// This method ensures that the function-like entity is uniquely registered,
     /// accounting for case-insensitive names. If an entity with the same name already
-    /// exists, it will not be registered again.
+    /// exists, it will not be registered again, unless both are built-in functions declared
+    /// for different PHP versions, in which case their parameters are merged.
     ///
     /// # Arguments
     ///
@@ -194,10 +196,32 @@ impl CodebaseReflection {
 
         if let FunctionLikeName::Function(name) = reflection.name {
             let lowercase_name = interner.lowered(&name.value);
-            if let Entry::Vacant(e) = self.function_names.entry(lowercase_name) {
-                e.insert(reflection.name);
-            } else {
-                exists = true;
+            match self.function_names.entry(lowercase_name) {
+                Entry::Vacant(e) => {
+                    e.insert(reflection.name);
+                }
+                Entry::Occupied(e) => {
+                    exists = true;
+
+                    // Built-in functions are declared once for each range of PHP versions with a different
+                    // signature, e.g. `dba_open()`, in which case the declarations are merged together.
+                    if let Some(existing) = self.function_like_reflections.get_mut(e.get()) {
+                        let is_built_in = |function: &FunctionLikeReflection| {
+                            function.span.start.source.category() == SourceCategory::BuiltIn
+                        };
+
+                        if is_built_in(existing)
+                            && is_built_in(&reflection)
+                            && existing.availability_reflection.is_disjoint_from(&reflection.availability_reflection)
+                        {
+                            existing.availability_reflection =
+                                existing.availability_reflection.union(reflection.availability_reflection);
+                            existing.parameters.extend(reflection.parameters);
+
+                            return exists;
+                        }
+                    }
+                }
             }
         }
 
@@ -432,6 +456,8 @@ impl CodebaseReflection {
 
     /// Retrieves a closure reflection by its position, if it exists.
     ///
+    /// When closures are nested, the innermost closure containing the position is returned.
+    ///
     /// # Arguments
     ///
     /// - `position`: The position to search for as an implementation of `HasPosition`.
@@ -441,20 +467,24 @@ impl CodebaseReflection {
     /// - `Some(&FunctionLikeReflection)` if the closure exists at the given position.
     /// - `None` otherwise.
     pub fn get_closure(&self, position: &impl HasPosition) -> Option<&FunctionLikeReflection> {
-        self.function_like_reflections.iter().find_map(|(identifier, function_like)| match identifier {
-            FunctionLikeName::Closure(span) => {
-                if span.contains(position) {
-                    Some(function_like)
-                } else {
-                    None
+        let position = position.position();
+
+        self.function_like_reflections
+            .iter()
+            .filter(|(identifier, _)| match identifier {
+                FunctionLikeName::Closure(span) => {
+                    span.start.source == position.source && span.has_offset(position.offset)
                 }
-            }
-            _ => None,
-        })
+                _ => false,
+            })
+            .max_by_key(|(_, function_like)| function_like.span.start.offset)
+            .map(|(_, function_like)| function_like)
     }
 
     /// Retrieves an arrow function reflection by its position, if it exists.
     ///
+    /// When arrow functions are nested, the innermost arrow function containing the position is returned.
+    ///
     /// # Arguments
     ///
     /// - `position`: The position to search for as an implementation of `HasPosition`.
@@ -464,16 +494,18 @@ impl CodebaseReflection {
     /// - `Some(&FunctionLikeReflection)` if the arrow function exists at the given position.
     /// - `None` otherwise.
     pub fn get_arrow_function(&self, position: &impl HasPosition) -> Option<&FunctionLikeReflection> {
-        self.function_like_reflections.iter().find_map(|(identifier, function_like)| match identifier {
-            FunctionLikeName::ArrowFunction(span) => {
-                if span.contains(position) {
-                    Some(function_like)
-                } else {
-                    None
+        let position = position.position();
+
+        self.function_like_reflections
+            .iter()
+            .filter(|(identifier, _)| match identifier {
+                FunctionLikeName::ArrowFunction(span) => {
+                    span.start.source == position.source && span.has_offset(position.offset)
                 }
-            }
-            _ => None,
-        })
+                _ => false,
+            })
+            .max_by_key(|(_, function_like)| function_like.span.start.offset)
+            .map(|(_, function_like)| function_like)
     }
 
     /// Retrieves a class-like reflection by its identifier, if it exists.
@@ -613,7 +645,7 @@ impl CodebaseReflection {
         self.class_like_reflections.get(&ClassLikeName::AnonymousClass(node.span()))
     }
 
-    /// Returns the function-like reflection (function, closure, etc.) that encloses the given offset.
+    /// Returns the function-like reflection (function, closure, etc.) that encloses the given position.
     ///
     /// This method iterates through the reflections in the codebase, filtering for function-like reflections
     /// that contain the given offset in their definition range. It returns the reflection with the
@@ -628,14 +660,18 @@ impl CodebaseReflection {
     ///
     /// * `Option<&FunctionLikeReflection>` - The enclosing function-like reflection, if found.
     pub fn get_enclosing_function_like(&self, has_position: &impl HasPosition) -> Option<&FunctionLikeReflection> {
+        let position = has_position.position();
+
         self.function_like_reflections
             .iter()
-            .filter(|(_, function_like)| function_like.span.has_offset(has_position.offset()))
+            .filter(|(_, function_like)| {
+                function_like.span.start.source == position.source && function_like.span.has_offset(position.offset)
+            })
             .max_by_key(|(_, function_like)| function_like.span.start.offset)
             .map(|(_, function_like)| function_like)
     }
 
-    /// Returns the class-like reflection (class, trait, etc.) that encloses the given offset.
+    /// Returns the class-like reflection (class, trait, etc.) that encloses the given position.
     ///
     /// This method iterates through the reflections in the codebase, filtering for class-like reflections
     /// that contain the given offset in their definition range. It returns the reflection with the
@@ -650,9 +686,13 @@ impl CodebaseReflection {
     ///
     /// * `Option<&ClassLikeReflection>` - The enclosing class-like reflection, if found.
     pub fn get_enclosing_class_like(&self, has_position: &impl HasPosition) -> Option<&ClassLikeReflection> {
+        let position = has_position.position();
+
         self.class_like_reflections
             .iter()
-            .filter(|(_, class_like)| class_like.span.has_offset(has_position.offset()))
+            .filter(|(_, class_like)| {
+                class_like.span.start.source == position.source && class_like.span.has_offset(position.offset)
+            })
             .max_by_key(|(_, class_like)| class_like.span.start.offset)
             .map(|(_, class_like)| class_like)
     }
@@ -1,3 +1,5 @@
+use mago_ast::Sequence;
+use mago_ast::Trivia;
 use mago_interner::ThreadedInterner;
 use mago_names::Names;
 use mago_source::Source;
@@ -7,10 +9,16 @@ pub struct Context<'a> {
     pub interner: &'a ThreadedInterner,
     pub source: &'a Source,
     pub names: &'a Names,
+    pub trivia: &'a Sequence<Trivia>,
 }
 
 impl<'a> Context<'a> {
-    pub fn new(interner: &'a ThreadedInterner, source: &'a Source, names: &'a Names) -> Self {
-        Self { interner, source, names }
+    pub fn new(
+        interner: &'a ThreadedInterner,
+        source: &'a Source,
+        names: &'a Names,
+        trivia: &'a Sequence<Trivia>,
+    ) -> Self {
+        Self { interner, source, names, trivia }
     }
 }
@@ -0,0 +1,259 @@
+use std::str::FromStr;
+
+use mago_ast::*;
+use mago_php_version::PHPVersion;
+use mago_reflection::availability::AvailabilityReflection;
+use mago_source::SourceCategory;
+use mago_span::*;
+
+use crate::internal::context::Context;
+use crate::internal::reflect::docblock::get_docblock;
+
+const DEPRECATED_ATTRIBUTE: &str = "JetBrains\\PhpStorm\\Deprecated";
+const ELEMENT_AVAILABLE_ATTRIBUTE: &str = "JetBrains\\PhpStorm\\Internal\\PhpStormStubsElementAvailable";
+
+/// The stub directories of PHP itself, and of the extensions bundled with it, whose tags refer to PHP versions
+/// even when written without the `PHP` prefix.
+const CORE_STUB_DIRECTORIES: &[&str] = &[
+    "apache",
+    "bcmath",
+    "bz2",
+    "calendar",
+    "com_dotnet",
+    "core",
+    "ctype",
+    "curl",
+    "date",
+    "dba",
+    "dom",
+    "enchant",
+    "exif",
+    "ffi",
+    "fileinfo",
+    "filter",
+    "fpm",
+    "ftp",
+    "gd",
+    "gettext",
+    "gmp",
+    "hash",
+    "iconv",
+    "imap",
+    "interbase",
+    "intl",
+    "json",
+    "ldap",
+    "libxml",
+    "mbstring",
+    "mcrypt",
+    "mssql",
+    "mysql",
+    "mysqli",
+    "oci8",
+    "odbc",
+    "openssl",
+    "pcntl",
+    "pcre",
+    "pdo",
+    "phar",
+    "phpdbg",
+    "posix",
+    "pspell",
+    "random",
+    "readline",
+    "recode",
+    "reflection",
+    "regex",
+    "session",
+    "shmop",
+    "simplexml",
+    "snmp",
+    "soap",
+    "sockets",
+    "sodium",
+    "spl",
+    "sqlite3",
+    "standard",
+    "superglobals",
+    "sybase",
+    "sysvmsg",
+    "sysvsem",
+    "sysvshm",
+    "tidy",
+    "tokenizer",
+    "wddx",
+    "xml",
+    "xmlreader",
+    "xmlwriter",
+    "xsl",
+    "zend",
+    "zend-opcache",
+    "zip",
+    "zlib",
+];
+
+/// Reflects the PHP versions in which a built-in entity is available, from its docblock
+/// tags and its `#[Deprecated]` attribute.
+///
+/// For entities that are not built-in, an empty availability is returned, as the tags refer to
+/// library versions, rather than PHP versions.
+pub fn reflect_availability<'ast>(
+    node: &'ast impl HasSpan,
+    attribute_lists: &'ast Sequence<AttributeList>,
+    context: &'ast mut Context<'_>,
+) -> AvailabilityReflection {
+    let mut reflection = AvailabilityReflection::default();
+    if context.source.identifier.category() != SourceCategory::BuiltIn {
+        return reflection;
+    }
+
+    if let Some(docblock) = get_docblock(node, context) {
+        let is_core_stub = is_core_stub(context);
+        for line in context.interner.lookup(&docblock.value).lines() {
+            let mut words =
+                line.trim_start_matches(|c: char| c.is_whitespace() || c == '/' || c == '*').split_whitespace();
+
+            let (Some(tag), Some(version)) = (words.next(), parse_tag_version(&mut words, is_core_stub)) else {
+                continue;
+            };
+
+            match tag {
+                "@since" => reflection.since = Some(version),
+                "@deprecated" => reflection.deprecated = Some(version),
+                "@removed" => reflection.removed = Some(version),
+                _ => {}
+            }
+        }
+    }
+
+    if reflection.deprecated.is_none() {
+        reflection.deprecated = get_deprecation_version(attribute_lists, context);
+    }
+
+    reflection
+}
+
+/// Reflects the PHP versions in which a built-in element is available, from its `#[PhpStormStubsElementAvailable]`
+/// attribute, which the stubs use for parameters, and for functions declared once for each range of PHP versions
+/// with a different signature.
+///
+/// The attribute takes the first and the last PHP versions in which the element is available, e.g.
+/// `#[PhpStormStubsElementAvailable(from: '5.3', to: '7.4')]` for a parameter removed in PHP 8.0.
+pub fn reflect_element_availability(
+    attribute_lists: &Sequence<AttributeList>,
+    context: &Context<'_>,
+) -> AvailabilityReflection {
+    let mut reflection = AvailabilityReflection::default();
+    if context.source.identifier.category() != SourceCategory::BuiltIn {
+        return reflection;
+    }
+
+    for attribute in get_attributes(attribute_lists, ELEMENT_AVAILABLE_ATTRIBUTE, context) {
+        if let Some(from) = get_version_argument(attribute, "from", Some(0), context) {
+            reflection.since = Some(from);
+        }
+
+        if let Some(to) = get_version_argument(attribute, "to", Some(1), context) {
+            // The last version is inclusive, so the element is removed in the release that follows it.
+            reflection.removed = Some(get_next_release(to));
+        }
+    }
+
+    reflection
+}
+
+/// Returns the PHP release that follows the given version, e.g. PHP 8.0 for PHP 7.4.
+fn get_next_release(version: PHPVersion) -> PHPVersion {
+    match (version.major(), version.minor()) {
+        (4, 4) => PHPVersion::new(5, 0, 0),
+        (5, 6) => PHPVersion::PHP70,
+        (7, 4) => PHPVersion::PHP80,
+        (major, minor) => PHPVersion::new(major, minor + 1, 0),
+    }
+}
+
+/// Returns the version passed as the `since` argument of the `#[Deprecated]` attribute, if any.
+fn get_deprecation_version(attribute_lists: &Sequence<AttributeList>, context: &Context<'_>) -> Option<PHPVersion> {
+    get_attributes(attribute_lists, DEPRECATED_ATTRIBUTE, context)
+        .into_iter()
+        .find_map(|attribute| get_version_argument(attribute, "since", None, context))
+}
+
+/// Returns whether the source being reflected is a stub of PHP itself, or of an extension bundled with it,
+/// e.g. `stubs/standard/basic.php`, rather than a stub of a PECL extension, e.g. `stubs/redis/Redis.php`.
+fn is_core_stub(context: &Context<'_>) -> bool {
+    let name = context.interner.lookup(&context.source.identifier.0);
+    let mut components = name.split(['/', '\\']);
+
+    components.next().is_some_and(|root| root.eq_ignore_ascii_case("stubs"))
+        && components.next().is_some_and(|directory| {
+            let directory = directory.to_ascii_lowercase().replace(' ', "-");
+
+            CORE_STUB_DIRECTORIES.contains(&directory.as_str())
+        })
+}
+
+/// Parses the PHP version of a `@since`, `@deprecated`, or `@removed` tag.
+///
+/// The stubs of PECL extensions use these tags for versions of the extension itself, e.g. `@since 5.0`,
+/// so versions written as a PHP major and minor version, e.g. `@since 8.0`, are only accepted in the stubs
+/// of PHP and its bundled extensions. Elsewhere, only versions prefixed with `PHP`, e.g. `@since PHP 8.0.3`,
+/// are accepted.
+fn parse_tag_version<'a>(words: &mut impl Iterator<Item = &'a str>, is_core_stub: bool) -> Option<PHPVersion> {
+    let word = words.next()?;
+    if word.eq_ignore_ascii_case("PHP") {
+        return PHPVersion::from_str(words.next()?).ok();
+    }
+
+    if !is_core_stub {
+        return None;
+    }
+
+    let (major, minor) = word.split_once('.')?;
+    let major = major.parse::<u32>().ok().filter(|major| (4..=8).contains(major))?;
+    let minor = minor.parse::<u32>().ok()?;
+
+    Some(PHPVersion::new(major, minor, 0))
+}
+
+fn get_attributes<'ast>(
+    attribute_lists: &'ast Sequence<AttributeList>,
+    attribute_name: &'static str,
+    context: &Context<'_>,
+) -> Vec<&'ast Attribute> {
+    attribute_lists
+        .iter()
+        .flat_map(|attribute_list| attribute_list.attributes.iter())
+        .filter(|attribute| {
+            context.interner.lookup(context.names.get(&attribute.name)).eq_ignore_ascii_case(attribute_name)
+        })
+        .collect()
+}
+
+/// Returns the version passed to an attribute, either as the named argument, or at the given position.
+fn get_version_argument(
+    attribute: &Attribute,
+    name: &str,
+    position: Option<usize>,
+    context: &Context<'_>,
+) -> Option<PHPVersion> {
+    let argument_list = attribute.arguments.as_ref()?;
+
+    let value = argument_list.arguments.iter().enumerate().find_map(|(index, argument)| match argument {
+        Argument::Named(NamedArgument { name: argument_name, value, .. })
+            if context.interner.lookup(&argument_name.value) == name =>
+        {
+            Some(value)
+        }
+        Argument::Positional(PositionalArgument { value, .. }) if position == Some(index) => Some(value),
+        _ => None,
+    })?;
+
+    let Expression::Literal(Literal::String(string)) = value else {
+        return None;
+    };
+
+    let value = context.interner.lookup(&string.value);
+
+    PHPVersion::from_str(&value[1..value.len() - 1]).ok()
+}
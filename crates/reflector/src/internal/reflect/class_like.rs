@@ -18,6 +18,8 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflect::attribute::reflect_attributes;
+use crate::internal::reflect::availability::reflect_availability;
+use crate::internal::reflect::throws::reflect_thrown_types;
 
 use super::function_like::reflect_function_like_parameter_list;
 use super::function_like::reflect_function_like_return_type_hint;
@@ -67,6 +69,7 @@ pub fn reflect_class<'ast>(class: &'ast Class, context: &'ast mut Context<'_>) -
         used_traits: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        availability_reflection: reflect_availability(class, &class.attribute_lists, context),
         issues: Default::default(),
     };
 
@@ -120,6 +123,7 @@ pub fn reflect_anonymous_class<'ast>(
         used_traits: Default::default(),
         is_populated: false,
         is_anonymous: true,
+        availability_reflection: Default::default(),
         issues: Default::default(),
     };
 
@@ -160,6 +164,7 @@ pub fn reflect_interface<'ast>(interface: &'ast Interface, context: &'ast mut Co
         used_traits: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        availability_reflection: reflect_availability(interface, &interface.attribute_lists, context),
         issues: Default::default(),
     };
 
@@ -185,6 +190,7 @@ pub fn reflect_trait<'ast>(r#trait: &'ast Trait, context: &'ast mut Context<'_>)
         used_traits: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        availability_reflection: reflect_availability(r#trait, &r#trait.attribute_lists, context),
         issues: Default::default(),
     };
 
@@ -228,6 +234,7 @@ pub fn reflect_enum<'ast>(r#enum: &'ast Enum, context: &'ast mut Context<'_>) ->
         used_traits: Default::default(),
         is_populated: false,
         is_anonymous: false,
+        availability_reflection: reflect_availability(r#enum, &r#enum.attribute_lists, context),
         issues: Default::default(),
     };
 
@@ -399,9 +406,11 @@ fn reflect_class_like_method<'ast>(
             returns_by_reference: method.ampersand.is_some(),
             has_yield,
             has_throws,
+            thrown_types: reflect_thrown_types(method, context),
             is_anonymous: false,
             // TODO: parse docblock to determine if pure
             is_pure: false,
+            availability_reflection: reflect_availability(method, &method.attribute_lists, context),
             is_static: method.modifiers.contains_static(),
             is_final: class_like.is_final || method.modifiers.contains_final(),
             is_abstract,
@@ -570,10 +579,12 @@ fn reflect_class_like_property<'ast>(
                                 returns_by_reference: hook.ampersand.is_some(),
                                 has_yield,
                                 has_throws,
+                                thrown_types: vec![],
                                 is_anonymous: false,
                                 is_static: false,
                                 is_final: true,
                                 is_pure: false,
+                                availability_reflection: Default::default(),
                                 is_abstract: false,
                                 is_overriding: false,
                                 span: hook.span(),
@@ -0,0 +1,23 @@
+use mago_ast::*;
+use mago_span::*;
+
+use crate::internal::context::Context;
+
+/// Returns the docblock comment immediately preceding the given node, if any.
+pub fn get_docblock<'ast>(node: &'ast impl HasSpan, context: &'ast Context<'_>) -> Option<&'ast Trivia> {
+    let start = node.span().start.offset;
+    let index = context.trivia.as_slice().partition_point(|trivia| trivia.span.end.offset <= start);
+
+    let trivia =
+        context.trivia.as_slice()[..index].iter().rev().find(|trivia| trivia.kind != TriviaKind::WhiteSpace)?;
+    if trivia.kind != TriviaKind::DocBlockComment {
+        return None;
+    }
+
+    let content = context.interner.lookup(&context.source.content);
+    if !content[trivia.span.end.offset..start].trim().is_empty() {
+        return None;
+    }
+
+    Some(trivia)
+}
@@ -9,8 +9,11 @@ use mago_span::*;
 
 use crate::internal::context::Context;
 use crate::internal::reflect::attribute::reflect_attributes;
+use crate::internal::reflect::availability::reflect_availability;
+use crate::internal::reflect::availability::reflect_element_availability;
 use crate::internal::reflect::r#type::maybe_reflect_hint;
 use crate::internal::reflect::r#type::reflect_hint;
+use crate::internal::reflect::throws::reflect_thrown_types;
 
 pub fn reflect_function<'ast>(
     function: &'ast Function,
@@ -19,22 +22,33 @@ pub fn reflect_function<'ast>(
 ) -> FunctionLikeReflection {
     let name = Name::new(*context.names.get(&function.name), function.name.span);
 
+    // A function declared for a range of PHP versions only has its parameters available in these versions,
+    // so that the declarations of each range can be merged into a single reflection.
+    let element_availability = reflect_element_availability(&function.attribute_lists, context);
+    let mut parameters = reflect_function_like_parameter_list(&function.parameter_list, context, scope);
+    for parameter in parameters.iter_mut() {
+        parameter.availability_reflection = parameter.availability_reflection.intersect(element_availability);
+    }
+
     FunctionLikeReflection {
         attribute_reflections: reflect_attributes(&function.attribute_lists, context),
         visibility_reflection: None,
         name: FunctionLikeName::Function(name),
         // TODO: parse docblock to get the template list
         templates: vec![],
-        parameters: reflect_function_like_parameter_list(&function.parameter_list, context, scope),
+        parameters,
         return_type_reflection: reflect_function_like_return_type_hint(&function.return_type_hint, context, scope),
         returns_by_reference: function.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&function.body),
         has_throws: mago_ast_utils::block_has_throws(&function.body),
+        thrown_types: reflect_thrown_types(function, context),
         is_anonymous: false,
         is_static: true,
         is_final: true,
         // TODO: parse docblock to determine if pure
         is_pure: false,
+        availability_reflection: reflect_availability(function, &function.attribute_lists, context)
+            .intersect(element_availability),
         is_abstract: false,
         is_overriding: false,
         span: function.span(),
@@ -59,11 +73,13 @@ pub fn reflect_closure<'ast>(
         returns_by_reference: closure.ampersand.is_some(),
         has_yield: mago_ast_utils::block_has_yield(&closure.body),
         has_throws: mago_ast_utils::block_has_throws(&closure.body),
+        thrown_types: reflect_thrown_types(closure, context),
         is_anonymous: true,
         is_static: closure.r#static.is_some(),
         is_final: true,
         // TODO: parse docblock to determine if pure
         is_pure: false,
+        availability_reflection: Default::default(),
         is_abstract: false,
         is_overriding: false,
         span: closure.span(),
@@ -92,11 +108,13 @@ pub fn reflect_arrow_function<'ast>(
         returns_by_reference: arrow_function.ampersand.is_some(),
         has_yield: mago_ast_utils::expression_has_yield(&arrow_function.expression),
         has_throws: mago_ast_utils::expression_has_throws(&arrow_function.expression),
+        thrown_types: reflect_thrown_types(arrow_function, context),
         is_anonymous: true,
         is_static: arrow_function.r#static.is_some(),
         is_final: true,
         // TODO: parse docblock to determine if pure
         is_pure: false,
+        availability_reflection: Default::default(),
         is_abstract: false,
         is_overriding: false,
         span: arrow_function.span(),
@@ -134,6 +152,7 @@ pub fn reflect_function_like_parameter<'ast>(
             type_reflection: mago_typing::infere(context.interner, context.source, context.names, &d.value),
             span: d.span(),
         }),
+        availability_reflection: reflect_element_availability(&parameter.attribute_lists, context),
         span: parameter.span(),
     }
 }
@@ -1,5 +1,8 @@
 pub mod attribute;
+pub mod availability;
 pub mod class_like;
 pub mod constant;
+pub mod docblock;
 pub mod function_like;
+pub mod throws;
 pub mod r#type;
@@ -0,0 +1,55 @@
+use mago_reflection::identifier::Name;
+use mago_span::*;
+
+use crate::internal::context::Context;
+use crate::internal::reflect::docblock::get_docblock;
+
+/// Reflects the exception types declared by the `@throws` tags in the docblock of the given node.
+///
+/// Each type is resolved to its fully qualified name, and is paired with its span within the docblock.
+/// Types that are not plain class names, such as generics or conditional types, are ignored.
+pub fn reflect_thrown_types(node: &impl HasSpan, context: &mut Context<'_>) -> Vec<Name> {
+    let Some(docblock) = get_docblock(node, context) else {
+        return vec![];
+    };
+
+    let span = docblock.span;
+    let value = context.interner.lookup(&docblock.value);
+
+    let mut offset = 0;
+    let mut types = vec![];
+    for line in value.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+
+        let content = line.trim_start_matches(|c: char| c.is_whitespace() || c == '/' || c == '*');
+        let Some(rest) = content.strip_prefix("@throws") else {
+            continue;
+        };
+
+        if !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let trimmed = rest.trim_start();
+        let Some(r#type) = trimmed.split_whitespace().next() else {
+            continue;
+        };
+
+        let mut type_offset = line_offset + (line.len() - trimmed.len());
+        for part in r#type.split('|') {
+            let part_offset = type_offset;
+            type_offset += part.len() + 1;
+
+            if part.is_empty() || !part.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '\\' || !c.is_ascii()) {
+                continue;
+            }
+
+            let span = span.subspan(part_offset, part_offset + part.len());
+
+            types.push(Name::new(context.names.resolve_class_name(context.interner, &span, part), span));
+        }
+    }
+
+    types
+}
@@ -27,7 +27,7 @@ mod populator;
 pub fn reflect(interner: &ThreadedInterner, source: &Source, program: &Program, names: &Names) -> CodebaseReflection {
     let mut walker = ReflectionWalker::new();
 
-    let mut context = Context::new(interner, source, names);
+    let mut context = Context::new(interner, source, names, &program.trivia);
 
     walker.walk_program(program, &mut context);
 
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use serde::Serialize;
+use strum::Display;
+use strum::EnumIter;
+
+/// The kind of a sink, i.e. the vulnerability tainted data reaching it could lead to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Display, EnumIter)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum SinkKind {
+    /// A SQL query, e.g. `mysqli_query()` or `PDO::query()`.
+    Sql,
+    /// A shell command, e.g. `exec()` or `system()`.
+    Shell,
+    /// A file inclusion, e.g. `include` or `require`.
+    Include,
+    /// HTML output, e.g. `echo` or `print`.
+    Html,
+    /// An unserialization, e.g. `unserialize()`.
+    Unserialize,
+    /// An HTTP header, e.g. `header()`.
+    Header,
+}
+
+/// User-defined sources, sinks, and sanitizers, added to the built-in ones.
+///
+/// Names are either function names (e.g. `"App\\read_input"`), or method names in the
+/// `Class::method` form (e.g. `"App\\Http\\Input::get"`). Method names also match the
+/// methods of child classes and implementations.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TaintConfiguration {
+    /// Functions and methods returning tainted data.
+    pub sources: Vec<String>,
+
+    /// Functions and methods that must not receive tainted data.
+    pub sinks: Vec<SinkDefinition>,
+
+    /// Functions and methods returning data that is safe to use in some, or all, sinks.
+    pub sanitizers: Vec<SanitizerDefinition>,
+}
+
+/// A function or method that must not receive tainted data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SinkDefinition {
+    /// The name of the function or method.
+    pub name: String,
+
+    /// The kind of the sink.
+    pub kind: SinkKind,
+
+    /// The zero-based positions of the dangerous parameters, all parameters are dangerous when empty.
+    #[serde(default)]
+    pub parameters: Vec<usize>,
+}
+
+/// A function or method returning data that is safe to use in some, or all, sinks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SanitizerDefinition {
+    /// The name of the function or method.
+    pub name: String,
+
+    /// The kinds of sinks the returned data is safe for, all kinds when empty.
+    #[serde(default)]
+    pub kinds: Vec<SinkKind>,
+}
+
+impl SinkKind {
+    /// Returns a description of what data reaching this kind of sink is used as, e.g. `"an SQL query"`.
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            SinkKind::Sql => "an SQL query",
+            SinkKind::Shell => "a shell command",
+            SinkKind::Include => "a file inclusion",
+            SinkKind::Html => "HTML output",
+            SinkKind::Unserialize => "an unserialization",
+            SinkKind::Header => "an HTTP header",
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use ahash::HashMap;
+
+use crate::configuration::SinkKind;
+use crate::internal::taint::Step;
+use crate::internal::taint::Taint;
+
+/// The summaries of the user-defined functions and methods of a codebase.
+///
+/// Summaries describe how tainted data flows through a function-like without re-analyzing its body at
+/// every call: which parameters, or sources, flow into the returned value, and which parameters reach a sink.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaintDatabase {
+    summaries: HashMap<String, FunctionSummary>,
+}
+
+/// How tainted data flows through a function or method.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FunctionSummary {
+    /// The names of the parameters, used to map named arguments.
+    pub(crate) parameters: Vec<String>,
+
+    /// The taints of the returned value, originating either from a source or from a parameter.
+    pub(crate) returns: Vec<Taint>,
+
+    /// The parameters reaching a sink.
+    pub(crate) sinks: Vec<ParameterSink>,
+}
+
+/// A parameter reaching a sink, along with the path it takes to get there.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ParameterSink {
+    pub parameter: usize,
+    pub kind: SinkKind,
+    pub sink: String,
+    pub path: Vec<Step>,
+}
+
+impl TaintDatabase {
+    /// Creates an empty database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of summarized functions and methods.
+    pub fn len(&self) -> usize {
+        self.summaries.len()
+    }
+
+    /// Returns whether no function or method is summarized.
+    pub fn is_empty(&self) -> bool {
+        self.summaries.is_empty()
+    }
+
+    /// Returns the summary of the function or method with the given normalized name,
+    /// e.g. `app\read_input` or `app\repository::find`.
+    pub fn get(&self, name: &str) -> Option<&FunctionSummary> {
+        self.summaries.get(name)
+    }
+
+    /// Returns whether a summary exists for the function or method with the given normalized name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.summaries.contains_key(name)
+    }
+
+    /// Adds the given summaries to the database, replacing existing summaries with the same name.
+    pub fn extend(&mut self, summaries: impl IntoIterator<Item = (String, FunctionSummary)>) {
+        self.summaries.extend(summaries);
+    }
+}
+
+impl FunctionSummary {
+    /// Returns whether the returned value may be tainted.
+    pub fn returns_tainted_data(&self) -> bool {
+        !self.returns.is_empty()
+    }
+
+    /// Returns the kinds of the sinks reached by the parameter at the given position.
+    pub fn get_parameter_sink_kinds(&self, parameter: usize) -> Vec<SinkKind> {
+        let mut kinds: Vec<_> =
+            self.sinks.iter().filter(|sink| sink.parameter == parameter).map(|sink| sink.kind).collect();
+
+        kinds.sort();
+        kinds.dedup();
+        kinds
+    }
+
+    /// Returns the position of the parameter with the given name, without the leading `$`.
+    pub(crate) fn get_parameter_position(&self, name: &str) -> Option<usize> {
+        self.parameters.iter().position(|parameter| parameter == name)
+    }
+}
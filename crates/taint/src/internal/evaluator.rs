@@ -0,0 +1,1370 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use mago_ast::*;
+use mago_interner::StringIdentifier;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::r#type::kind::ObjectTypeKind;
+use mago_reflection::r#type::kind::TypeKind;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_source::Source;
+use mago_span::HasSpan;
+use mago_span::Span;
+
+use crate::configuration::SinkKind;
+use crate::database::FunctionSummary;
+use crate::database::ParameterSink;
+use crate::database::TaintDatabase;
+use crate::internal::specification::normalize;
+use crate::internal::specification::Specification;
+use crate::internal::specification::SERVER_HEADER_KEY_PREFIX;
+use crate::internal::specification::SERVER_SOURCE_KEYS;
+use crate::internal::specification::SUPERGLOBAL_SOURCES;
+use crate::internal::taint::join;
+use crate::internal::taint::with_step;
+use crate::internal::taint::Kinds;
+use crate::internal::taint::Origin;
+use crate::internal::taint::Step;
+use crate::internal::taint::Taint;
+use crate::internal::taint::Taints;
+use crate::Finding;
+
+/// The taints and types of the variables and properties at a point of a function-like body.
+#[derive(Debug, Clone, Default)]
+struct State {
+    variables: HashMap<StringIdentifier, Taints>,
+    properties: HashMap<StringIdentifier, Taints>,
+    types: HashMap<StringIdentifier, StringIdentifier>,
+}
+
+/// The function-like body, or top-level code, being evaluated.
+#[derive(Debug, Default)]
+struct Scope {
+    class: Option<StringIdentifier>,
+    state: State,
+    returns: Taints,
+    sinks: Vec<ParameterSink>,
+}
+
+/// A function or method being called.
+struct Callee<'c> {
+    /// The name used in messages, e.g. `PDO::query()`.
+    display: String,
+    /// The normalized names of the function, or of the method in the class and all its ancestors.
+    keys: Vec<String>,
+    /// The reflection of the function-like, if known, used to map named arguments.
+    reflection: Option<&'c mago_reflection::function_like::FunctionLikeReflection>,
+}
+
+/// An evaluated argument of a call.
+struct Arg {
+    position: usize,
+    name: Option<String>,
+    spread: bool,
+    span: Span,
+    taints: Taints,
+}
+
+/// What the result of a call to an unknown function-like carries.
+enum Fallback {
+    /// The taints of all the arguments, e.g. for `trim()` or `sprintf()`.
+    Arguments,
+    /// The taints of the object the method is called on.
+    Receiver(Taints),
+}
+
+/// Evaluates the statements and expressions of a program, tracking tainted data through variables,
+/// properties of `$this`, calls, and returns.
+///
+/// Branches are evaluated from the same state and joined afterwards, and loop bodies are evaluated twice,
+/// so that data assigned in one iteration flows into the next.
+pub(crate) struct Evaluator<'a> {
+    interner: &'a ThreadedInterner,
+    codebase: &'a CodebaseReflection,
+    source: &'a Source,
+    names: &'a Names,
+    specification: &'a Specification,
+    database: &'a TaintDatabase,
+    kinds: Kinds,
+    reported: HashSet<(SinkKind, Span, Span)>,
+    pub summaries: Vec<(String, FunctionSummary)>,
+    pub findings: Vec<Finding>,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(
+        interner: &'a ThreadedInterner,
+        codebase: &'a CodebaseReflection,
+        source: &'a Source,
+        names: &'a Names,
+        specification: &'a Specification,
+        database: &'a TaintDatabase,
+        kinds: Kinds,
+    ) -> Self {
+        Self {
+            interner,
+            codebase,
+            source,
+            names,
+            specification,
+            database,
+            kinds,
+            reported: HashSet::default(),
+            summaries: vec![],
+            findings: vec![],
+        }
+    }
+
+    pub fn program(&mut self, program: &Program) {
+        let mut scope = Scope::default();
+
+        self.statements(program.statements.as_slice(), &mut scope);
+    }
+
+    fn function_like(
+        &mut self,
+        key: Option<String>,
+        class: Option<StringIdentifier>,
+        parameter_list: &FunctionLikeParameterList,
+        body: &Block,
+    ) {
+        let mut scope = Scope { class, ..Default::default() };
+        let mut parameters = vec![];
+
+        for (position, parameter) in parameter_list.parameters.iter().enumerate() {
+            let name = parameter.variable.name;
+
+            scope.state.variables.insert(name, vec![Taint::new(Origin::Parameter(position), self.kinds)]);
+            if let Some(class) = parameter.hint.as_ref().and_then(|hint| self.get_hinted_class(hint)) {
+                scope.state.types.insert(name, class);
+            }
+
+            parameters.push(self.interner.lookup(&name).trim_start_matches('$').to_string());
+        }
+
+        self.statements(body.statements.as_slice(), &mut scope);
+
+        if let Some(key) = key {
+            self.summaries.push((key, FunctionSummary { parameters, returns: scope.returns, sinks: scope.sinks }));
+        }
+    }
+
+    fn class_like(&mut self, name: Option<StringIdentifier>, members: &[ClassLikeMember]) {
+        for member in members {
+            let ClassLikeMember::Method(method) = member else {
+                continue;
+            };
+
+            let MethodBody::Concrete(body) = &method.body else {
+                continue;
+            };
+
+            let key = name.map(|name| {
+                normalize(&format!("{}::{}", self.interner.lookup(&name), self.interner.lookup(&method.name.value)))
+            });
+
+            self.function_like(key, name, &method.parameter_list, body);
+        }
+    }
+
+    fn statements(&mut self, statements: &[Statement], scope: &mut Scope) {
+        let mut echo_tag = false;
+
+        for statement in statements {
+            match statement {
+                Statement::OpeningTag(OpeningTag::Echo(_)) => {
+                    echo_tag = true;
+
+                    continue;
+                }
+                Statement::Expression(expression_statement) if echo_tag => {
+                    let taints = self.expression(&expression_statement.expression, scope);
+
+                    self.sink(SinkKind::Html, "echo", &taints, expression_statement.expression.span(), scope);
+                }
+                _ => self.statement(statement, scope),
+            }
+
+            echo_tag = false;
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement, scope: &mut Scope) {
+        match statement {
+            Statement::Namespace(namespace) => match &namespace.body {
+                NamespaceBody::Implicit(body) => self.statements(body.statements.as_slice(), scope),
+                NamespaceBody::BraceDelimited(block) => self.statements(block.statements.as_slice(), scope),
+            },
+            Statement::Class(class) => {
+                self.class_like(Some(*self.names.get(&class.name)), class.members.as_slice());
+            }
+            Statement::Interface(interface) => {
+                self.class_like(Some(*self.names.get(&interface.name)), interface.members.as_slice());
+            }
+            Statement::Trait(r#trait) => {
+                self.class_like(Some(*self.names.get(&r#trait.name)), r#trait.members.as_slice());
+            }
+            Statement::Enum(r#enum) => {
+                self.class_like(Some(*self.names.get(&r#enum.name)), r#enum.members.as_slice());
+            }
+            Statement::Function(function) => {
+                let key = normalize(self.interner.lookup(self.names.get(&function.name)));
+
+                self.function_like(Some(key), None, &function.parameter_list, &function.body);
+            }
+            Statement::Block(block) => self.statements(block.statements.as_slice(), scope),
+            Statement::Declare(declare) => match &declare.body {
+                DeclareBody::Statement(statement) => self.statement(statement, scope),
+                DeclareBody::ColonDelimited(body) => self.statements(body.statements.as_slice(), scope),
+            },
+            Statement::Try(r#try) => {
+                self.statements(r#try.block.statements.as_slice(), scope);
+
+                let mut branches = vec![];
+                for clause in r#try.catch_clauses.iter() {
+                    branches.push(clause.block.statements.as_slice());
+                }
+
+                self.branches(&branches, false, scope);
+
+                if let Some(finally) = &r#try.finally_clause {
+                    self.statements(finally.block.statements.as_slice(), scope);
+                }
+            }
+            Statement::Foreach(foreach) => {
+                let taints = self.expression(&foreach.expression, scope);
+                let body = match &foreach.body {
+                    ForeachBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForeachBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                let initial = scope.state.clone();
+                for _ in 0..2 {
+                    match &foreach.target {
+                        ForeachTarget::Value(target) => self.assign(&target.value, taints.clone(), None, scope),
+                        ForeachTarget::KeyValue(target) => {
+                            self.assign(&target.key, taints.clone(), None, scope);
+                            self.assign(&target.value, taints.clone(), None, scope);
+                        }
+                    }
+
+                    self.statements(body, scope);
+                    join_state(&mut scope.state, initial.clone());
+                }
+            }
+            Statement::For(r#for) => {
+                for initialization in r#for.initializations.iter() {
+                    self.expression(initialization, scope);
+                }
+
+                let body = match &r#for.body {
+                    ForBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    ForBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                let initial = scope.state.clone();
+                for _ in 0..2 {
+                    for condition in r#for.conditions.iter() {
+                        self.expression(condition, scope);
+                    }
+
+                    self.statements(body, scope);
+
+                    for increment in r#for.increments.iter() {
+                        self.expression(increment, scope);
+                    }
+
+                    join_state(&mut scope.state, initial.clone());
+                }
+            }
+            Statement::While(r#while) => {
+                let body = match &r#while.body {
+                    WhileBody::Statement(statement) => std::slice::from_ref(statement.as_ref()),
+                    WhileBody::ColonDelimited(body) => body.statements.as_slice(),
+                };
+
+                let initial = scope.state.clone();
+                for _ in 0..2 {
+                    self.expression(&r#while.condition, scope);
+                    self.statements(body, scope);
+                    join_state(&mut scope.state, initial.clone());
+                }
+            }
+            Statement::DoWhile(do_while) => {
+                let initial = scope.state.clone();
+                for _ in 0..2 {
+                    self.statement(&do_while.statement, scope);
+                    self.expression(&do_while.condition, scope);
+                    join_state(&mut scope.state, initial.clone());
+                }
+            }
+            Statement::Switch(switch) => {
+                self.expression(&switch.expression, scope);
+
+                let cases = match &switch.body {
+                    SwitchBody::BraceDelimited(body) => body.cases.as_slice(),
+                    SwitchBody::ColonDelimited(body) => body.cases.as_slice(),
+                };
+
+                let mut branches = vec![];
+                for case in cases {
+                    match case {
+                        SwitchCase::Expression(case) => {
+                            self.expression(&case.expression, scope);
+
+                            branches.push(case.statements.as_slice());
+                        }
+                        SwitchCase::Default(case) => branches.push(case.statements.as_slice()),
+                    }
+                }
+
+                self.branches(&branches, false, scope);
+            }
+            Statement::If(r#if) => {
+                self.expression(&r#if.condition, scope);
+
+                match &r#if.body {
+                    IfBody::Statement(body) => {
+                        let mut branches = vec![std::slice::from_ref(body.statement.as_ref())];
+                        for clause in body.else_if_clauses.iter() {
+                            self.expression(&clause.condition, scope);
+
+                            branches.push(std::slice::from_ref(clause.statement.as_ref()));
+                        }
+
+                        if let Some(clause) = &body.else_clause {
+                            branches.push(std::slice::from_ref(clause.statement.as_ref()));
+                        }
+
+                        self.branches(&branches, body.else_clause.is_some(), scope);
+                    }
+                    IfBody::ColonDelimited(body) => {
+                        let mut branches = vec![body.statements.as_slice()];
+                        for clause in body.else_if_clauses.iter() {
+                            self.expression(&clause.condition, scope);
+
+                            branches.push(clause.statements.as_slice());
+                        }
+
+                        if let Some(clause) = &body.else_clause {
+                            branches.push(clause.statements.as_slice());
+                        }
+
+                        self.branches(&branches, body.else_clause.is_some(), scope);
+                    }
+                }
+            }
+            Statement::Return(r#return) => {
+                if let Some(value) = &r#return.value {
+                    let taints = self.expression(value, scope);
+
+                    join(&mut scope.returns, taints);
+                }
+            }
+            Statement::Expression(expression_statement) => {
+                self.expression(&expression_statement.expression, scope);
+            }
+            Statement::Echo(echo) => {
+                for value in echo.values.iter() {
+                    let taints = self.expression(value, scope);
+
+                    self.sink(SinkKind::Html, "echo", &taints, value.span(), scope);
+                }
+            }
+            Statement::Static(r#static) => {
+                for item in r#static.items.iter() {
+                    if let StaticItem::Concrete(item) = item {
+                        let taints = self.expression(&item.value, scope);
+
+                        join(scope.state.variables.entry(item.variable.name).or_default(), taints);
+                    }
+                }
+            }
+            Statement::Unset(unset) => {
+                for value in unset.values.iter() {
+                    if let Expression::Variable(Variable::Direct(variable)) = value {
+                        scope.state.variables.remove(&variable.name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Evaluates each branch from the current state, and joins the resulting states.
+    ///
+    /// When the branches are not exhaustive, e.g. an `if` without an `else`, the current state is joined as well.
+    fn branches(&mut self, branches: &[&[Statement]], exhaustive: bool, scope: &mut Scope) {
+        if branches.is_empty() {
+            return;
+        }
+
+        let initial = scope.state.clone();
+        let mut result: Option<State> = if exhaustive { None } else { Some(initial.clone()) };
+
+        for branch in branches {
+            scope.state = initial.clone();
+            self.statements(branch, scope);
+
+            let state = std::mem::take(&mut scope.state);
+            match &mut result {
+                Some(result) => join_state(result, state),
+                None => result = Some(state),
+            }
+        }
+
+        scope.state = result.unwrap_or(initial);
+    }
+
+    fn expression(&mut self, expression: &Expression, scope: &mut Scope) -> Taints {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.expression(&parenthesized.expression, scope),
+            Expression::Binary(binary) => {
+                let lhs = self.expression(&binary.lhs, scope);
+                let rhs = self.expression(&binary.rhs, scope);
+
+                match binary.operator {
+                    BinaryOperator::StringConcat(_) | BinaryOperator::NullCoalesce(_) | BinaryOperator::Elvis(_) => {
+                        let mut taints = lhs;
+                        join(&mut taints, rhs);
+                        taints
+                    }
+                    _ => vec![],
+                }
+            }
+            Expression::UnaryPrefix(unary) => {
+                let taints = self.expression(&unary.operand, scope);
+
+                match unary.operator {
+                    UnaryPrefixOperator::ErrorControl(_)
+                    | UnaryPrefixOperator::Reference(_)
+                    | UnaryPrefixOperator::ArrayCast(..)
+                    | UnaryPrefixOperator::ObjectCast(..)
+                    | UnaryPrefixOperator::StringCast(..)
+                    | UnaryPrefixOperator::BinaryCast(..) => taints,
+                    _ => vec![],
+                }
+            }
+            Expression::UnaryPostfix(unary) => {
+                self.expression(&unary.operand, scope);
+
+                vec![]
+            }
+            Expression::CompositeString(composite_string) => {
+                let mut taints = vec![];
+                for part in composite_string.parts().iter() {
+                    match part {
+                        StringPart::Literal(_) => {}
+                        StringPart::Expression(expression) => join(&mut taints, self.expression(expression, scope)),
+                        StringPart::BracedExpression(part) => {
+                            join(&mut taints, self.expression(&part.expression, scope));
+                        }
+                    }
+                }
+
+                if let CompositeString::ShellExecute(shell_execute) = composite_string {
+                    self.sink(SinkKind::Shell, "the shell execution operator", &taints, shell_execute.span(), scope);
+                }
+
+                taints
+            }
+            Expression::AssignmentOperation(assignment) => {
+                let taints = self.expression(&assignment.rhs, scope);
+
+                match assignment.operator {
+                    AssignmentOperator::Assign(_) => {
+                        let class = self.get_class(&assignment.rhs, scope);
+
+                        self.assign(&assignment.lhs, taints.clone(), class, scope);
+
+                        taints
+                    }
+                    AssignmentOperator::Concat(_) | AssignmentOperator::Coalesce(_) => {
+                        let mut result = self.expression(&assignment.lhs, scope);
+                        join(&mut result, taints);
+
+                        self.assign(&assignment.lhs, result.clone(), None, scope);
+
+                        result
+                    }
+                    _ => {
+                        self.assign(&assignment.lhs, vec![], None, scope);
+
+                        vec![]
+                    }
+                }
+            }
+            Expression::Conditional(conditional) => {
+                let condition = self.expression(&conditional.condition, scope);
+                let mut taints = match &conditional.then {
+                    Some(then) => self.expression(then, scope),
+                    None => condition,
+                };
+
+                join(&mut taints, self.expression(&conditional.r#else, scope));
+
+                taints
+            }
+            Expression::Array(array) => self.array_elements(array.elements.as_slice(), scope),
+            Expression::LegacyArray(array) => self.array_elements(array.elements.as_slice(), scope),
+            Expression::ArrayAccess(array_access) => {
+                self.expression(&array_access.index, scope);
+
+                if let Expression::Variable(Variable::Direct(variable)) = array_access.array.as_ref() {
+                    if self.interner.lookup(&variable.name) == "$_SERVER" {
+                        return self.server_variable(variable, &array_access.index);
+                    }
+                }
+
+                self.expression(&array_access.array, scope)
+            }
+            Expression::ArrayAppend(array_append) => self.expression(&array_append.array, scope),
+            Expression::AnonymousClass(anonymous_class) => {
+                if let Some(arguments) = &anonymous_class.arguments {
+                    self.arguments(arguments, scope);
+                }
+
+                self.class_like(None, anonymous_class.members.as_slice());
+
+                vec![]
+            }
+            Expression::Closure(closure) => {
+                let mut closure_scope = Scope { class: scope.class, ..Default::default() };
+                closure_scope.state.properties = scope.state.properties.clone();
+
+                if let Some(use_clause) = &closure.use_clause {
+                    for variable in use_clause.variables.iter() {
+                        let name = variable.variable.name;
+
+                        if let Some(taints) = scope.state.variables.get(&name) {
+                            closure_scope.state.variables.insert(name, taints.clone());
+                        }
+
+                        if let Some(class) = scope.state.types.get(&name) {
+                            closure_scope.state.types.insert(name, *class);
+                        }
+                    }
+                }
+
+                self.statements(closure.body.statements.as_slice(), &mut closure_scope);
+
+                vec![]
+            }
+            Expression::ArrowFunction(arrow_function) => {
+                let mut arrow_function_scope =
+                    Scope { class: scope.class, state: scope.state.clone(), ..Default::default() };
+
+                for parameter in arrow_function.parameter_list.parameters.iter() {
+                    arrow_function_scope.state.variables.remove(&parameter.variable.name);
+                    arrow_function_scope.state.types.remove(&parameter.variable.name);
+                }
+
+                self.expression(&arrow_function.expression, &mut arrow_function_scope);
+
+                vec![]
+            }
+            Expression::Variable(variable) => self.variable(variable, scope),
+            Expression::Match(r#match) => {
+                self.expression(&r#match.expression, scope);
+
+                let mut taints = vec![];
+                for arm in r#match.arms.iter() {
+                    match arm {
+                        MatchArm::Expression(arm) => {
+                            for condition in arm.conditions.iter() {
+                                self.expression(condition, scope);
+                            }
+
+                            join(&mut taints, self.expression(&arm.expression, scope));
+                        }
+                        MatchArm::Default(arm) => join(&mut taints, self.expression(&arm.expression, scope)),
+                    }
+                }
+
+                taints
+            }
+            Expression::Yield(r#yield) => {
+                match r#yield {
+                    Yield::Value(value) => {
+                        if let Some(value) = &value.value {
+                            self.expression(value, scope);
+                        }
+                    }
+                    Yield::Pair(pair) => {
+                        self.expression(&pair.key, scope);
+                        self.expression(&pair.value, scope);
+                    }
+                    Yield::From(from) => {
+                        self.expression(&from.iterator, scope);
+                    }
+                }
+
+                vec![]
+            }
+            Expression::Construct(construct) => {
+                match construct {
+                    Construct::Isset(isset) => {
+                        for value in isset.values.iter() {
+                            self.expression(value, scope);
+                        }
+                    }
+                    Construct::Empty(empty) => {
+                        self.expression(&empty.value, scope);
+                    }
+                    Construct::Eval(eval) => {
+                        self.expression(&eval.value, scope);
+                    }
+                    Construct::Include(include) => self.include("include", &include.value, scope),
+                    Construct::IncludeOnce(include) => self.include("include_once", &include.value, scope),
+                    Construct::Require(require) => self.include("require", &require.value, scope),
+                    Construct::RequireOnce(require) => self.include("require_once", &require.value, scope),
+                    Construct::Print(print) => {
+                        let taints = self.expression(&print.value, scope);
+
+                        self.sink(SinkKind::Html, "print", &taints, print.value.span(), scope);
+                    }
+                    Construct::Exit(exit) => self.exit("exit", exit.arguments.as_ref(), scope),
+                    Construct::Die(die) => self.exit("die", die.arguments.as_ref(), scope),
+                }
+
+                vec![]
+            }
+            Expression::Throw(throw) => {
+                self.expression(&throw.exception, scope);
+
+                vec![]
+            }
+            Expression::Clone(clone) => self.expression(&clone.object, scope),
+            Expression::Call(call) => {
+                let taints = self.call(call, scope);
+
+                if !taints.is_empty() && self.is_scalar(expression) {
+                    return vec![];
+                }
+
+                taints
+            }
+            Expression::Access(access) => match access {
+                Access::Property(property_access) => {
+                    self.property(&property_access.object, &property_access.property, scope)
+                }
+                Access::NullSafeProperty(property_access) => {
+                    self.property(&property_access.object, &property_access.property, scope)
+                }
+                Access::StaticProperty(property_access) => {
+                    self.expression(&property_access.class, scope);
+
+                    vec![]
+                }
+                Access::ClassConstant(_) => vec![],
+            },
+            Expression::Instantiation(instantiation) => {
+                let class = self.get_instantiated_class(&instantiation.class, scope);
+                let arguments = match &instantiation.arguments {
+                    Some(arguments) => self.arguments(arguments, scope),
+                    None => vec![],
+                };
+
+                if let Some(class) = class {
+                    let callee = self.get_method_callee(class, "__construct");
+
+                    self.invoke(&callee, &arguments, instantiation.span(), Fallback::Receiver(vec![]), scope);
+                }
+
+                vec![]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn array_elements(&mut self, elements: &[ArrayElement], scope: &mut Scope) -> Taints {
+        let mut taints = vec![];
+        for element in elements {
+            match element {
+                ArrayElement::KeyValue(element) => {
+                    join(&mut taints, self.expression(&element.key, scope));
+                    join(&mut taints, self.expression(&element.value, scope));
+                }
+                ArrayElement::Value(element) => join(&mut taints, self.expression(&element.value, scope)),
+                ArrayElement::Variadic(element) => join(&mut taints, self.expression(&element.value, scope)),
+                ArrayElement::Missing(_) => {}
+            }
+        }
+
+        taints
+    }
+
+    fn variable(&mut self, variable: &Variable, scope: &mut Scope) -> Taints {
+        match variable {
+            Variable::Direct(variable) => {
+                let name = self.interner.lookup(&variable.name);
+                if SUPERGLOBAL_SOURCES.contains(&name) || name == "$_SERVER" {
+                    return vec![self.source_taint(name.to_string(), variable.span)];
+                }
+
+                scope.state.variables.get(&variable.name).cloned().unwrap_or_default()
+            }
+            Variable::Indirect(variable) => {
+                self.expression(&variable.expression, scope);
+
+                vec![]
+            }
+            Variable::Nested(variable) => {
+                self.variable(&variable.variable, scope);
+
+                vec![]
+            }
+        }
+    }
+
+    /// Returns the taint of a `$_SERVER` entry, only the entries controlled by the client are tainted.
+    fn server_variable(&self, variable: &DirectVariable, index: &Expression) -> Taints {
+        let Expression::Literal(Literal::String(key)) = index else {
+            return vec![self.source_taint("$_SERVER".to_string(), variable.span)];
+        };
+
+        let key = self.interner.lookup(&key.value).trim_matches(['"', '\'']);
+        if key.starts_with(SERVER_HEADER_KEY_PREFIX) || SERVER_SOURCE_KEYS.contains(&key) {
+            vec![self.source_taint("$_SERVER".to_string(), variable.span)]
+        } else {
+            vec![]
+        }
+    }
+
+    fn property(&mut self, object: &Expression, selector: &ClassLikeMemberSelector, scope: &mut Scope) -> Taints {
+        if let (Some(property), true) = (self.get_selected_name(selector), self.is_this(object)) {
+            return scope.state.properties.get(&property).cloned().unwrap_or_default();
+        }
+
+        if let ClassLikeMemberSelector::Expression(selector) = selector {
+            self.expression(&selector.expression, scope);
+        }
+
+        self.expression(object, scope)
+    }
+
+    fn include(&mut self, construct: &str, value: &Expression, scope: &mut Scope) {
+        let taints = self.expression(value, scope);
+
+        self.sink(SinkKind::Include, construct, &taints, value.span(), scope);
+    }
+
+    fn exit(&mut self, construct: &str, arguments: Option<&ArgumentList>, scope: &mut Scope) {
+        let Some(arguments) = arguments else {
+            return;
+        };
+
+        for argument in self.arguments(arguments, scope) {
+            self.sink(SinkKind::Html, construct, &argument.taints, argument.span, scope);
+        }
+    }
+
+    /// Assigns the given taints to the target of an assignment.
+    ///
+    /// Assigning to a variable, or to a property of `$this`, replaces its taints, while assigning
+    /// to an element of an array adds to the taints of the array.
+    fn assign(&mut self, target: &Expression, taints: Taints, class: Option<StringIdentifier>, scope: &mut Scope) {
+        match target {
+            Expression::Variable(Variable::Direct(variable)) => {
+                let message = format!("Assigned to `{}` here.", self.interner.lookup(&variable.name));
+
+                scope.state.variables.insert(variable.name, with_step(taints, variable.span, &message));
+                match class {
+                    Some(class) => scope.state.types.insert(variable.name, class),
+                    None => scope.state.types.remove(&variable.name),
+                };
+            }
+            Expression::ArrayAccess(array_access) => {
+                self.expression(&array_access.index, scope);
+
+                if let Some(variable) = get_root_variable(&array_access.array) {
+                    let message = format!("Added to `{}` here.", self.interner.lookup(&variable.name));
+
+                    join(
+                        scope.state.variables.entry(variable.name).or_default(),
+                        with_step(taints, variable.span, &message),
+                    );
+                }
+            }
+            Expression::ArrayAppend(array_append) => {
+                if let Some(variable) = get_root_variable(&array_append.array) {
+                    let message = format!("Added to `{}` here.", self.interner.lookup(&variable.name));
+
+                    join(
+                        scope.state.variables.entry(variable.name).or_default(),
+                        with_step(taints, variable.span, &message),
+                    );
+                }
+            }
+            Expression::Access(Access::Property(property_access)) if self.is_this(&property_access.object) => {
+                if let Some(property) = self.get_selected_name(&property_access.property) {
+                    let message = format!("Assigned to `$this->{}` here.", self.interner.lookup(&property));
+
+                    scope
+                        .state
+                        .properties
+                        .insert(property, with_step(taints, property_access.property.span(), &message));
+                }
+            }
+            Expression::List(list) => self.destructure(list.elements.as_slice(), taints, scope),
+            Expression::Array(array) => self.destructure(array.elements.as_slice(), taints, scope),
+            Expression::LegacyArray(array) => self.destructure(array.elements.as_slice(), taints, scope),
+            _ => {}
+        }
+    }
+
+    fn destructure(&mut self, elements: &[ArrayElement], taints: Taints, scope: &mut Scope) {
+        for element in elements {
+            match element {
+                ArrayElement::KeyValue(element) => self.assign(&element.value, taints.clone(), None, scope),
+                ArrayElement::Value(element) => self.assign(&element.value, taints.clone(), None, scope),
+                _ => {}
+            }
+        }
+    }
+
+    fn call(&mut self, call: &Call, scope: &mut Scope) -> Taints {
+        match call {
+            Call::Function(function_call) => {
+                let Expression::Identifier(identifier) = function_call.function.as_ref() else {
+                    self.expression(&function_call.function, scope);
+                    self.arguments(&function_call.argument_list, scope);
+
+                    return vec![];
+                };
+
+                let name = self.resolve_function_name(identifier);
+                let callee = Callee {
+                    display: format!("{}()", name),
+                    keys: vec![normalize(&name)],
+                    reflection: self.codebase.get_function(self.interner, &self.interner.intern(&name)),
+                };
+
+                let arguments = self.arguments(&function_call.argument_list, scope);
+
+                self.invoke(&callee, &arguments, function_call.span(), Fallback::Arguments, scope)
+            }
+            Call::Method(method_call) => self.method_call(
+                &method_call.object,
+                &method_call.method,
+                &method_call.argument_list,
+                call.span(),
+                scope,
+            ),
+            Call::NullSafeMethod(method_call) => self.method_call(
+                &method_call.object,
+                &method_call.method,
+                &method_call.argument_list,
+                call.span(),
+                scope,
+            ),
+            Call::StaticMethod(static_method_call) => {
+                let class = self.get_referenced_class(&static_method_call.class, scope);
+                let arguments = self.arguments(&static_method_call.argument_list, scope);
+
+                match (class, self.get_selected_name(&static_method_call.method)) {
+                    (Some(class), Some(method)) => {
+                        let callee = self.get_method_callee(class, self.interner.lookup(&method));
+
+                        self.invoke(&callee, &arguments, call.span(), Fallback::Receiver(vec![]), scope)
+                    }
+                    _ => vec![],
+                }
+            }
+        }
+    }
+
+    fn method_call(
+        &mut self,
+        object: &Expression,
+        selector: &ClassLikeMemberSelector,
+        argument_list: &ArgumentList,
+        span: Span,
+        scope: &mut Scope,
+    ) -> Taints {
+        let class = self.get_class(object, scope);
+        let receiver = self.expression(object, scope);
+        let arguments = self.arguments(argument_list, scope);
+
+        match (class, self.get_selected_name(selector)) {
+            (Some(class), Some(method)) => {
+                let callee = self.get_method_callee(class, self.interner.lookup(&method));
+
+                self.invoke(&callee, &arguments, span, Fallback::Receiver(receiver), scope)
+            }
+            _ => receiver,
+        }
+    }
+
+    fn arguments(&mut self, argument_list: &ArgumentList, scope: &mut Scope) -> Vec<Arg> {
+        let mut arguments = vec![];
+        for (position, argument) in argument_list.arguments.iter().enumerate() {
+            let (name, spread, value) = match argument {
+                Argument::Positional(argument) => (None, argument.ellipsis.is_some(), &argument.value),
+                Argument::Named(argument) => (
+                    Some(self.interner.lookup(&argument.name.value).to_string()),
+                    argument.ellipsis.is_some(),
+                    &argument.value,
+                ),
+            };
+
+            let taints = self.expression(value, scope);
+
+            arguments.push(Arg { position, name, spread, span: value.span(), taints });
+        }
+
+        arguments
+    }
+
+    /// Applies a call to the given function-like, reporting tainted arguments reaching its sinks,
+    /// and returning the taints of its result.
+    fn invoke(
+        &mut self,
+        callee: &Callee<'_>,
+        arguments: &[Arg],
+        span: Span,
+        fallback: Fallback,
+        scope: &mut Scope,
+    ) -> Taints {
+        let specification = self.specification;
+        let database = self.database;
+
+        for key in &callee.keys {
+            for sink in specification.get_sinks(key) {
+                for argument in arguments {
+                    let dangerous = sink.parameters.is_empty()
+                        || sink
+                            .parameters
+                            .iter()
+                            .any(|parameter| self.is_argument_for(argument, *parameter, callee, None));
+
+                    if dangerous {
+                        self.sink(sink.kind, &callee.display, &argument.taints, argument.span, scope);
+                    }
+                }
+            }
+        }
+
+        let mut result = vec![];
+        let mut is_known = false;
+
+        if callee.keys.iter().any(|key| specification.is_source(key)) {
+            result.push(self.source_taint(callee.display.clone(), span));
+            is_known = true;
+        }
+
+        if let Some(sanitized) = callee.keys.iter().find_map(|key| specification.get_sanitized_kinds(key)) {
+            if let Some(argument) = arguments.iter().find(|argument| argument.position == 0 && argument.name.is_none())
+            {
+                join(
+                    &mut result,
+                    argument
+                        .taints
+                        .iter()
+                        .cloned()
+                        .map(|taint| Taint { kinds: taint.kinds.difference(sanitized), ..taint }),
+                );
+            }
+
+            is_known = true;
+        }
+
+        if let Some(summary) = callee.keys.iter().find_map(|key| database.get(key)) {
+            self.apply_summary(callee, summary, arguments, span, &mut result, scope);
+
+            is_known = true;
+        }
+
+        if !is_known {
+            match fallback {
+                Fallback::Arguments => {
+                    for argument in arguments {
+                        join(&mut result, argument.taints.iter().cloned());
+                    }
+                }
+                Fallback::Receiver(receiver) => join(&mut result, receiver),
+            }
+        }
+
+        result
+    }
+
+    fn apply_summary(
+        &mut self,
+        callee: &Callee<'_>,
+        summary: &FunctionSummary,
+        arguments: &[Arg],
+        span: Span,
+        result: &mut Taints,
+        scope: &mut Scope,
+    ) {
+        for parameter_sink in &summary.sinks {
+            if !self.kinds.contains(parameter_sink.kind) {
+                continue;
+            }
+
+            for argument in arguments {
+                if !self.is_argument_for(argument, parameter_sink.parameter, callee, Some(summary)) {
+                    continue;
+                }
+
+                for taint in &argument.taints {
+                    if !taint.kinds.contains(parameter_sink.kind) {
+                        continue;
+                    }
+
+                    match &taint.origin {
+                        Origin::Source { .. } => self.report(
+                            parameter_sink.kind,
+                            &parameter_sink.sink,
+                            taint,
+                            argument.span,
+                            Some((&callee.display, &parameter_sink.path)),
+                        ),
+                        Origin::Parameter(parameter) => {
+                            let taint = taint
+                                .clone()
+                                .with_step(argument.span, format!("Passed to `{}` here.", callee.display))
+                                .with_steps(&parameter_sink.path);
+
+                            add_parameter_sink(
+                                &mut scope.sinks,
+                                ParameterSink {
+                                    parameter: *parameter,
+                                    kind: parameter_sink.kind,
+                                    sink: parameter_sink.sink.clone(),
+                                    path: taint.path,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let returned = format!("Returned from `{}` here.", callee.display);
+        for returned_taint in &summary.returns {
+            match &returned_taint.origin {
+                Origin::Source { .. } => {
+                    let kinds = returned_taint.kinds.intersection(self.kinds);
+
+                    join(result, [Taint { kinds, ..returned_taint.clone() }.with_step(span, &returned)]);
+                }
+                Origin::Parameter(parameter) => {
+                    for argument in arguments {
+                        if !self.is_argument_for(argument, *parameter, callee, Some(summary)) {
+                            continue;
+                        }
+
+                        for taint in &argument.taints {
+                            let taint =
+                                Taint { kinds: taint.kinds.intersection(returned_taint.kinds), ..taint.clone() }
+                                    .with_steps(&returned_taint.path)
+                                    .with_step(span, &returned);
+
+                            join(result, [taint]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks the given taints reaching a sink, reporting the ones originating from a source, and
+    /// recording the ones originating from a parameter in the summary of the current function-like.
+    fn sink(&mut self, kind: SinkKind, sink: &str, taints: &Taints, span: Span, scope: &mut Scope) {
+        if !self.kinds.contains(kind) {
+            return;
+        }
+
+        for taint in taints {
+            if !taint.kinds.contains(kind) {
+                continue;
+            }
+
+            match &taint.origin {
+                Origin::Source { .. } => self.report(kind, sink, taint, span, None),
+                Origin::Parameter(parameter) => {
+                    let taint = taint.clone().with_step(span, format!("Reaches `{}` here.", sink));
+
+                    add_parameter_sink(
+                        &mut scope.sinks,
+                        ParameterSink { parameter: *parameter, kind, sink: sink.to_string(), path: taint.path },
+                    );
+                }
+            }
+        }
+    }
+
+    fn report(&mut self, kind: SinkKind, sink: &str, taint: &Taint, span: Span, through: Option<(&str, &[Step])>) {
+        let Origin::Source { name, span: source_span } = &taint.origin else {
+            return;
+        };
+
+        if !self.reported.insert((kind, span, *source_span)) {
+            return;
+        }
+
+        let primary = match through {
+            Some((callee, _)) => {
+                Annotation::primary(span).with_message(format!("Tainted data is passed to `{}` here.", callee))
+            }
+            None => Annotation::primary(span).with_message(format!("Tainted data reaches `{}` here.", sink)),
+        };
+
+        let mut annotations = vec![primary];
+        let mut spans = vec![span];
+        let steps = std::iter::once(Step {
+            span: *source_span,
+            message: format!("Tainted data originates from `{}` here.", name),
+        })
+        .chain(taint.path.iter().cloned())
+        .chain(through.map(|(_, path)| path.to_vec()).unwrap_or_default());
+
+        for step in steps {
+            if spans.contains(&step.span) {
+                continue;
+            }
+
+            spans.push(step.span);
+            annotations.push(Annotation::secondary(step.span).with_message(step.message));
+        }
+
+        self.findings.push(Finding {
+            kind,
+            message: format!("Tainted data from `{}` is used in {} by `{}`.", name, kind.get_description(), sink),
+            annotations,
+        });
+    }
+
+    fn source_taint(&self, name: String, span: Span) -> Taint {
+        Taint::new(Origin::Source { name, span }, self.kinds)
+    }
+
+    fn is_argument_for(
+        &self,
+        argument: &Arg,
+        parameter: usize,
+        callee: &Callee<'_>,
+        summary: Option<&FunctionSummary>,
+    ) -> bool {
+        match &argument.name {
+            Some(name) => {
+                let position = summary.and_then(|summary| summary.get_parameter_position(name)).or_else(|| {
+                    callee.reflection.and_then(|reflection| {
+                        reflection
+                            .parameters
+                            .iter()
+                            .position(|candidate| self.interner.lookup(&candidate.name).trim_start_matches('$') == name)
+                    })
+                });
+
+                position == Some(parameter)
+            }
+            None => argument.position == parameter || (argument.spread && argument.position <= parameter),
+        }
+    }
+
+    /// Returns the callee of a method, matching the method in the class and all its ancestors.
+    fn get_method_callee(&self, class: StringIdentifier, method: &str) -> Callee<'a> {
+        let class_name = self.interner.lookup(&class);
+        let mut keys = vec![normalize(&format!("{}::{}", class_name, method))];
+        let mut reflection = None;
+
+        if let Some(class_like) = self.codebase.get_named_class_like(self.interner, &class) {
+            let inheritance = &class_like.inheritance;
+            let mut ancestors: Vec<_> = inheritance
+                .all_extended_classes
+                .iter()
+                .chain(inheritance.all_implemented_interfaces.iter())
+                .chain(inheritance.all_extended_interfaces.iter())
+                .map(|name| self.interner.lookup(&name.value))
+                .chain(class_like.used_traits.iter().map(|name| self.interner.lookup(name)))
+                .collect();
+
+            ancestors.sort_unstable();
+            for ancestor in ancestors {
+                keys.push(normalize(&format!("{}::{}", ancestor, method)));
+            }
+
+            reflection = class_like.get_method(&self.interner.intern(method));
+        }
+
+        Callee { display: format!("{}::{}()", get_short_name(class_name), method), keys, reflection }
+    }
+
+    /// Resolves the class of an object, using the known types of variables, property and return types,
+    /// and the type inferred by the typing crate.
+    fn get_class(&self, expression: &Expression, scope: &Scope) -> Option<StringIdentifier> {
+        match expression {
+            Expression::Parenthesized(parenthesized) => self.get_class(&parenthesized.expression, scope),
+            Expression::Variable(Variable::Direct(variable)) => {
+                if self.interner.lookup(&variable.name) == "$this" {
+                    return scope.class;
+                }
+
+                scope.state.types.get(&variable.name).copied()
+            }
+            Expression::Instantiation(instantiation) => self.get_instantiated_class(&instantiation.class, scope),
+            Expression::Access(Access::Property(property_access)) => {
+                let class = self.get_class(&property_access.object, scope)?;
+                let property = self.get_selected_name(&property_access.property)?;
+                let class_like = self.codebase.get_named_class_like(self.interner, &class)?;
+                let property = self.interner.intern(format!("${}", self.interner.lookup(&property)));
+                if let Some(property) = class_like.get_property(&property) {
+                    return get_named_object(&property.type_reflection.as_ref()?.kind);
+                }
+
+                // Promoted properties are only reflected as parameters of the constructor.
+                let constructor = class_like.get_method(&self.interner.intern("__construct"))?;
+                let parameter = constructor
+                    .parameters
+                    .iter()
+                    .find(|parameter| parameter.is_promoted_property && parameter.name == property)?;
+
+                get_named_object(&parameter.type_reflection.as_ref()?.kind)
+            }
+            Expression::Call(Call::Method(method_call)) => {
+                let class = self.get_class(&method_call.object, scope)?;
+                let method = self.get_selected_name(&method_call.method)?;
+                let class_like = self.codebase.get_named_class_like(self.interner, &class)?;
+                let return_type = class_like.get_method(&method)?.return_type_reflection.as_ref()?;
+
+                get_named_object(&return_type.type_reflection.kind)
+            }
+            _ => get_named_object(&mago_typing::resolve_kind(
+                self.interner,
+                self.source,
+                self.names,
+                self.codebase,
+                expression,
+            )),
+        }
+    }
+
+    fn get_instantiated_class(&self, class: &Expression, scope: &Scope) -> Option<StringIdentifier> {
+        match class {
+            Expression::Identifier(identifier) => Some(*self.names.get(identifier)),
+            Expression::Self_(_) | Expression::Static(_) => scope.class,
+            Expression::Parent(_) => self.get_parent_class(scope),
+            _ => None,
+        }
+    }
+
+    fn get_referenced_class(&self, class: &Expression, scope: &Scope) -> Option<StringIdentifier> {
+        match class {
+            Expression::Identifier(_) | Expression::Self_(_) | Expression::Static(_) | Expression::Parent(_) => {
+                self.get_instantiated_class(class, scope)
+            }
+            _ => self.get_class(class, scope),
+        }
+    }
+
+    fn get_parent_class(&self, scope: &Scope) -> Option<StringIdentifier> {
+        let class_like = self.codebase.get_named_class_like(self.interner, &scope.class?)?;
+
+        class_like.inheritance.direct_extended_class.map(|name| name.value)
+    }
+
+    fn get_hinted_class(&self, hint: &Hint) -> Option<StringIdentifier> {
+        match hint {
+            Hint::Identifier(identifier) => Some(*self.names.get(identifier)),
+            Hint::Nullable(nullable) => self.get_hinted_class(&nullable.hint),
+            Hint::Parenthesized(parenthesized) => self.get_hinted_class(&parenthesized.hint),
+            _ => None,
+        }
+    }
+
+    fn get_selected_name(&self, selector: &ClassLikeMemberSelector) -> Option<StringIdentifier> {
+        match selector {
+            ClassLikeMemberSelector::Identifier(identifier) => Some(identifier.value),
+            _ => None,
+        }
+    }
+
+    fn is_this(&self, expression: &Expression) -> bool {
+        matches!(expression, Expression::Variable(Variable::Direct(variable)) if self.interner.lookup(&variable.name) == "$this")
+    }
+
+    /// Returns whether the given expression is known to be an integer, a float, or a boolean, which are safe
+    /// to use in any sink.
+    fn is_scalar(&self, expression: &Expression) -> bool {
+        let kind = mago_typing::resolve_kind(self.interner, self.source, self.names, self.codebase, expression);
+
+        kind.is_integer().is_true() || kind.is_float().is_true() || kind.is_bool().is_true()
+    }
+
+    /// Resolves the name of a called function, following PHP's fallback to the global namespace.
+    fn resolve_function_name(&self, identifier: &Identifier) -> String {
+        if self.names.is_imported(identifier) {
+            return self.interner.lookup(self.names.get(identifier)).to_string();
+        }
+
+        let name = self.interner.lookup(&identifier.value());
+        if let Some(stripped) = name.strip_prefix('\\') {
+            return stripped.to_string();
+        }
+
+        let qualified_name_id = self.names.get(identifier);
+        let qualified_name = self.interner.lookup(qualified_name_id);
+        if self.codebase.function_exists(self.interner, qualified_name_id)
+            || self.database.contains(&normalize(qualified_name))
+            || self.specification.contains(&normalize(qualified_name))
+        {
+            return qualified_name.to_string();
+        }
+
+        if !name.contains('\\') {
+            return name.to_string();
+        }
+
+        qualified_name.to_string()
+    }
+}
+
+fn join_state(state: &mut State, other: State) {
+    for (name, taints) in other.variables {
+        join(state.variables.entry(name).or_default(), taints);
+    }
+
+    for (name, taints) in other.properties {
+        join(state.properties.entry(name).or_default(), taints);
+    }
+
+    for (name, class) in other.types {
+        state.types.entry(name).or_insert(class);
+    }
+}
+
+fn add_parameter_sink(sinks: &mut Vec<ParameterSink>, sink: ParameterSink) {
+    let exists = sinks.iter().any(|existing| {
+        existing.parameter == sink.parameter && existing.kind == sink.kind && existing.sink == sink.sink
+    });
+
+    if !exists {
+        sinks.push(sink);
+    }
+}
+
+fn get_root_variable(expression: &Expression) -> Option<&DirectVariable> {
+    match expression {
+        Expression::Variable(Variable::Direct(variable)) => Some(variable),
+        Expression::ArrayAccess(array_access) => get_root_variable(&array_access.array),
+        Expression::ArrayAppend(array_append) => get_root_variable(&array_append.array),
+        _ => None,
+    }
+}
+
+fn get_named_object(kind: &TypeKind) -> Option<StringIdentifier> {
+    match kind {
+        TypeKind::Object(ObjectTypeKind::NamedObject { name, .. }) => Some(*name),
+        TypeKind::Union { kinds } => kinds.iter().find_map(get_named_object),
+        _ => None,
+    }
+}
+
+fn get_short_name(name: &str) -> &str {
+    name.rsplit('\\').next().unwrap_or(name)
+}
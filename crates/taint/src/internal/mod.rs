@@ -0,0 +1,3 @@
+pub(crate) mod evaluator;
+pub(crate) mod specification;
+pub(crate) mod taint;
@@ -0,0 +1,236 @@
+use ahash::HashMap;
+use ahash::HashSet;
+
+use crate::configuration::SinkKind;
+use crate::configuration::TaintConfiguration;
+use crate::internal::taint::Kinds;
+
+/// Superglobals holding user input.
+pub(crate) const SUPERGLOBAL_SOURCES: [&str; 5] = ["$_GET", "$_POST", "$_REQUEST", "$_COOKIE", "$_FILES"];
+
+/// Keys of `$_SERVER` holding values controlled by the client.
+pub(crate) const SERVER_SOURCE_KEYS: [&str; 5] =
+    ["REQUEST_URI", "QUERY_STRING", "PATH_INFO", "PHP_SELF", "PATH_TRANSLATED"];
+
+/// The prefix of the `$_SERVER` keys holding the request headers.
+pub(crate) const SERVER_HEADER_KEY_PREFIX: &str = "HTTP_";
+
+const SOURCES: [&str; 38] = [
+    "filter_input",
+    "filter_input_array",
+    "getallheaders",
+    "apache_request_headers",
+    "Symfony\\Component\\HttpFoundation\\Request::get",
+    "Symfony\\Component\\HttpFoundation\\Request::getContent",
+    "Symfony\\Component\\HttpFoundation\\Request::getPayload",
+    "Symfony\\Component\\HttpFoundation\\Request::getRequestUri",
+    "Symfony\\Component\\HttpFoundation\\Request::getUri",
+    "Symfony\\Component\\HttpFoundation\\Request::getPathInfo",
+    "Symfony\\Component\\HttpFoundation\\Request::getQueryString",
+    "Symfony\\Component\\HttpFoundation\\ParameterBag::get",
+    "Symfony\\Component\\HttpFoundation\\ParameterBag::all",
+    "Symfony\\Component\\HttpFoundation\\ParameterBag::getString",
+    "Symfony\\Component\\HttpFoundation\\HeaderBag::get",
+    "Symfony\\Component\\HttpFoundation\\HeaderBag::all",
+    "Illuminate\\Http\\Request::input",
+    "Illuminate\\Http\\Request::query",
+    "Illuminate\\Http\\Request::post",
+    "Illuminate\\Http\\Request::all",
+    "Illuminate\\Http\\Request::only",
+    "Illuminate\\Http\\Request::except",
+    "Illuminate\\Http\\Request::header",
+    "Illuminate\\Http\\Request::cookie",
+    "Illuminate\\Http\\Request::string",
+    "Illuminate\\Http\\Request::str",
+    "Illuminate\\Http\\Request::get",
+    "Illuminate\\Http\\Request::json",
+    "Illuminate\\Http\\Request::validated",
+    "Illuminate\\Http\\Request::safe",
+    "Illuminate\\Http\\Request::fullUrl",
+    "Illuminate\\Http\\Request::path",
+    "Psr\\Http\\Message\\ServerRequestInterface::getQueryParams",
+    "Psr\\Http\\Message\\ServerRequestInterface::getParsedBody",
+    "Psr\\Http\\Message\\ServerRequestInterface::getCookieParams",
+    "Psr\\Http\\Message\\ServerRequestInterface::getUploadedFiles",
+    "Psr\\Http\\Message\\MessageInterface::getHeader",
+    "Psr\\Http\\Message\\MessageInterface::getHeaderLine",
+];
+
+const SINKS: [(&str, SinkKind, &[usize]); 69] = [
+    ("mysqli_query", SinkKind::Sql, &[1]),
+    ("mysqli_real_query", SinkKind::Sql, &[1]),
+    ("mysqli_multi_query", SinkKind::Sql, &[1]),
+    ("mysqli_prepare", SinkKind::Sql, &[1]),
+    ("mysqli_execute_query", SinkKind::Sql, &[1]),
+    ("pg_query", SinkKind::Sql, &[]),
+    ("pg_send_query", SinkKind::Sql, &[1]),
+    ("pg_prepare", SinkKind::Sql, &[2]),
+    ("mysqli::query", SinkKind::Sql, &[0]),
+    ("mysqli::real_query", SinkKind::Sql, &[0]),
+    ("mysqli::multi_query", SinkKind::Sql, &[0]),
+    ("mysqli::prepare", SinkKind::Sql, &[0]),
+    ("mysqli::execute_query", SinkKind::Sql, &[0]),
+    ("PDO::query", SinkKind::Sql, &[0]),
+    ("PDO::exec", SinkKind::Sql, &[0]),
+    ("PDO::prepare", SinkKind::Sql, &[0]),
+    ("SQLite3::query", SinkKind::Sql, &[0]),
+    ("SQLite3::querySingle", SinkKind::Sql, &[0]),
+    ("SQLite3::exec", SinkKind::Sql, &[0]),
+    ("SQLite3::prepare", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::executeQuery", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::executeStatement", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::prepare", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::fetchAllAssociative", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::fetchAssociative", SinkKind::Sql, &[0]),
+    ("Doctrine\\DBAL\\Connection::fetchOne", SinkKind::Sql, &[0]),
+    ("Doctrine\\ORM\\EntityManagerInterface::createQuery", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::select", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::selectOne", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::insert", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::update", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::delete", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::statement", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::affectingStatement", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\ConnectionInterface::unprepared", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::select", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::selectOne", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::insert", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::update", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::delete", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::statement", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::unprepared", SinkKind::Sql, &[0]),
+    ("Illuminate\\Support\\Facades\\DB::raw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::whereRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::orWhereRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::selectRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::orderByRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::groupByRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::havingRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Query\\Builder::fromRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Eloquent\\Builder::whereRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Eloquent\\Builder::orWhereRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Eloquent\\Builder::selectRaw", SinkKind::Sql, &[0]),
+    ("Illuminate\\Database\\Eloquent\\Builder::orderByRaw", SinkKind::Sql, &[0]),
+    ("exec", SinkKind::Shell, &[0]),
+    ("system", SinkKind::Shell, &[0]),
+    ("passthru", SinkKind::Shell, &[0]),
+    ("shell_exec", SinkKind::Shell, &[0]),
+    ("popen", SinkKind::Shell, &[0]),
+    ("proc_open", SinkKind::Shell, &[0]),
+    ("pcntl_exec", SinkKind::Shell, &[0, 1]),
+    ("Symfony\\Component\\Process\\Process::fromShellCommandline", SinkKind::Shell, &[0]),
+    ("printf", SinkKind::Html, &[]),
+    ("vprintf", SinkKind::Html, &[]),
+    ("print_r", SinkKind::Html, &[0]),
+    ("unserialize", SinkKind::Unserialize, &[0]),
+    ("header", SinkKind::Header, &[0]),
+    ("mail", SinkKind::Header, &[3]),
+    ("mb_send_mail", SinkKind::Header, &[3]),
+];
+
+const SANITIZERS: [(&str, &[SinkKind]); 27] = [
+    ("intval", &[]),
+    ("floatval", &[]),
+    ("boolval", &[]),
+    ("htmlspecialchars", &[SinkKind::Html]),
+    ("htmlentities", &[SinkKind::Html]),
+    ("strip_tags", &[SinkKind::Html]),
+    ("urlencode", &[SinkKind::Html, SinkKind::Header]),
+    ("rawurlencode", &[SinkKind::Html, SinkKind::Header]),
+    ("http_build_query", &[SinkKind::Html, SinkKind::Header]),
+    ("escapeshellarg", &[SinkKind::Shell]),
+    ("escapeshellcmd", &[SinkKind::Shell]),
+    ("basename", &[SinkKind::Include]),
+    ("addslashes", &[SinkKind::Sql]),
+    ("mysqli_real_escape_string", &[SinkKind::Sql]),
+    ("mysqli_escape_string", &[SinkKind::Sql]),
+    ("mysqli::real_escape_string", &[SinkKind::Sql]),
+    ("mysqli::escape_string", &[SinkKind::Sql]),
+    ("pg_escape_string", &[SinkKind::Sql]),
+    ("pg_escape_literal", &[SinkKind::Sql]),
+    ("pg_escape_identifier", &[SinkKind::Sql]),
+    ("PDO::quote", &[SinkKind::Sql]),
+    ("SQLite3::escapeString", &[SinkKind::Sql]),
+    ("Doctrine\\DBAL\\Connection::quote", &[SinkKind::Sql]),
+    ("Doctrine\\DBAL\\Connection::quoteIdentifier", &[SinkKind::Sql]),
+    ("Symfony\\Component\\HttpFoundation\\ParameterBag::getInt", &[]),
+    ("Symfony\\Component\\HttpFoundation\\ParameterBag::getBoolean", &[]),
+    ("Symfony\\Component\\HttpFoundation\\ParameterBag::getAlnum", &[]),
+];
+
+/// A sink, as a kind and the dangerous parameters, all parameters are dangerous when empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Sink {
+    pub kind: SinkKind,
+    pub parameters: Vec<usize>,
+}
+
+/// The sources, sinks, and sanitizers, keyed by their normalized name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Specification {
+    sources: HashSet<String>,
+    sinks: HashMap<String, Vec<Sink>>,
+    sanitizers: HashMap<String, Kinds>,
+}
+
+impl Specification {
+    /// Creates the specification from the built-in sources, sinks, and sanitizers, and the given configuration.
+    pub fn new(configuration: &TaintConfiguration) -> Self {
+        let mut specification = Self::default();
+
+        for name in SOURCES.into_iter().chain(configuration.sources.iter().map(String::as_str)) {
+            specification.sources.insert(normalize(name));
+        }
+
+        for (name, kind, parameters) in SINKS {
+            specification.add_sink(name, kind, parameters.to_vec());
+        }
+
+        for sink in &configuration.sinks {
+            specification.add_sink(&sink.name, sink.kind, sink.parameters.clone());
+        }
+
+        for (name, kinds) in SANITIZERS {
+            specification.add_sanitizer(name, kinds);
+        }
+
+        for sanitizer in &configuration.sanitizers {
+            specification.add_sanitizer(&sanitizer.name, &sanitizer.kinds);
+        }
+
+        specification
+    }
+
+    pub fn is_source(&self, name: &str) -> bool {
+        self.sources.contains(name)
+    }
+
+    pub fn get_sinks(&self, name: &str) -> &[Sink] {
+        self.sinks.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn get_sanitized_kinds(&self, name: &str) -> Option<Kinds> {
+        self.sanitizers.get(name).copied()
+    }
+
+    /// Returns whether the function or method with the given name is a source, a sink, or a sanitizer.
+    pub fn contains(&self, name: &str) -> bool {
+        self.sources.contains(name) || self.sinks.contains_key(name) || self.sanitizers.contains_key(name)
+    }
+
+    fn add_sink(&mut self, name: &str, kind: SinkKind, parameters: Vec<usize>) {
+        self.sinks.entry(normalize(name)).or_default().push(Sink { kind, parameters });
+    }
+
+    fn add_sanitizer(&mut self, name: &str, kinds: &[SinkKind]) {
+        let kinds = if kinds.is_empty() { Kinds::all() } else { Kinds::of(kinds.iter().copied()) };
+
+        let existing = self.sanitizers.entry(normalize(name)).or_default();
+        *existing = existing.union(kinds);
+    }
+}
+
+/// Normalizes a function or method name, as names are case-insensitive, and may be fully qualified.
+pub(crate) fn normalize(name: &str) -> String {
+    name.trim_start_matches('\\').to_ascii_lowercase()
+}
@@ -0,0 +1,122 @@
+use strum::IntoEnumIterator;
+
+use mago_span::Span;
+
+use crate::configuration::SinkKind;
+
+/// The maximum number of distinct taints tracked for a single value.
+const MAXIMUM_TAINTS: usize = 8;
+
+/// The maximum number of steps recorded in the path of a taint.
+const MAXIMUM_STEPS: usize = 16;
+
+/// A set of sink kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) struct Kinds(u8);
+
+/// Where tainted data comes from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Origin {
+    /// A taint source, e.g. `$_GET` or `Request::input()`.
+    Source { name: String, span: Span },
+    /// A parameter of the function-like being analyzed, by position.
+    Parameter(usize),
+}
+
+/// A step in the path of tainted data, e.g. an assignment, or a call.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Step {
+    pub span: Span,
+    pub message: String,
+}
+
+/// Tainted data, with the sinks it is still dangerous for and the path it took.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Taint {
+    pub origin: Origin,
+    pub kinds: Kinds,
+    pub path: Vec<Step>,
+}
+
+/// The taints of a value, empty when the value is safe.
+pub(crate) type Taints = Vec<Taint>;
+
+impl Kinds {
+    pub fn of(kinds: impl IntoIterator<Item = SinkKind>) -> Self {
+        let mut result = Self::default();
+        for kind in kinds {
+            result.0 |= Self::bit(kind);
+        }
+
+        result
+    }
+
+    pub fn all() -> Self {
+        Self::of(SinkKind::iter())
+    }
+
+    pub fn contains(&self, kind: SinkKind) -> bool {
+        self.0 & Self::bit(kind) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: Kinds) -> Kinds {
+        Kinds(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: Kinds) -> Kinds {
+        Kinds(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: Kinds) -> Kinds {
+        Kinds(self.0 & !other.0)
+    }
+
+    fn bit(kind: SinkKind) -> u8 {
+        1 << (kind as u8)
+    }
+}
+
+impl Taint {
+    pub fn new(origin: Origin, kinds: Kinds) -> Self {
+        Self { origin, kinds, path: vec![] }
+    }
+
+    /// Returns this taint with the given step appended to its path.
+    pub fn with_step(mut self, span: Span, message: impl Into<String>) -> Self {
+        if self.path.len() < MAXIMUM_STEPS {
+            self.path.push(Step { span, message: message.into() });
+        }
+
+        self
+    }
+
+    /// Returns this taint with the given steps appended to its path.
+    pub fn with_steps(mut self, steps: &[Step]) -> Self {
+        let available = MAXIMUM_STEPS.saturating_sub(self.path.len());
+        self.path.extend(steps.iter().take(available).cloned());
+
+        self
+    }
+}
+
+/// Adds the given taints to the existing ones, skipping taints with the same origin and kinds.
+pub(crate) fn join(taints: &mut Taints, other: impl IntoIterator<Item = Taint>) {
+    for taint in other {
+        if taint.kinds.is_empty() || taints.len() >= MAXIMUM_TAINTS {
+            continue;
+        }
+
+        if !taints.iter().any(|existing| existing.origin == taint.origin && existing.kinds == taint.kinds) {
+            taints.push(taint);
+        }
+    }
+}
+
+/// Appends the given step to the path of all the given taints.
+pub(crate) fn with_step(taints: Taints, span: Span, message: &str) -> Taints {
+    taints.into_iter().map(|taint| taint.with_step(span, message)).collect()
+}
@@ -0,0 +1,122 @@
+//! Inter-procedural taint analysis for PHP.
+//!
+//! Tainted data comes from sources, such as superglobals or framework request accessors, and must not reach
+//! sinks, such as SQL queries or shell commands, without going through a sanitizer first.
+//!
+//! The analysis runs in two phases: [`TaintAnalyzer::summarize`] summarizes every user-defined function and
+//! method of the codebase, describing which parameters reach a sink and which flow into the returned value,
+//! then [`TaintAnalyzer::analyze`] follows tainted data through each file, using these summaries at call sites.
+//!
+//! Properties are only tracked within a single method, through `$this`; data stored in a property by one
+//! method and read by another is not considered tainted.
+
+use mago_ast::Program;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_reflection::CodebaseReflection;
+use mago_reporting::Annotation;
+use mago_source::Source;
+
+use crate::configuration::SinkKind;
+use crate::configuration::TaintConfiguration;
+use crate::database::TaintDatabase;
+use crate::internal::evaluator::Evaluator;
+use crate::internal::specification::Specification;
+use crate::internal::taint::Kinds;
+
+pub mod configuration;
+pub mod database;
+
+mod internal;
+
+/// The maximum number of times the summaries are recomputed, before giving up on reaching a fixpoint.
+const MAXIMUM_SUMMARY_ITERATIONS: usize = 5;
+
+/// Tainted data reaching a sink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// The kind of the sink reached.
+    pub kind: SinkKind,
+
+    /// A description of the flow, e.g. ``Tainted data from `$_GET` is used in an SQL query by `mysqli_query()`.``
+    pub message: String,
+
+    /// The primary annotation at the sink, followed by secondary annotations at the source, and along the flow.
+    pub annotations: Vec<Annotation>,
+}
+
+/// Tracks tainted data from sources to sinks.
+#[derive(Debug, Clone)]
+pub struct TaintAnalyzer {
+    specification: Specification,
+    database: TaintDatabase,
+}
+
+impl TaintAnalyzer {
+    /// Creates an analyzer using the built-in sources, sinks, and sanitizers, along with the configured ones.
+    pub fn new(configuration: &TaintConfiguration) -> Self {
+        Self { specification: Specification::new(configuration), database: TaintDatabase::new() }
+    }
+
+    /// Returns the summaries of the user-defined functions and methods.
+    pub fn database(&self) -> &TaintDatabase {
+        &self.database
+    }
+
+    /// Summarizes the functions and methods declared in the given programs.
+    ///
+    /// Summaries depend on the summaries of the functions they call, so they are recomputed until they
+    /// no longer change.
+    pub fn summarize(
+        &mut self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        programs: &[(&Source, &Program, &Names)],
+    ) {
+        for _ in 0..MAXIMUM_SUMMARY_ITERATIONS {
+            let mut database = TaintDatabase::new();
+            for (source, program, names) in programs {
+                let mut evaluator = Evaluator::new(
+                    interner,
+                    codebase,
+                    source,
+                    names,
+                    &self.specification,
+                    &self.database,
+                    Kinds::all(),
+                );
+
+                evaluator.program(program);
+                database.extend(evaluator.summaries);
+            }
+
+            if database == self.database {
+                break;
+            }
+
+            self.database = database;
+        }
+    }
+
+    /// Returns the tainted data reaching sinks of the given kinds in the given program.
+    pub fn analyze(
+        &self,
+        interner: &ThreadedInterner,
+        codebase: &CodebaseReflection,
+        source: &Source,
+        program: &Program,
+        names: &Names,
+        kinds: &[SinkKind],
+    ) -> Vec<Finding> {
+        let kinds = Kinds::of(kinds.iter().copied());
+        if kinds.is_empty() {
+            return vec![];
+        }
+
+        let mut evaluator =
+            Evaluator::new(interner, codebase, source, names, &self.specification, &self.database, kinds);
+
+        evaluator.program(program);
+        evaluator.findings
+    }
+}
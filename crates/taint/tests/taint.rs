@@ -0,0 +1,273 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_reflection::CodebaseReflection;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+use mago_taint::configuration::SanitizerDefinition;
+use mago_taint::configuration::SinkDefinition;
+use mago_taint::configuration::SinkKind;
+use mago_taint::configuration::TaintConfiguration;
+use mago_taint::Finding;
+use mago_taint::TaintAnalyzer;
+
+const ALL_KINDS: [SinkKind; 6] =
+    [SinkKind::Sql, SinkKind::Shell, SinkKind::Include, SinkKind::Html, SinkKind::Unserialize, SinkKind::Header];
+
+fn analyze_with(configuration: &TaintConfiguration, files: &[&str]) -> Vec<Finding> {
+    let interner = ThreadedInterner::new();
+    let manager = SourceManager::new(interner.clone());
+
+    let mut semantics = vec![];
+    let mut codebase = CodebaseReflection::new();
+    for (i, code) in files.iter().enumerate() {
+        let source_id = manager.insert_content(format!("{}.php", i), code.to_string(), SourceCategory::UserDefined);
+        let source = manager.load(&source_id).unwrap();
+        let (program, error) = parse_source(&interner, &source);
+        assert!(error.is_none(), "failed to parse source {}: {:?}", i, error);
+
+        let names = Names::resolve(&interner, &program);
+        let reflection = mago_reflector::reflect(&interner, &source, &program, &names);
+        codebase = mago_reflector::merge(&interner, codebase, reflection);
+
+        semantics.push((source, program, names));
+    }
+
+    mago_reflector::populate(&interner, &mut codebase);
+
+    let mut analyzer = TaintAnalyzer::new(configuration);
+    let programs: Vec<_> = semantics.iter().map(|(source, program, names)| (source, program, names)).collect();
+    analyzer.summarize(&interner, &codebase, &programs);
+
+    let mut findings = vec![];
+    for (source, program, names) in &semantics {
+        findings.extend(analyzer.analyze(&interner, &codebase, source, program, names, &ALL_KINDS));
+    }
+
+    findings
+}
+
+fn analyze(files: &[&str]) -> Vec<Finding> {
+    analyze_with(&TaintConfiguration::default(), files)
+}
+
+fn messages(findings: &[Finding]) -> Vec<&str> {
+    findings.iter().map(|finding| finding.message.as_str()).collect()
+}
+
+fn annotation_messages(finding: &Finding) -> Vec<&str> {
+    finding.annotations.iter().filter_map(|annotation| annotation.message.as_deref()).collect()
+}
+
+#[test]
+fn test_direct_flows() {
+    let findings = analyze(&[indoc! {r#"
+        <?php
+
+        $id = $_GET['id'];
+        $query = "SELECT * FROM users WHERE id = " . $id;
+        mysqli_query($connection, $query);
+
+        system('ls ' . $_POST['directory']);
+        include $_REQUEST['page'] . '.php';
+        echo "Hello, {$_COOKIE['name']}!";
+        unserialize($_COOKIE['session']);
+        header('Location: ' . $_SERVER['HTTP_REFERER']);
+
+        echo $_SERVER['SERVER_NAME'];
+    "#}]);
+
+    assert_eq!(
+        messages(&findings),
+        vec![
+            "Tainted data from `$_GET` is used in an SQL query by `mysqli_query()`.",
+            "Tainted data from `$_POST` is used in a shell command by `system()`.",
+            "Tainted data from `$_REQUEST` is used in a file inclusion by `include`.",
+            "Tainted data from `$_COOKIE` is used in HTML output by `echo`.",
+            "Tainted data from `$_COOKIE` is used in an unserialization by `unserialize()`.",
+            "Tainted data from `$_SERVER` is used in an HTTP header by `header()`.",
+        ]
+    );
+
+    assert_eq!(
+        annotation_messages(&findings[0]),
+        vec![
+            "Tainted data reaches `mysqli_query()` here.",
+            "Tainted data originates from `$_GET` here.",
+            "Assigned to `$id` here.",
+            "Assigned to `$query` here.",
+        ]
+    );
+}
+
+#[test]
+fn test_sanitizers() {
+    let findings = analyze(&[indoc! {r#"
+        <?php
+
+        echo htmlspecialchars($_GET['name']);
+        echo (int) $_GET['page'];
+        echo count($_GET) + 1;
+        system('ls ' . escapeshellarg($_GET['directory']));
+
+        $name = htmlspecialchars($_GET['name']);
+        mysqli_query($connection, "SELECT * FROM users WHERE name = '$name'");
+    "#}]);
+
+    assert_eq!(messages(&findings), vec!["Tainted data from `$_GET` is used in an SQL query by `mysqli_query()`."]);
+}
+
+#[test]
+fn test_branches_and_reassignments() {
+    let findings = analyze(&[indoc! {r#"
+        <?php
+
+        $name = $_GET['name'];
+        $name = 'guest';
+        echo $name;
+
+        $page = 'home';
+        if ($condition) {
+            $page = $_GET['page'];
+        }
+
+        include $page . '.php';
+    "#}]);
+
+    assert_eq!(messages(&findings), vec!["Tainted data from `$_GET` is used in a file inclusion by `include`."]);
+}
+
+#[test]
+fn test_inter_procedural_flows() {
+    let findings = analyze(&[
+        indoc! {r#"
+            <?php
+
+            namespace App;
+
+            function read_input(string $key): string
+            {
+                return $_GET[$key];
+            }
+
+            function run(string $command): void
+            {
+                $output = shell_exec($command);
+            }
+
+            function wrap(string $value): string
+            {
+                return '<b>' . $value . '</b>';
+            }
+        "#},
+        indoc! {r#"
+            <?php
+
+            use function App\read_input;
+            use function App\run;
+            use function App\wrap;
+
+            run('ls ' . read_input('directory'));
+            echo wrap(read_input('name'));
+            echo wrap('static');
+        "#},
+    ]);
+
+    assert_eq!(
+        messages(&findings),
+        vec![
+            "Tainted data from `$_GET` is used in a shell command by `shell_exec()`.",
+            "Tainted data from `$_GET` is used in HTML output by `echo`.",
+        ]
+    );
+
+    assert_eq!(
+        annotation_messages(&findings[0]),
+        vec![
+            "Tainted data is passed to `App\\run()` here.",
+            "Tainted data originates from `$_GET` here.",
+            "Returned from `App\\read_input()` here.",
+            "Reaches `shell_exec()` here.",
+        ]
+    );
+}
+
+#[test]
+fn test_methods() {
+    let findings = analyze(&[indoc! {r#"
+        <?php
+
+        namespace App;
+
+        use Symfony\Component\HttpFoundation\Request;
+
+        final class UserRepository
+        {
+            public function __construct(private \PDO $connection) {}
+
+            public function findByName(string $name): mixed
+            {
+                return $this->connection->query("SELECT * FROM users WHERE name = '$name'");
+            }
+
+            public function findById(string $id): mixed
+            {
+                return $this->connection->query('SELECT * FROM users WHERE id = ' . (int) $id);
+            }
+        }
+
+        final class UserController
+        {
+            public function show(Request $request, UserRepository $users): void
+            {
+                $users->findByName($request->get('name'));
+                $users->findById($request->get('id'));
+            }
+        }
+    "#}]);
+
+    assert_eq!(
+        messages(&findings),
+        vec!["Tainted data from `Request::get()` is used in an SQL query by `PDO::query()`."]
+    );
+}
+
+#[test]
+fn test_echo_tag() {
+    let findings = analyze(&[indoc! {r#"
+        <h1><?= $_GET['title'] ?></h1>
+        <p><?= htmlspecialchars($_GET['body']) ?></p>
+    "#}]);
+
+    assert_eq!(messages(&findings), vec!["Tainted data from `$_GET` is used in HTML output by `echo`."]);
+}
+
+#[test]
+fn test_configured_sources_sinks_and_sanitizers() {
+    let configuration = TaintConfiguration {
+        sources: vec!["App\\Http\\Input::get".to_string()],
+        sinks: vec![SinkDefinition { name: "App\\Db::raw".to_string(), kind: SinkKind::Sql, parameters: vec![0] }],
+        sanitizers: vec![SanitizerDefinition { name: "App\\quote".to_string(), kinds: vec![SinkKind::Sql] }],
+    };
+
+    let findings = analyze_with(
+        &configuration,
+        &[indoc! {r#"
+            <?php
+
+            namespace App;
+
+            function handle(Http\Input $input): void
+            {
+                Db::raw('SELECT * FROM posts WHERE slug = ' . $input->get('slug'));
+                Db::raw('SELECT * FROM posts WHERE slug = ' . quote($input->get('slug')));
+                Db::raw('SELECT * FROM posts', $input->get('slug'));
+            }
+        "#}],
+    );
+
+    assert_eq!(messages(&findings), vec!["Tainted data from `Input::get()` is used in an SQL query by `Db::raw()`."]);
+}
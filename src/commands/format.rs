@@ -1,17 +1,32 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::time::Duration;
+use std::time::Instant;
 
 use clap::Parser;
 
 use mago_formatter::format;
+use mago_formatter::format_with_deadline;
 use mago_formatter::settings::FormatSettings;
+use mago_formatter::verify;
 use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_parser::parse;
 use mago_parser::parse_source;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_source::Source;
 use mago_source::SourceCategory;
 use mago_source::SourceIdentifier;
 use mago_source::SourceManager;
+use mago_span::HasSpan;
 
 use crate::config::Configuration;
+use crate::enum_variants;
 use crate::error::Error;
 use crate::source;
 use crate::utils;
@@ -19,6 +34,12 @@ use crate::utils::progress::create_progress_bar;
 use crate::utils::progress::remove_progress_bar;
 use crate::utils::progress::ProgressBarTheme;
 
+/// The exit code when some source files need formatting in dry-run mode.
+const NEEDS_FORMATTING_EXIT_CODE: u8 = 1;
+
+/// The exit code when some source files could not be formatted.
+const FAILED_TO_FORMAT_EXIT_CODE: u8 = 2;
+
 /// Represents the `format` command, which is responsible for formatting source files
 /// according to specified rules in the configuration file.
 #[derive(Parser, Debug)]
@@ -30,6 +51,9 @@ use crate::utils::progress::ProgressBarTheme;
 The `format` command applies consistent formatting to source files based on the rules defined in the configuration file.
 
 This command helps maintain a consistent codebase style, improving readability and collaboration.
+
+Exit codes: `0` if all source files are formatted, `1` if some source files need formatting in dry-run mode,
+and `2` if some source files could not be formatted, in which case they are reported and left untouched.
 "#
 )]
 pub struct FormatCommand {
@@ -38,8 +62,70 @@ pub struct FormatCommand {
     pub path: Vec<PathBuf>,
 
     /// Perform a dry run to check if files are already formatted.
-    #[arg(long, short = 'd', help = "Check if the source files are already formatted without making changes")]
+    #[arg(
+        long,
+        short = 'd',
+        visible_alias = "check",
+        help = "Check if the source files are already formatted without making changes"
+    )]
     pub dry_run: bool,
+
+    /// Verify that the formatted code is equivalent to the original code before writing it.
+    #[arg(
+        long,
+        help = "Verify that the formatted code is equivalent to the original code before writing it, enabled by default in dry-run mode"
+    )]
+    pub verify: bool,
+
+    /// The maximum number of seconds to spend formatting a single file, overriding the configuration.
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        help = "The maximum number of seconds to spend formatting a single file, overriding the configuration"
+    )]
+    pub timeout: Option<u64>,
+
+    /// Specify where the results should be reported.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Specify where the results should be reported",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingTarget)
+    )]
+    pub reporting_target: ReportingTarget,
+
+    /// Choose the format for reporting issues.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the format for reporting issues",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingFormat)
+    )]
+    pub reporting_format: ReportingFormat,
+}
+
+/// The outcome of formatting a single source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FormatOutcome {
+    /// The source file was already formatted.
+    Unchanged,
+    /// The source file was formatted, or needs formatting in dry-run mode.
+    Changed,
+    /// The source file could not be formatted, and was left untouched.
+    Skipped(Issue),
+}
+
+/// The results of formatting all source files.
+#[derive(Debug)]
+struct FormatSummary {
+    /// The number of source files that were formatted, or need formatting in dry-run mode.
+    changed: usize,
+    /// The names of the source files that could not be formatted.
+    skipped: Vec<String>,
+    /// The issues explaining why source files could not be formatted.
+    issues: IssueCollection,
 }
 
 /// Executes the format command with the provided configuration and options.
@@ -51,7 +137,8 @@ pub struct FormatCommand {
 ///
 /// # Returns
 ///
-/// Exit code: `0` if successful or no changes were needed, `1` if issues were found during the check.
+/// Exit code: `0` if successful or no changes were needed, `1` if some source files need formatting
+/// in dry-run mode, or `2` if any source file could not be formatted.
 pub async fn execute(command: FormatCommand, configuration: Configuration) -> Result<ExitCode, Error> {
     // Initialize the interner for managing identifiers.
     let interner = ThreadedInterner::new();
@@ -64,24 +151,44 @@ pub async fn execute(command: FormatCommand, configuration: Configuration) -> Re
     };
 
     // Extract formatting settings from the configuration.
-    let settings = configuration.format.get_settings();
+    let settings = configuration.format.get_settings(configuration.php_version);
+
+    // Verification is always enabled in dry-run mode.
+    let verify = command.verify || command.dry_run;
 
-    // Format all sources and get the count of changed files.
-    let changed = format_all(interner, source_manager, settings, command.dry_run).await?;
+    // The time budget for each source file, if any.
+    let timeout = command.timeout.or(configuration.format.timeout).map(Duration::from_secs);
+
+    // Format all sources, and report the ones that could not be formatted.
+    let summary =
+        format_all(interner.clone(), source_manager.clone(), settings, command.dry_run, verify, timeout).await?;
+
+    Reporter::new(interner, source_manager, command.reporting_target)
+        .report(summary.issues, command.reporting_format)?;
 
     // Provide feedback and return appropriate exit code.
-    if changed == 0 {
+    if !summary.skipped.is_empty() {
+        tracing::error!(
+            "Skipped {} source files that could not be formatted: {}.",
+            summary.skipped.len(),
+            summary.skipped.join(", ")
+        );
+
+        return Ok(ExitCode::from(FAILED_TO_FORMAT_EXIT_CODE));
+    }
+
+    if summary.changed == 0 {
         tracing::info!("All source files are already formatted.");
 
         return Ok(ExitCode::SUCCESS);
     }
 
     Ok(if command.dry_run {
-        tracing::info!("Found {} source files that need formatting.", changed);
+        tracing::info!("Found {} source files that need formatting.", summary.changed);
 
-        ExitCode::FAILURE
+        ExitCode::from(NEEDS_FORMATTING_EXIT_CODE)
     } else {
-        tracing::info!("Formatted {} source files successfully.", changed);
+        tracing::info!("Formatted {} source files successfully.", summary.changed);
 
         ExitCode::SUCCESS
     })
@@ -94,18 +201,22 @@ pub async fn execute(command: FormatCommand, configuration: Configuration) -> Re
 /// * `interner` - The interner to manage source identifiers.
 /// * `source_manager` - The manager responsible for handling source files.
 /// * `settings` - Formatting settings to apply.
-/// * `check` - A flag to determine whether to check or apply formatting.
+/// * `dry_run` - A flag to determine whether to check or apply formatting.
+/// * `verify` - A flag to determine whether to verify the formatted code before applying it.
+/// * `timeout` - The maximum time to spend formatting a single source file, if any.
 ///
 /// # Returns
 ///
-/// A result containing the number of changed files or a source error.
+/// A result containing the summary of formatting all source files, or a source error.
 #[inline]
 async fn format_all(
     interner: ThreadedInterner,
     source_manager: SourceManager,
     settings: FormatSettings,
     dry_run: bool,
-) -> Result<usize, Error> {
+    verify: bool,
+    timeout: Option<Duration>,
+) -> Result<FormatSummary, Error> {
     // Collect all user-defined sources.
     let sources: Vec<_> = source_manager.source_ids_for_category(SourceCategory::UserDefined).collect();
 
@@ -113,15 +224,15 @@ async fn format_all(
     let progress_bar = create_progress_bar(length, "✨ Formatting", ProgressBarTheme::Green);
     let mut handles = Vec::with_capacity(length);
 
-    // Spawn async tasks to format each source concurrently.
-    for source in sources.into_iter() {
-        handles.push(tokio::spawn({
+    // Spawn blocking tasks to format each source concurrently.
+    for &source in &sources {
+        handles.push(tokio::task::spawn_blocking({
             let interner = interner.clone();
             let manager = source_manager.clone();
             let progress_bar = progress_bar.clone();
 
-            async move {
-                let result = format_source(&interner, &manager, &source, settings, dry_run);
+            move || {
+                let result = format_source(&interner, &manager, &source, settings, dry_run, verify, timeout);
 
                 progress_bar.inc(1);
 
@@ -130,21 +241,26 @@ async fn format_all(
         }));
     }
 
-    let mut changed = 0;
+    let mut summary = FormatSummary { changed: 0, skipped: vec![], issues: IssueCollection::new() };
 
     // Process each formatting task and update progress bar.
-    for handle in handles {
-        if handle.await?? {
-            changed += 1;
+    for (source, handle) in sources.into_iter().zip(handles) {
+        match handle.await?? {
+            FormatOutcome::Unchanged => {}
+            FormatOutcome::Changed => summary.changed += 1,
+            FormatOutcome::Skipped(issue) => {
+                summary.skipped.push(interner.lookup(&source.0).to_string());
+                summary.issues.push(issue);
+            }
         }
     }
 
     remove_progress_bar(progress_bar);
 
-    Ok(changed)
+    Ok(summary)
 }
 
-/// Formats a single source file.
+/// Formats a single source file, and applies the changes.
 ///
 /// # Arguments
 ///
@@ -152,11 +268,13 @@ async fn format_all(
 /// * `manager` - Reference to the source manager.
 /// * `source` - Identifier of the source file to format.
 /// * `settings` - Formatting settings to apply.
-/// * `check` - A flag to determine whether to check or apply formatting.
+/// * `dry_run` - A flag to determine whether to check or apply formatting.
+/// * `verify` - A flag to determine whether to verify the formatted code before applying it.
+/// * `timeout` - The maximum time to spend formatting the source file, if any.
 ///
 /// # Returns
 ///
-/// A result indicating whether the file was changed or an error occurred.
+/// A result containing the outcome of formatting the file, or an error.
 #[inline]
 fn format_source(
     interner: &ThreadedInterner,
@@ -164,28 +282,87 @@ fn format_source(
     source: &SourceIdentifier,
     settings: FormatSettings,
     dry_run: bool,
-) -> Result<bool, Error> {
+    verify: bool,
+    timeout: Option<Duration>,
+) -> Result<FormatOutcome, Error> {
     // Load the source file.
     let source = manager.load(source)?;
 
+    let formatted = match format_code(interner, &source, settings, verify, timeout) {
+        Ok(formatted) => formatted,
+        Err(issue) => return Ok(FormatOutcome::Skipped(*issue)),
+    };
+
+    Ok(if utils::apply_changes(interner, manager, &source, formatted, dry_run)? {
+        FormatOutcome::Changed
+    } else {
+        FormatOutcome::Unchanged
+    })
+}
+
+/// Formats the code of a single source file, giving up once the time budget, if any, is spent.
+///
+/// # Returns
+///
+/// The formatted code, or an issue explaining why the source file could not be formatted.
+fn format_code(
+    interner: &ThreadedInterner,
+    source: &Source,
+    settings: FormatSettings,
+    verify: bool,
+    timeout: Option<Duration>,
+) -> Result<String, Box<Issue>> {
+    let started = Instant::now();
+    let name = interner.lookup(&source.identifier.0);
+
     // Parse the source file to generate an AST.
-    let (program, error) = parse_source(interner, &source);
+    let (program, error) = parse_source(interner, source);
+    if let Some(error) = error {
+        let issue = Issue::from(&error).with_help("Fix the syntax error so that this file can be formatted.");
 
-    // Handle parsing errors and perform formatting.
-    let changed = match error {
-        Some(error) => {
-            let source_name = interner.lookup(&source.identifier.0);
+        return Err(Box::new(issue));
+    }
+
+    let formatted = match timeout {
+        Some(timeout) => match format_with_deadline(interner, source, &program, settings, started + timeout) {
+            Some(formatted) => formatted,
+            None => {
+                let issue = Issue::error(format!(
+                    "Formatting `{}` took longer than the time budget of {} seconds.",
+                    name,
+                    timeout.as_secs()
+                ))
+                .with_help(
+                    "Increase the time budget with `--timeout`, or the `timeout` option of the `[format]` section.",
+                );
+
+                return Err(Box::new(issue));
+            }
+        },
+        None => format(interner, source, &program, settings),
+    };
 
-            tracing::error!("Skipping formatting for source '{}': {}.", source_name, error);
+    // Re-parse the formatted code, and make sure it is equivalent to the original code.
+    let content = interner.lookup(&source.content);
+    if verify && formatted != content {
+        let (formatted_program, error) = parse(interner, Input::new(source.identifier, formatted.as_bytes()));
 
-            false
+        if let Some(error) = error {
+            let issue = Issue::error(format!("The formatted code of `{}` could not be parsed: {}.", name, error))
+                .with_note("This file was left untouched.")
+                .with_help("Please report this issue.");
+
+            return Err(Box::new(issue));
         }
-        None => {
-            let formatted = format(interner, &source, &program, settings);
 
-            utils::apply_changes(interner, manager, &source, formatted, dry_run)?
+        if let Err(error) = verify::verify(interner, &settings, content, &program, &formatted, &formatted_program) {
+            let issue = Issue::error(format!("Formatting would change the behavior of the code: {}.", error))
+                .with_annotation(Annotation::primary(error.span()).with_message("This file was left untouched."))
+                .with_help("Please report this issue.");
+
+            return Err(Box::new(issue));
         }
-    };
+    }
 
-    Ok(changed)
+    Ok(formatted)
 }
@@ -1,13 +1,20 @@
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
 
 use clap::Parser;
 use colored::Colorize;
 
+use mago_ast::Program;
 use mago_interner::ThreadedInterner;
+use mago_linter::external::ExternalPlugin;
+use mago_linter::pattern::PatternPlugin;
+use mago_linter::pattern::PatternRuleDefinition;
+use mago_linter::plugin::taint::TaintPlugin;
 use mago_linter::settings::RuleSettings;
 use mago_linter::settings::Settings;
 use mago_linter::Linter;
+use mago_names::Names;
 use mago_reflection::CodebaseReflection;
 use mago_reflector::reflect;
 use mago_reporting::reporter::Reporter;
@@ -18,10 +25,13 @@ use mago_reporting::IssueCollection;
 use mago_reporting::Level;
 use mago_semantics::Semantics;
 use mago_source::error::SourceError;
+use mago_source::Source;
 use mago_source::SourceCategory;
 use mago_source::SourceManager;
+use mago_taint::TaintAnalyzer;
 
 use crate::config::linter::LinterLevel;
+use crate::config::linter::LinterPatternFile;
 use crate::config::Configuration;
 use crate::enum_variants;
 use crate::error::Error;
@@ -133,7 +143,8 @@ pub(super) fn create_linter(
     interner: &ThreadedInterner,
     configuration: &Configuration,
     codebase: CodebaseReflection,
-) -> Linter {
+    programs: &[(&Source, &Program, &Names)],
+) -> Result<Linter, Error> {
     let mut settings = Settings::new(configuration.php_version);
 
     if let Some(default_plugins) = configuration.linter.default_plugins {
@@ -163,7 +174,57 @@ pub(super) fn create_linter(
         linter.add_plugin(plugin);
     });
 
-    linter
+    let mut patterns = configuration.linter.patterns.clone();
+    for path in &configuration.linter.pattern_files {
+        let path = if path.is_absolute() { path.clone() } else { configuration.source.root.join(path) };
+
+        tracing::debug!("Loading patterns from `{}`...", path.display());
+
+        let content = std::fs::read_to_string(&path).map_err(|error| Error::ReadingPatternFile(path.clone(), error))?;
+        let file = toml::from_str::<LinterPatternFile>(&content)?;
+
+        patterns.extend(file.patterns);
+    }
+
+    let definitions = patterns.into_iter().filter_map(|pattern| {
+        let level = match pattern.level {
+            Some(LinterLevel::Off) => return None,
+            Some(LinterLevel::Help) => Level::Help,
+            Some(LinterLevel::Note) => Level::Note,
+            Some(LinterLevel::Warning) | None => Level::Warning,
+            Some(LinterLevel::Error) => Level::Error,
+        };
+
+        Some(PatternRuleDefinition {
+            name: pattern.name,
+            pattern: pattern.pattern,
+            message: pattern.message,
+            level,
+            help: pattern.help,
+            rewrite: pattern.rewrite,
+            extends: pattern.extends,
+        })
+    });
+
+    linter.add_plugin(PatternPlugin::new(interner, definitions)?);
+    // Taint analysis needs the summaries of all functions and methods, computed before linting any file.
+    let mut analyzer = TaintAnalyzer::new(&configuration.linter.taint);
+    if linter.is_plugin_enabled(&TaintPlugin::definition()) {
+        analyzer.summarize(interner, linter.get_codebase(), programs);
+    }
+
+    linter.add_plugin(TaintPlugin::new(Arc::new(analyzer)));
+
+    for path in &configuration.linter.external_plugins {
+        let path = if path.is_absolute() { path.clone() } else { configuration.source.root.join(path) };
+
+        tracing::debug!("Loading external plugin from `{}`...", path.display());
+
+        let codebase = linter.get_codebase().clone();
+        linter.add_plugin(ExternalPlugin::load(&path, codebase)?);
+    }
+
+    Ok(linter)
 }
 
 /// Displays detailed information about a single lint rule, including its name,
@@ -176,7 +237,7 @@ pub(super) fn explain_rule(
     rule: &str,
     configuration: &Configuration,
 ) -> Result<ExitCode, Error> {
-    let linter = create_linter(interner, configuration, CodebaseReflection::new());
+    let linter = create_linter(interner, configuration, CodebaseReflection::new(), &[])?;
     let configured_rules = linter.get_configured_rules();
 
     // Attempt to locate the rule
@@ -219,6 +280,15 @@ pub(super) fn explain_rule(
         println!("{}", "- This rule does not have any maximum PHP version requirements.".dimmed());
     }
 
+    if let Some(feature) = rule_definition.feature {
+        println!(
+            "{} {} {}",
+            "- This rule only applies to PHP versions that do not support the".dimmed(),
+            format!("{:?}", feature).bold().green(),
+            "feature.".dimmed()
+        );
+    }
+
     if !rule_definition.options.is_empty() {
         println!();
         println!("{}:", "## Configuration Options".bold().underline());
@@ -301,7 +371,7 @@ pub(super) fn explain_rule(
 }
 
 pub(super) fn list_rules(interner: &ThreadedInterner, configuration: &Configuration) -> Result<ExitCode, Error> {
-    let linter = create_linter(interner, configuration, CodebaseReflection::new());
+    let linter = create_linter(interner, configuration, CodebaseReflection::new(), &[])?;
     let configured_rules = linter.get_configured_rules();
     if configured_rules.is_empty() {
         println!("{}", "No rules are currently configured or enabled.".bright_red());
@@ -384,7 +454,9 @@ pub(super) async fn lint_sources(
 
     remove_progress_bar(progress_bar);
 
-    let linter = create_linter(interner, configuration, codebase);
+    let programs: Vec<_> =
+        semantics.iter().map(|semantic| (&semantic.source, &semantic.program, &semantic.names)).collect();
+    let linter = create_linter(interner, configuration, codebase, &programs)?;
     let progress_bar = create_progress_bar(length, "🧹  Linting", ProgressBarTheme::Red);
     let mut handles = Vec::with_capacity(length);
     for semantic in semantics {
@@ -0,0 +1,160 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_interner::ThreadedInterner;
+use mago_metrics::output::MetricsFormat;
+use mago_metrics::CodebaseMetrics;
+use mago_metrics::FileMetrics;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_reflector::reflect;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::reflection::reflect_all_non_user_defined_sources;
+use crate::source;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+use crate::utils::progress::ProgressBarTheme;
+
+/// Represents the `metrics` command, which reports code metrics for the project source files.
+#[derive(Parser, Debug)]
+#[command(
+    name = "metrics",
+    about = "Report code metrics for every file, class-like, function, and method",
+    long_about = r#"
+The `metrics` command measures every file, class-like, function, and method of the project:
+lines of code (LOC, LLOC, CLOC), cyclomatic complexity, Halstead volume, difficulty, and effort,
+Kan defect, maintainability index, afferent and efferent coupling, and depth of inheritance.
+
+The metrics can be written as JSON or CSV to track trends over time, or as an HTML summary.
+"#
+)]
+pub struct MetricsCommand {
+    /// Measure specific files or directories, overriding the source configuration.
+    #[arg(help = "Measure specific files or directories, overriding the source configuration")]
+    pub path: Vec<PathBuf>,
+
+    /// The format to write the metrics in.
+    #[arg(
+        long,
+        default_value_t,
+        help = "The format to write the metrics in",
+        ignore_case = true,
+        value_parser = enum_variants!(MetricsFormat)
+    )]
+    pub format: MetricsFormat,
+
+    /// The file to write the metrics to, instead of the standard output.
+    #[arg(long, short = 'o', help = "The file to write the metrics to, instead of the standard output")]
+    pub output: Option<PathBuf>,
+}
+
+/// Executes the metrics command with the provided configuration and options.
+///
+/// # Arguments
+///
+/// * `command` - The `MetricsCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration loaded from file or defaults.
+///
+/// # Returns
+///
+/// Exit code: `0` once the metrics are written.
+pub async fn execute(command: MetricsCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+
+    // Load sources, including external ones, to measure the depth of inheritance across dependencies.
+    let source_manager = if !command.path.is_empty() {
+        source::from_paths(&interner, &configuration.source, command.path, true).await?
+    } else {
+        source::load(&interner, &configuration.source, true, true).await?
+    };
+
+    let metrics = measure_sources(&interner, &source_manager).await?;
+
+    match &command.output {
+        Some(path) => {
+            let file = File::create(path).map_err(|error| Error::WritingFile(path.clone(), error))?;
+            let mut writer = BufWriter::new(file);
+
+            command
+                .format
+                .write(&mut writer, &metrics)
+                .and_then(|_| writer.flush())
+                .map_err(|error| Error::WritingFile(path.clone(), error))?;
+
+            tracing::info!("Wrote the metrics of {} source files to `{}`.", metrics.files.len(), path.display());
+        }
+        None => {
+            let mut writer = std::io::stdout().lock();
+
+            command.format.write(&mut writer, &metrics).map_err(Error::WritingOutput)?;
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Measures all user-defined sources, and their coupling using the reflection of the whole codebase.
+#[inline]
+async fn measure_sources(interner: &ThreadedInterner, manager: &SourceManager) -> Result<CodebaseMetrics, Error> {
+    // Collect all user-defined sources.
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined).collect();
+    let length = sources.len();
+
+    let progress_bar = create_progress_bar(length, "📏  Measuring", ProgressBarTheme::Blue);
+    let mut codebase = reflect_all_non_user_defined_sources(interner, manager).await?;
+    let mut handles = Vec::with_capacity(length);
+    for source_id in sources {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = manager.clone();
+            let progress_bar = progress_bar.clone();
+
+            async move {
+                let source = manager.load(&source_id)?;
+                let (program, error) = parse_source(&interner, &source);
+                if let Some(error) = error {
+                    tracing::warn!(
+                        "Source '{}' contains a syntax error, its metrics may be inaccurate: {}.",
+                        interner.lookup(&source_id.0),
+                        error
+                    );
+                }
+
+                let names = Names::resolve(&interner, &program);
+                let metrics = FileMetrics::measure(&interner, &source, &program, &names);
+                let reflection = reflect(&interner, &source, &program, &names);
+
+                progress_bar.inc(1);
+
+                Result::<_, Error>::Ok((metrics, reflection))
+            }
+        }));
+    }
+
+    let mut files = Vec::with_capacity(length);
+    for handle in handles {
+        let (metrics, reflection) = handle.await??;
+
+        codebase = mago_reflector::merge(interner, codebase, reflection);
+        files.push(metrics);
+    }
+
+    mago_reflector::populate(interner, &mut codebase);
+
+    remove_progress_bar(progress_bar);
+
+    let mut metrics = CodebaseMetrics::new(files);
+    metrics.measure_coupling(interner, &codebase);
+
+    Ok(metrics)
+}
@@ -7,12 +7,20 @@ use crate::commands::ast::AstCommand;
 use crate::commands::fix::FixCommand;
 use crate::commands::format::FormatCommand;
 use crate::commands::lint::LintCommand;
+use crate::commands::metrics::MetricsCommand;
+use crate::commands::refactor::RefactorCommand;
+use crate::commands::rewrite::RewriteCommand;
+use crate::commands::search::SearchCommand;
 use crate::commands::self_update::SelfUpdateCommand;
 
 pub mod ast;
 pub mod fix;
 pub mod format;
 pub mod lint;
+pub mod metrics;
+pub mod refactor;
+pub mod rewrite;
+pub mod search;
 pub mod self_update;
 
 pub const CLAP_STYLING: Styles = Styles::styled()
@@ -56,6 +64,14 @@ pub enum MagoCommand {
     Fix(FixCommand),
     #[command(name = "format")]
     Format(FormatCommand),
+    #[command(name = "search")]
+    Search(SearchCommand),
+    #[command(name = "rewrite")]
+    Rewrite(RewriteCommand),
+    #[command(name = "refactor")]
+    Refactor(RefactorCommand),
+    #[command(name = "metrics")]
+    Metrics(MetricsCommand),
     #[command(name = "self-update")]
     SelfUpdate(SelfUpdateCommand),
 }
@@ -0,0 +1,434 @@
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use clap::Parser;
+use clap::Subcommand;
+
+use mago_ast::Program;
+use mago_composer::AutoloadPsr4value;
+use mago_composer::ComposerPackage;
+use mago_composer::ComposerPackageAutoloadDevPsr4value;
+use mago_fixer::FixPlan;
+use mago_interner::ThreadedInterner;
+use mago_names::Names;
+use mago_parser::parse_source;
+use mago_refactor::move_class::ClassMover;
+use mago_refactor::rename::Renamer;
+use mago_refactor::symbol::Symbol;
+use mago_reflection::CodebaseReflection;
+use mago_reflector::reflect;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_reporting::IssueCollection;
+use mago_source::SourceCategory;
+use mago_source::SourceManager;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::reflection::reflect_all_non_user_defined_sources;
+use crate::source;
+use crate::utils;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+use crate::utils::progress::ProgressBarTheme;
+
+/// Represents the `refactor` command, which performs semantic refactorings across the project.
+#[derive(Parser, Debug)]
+#[command(
+    name = "refactor",
+    about = "Perform semantic refactorings across the project source code",
+    long_about = r#"
+The `refactor` command performs refactorings that understand the meaning of the code, such as
+renaming a symbol along with all of its references, imports, overrides, and implementations, or
+moving a class to another namespace.
+
+References that cannot be resolved with certainty, e.g. method calls on a variable of an unknown
+type or strings containing the name of the symbol, are left untouched and reported for review.
+"#
+)]
+pub struct RefactorCommand {
+    #[command(subcommand)]
+    pub refactoring: Refactoring,
+}
+
+/// The refactorings available through the `refactor` command.
+#[derive(Subcommand, Debug)]
+pub enum Refactoring {
+    /// Rename a symbol along with all of its references.
+    #[command(name = "rename")]
+    Rename(RenameCommand),
+    /// Move a class-like to another namespace, along with its file.
+    #[command(name = "move-class")]
+    MoveClass(MoveClassCommand),
+}
+
+/// Represents the `refactor rename` command.
+#[derive(Parser, Debug)]
+pub struct RenameCommand {
+    /// The fully qualified name of the symbol to rename.
+    #[arg(long, help = "The symbol to rename, e.g. 'App\\Foo', 'App\\Foo::bar', 'App\\Foo::$bar', or 'App\\FOO'")]
+    pub symbol: String,
+
+    /// The new short name of the symbol.
+    #[arg(long, help = "The new short name of the symbol, e.g. 'baz' to rename 'App\\Foo::bar' to 'App\\Foo::baz'")]
+    pub to: String,
+
+    /// Run the command without writing any changes to disk.
+    #[arg(long, short = 'd', help = "Preview the changes without applying them, showing what changes would be made")]
+    pub dry_run: bool,
+
+    /// Specify where the uncertain references should be reported.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Specify where the uncertain references should be reported",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingTarget)
+    )]
+    pub reporting_target: ReportingTarget,
+
+    /// Choose the format for reporting uncertain references.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the format for reporting uncertain references",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingFormat)
+    )]
+    pub reporting_format: ReportingFormat,
+}
+
+/// Represents the `refactor move-class` command.
+#[derive(Parser, Debug)]
+pub struct MoveClassCommand {
+    /// The fully qualified name of the class-like to move.
+    #[arg(help = "The class-like to move, e.g. 'App\\Old\\Foo'")]
+    pub class: String,
+
+    /// The new fully qualified name of the class-like.
+    #[arg(help = "The new fully qualified name of the class-like, e.g. 'App\\New\\Foo'")]
+    pub to: String,
+
+    /// Run the command without writing any changes to disk.
+    #[arg(long, short = 'd', help = "Preview the changes without applying them, showing what changes would be made")]
+    pub dry_run: bool,
+
+    /// Specify where the uncertain references should be reported.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Specify where the uncertain references should be reported",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingTarget)
+    )]
+    pub reporting_target: ReportingTarget,
+
+    /// Choose the format for reporting uncertain references.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the format for reporting uncertain references",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingFormat)
+    )]
+    pub reporting_format: ReportingFormat,
+}
+
+/// Executes the refactor command with the provided configuration and options.
+///
+/// # Arguments
+///
+/// * `command` - The `RefactorCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration loaded from file or defaults.
+///
+/// # Returns
+///
+/// Exit code: `0` if successful, `1` if changes were found during a dry run.
+pub async fn execute(command: RefactorCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    match command.refactoring {
+        Refactoring::Rename(command) => rename(command, configuration).await,
+        Refactoring::MoveClass(command) => move_class(command, configuration).await,
+    }
+}
+
+async fn rename(command: RenameCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let source_manager = source::load(&interner, &configuration.source, true, true).await?;
+    let codebase = build_codebase(&interner, &source_manager).await?;
+
+    let symbol = Symbol::resolve(&interner, &codebase, &command.symbol)?;
+    let renamer = Renamer::new(&interner, &codebase, symbol, &command.to)?;
+
+    let new_name = renamer.get_new_fully_qualified_name(&interner);
+    let old_name = symbol.get_fully_qualified_name(&interner);
+
+    // A class-like declared in the file PSR-4 expects for its old name is moved to the file expected for its new name.
+    let mut file_move = None;
+    if let Some(source) = renamer.get_source() {
+        let root = &configuration.source.root;
+        let path = source_manager.load(&source)?.path;
+        let old_path = resolve_psr4_path(root, &old_name)?;
+        let new_path = resolve_psr4_path(root, &new_name)?;
+
+        if let (Some(path), Some(new_path)) = (path, new_path) {
+            if old_path.as_ref() == Some(&path) && path != new_path {
+                ensure_file_can_be_moved(&path, &new_path)?;
+
+                file_move = Some((path, new_path));
+            }
+        }
+    }
+
+    let (changed, issues) = refactor_sources(&interner, &source_manager, command.dry_run, |program, names| {
+        renamer.rename(&interner, &codebase, program, names)
+    })?;
+
+    if let Some((path, new_path)) = &file_move {
+        move_file(path, new_path, command.dry_run)?;
+    }
+
+    Reporter::new(interner, source_manager, command.reporting_target).report(issues, command.reporting_format)?;
+
+    if changed == 0 && file_move.is_none() {
+        tracing::info!("No references to `{}` were found.", old_name);
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    Ok(if command.dry_run {
+        tracing::info!("Renaming `{}` to `{}` would change {} source files.", old_name, new_name, changed);
+
+        ExitCode::FAILURE
+    } else {
+        tracing::info!("Renamed `{}` to `{}` in {} source files.", old_name, new_name, changed);
+
+        ExitCode::SUCCESS
+    })
+}
+
+async fn move_class(command: MoveClassCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let source_manager = source::load(&interner, &configuration.source, true, true).await?;
+    let codebase = build_codebase(&interner, &source_manager).await?;
+
+    let mover = ClassMover::new(&interner, &codebase, &command.class, &command.to)?;
+    let path = source_manager.load(&mover.get_source())?.path;
+    let target = resolve_psr4_path(&configuration.source.root, mover.get_new_name())?;
+    if let (Some(path), Some(target)) = (&path, &target) {
+        ensure_file_can_be_moved(path, target)?;
+    }
+
+    let (changed, issues) = refactor_sources(&interner, &source_manager, command.dry_run, |program, names| {
+        mover.move_class(&interner, &codebase, program, names)
+    })?;
+
+    let moved = match (path, target) {
+        (Some(path), Some(target)) if path != target => {
+            move_file(&path, &target, command.dry_run)?;
+
+            true
+        }
+        (Some(_), None) => {
+            tracing::warn!("No PSR-4 mapping matches `{}`, the file was left in place.", mover.get_new_name());
+
+            false
+        }
+        _ => false,
+    };
+
+    Reporter::new(interner, source_manager, command.reporting_target).report(issues, command.reporting_format)?;
+
+    if changed == 0 && !moved {
+        tracing::info!("Nothing to move.");
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    Ok(if command.dry_run {
+        tracing::info!(
+            "Moving `{}` to `{}` would change {} source files.",
+            mover.get_old_name(),
+            mover.get_new_name(),
+            changed
+        );
+
+        ExitCode::FAILURE
+    } else {
+        tracing::info!(
+            "Moved `{}` to `{}`, changing {} source files.",
+            mover.get_old_name(),
+            mover.get_new_name(),
+            changed
+        );
+
+        ExitCode::SUCCESS
+    })
+}
+
+/// Applies a refactoring to every user-defined source, skipping the sources that could not be parsed.
+///
+/// Returns the number of changed sources, and the references that could not be refactored with certainty.
+fn refactor_sources(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    dry_run: bool,
+    refactor: impl Fn(&Program, &Names) -> (FixPlan, IssueCollection),
+) -> Result<(usize, IssueCollection), Error> {
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined).collect();
+    let progress_bar = create_progress_bar(sources.len(), "✏️  Refactoring", ProgressBarTheme::Cyan);
+
+    let mut changed = 0;
+    let mut issues = IssueCollection::new();
+    for source_id in sources {
+        let source = manager.load(&source_id)?;
+        let (program, error) = parse_source(interner, &source);
+        progress_bar.inc(1);
+
+        if let Some(error) = error {
+            let source_name = interner.lookup(&source.identifier.0);
+
+            tracing::error!("Skipping source '{}', it could not be parsed: {}.", source_name, error);
+
+            continue;
+        }
+
+        let names = Names::resolve(interner, &program);
+        let (plan, source_issues) = refactor(&program, &names);
+        issues.extend(source_issues);
+
+        if plan.is_empty() {
+            continue;
+        }
+
+        let refactored = plan.execute(interner.lookup(&source.content)).get_fixed();
+        if utils::apply_changes(interner, manager, &source, refactored, dry_run)? {
+            changed += 1;
+        }
+    }
+
+    remove_progress_bar(progress_bar);
+
+    Ok((changed, issues))
+}
+
+/// Ensures a file can be moved to the given path, i.e. that no other file exists there.
+fn ensure_file_can_be_moved(path: &Path, target: &Path) -> Result<(), Error> {
+    if path != target && target.exists() {
+        let error = std::io::Error::new(std::io::ErrorKind::AlreadyExists, "the file already exists");
+
+        return Err(Error::MovingFile(target.to_path_buf(), error));
+    }
+
+    Ok(())
+}
+
+/// Moves a file to the given path, creating its directory if needed, or only logs the move during a dry run.
+fn move_file(path: &Path, target: &Path, dry_run: bool) -> Result<(), Error> {
+    if dry_run {
+        tracing::info!("Would move '{}' to '{}'.", path.display(), target.display());
+
+        return Ok(());
+    }
+
+    if let Some(directory) = target.parent() {
+        std::fs::create_dir_all(directory).map_err(|error| Error::MovingFile(target.to_path_buf(), error))?;
+    }
+
+    std::fs::rename(path, target).map_err(|error| Error::MovingFile(target.to_path_buf(), error))?;
+
+    tracing::info!("Moved '{}' to '{}'.", path.display(), target.display());
+
+    Ok(())
+}
+
+/// Resolves the path a class-like should be declared in, according to the PSR-4 mapping
+/// of the `composer.json` file at the root of the project, if any.
+///
+/// When several prefixes match the name, the longest one is used, along with its first directory.
+fn resolve_psr4_path(root: &Path, class_like: &str) -> Result<Option<PathBuf>, Error> {
+    let Ok(content) = std::fs::read_to_string(root.join("composer.json")) else {
+        return Ok(None);
+    };
+
+    let package = ComposerPackage::from_str(&content)?;
+
+    let mut mappings: Vec<(String, Option<String>)> = vec![];
+    if let Some(autoload) = package.autoload {
+        mappings.extend(autoload.psr_4.into_iter().map(|(prefix, directories)| {
+            let directory = match directories {
+                AutoloadPsr4value::String(directory) => Some(directory),
+                AutoloadPsr4value::Array(directories) => directories.into_iter().next(),
+            };
+
+            (prefix, directory)
+        }));
+    }
+
+    if let Some(autoload) = package.autoload_dev {
+        mappings.extend(autoload.psr_4.into_iter().map(|(prefix, directories)| {
+            let directory = match directories {
+                ComposerPackageAutoloadDevPsr4value::String(directory) => Some(directory),
+                ComposerPackageAutoloadDevPsr4value::Array(directories) => directories.into_iter().next(),
+            };
+
+            (prefix, directory)
+        }));
+    }
+
+    let mapping = mappings
+        .into_iter()
+        .filter_map(|(prefix, directory)| {
+            let relative = class_like.strip_prefix(prefix.as_str())?;
+
+            Some((prefix.len(), relative.to_string(), directory?))
+        })
+        .max_by_key(|(length, _, _)| *length);
+
+    Ok(mapping.map(|(_, relative, directory)| {
+        let mut path = root.join(directory);
+        path.extend(relative.split('\\'));
+        path.set_extension("php");
+
+        path
+    }))
+}
+
+/// Builds the reflection of the whole codebase, including the external and built-in sources.
+async fn build_codebase(interner: &ThreadedInterner, manager: &SourceManager) -> Result<CodebaseReflection, Error> {
+    let sources: Vec<_> = manager.source_ids_for_category(SourceCategory::UserDefined).collect();
+
+    let progress_bar = create_progress_bar(sources.len(), "🔎  Scanning", ProgressBarTheme::Yellow);
+    let mut codebase = reflect_all_non_user_defined_sources(interner, manager).await?;
+    let mut handles = Vec::with_capacity(sources.len());
+    for source_id in sources {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = manager.clone();
+            let progress_bar = progress_bar.clone();
+
+            async move {
+                let source = manager.load(&source_id)?;
+                let (program, _) = parse_source(&interner, &source);
+                let names = Names::resolve(&interner, &program);
+                let reflections = reflect(&interner, &source, &program, &names);
+                progress_bar.inc(1);
+
+                Result::<_, Error>::Ok(reflections)
+            }
+        }));
+    }
+
+    for handle in handles {
+        codebase = mago_reflector::merge(interner, codebase, handle.await??);
+    }
+
+    mago_reflector::populate(interner, &mut codebase);
+
+    remove_progress_bar(progress_bar);
+
+    Ok(codebase)
+}
@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_fixer::FixPlan;
+use mago_fixer::SafetyClassification;
+use mago_interner::ThreadedInterner;
+use mago_lexer::input::Input;
+use mago_parser::parse;
+use mago_pattern::Match;
+use mago_pattern::Pattern;
+use mago_pattern::Template;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+
+use crate::commands::search::search_all;
+use crate::config::Configuration;
+use crate::error::Error;
+use crate::source;
+use crate::utils;
+
+/// Represents the `rewrite` command, which replaces code matching a structural pattern.
+#[derive(Parser, Debug)]
+#[command(
+    name = "rewrite",
+    about = "Rewrite the source code matching a structural pattern",
+    long_about = r#"
+The `rewrite` command replaces all the code matching a PHP pattern using a replacement template,
+e.g. `mago rewrite 'legacy_helper($a, $b)' 'helper($b, $a)'`.
+
+Metavariables captured by the pattern are substituted in the replacement with the code they matched.
+When matches are nested, only the outermost match is rewritten; run the command again to rewrite the rest.
+Sources that would no longer parse after the rewrite are left untouched.
+"#
+)]
+pub struct RewriteCommand {
+    /// The pattern to search for.
+    #[arg(help = "The pattern to search for, e.g. 'legacy_helper($a, $b)'")]
+    pub pattern: String,
+
+    /// The replacement template.
+    #[arg(help = "The replacement template, e.g. 'helper($b, $a)'")]
+    pub replacement: String,
+
+    /// Rewrite specific files or directories, overriding the source configuration.
+    #[arg(help = "Rewrite specific files or directories, overriding the source configuration")]
+    pub path: Vec<PathBuf>,
+
+    /// Run the command without writing any changes to disk.
+    #[arg(long, short = 'd', help = "Preview the rewrites without applying them, showing what changes would be made")]
+    pub dry_run: bool,
+}
+
+/// Executes the rewrite command with the provided configuration and options.
+///
+/// # Arguments
+///
+/// * `command` - The `RewriteCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration loaded from file or defaults.
+///
+/// # Returns
+///
+/// Exit code: `0` if successful, `1` if changes were found during a dry run.
+pub async fn execute(command: RewriteCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let pattern = Pattern::new(&interner, &command.pattern)?;
+    let template = Template::new(&command.replacement);
+
+    // Load sources
+    let source_manager = if !command.path.is_empty() {
+        source::from_paths(&interner, &configuration.source, command.path, false).await?
+    } else {
+        source::load(&interner, &configuration.source, false, false).await?
+    };
+
+    let results = search_all(&interner, &source_manager, &pattern).await?;
+
+    let mut changed = 0;
+    let mut rewrites = 0;
+    for (source, matches) in results {
+        let plan = create_fix_plan(&matches, &template);
+        let count = plan.len();
+
+        if rewrite_source(&interner, &source_manager, &source, plan, command.dry_run)? {
+            changed += 1;
+            rewrites += count;
+        }
+    }
+
+    if changed == 0 {
+        tracing::info!("No code was rewritten.");
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    Ok(if command.dry_run {
+        tracing::info!("Found {} rewrites in {} source files.", rewrites, changed);
+
+        ExitCode::FAILURE
+    } else {
+        tracing::info!("Applied {} rewrites in {} source files.", rewrites, changed);
+
+        ExitCode::SUCCESS
+    })
+}
+
+/// Creates a plan replacing every match with the rendered template.
+///
+/// Matches are reported outermost first, so a match starting before the end of the
+/// previously replaced one is nested within it, and is skipped.
+fn create_fix_plan(matches: &[Match], template: &Template) -> FixPlan {
+    let mut plan = FixPlan::new();
+    let mut last_end = None;
+
+    for result in matches {
+        if last_end.is_some_and(|end| result.span.start.offset < end) {
+            continue;
+        }
+
+        plan.replace(result.span.to_range(), template.render(result), SafetyClassification::Safe);
+        last_end = Some(result.span.end.offset);
+    }
+
+    plan
+}
+
+/// Applies the plan to the given source, unless the rewritten code fails to parse.
+fn rewrite_source(
+    interner: &ThreadedInterner,
+    manager: &SourceManager,
+    source: &SourceIdentifier,
+    plan: FixPlan,
+    dry_run: bool,
+) -> Result<bool, Error> {
+    let source = manager.load(source)?;
+    let rewritten = plan.execute(interner.lookup(&source.content)).get_fixed();
+
+    let (_, error) = parse(interner, Input::new(source.identifier, rewritten.as_bytes()));
+    if let Some(error) = error {
+        let source_name = interner.lookup(&source.identifier.0);
+
+        tracing::error!("Skipping rewrite for source '{}', the rewritten code is invalid: {}.", source_name, error);
+
+        return Ok(false);
+    }
+
+    utils::apply_changes(interner, manager, &source, rewritten, dry_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use mago_parser::parse_source;
+    use mago_source::SourceCategory;
+
+    fn rewrite(pattern: &str, replacement: &str, code: &str) -> (bool, String) {
+        let interner = ThreadedInterner::new();
+        let manager = SourceManager::new(interner.clone());
+        let source_id = manager.insert_content("test.php".to_string(), code.to_string(), SourceCategory::UserDefined);
+        let source = manager.load(&source_id).unwrap();
+
+        let (program, error) = parse_source(&interner, &source);
+        assert!(error.is_none(), "failed to parse `{}`", code);
+
+        let pattern = Pattern::new(&interner, pattern).unwrap();
+        let matches = pattern.find_all(code, &program);
+        let plan = create_fix_plan(&matches, &Template::new(replacement));
+
+        let changed = rewrite_source(&interner, &manager, &source_id, plan, false).unwrap();
+        let content = manager.load(&source_id).unwrap().content;
+
+        (changed, interner.lookup(&content).to_string())
+    }
+
+    #[test]
+    fn test_nested_matches_are_skipped() {
+        let (changed, code) = rewrite("foo($a)", "bar($a)", "<?php foo(foo(1)); foo(2);");
+
+        assert!(changed);
+        assert_eq!(code, "<?php bar(foo(1)); bar(2);");
+    }
+
+    #[test]
+    fn test_repeated_metavariable_with_different_case_is_not_rewritten() {
+        let (changed, code) = rewrite("$x === $x", "true", "<?php $a === $A; 'a' === 'A'; $b === $b;");
+
+        assert!(changed);
+        assert_eq!(code, "<?php $a === $A; 'a' === 'A'; true;");
+    }
+
+    #[test]
+    fn test_rewrite_producing_invalid_code_is_rejected() {
+        let (changed, code) = rewrite("foo($a)", "bar($a", "<?php foo(1);");
+
+        assert!(!changed);
+        assert_eq!(code, "<?php foo(1);");
+    }
+}
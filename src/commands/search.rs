@@ -0,0 +1,203 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use mago_interner::ThreadedInterner;
+use mago_parser::parse_source;
+use mago_pattern::Match;
+use mago_pattern::Pattern;
+use mago_reporting::reporter::Reporter;
+use mago_reporting::reporter::ReportingFormat;
+use mago_reporting::reporter::ReportingTarget;
+use mago_reporting::Annotation;
+use mago_reporting::Issue;
+use mago_reporting::IssueCollection;
+use mago_reporting::Level;
+use mago_source::SourceCategory;
+use mago_source::SourceIdentifier;
+use mago_source::SourceManager;
+
+use crate::config::Configuration;
+use crate::enum_variants;
+use crate::error::Error;
+use crate::source;
+use crate::utils::progress::create_progress_bar;
+use crate::utils::progress::remove_progress_bar;
+use crate::utils::progress::ProgressBarTheme;
+
+/// Represents the `search` command, which finds code matching a structural pattern.
+#[derive(Parser, Debug)]
+#[command(
+    name = "search",
+    about = "Search the source code for a structural pattern",
+    long_about = r#"
+The `search` command finds all the code matching a PHP pattern, e.g. `$x->setUp($$$args)`.
+
+Metavariables such as `$x` match any single node, and `$$$args` matches any number of
+arguments, statements, or elements. Unlike a regular expression, the pattern is matched
+against the syntax tree, so whitespace, comments, and the case of identifiers do not matter.
+"#
+)]
+pub struct SearchCommand {
+    /// The pattern to search for.
+    #[arg(help = "The pattern to search for, e.g. '$x->setUp($$$args)'")]
+    pub pattern: String,
+
+    /// Search specific files or directories, overriding the source configuration.
+    #[arg(help = "Search specific files or directories, overriding the source configuration")]
+    pub path: Vec<PathBuf>,
+
+    /// Specify where the results should be reported.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Specify where the results should be reported",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingTarget)
+    )]
+    pub reporting_target: ReportingTarget,
+
+    /// Choose the format for reporting matches.
+    #[arg(
+        long,
+        default_value_t,
+        help = "Choose the format for reporting matches",
+        ignore_case = true,
+        value_parser = enum_variants!(ReportingFormat)
+    )]
+    pub reporting_format: ReportingFormat,
+}
+
+/// Executes the search command with the provided configuration and options.
+///
+/// # Arguments
+///
+/// * `command` - The `SearchCommand` structure containing user-specified options.
+/// * `configuration` - The application configuration loaded from file or defaults.
+///
+/// # Returns
+///
+/// Exit code: `0` if at least one match was found, `1` otherwise.
+pub async fn execute(command: SearchCommand, configuration: Configuration) -> Result<ExitCode, Error> {
+    let interner = ThreadedInterner::new();
+    let pattern = Pattern::new(&interner, &command.pattern)?;
+
+    // Load sources
+    let source_manager = if !command.path.is_empty() {
+        source::from_paths(&interner, &configuration.source, command.path, false).await?
+    } else {
+        source::load(&interner, &configuration.source, false, false).await?
+    };
+
+    let results = search_all(&interner, &source_manager, &pattern).await?;
+
+    let mut issues = IssueCollection::new();
+    for (source, matches) in &results {
+        for result in matches {
+            issues.push(match_to_issue(&interner, source, result));
+        }
+    }
+
+    if issues.is_empty() {
+        tracing::info!("No matches found for `{}`.", pattern.get_code());
+
+        return Ok(ExitCode::FAILURE);
+    }
+
+    tracing::info!("Found {} matches in {} source files.", issues.len(), results.len());
+
+    let reporter = Reporter::new(interner, source_manager, command.reporting_target);
+    reporter.report(issues, command.reporting_format)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Finds all the matches of the given pattern in the user-defined sources.
+///
+/// Sources that fail to parse are skipped, as a partial syntax tree could yield misleading matches.
+///
+/// # Arguments
+///
+/// * `interner` - The interner to manage identifiers.
+/// * `source_manager` - The manager responsible for handling source files.
+/// * `pattern` - The pattern to search for.
+///
+/// # Returns
+///
+/// The matches of each source that contains at least one match.
+pub(super) async fn search_all(
+    interner: &ThreadedInterner,
+    source_manager: &SourceManager,
+    pattern: &Pattern,
+) -> Result<Vec<(SourceIdentifier, Vec<Match>)>, Error> {
+    let sources: Vec<_> = source_manager.source_ids_for_category(SourceCategory::UserDefined).collect();
+
+    let length = sources.len();
+    let progress_bar = create_progress_bar(length, "🔎  Searching", ProgressBarTheme::Magenta);
+    let mut handles = Vec::with_capacity(length);
+
+    for source in sources.into_iter() {
+        handles.push(tokio::spawn({
+            let interner = interner.clone();
+            let manager = source_manager.clone();
+            let pattern = pattern.clone();
+            let progress_bar = progress_bar.clone();
+
+            async move {
+                let source = manager.load(&source)?;
+                let (program, error) = parse_source(&interner, &source);
+
+                let matches = match error {
+                    Some(error) => {
+                        let source_name = interner.lookup(&source.identifier.0);
+
+                        tracing::error!("Skipping search for source '{}': {}.", source_name, error);
+
+                        vec![]
+                    }
+                    None => pattern.find_all(interner.lookup(&source.content), &program),
+                };
+
+                progress_bar.inc(1);
+
+                Result::<_, Error>::Ok((source.identifier, matches))
+            }
+        }));
+    }
+
+    let mut results = vec![];
+    for handle in handles {
+        let (source, matches) = handle.await??;
+        if !matches.is_empty() {
+            results.push((source, matches));
+        }
+    }
+
+    remove_progress_bar(progress_bar);
+
+    Ok(results)
+}
+
+fn match_to_issue(interner: &ThreadedInterner, source: &SourceIdentifier, result: &Match) -> Issue {
+    let mut issue = Issue::new(Level::Note, format!("Match found in `{}`.", interner.lookup(&source.0)))
+        .with_annotation(Annotation::primary(result.span));
+
+    let mut bindings = result.bindings.iter().collect::<Vec<_>>();
+    bindings.sort_by_key(|(name, _)| name.as_str());
+
+    for (name, binding) in bindings {
+        let name = if binding.is_sequence { format!("$$${}", name) } else { format!("${}", name) };
+
+        match binding.span {
+            Some(span) => {
+                issue = issue.with_annotation(Annotation::secondary(span).with_message(format!("`{}`", name)));
+            }
+            None => {
+                issue = issue.with_note(format!("`{}` is empty.", name));
+            }
+        }
+    }
+
+    issue
+}
@@ -3,7 +3,9 @@ use config::ConfigBuilder;
 use serde::Deserialize;
 use serde::Serialize;
 
+use mago_formatter::preset::FormatPreset;
 use mago_formatter::settings::*;
+use mago_php_version::PHPVersion;
 
 use crate::config::ConfigurationEntry;
 use crate::error::Error;
@@ -12,6 +14,14 @@ use crate::error::Error;
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct FormatterConfiguration {
+    /// The preset to use as the base for all other settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset: Option<FormatPreset>,
+
+    /// The maximum number of seconds to spend formatting a single file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+
     /// Specify the maximum line length that the printer will wrap on.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub print_width: Option<usize>,
@@ -32,6 +42,18 @@ pub struct FormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub single_quote: Option<bool>,
 
+    /// How to print strings that only interpolate simple expressions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub string_interpolation: Option<StringInterpolationStyle>,
+
+    /// Whether to normalize the escape sequences of strings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalize_escapes: Option<bool>,
+
+    /// Whether to convert heredocs without interpolation or escape sequences into nowdocs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heredoc_to_nowdoc: Option<bool>,
+
     /// Enable or disable trailing commas in multi-line syntactic structures.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trailing_comma: Option<bool>,
@@ -97,6 +119,14 @@ pub struct FormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_spacing: Option<usize>,
 
+    /// Add a space around the `|` separating the types of a catch clause.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub space_around_catch_union: Option<bool>,
+
+    /// Keep the comments ending the body of a switch case at the indentation of the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent_trailing_case_comments: Option<bool>,
+
     /// The minimum length of a method call chain that triggers line-breaking formatting.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method_chain_break_threshold: Option<usize>,
@@ -116,11 +146,76 @@ pub struct FormatterConfiguration {
     /// Whether to add a line before a binary operator or after if it is broken.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line_before_binary_operator: Option<bool>,
+
+    /// How to sort consecutive `use` statements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_uses: Option<UseSortOrder>,
+
+    /// Whether to group `use` statements by kind (class, function, const).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separate_use_types: Option<bool>,
+
+    /// How to print grouped `use` statements (preserve, split, or merge them).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_grouping: Option<UseGroupingStyle>,
+
+    /// Whether to remove duplicate `use` statements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_duplicate_uses: Option<bool>,
+
+    /// Normalize the blank lines between the members of class-like declarations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub separate_class_members: Option<bool>,
+
+    /// Sort the members of class-like declarations in canonical order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_class_members: Option<bool>,
+
+    /// Whether to re-wrap the descriptions of docblocks at the print width.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docblock_wrap_descriptions: Option<bool>,
+
+    /// Whether to align the columns of consecutive `@param` tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docblock_align_params: Option<bool>,
+
+    /// Whether to sort docblock tags in the canonical order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docblock_sort_tags: Option<bool>,
+
+    /// Whether to normalize the spacing of types in docblock tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub docblock_normalize_types: Option<bool>,
+
+    /// Whether to align the assignment operators of consecutive assignment statements.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_assignments: Option<bool>,
+
+    /// Whether to align the `=>` operators of multi-line associative arrays.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_array_arrows: Option<bool>,
+
+    /// Whether to align the `=>` operators of match arms.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_match_arms: Option<bool>,
+
+    /// Whether to align the values of consecutive class constants.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_class_constants: Option<bool>,
+
+    /// The maximum number of spaces that may be added to align an operator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alignment_max_padding: Option<usize>,
+
+    /// Format files mixing inline HTML with PHP as templates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_mode: Option<bool>,
 }
 
 impl FormatterConfiguration {
-    pub fn get_settings(&self) -> FormatSettings {
-        let default = FormatSettings::default();
+    /// Returns the format settings, for code targeting the given PHP version.
+    pub fn get_settings(&self, php_version: PHPVersion) -> FormatSettings {
+        let default = self.preset.unwrap_or_default().settings();
 
         FormatSettings {
             print_width: self.print_width.unwrap_or(default.print_width),
@@ -128,6 +223,9 @@ impl FormatterConfiguration {
             use_tabs: self.use_tabs.unwrap_or(default.use_tabs),
             end_of_line: self.end_of_line.unwrap_or(default.end_of_line),
             single_quote: self.single_quote.unwrap_or(default.single_quote),
+            string_interpolation: self.string_interpolation.unwrap_or(default.string_interpolation),
+            normalize_escapes: self.normalize_escapes.unwrap_or(default.normalize_escapes),
+            heredoc_to_nowdoc: self.heredoc_to_nowdoc.unwrap_or(default.heredoc_to_nowdoc),
             trailing_comma: self.trailing_comma.unwrap_or(default.trailing_comma),
             space_around_declare_equals: self
                 .space_around_declare_equals
@@ -150,6 +248,10 @@ impl FormatterConfiguration {
             static_before_visibility: self.static_before_visibility.unwrap_or(default.static_before_visibility),
             null_type_hint: self.null_type_hint.unwrap_or(default.null_type_hint),
             type_spacing: self.type_spacing.unwrap_or(default.type_spacing),
+            space_around_catch_union: self.space_around_catch_union.unwrap_or(default.space_around_catch_union),
+            indent_trailing_case_comments: self
+                .indent_trailing_case_comments
+                .unwrap_or(default.indent_trailing_case_comments),
             method_chain_break_threshold: self
                 .method_chain_break_threshold
                 .unwrap_or(default.method_chain_break_threshold),
@@ -163,6 +265,23 @@ impl FormatterConfiguration {
             line_before_binary_operator: self
                 .line_before_binary_operator
                 .unwrap_or(default.line_before_binary_operator),
+            sort_uses: self.sort_uses.unwrap_or(default.sort_uses),
+            separate_use_types: self.separate_use_types.unwrap_or(default.separate_use_types),
+            use_grouping: self.use_grouping.unwrap_or(default.use_grouping),
+            remove_duplicate_uses: self.remove_duplicate_uses.unwrap_or(default.remove_duplicate_uses),
+            separate_class_members: self.separate_class_members.unwrap_or(default.separate_class_members),
+            sort_class_members: self.sort_class_members.unwrap_or(default.sort_class_members),
+            docblock_wrap_descriptions: self.docblock_wrap_descriptions.unwrap_or(default.docblock_wrap_descriptions),
+            docblock_align_params: self.docblock_align_params.unwrap_or(default.docblock_align_params),
+            docblock_sort_tags: self.docblock_sort_tags.unwrap_or(default.docblock_sort_tags),
+            docblock_normalize_types: self.docblock_normalize_types.unwrap_or(default.docblock_normalize_types),
+            align_assignments: self.align_assignments.unwrap_or(default.align_assignments),
+            align_array_arrows: self.align_array_arrows.unwrap_or(default.align_array_arrows),
+            align_match_arms: self.align_match_arms.unwrap_or(default.align_match_arms),
+            align_class_constants: self.align_class_constants.unwrap_or(default.align_class_constants),
+            alignment_max_padding: self.alignment_max_padding.unwrap_or(default.alignment_max_padding),
+            template_mode: self.template_mode.unwrap_or(default.template_mode),
+            php_version,
         }
     }
 }
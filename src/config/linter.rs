@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use ahash::HashMap;
 use config::builder::BuilderState;
 use config::ConfigBuilder;
@@ -5,6 +7,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use toml::value::Value;
 
+use mago_taint::configuration::TaintConfiguration;
+
 use crate::config::ConfigurationEntry;
 use crate::error::Error;
 
@@ -27,6 +31,14 @@ pub enum LinterLevel {
 pub struct LinterConfiguration {
     pub default_plugins: Option<bool>,
     pub plugins: Vec<String>,
+    #[serde(default)]
+    pub external_plugins: Vec<PathBuf>,
+    #[serde(default)]
+    pub patterns: Vec<LinterPatternConfiguration>,
+    #[serde(default)]
+    pub pattern_files: Vec<PathBuf>,
+    #[serde(default)]
+    pub taint: TaintConfiguration,
     pub rules: Vec<LinterRuleConfiguration>,
 }
 
@@ -38,6 +50,26 @@ pub struct LinterRuleConfiguration {
     pub options: HashMap<String, Value>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinterPatternConfiguration {
+    pub name: String,
+    pub pattern: String,
+    pub message: String,
+    pub level: Option<LinterLevel>,
+    pub help: Option<String>,
+    pub rewrite: Option<String>,
+    pub extends: Option<String>,
+}
+
+/// The content of a file listed in `linter.pattern_files`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LinterPatternFile {
+    #[serde(default)]
+    pub patterns: Vec<LinterPatternConfiguration>,
+}
+
 impl ConfigurationEntry for LinterConfiguration {
     fn configure<St: BuilderState>(self, builder: ConfigBuilder<St>) -> Result<ConfigBuilder<St>, Error> {
         use ::config::Value;
@@ -46,6 +78,9 @@ impl ConfigurationEntry for LinterConfiguration {
         let builder = builder
             .set_default("linter.default_plugins", Value::new(None, ValueKind::Nil))?
             .set_default("linter.plugins", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.external_plugins", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.patterns", Value::new(None, ValueKind::Array(vec![])))?
+            .set_default("linter.pattern_files", Value::new(None, ValueKind::Array(vec![])))?
             .set_default("linter.rules", Value::new(None, ValueKind::Array(vec![])))?;
 
         Ok(builder)
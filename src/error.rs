@@ -1,4 +1,7 @@
+use mago_linter::external::ExternalPluginError;
+use mago_pattern::error::PatternError;
 use mago_php_version::PHPVersion;
+use mago_refactor::error::RefactorError;
 use mago_reporting::error::ReportingError;
 use mago_source::error::SourceError;
 
@@ -15,6 +18,13 @@ pub enum Error {
     Join(tokio::task::JoinError),
     Json(serde_json::Error),
     SelfUpdate(self_update::errors::Error),
+    LoadingExternalPlugin(ExternalPluginError),
+    ReadingPatternFile(std::path::PathBuf, std::io::Error),
+    MovingFile(std::path::PathBuf, std::io::Error),
+    WritingFile(std::path::PathBuf, std::io::Error),
+    WritingOutput(std::io::Error),
+    Pattern(PatternError),
+    Refactor(RefactorError),
     PHPVersionIsTooOld(PHPVersion, PHPVersion),
     PHPVersionIsTooNew(PHPVersion, PHPVersion),
 }
@@ -33,6 +43,15 @@ impl std::fmt::Display for Error {
             Self::Join(error) => write!(f, "Failed to join tasks: {}", error),
             Self::Json(error) => write!(f, "Failed to parse JSON: {}", error),
             Self::SelfUpdate(error) => write!(f, "Failed to self update: {}", error),
+            Self::LoadingExternalPlugin(error) => write!(f, "Failed to load external plugin: {}", error),
+            Self::ReadingPatternFile(path, error) => {
+                write!(f, "Failed to read pattern file `{}`: {}", path.display(), error)
+            }
+            Self::MovingFile(path, error) => write!(f, "Failed to move file to `{}`: {}", path.display(), error),
+            Self::WritingFile(path, error) => write!(f, "Failed to write file `{}`: {}", path.display(), error),
+            Self::WritingOutput(error) => write!(f, "Failed to write output: {}", error),
+            Self::Pattern(error) => write!(f, "Invalid pattern: {}", error),
+            Self::Refactor(error) => write!(f, "Failed to refactor: {}", error),
             Self::PHPVersionIsTooOld(minimum, actual) => {
                 write!(f, "PHP version {} is not supported, minimum supported version is {}", actual, minimum)
             }
@@ -57,6 +76,13 @@ impl std::error::Error for Error {
             Self::Join(error) => Some(error),
             Self::Json(error) => Some(error),
             Self::SelfUpdate(error) => Some(error),
+            Self::LoadingExternalPlugin(error) => Some(error),
+            Self::ReadingPatternFile(_, error) => Some(error),
+            Self::MovingFile(_, error) => Some(error),
+            Self::WritingFile(_, error) => Some(error),
+            Self::WritingOutput(error) => Some(error),
+            Self::Pattern(error) => Some(error),
+            Self::Refactor(error) => Some(error),
             _ => None,
         }
     }
@@ -115,3 +141,21 @@ impl From<self_update::errors::Error> for Error {
         Self::SelfUpdate(error)
     }
 }
+
+impl From<ExternalPluginError> for Error {
+    fn from(error: ExternalPluginError) -> Self {
+        Self::LoadingExternalPlugin(error)
+    }
+}
+
+impl From<PatternError> for Error {
+    fn from(error: PatternError) -> Self {
+        Self::Pattern(error)
+    }
+}
+
+impl From<RefactorError> for Error {
+    fn from(error: RefactorError) -> Self {
+        Self::Refactor(error)
+    }
+}
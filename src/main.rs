@@ -59,6 +59,10 @@ pub fn run() -> Result<ExitCode, Error> {
         MagoCommand::Lint(cmd) => runtime.block_on(commands::lint::execute(cmd, configuration)),
         MagoCommand::Fix(cmd) => runtime.block_on(commands::fix::execute(cmd, configuration)),
         MagoCommand::Format(cmd) => runtime.block_on(commands::format::execute(cmd, configuration)),
+        MagoCommand::Search(cmd) => runtime.block_on(commands::search::execute(cmd, configuration)),
+        MagoCommand::Rewrite(cmd) => runtime.block_on(commands::rewrite::execute(cmd, configuration)),
+        MagoCommand::Refactor(cmd) => runtime.block_on(commands::refactor::execute(cmd, configuration)),
+        MagoCommand::Metrics(cmd) => runtime.block_on(commands::metrics::execute(cmd, configuration)),
         MagoCommand::Ast(cmd) => runtime.block_on(commands::ast::execute(cmd)),
         MagoCommand::SelfUpdate(cmd) => commands::self_update::execute(cmd),
     }
@@ -164,8 +164,8 @@ fn add_file_to_manager(
         return;
     }
 
-    // Skip files that do not have an accepted extension.
-    if path.is_file() && !is_accepted_file(&path, extensions) {
+    // Skip directories, and files that do not have an accepted extension.
+    if !path.is_file() || !is_accepted_file(&path, extensions) {
         return;
     }
 